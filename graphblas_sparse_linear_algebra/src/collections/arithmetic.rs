@@ -0,0 +1,12 @@
+use crate::error::SparseLinearAlgebraError;
+
+/// The std::ops operators of the collections cannot return an error, so they panic instead.
+pub(crate) fn unwrap_or_panic<R>(
+    result: Result<R, SparseLinearAlgebraError>,
+    operation: &str,
+) -> R {
+    match result {
+        Ok(product) => product,
+        Err(error) => panic!("{} failed: {}", operation, error),
+    }
+}
@@ -1,3 +1,4 @@
+use crate::collections::{PendingWork, WaitMode};
 use crate::error::SparseLinearAlgebraError;
 use crate::index::ElementIndex;
 
@@ -14,4 +15,11 @@ use crate::index::ElementIndex;
 pub trait Collection {
     fn clear(&mut self) -> Result<(), SparseLinearAlgebraError>;
     fn number_of_stored_elements(&self) -> Result<ElementIndex, SparseLinearAlgebraError>;
+    /// Memory allocated by GraphBLAS for the collection, including pending work
+    fn memory_usage_in_bytes(&self) -> Result<usize, SparseLinearAlgebraError>;
+    /// Forces completion of deferred work in Mode::NonBlocking, see WaitMode
+    fn wait(&mut self, mode: WaitMode) -> Result<(), SparseLinearAlgebraError>;
+    /// Reads the deferred work without finishing it.
+    /// This validates the whole collection, which takes time proportional to its size.
+    fn pending_work(&self) -> Result<PendingWork, SparseLinearAlgebraError>;
 }
@@ -0,0 +1,207 @@
+use std::ffi::c_void;
+use std::mem::{align_of, size_of, ManuallyDrop};
+use std::ptr;
+
+use suitesparse_graphblas_sys::free;
+
+use crate::collections::sparse_matrix::{GetMatrixDimensions, Size};
+use crate::error::{GraphblasError, GraphblasErrorType, SparseLinearAlgebraError};
+use crate::graphblas_bindings::GrB_Index;
+use crate::index::{ElementCount, ElementIndex, IndexConversion};
+
+// Index buffers are handed to GraphBLAS as they are, without conversion.
+const _: () = assert!(size_of::<ElementIndex>() == size_of::<GrB_Index>());
+
+// Buffers handed over to GraphBLAS are released by GraphBLAS with free().
+// An empty Vec does not own an allocation, so it must allocate before it can be handed over.
+pub(crate) fn ensure_allocation<T>(buffer: &mut Vec<T>) {
+    if buffer.capacity() == 0 {
+        buffer.reserve(1);
+    }
+}
+
+pub(crate) fn allocated_size_in_bytes<T>(
+    buffer: &Vec<T>,
+) -> Result<GrB_Index, SparseLinearAlgebraError> {
+    (buffer.capacity() * size_of::<T>()).as_graphblas_index()
+}
+
+/// Owns a buffer allocated by GraphBLAS, and frees it when dropped before it is converted into a Vec.
+///
+/// Unpacked buffers are wrapped as soon as GraphBLAS returns them,
+/// such that none of them leaks when converting one of them fails.
+pub(crate) struct GraphblasBuffer<T> {
+    pointer: *mut T,
+    allocated_size_in_bytes: GrB_Index,
+}
+
+impl<T> GraphblasBuffer<T> {
+    /// # Safety
+    /// The pointer must be null, or point to a buffer of allocated_size_in_bytes bytes that was allocated by GraphBLAS,
+    /// and that is not owned by anything else.
+    pub(crate) unsafe fn new(pointer: *mut T, allocated_size_in_bytes: GrB_Index) -> Self {
+        Self {
+            pointer,
+            allocated_size_in_bytes,
+        }
+    }
+
+    /// Takes ownership of the buffer without copying if its allocation can be represented by a Vec<T>,
+    /// and copies its content into a new Vec otherwise.
+    ///
+    /// # Safety
+    /// The buffer must hold at least length initialized values of type T,
+    /// and GraphBLAS must use the same allocator as the Rust global allocator.
+    pub(crate) unsafe fn into_vec(
+        self,
+        length: ElementCount,
+    ) -> Result<Vec<T>, SparseLinearAlgebraError> {
+        if self.pointer.is_null() && length == 0 {
+            return Ok(Vec::new());
+        }
+        let allocated_size_in_bytes = self.check_layout(length)?;
+
+        // A Vec must deallocate with the layout it was allocated with,
+        // which requires the allocated size to be a non-zero multiple of the element size.
+        if size_of::<T>() != 0
+            && allocated_size_in_bytes != 0
+            && allocated_size_in_bytes % size_of::<T>() == 0
+        {
+            let buffer = ManuallyDrop::new(self);
+            return Ok(Vec::from_raw_parts(
+                buffer.pointer,
+                length,
+                allocated_size_in_bytes / size_of::<T>(),
+            ));
+        }
+
+        // The values are moved bitwise, the buffer is freed without dropping them
+        let mut values = Vec::with_capacity(length);
+        ptr::copy_nonoverlapping(self.pointer, values.as_mut_ptr(), length);
+        values.set_len(length);
+        Ok(values)
+    }
+
+    /// Converts a bitmap values buffer into a Vec of bitmap.len() values.
+    ///
+    /// GraphBLAS leaves the values of absent elements uninitialized,
+    /// they are set to T::default() before the buffer is converted.
+    ///
+    /// # Safety
+    /// The buffer must hold an initialized value for every element whose bitmap entry is not 0,
+    /// and GraphBLAS must use the same allocator as the Rust global allocator.
+    pub(crate) unsafe fn into_vec_with_bitmap(
+        self,
+        bitmap: &[i8],
+    ) -> Result<Vec<T>, SparseLinearAlgebraError>
+    where
+        T: Default,
+    {
+        if self.pointer.is_null() && bitmap.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.check_layout(bitmap.len())?;
+
+        for (index, is_stored) in bitmap.iter().enumerate() {
+            if *is_stored == 0 {
+                ptr::write(self.pointer.add(index), T::default());
+            }
+        }
+        self.into_vec(bitmap.len())
+    }
+
+    /// Returns the allocated size in bytes if the buffer can hold length values of type T.
+    fn check_layout(&self, length: ElementCount) -> Result<ElementCount, SparseLinearAlgebraError> {
+        if self.pointer.is_null() {
+            return Err(GraphblasError::new(
+                GraphblasErrorType::NullPointer,
+                format!(
+                    "GraphBLAS returned a null buffer where {} elements were expected",
+                    length
+                ),
+            )
+            .into());
+        }
+
+        let allocated_size_in_bytes =
+            ElementCount::from_graphblas_index(self.allocated_size_in_bytes)?;
+        let required_size_in_bytes = match length.checked_mul(size_of::<T>()) {
+            Some(required_size_in_bytes) => required_size_in_bytes,
+            None => {
+                return Err(GraphblasError::new(
+                    GraphblasErrorType::InvalidValue,
+                    format!("The size of a buffer of {} elements overflows", length),
+                )
+                .into())
+            }
+        };
+        if allocated_size_in_bytes < required_size_in_bytes {
+            return Err(GraphblasError::new(
+                GraphblasErrorType::InsufficientSpace,
+                format!(
+                    "GraphBLAS returned a buffer of {} bytes where {} elements of {} bytes were expected",
+                    allocated_size_in_bytes,
+                    length,
+                    size_of::<T>()
+                ),
+            )
+            .into());
+        }
+        if !(self.pointer as usize).is_multiple_of(align_of::<T>()) {
+            return Err(GraphblasError::new(
+                GraphblasErrorType::InvalidObject,
+                format!(
+                    "GraphBLAS returned a buffer that is not aligned to {} bytes",
+                    align_of::<T>()
+                ),
+            )
+            .into());
+        }
+        Ok(allocated_size_in_bytes)
+    }
+}
+
+impl<T> Drop for GraphblasBuffer<T> {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { free(self.pointer as *mut c_void) };
+        }
+    }
+}
+
+/// The number of elements of a dense matrix, which fails instead of overflowing.
+pub(crate) fn number_of_dense_elements(
+    size: &Size,
+) -> Result<ElementCount, SparseLinearAlgebraError> {
+    size.row_height()
+        .checked_mul(size.column_width())
+        .ok_or_else(|| {
+            GraphblasError::new(
+                GraphblasErrorType::InvalidValue,
+                format!(
+                    "The number of elements of a {} by {} matrix overflows",
+                    size.row_height(),
+                    size.column_width()
+                ),
+            )
+            .into()
+        })
+}
+
+pub(crate) fn check_buffer_length(
+    buffer_name: &str,
+    length: ElementCount,
+    required_length: ElementCount,
+) -> Result<(), SparseLinearAlgebraError> {
+    if length < required_length {
+        return Err(GraphblasError::new(
+            GraphblasErrorType::DimensionMismatch,
+            format!(
+                "Length of {} must be at least {}, found {}",
+                buffer_name, required_length, length
+            ),
+        )
+        .into());
+    }
+    Ok(())
+}
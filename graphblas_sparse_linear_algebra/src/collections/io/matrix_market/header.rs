@@ -0,0 +1,196 @@
+use crate::error::{LogicError, LogicErrorType, SparseLinearAlgebraError};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatrixMarketFormat {
+    /// Lists the coordinate and value of each stored element
+    Coordinate,
+    /// Lists the value of every element, column by column
+    Array,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatrixMarketField {
+    Real,
+    Integer,
+    /// Coordinates without values
+    Pattern,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatrixMarketSymmetry {
+    General,
+    /// Only the lower triangle is listed, A(i,j) = A(j,i)
+    Symmetric,
+    /// Only the strictly lower triangle is listed, A(i,j) = -A(j,i)
+    SkewSymmetric,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MatrixMarketHeader {
+    format: MatrixMarketFormat,
+    field: MatrixMarketField,
+    symmetry: MatrixMarketSymmetry,
+}
+
+const HEADER_BANNER: &str = "%%MatrixMarket";
+
+impl MatrixMarketHeader {
+    pub fn new(
+        format: MatrixMarketFormat,
+        field: MatrixMarketField,
+        symmetry: MatrixMarketSymmetry,
+    ) -> Self {
+        Self {
+            format,
+            field,
+            symmetry,
+        }
+    }
+
+    pub fn format(&self) -> MatrixMarketFormat {
+        self.format
+    }
+
+    pub fn field(&self) -> MatrixMarketField {
+        self.field
+    }
+
+    pub fn symmetry(&self) -> MatrixMarketSymmetry {
+        self.symmetry
+    }
+
+    pub fn parse(header_line: &str) -> Result<Self, SparseLinearAlgebraError> {
+        let tokens: Vec<String> = header_line
+            .split_whitespace()
+            .map(|token| token.to_lowercase())
+            .collect();
+
+        if tokens.len() != 5 || tokens[0] != HEADER_BANNER.to_lowercase() {
+            return Err(invalid_file_format_error(format!(
+                "Expected a header like \"{} matrix coordinate real general\", found \"{}\"",
+                HEADER_BANNER, header_line
+            )));
+        }
+
+        if tokens[1] != "matrix" {
+            return Err(invalid_file_format_error(format!(
+                "Unsupported Matrix Market object: {}",
+                tokens[1]
+            )));
+        }
+
+        let format = match tokens[2].as_str() {
+            "coordinate" => MatrixMarketFormat::Coordinate,
+            "array" => MatrixMarketFormat::Array,
+            _ => {
+                return Err(invalid_file_format_error(format!(
+                    "Unsupported Matrix Market format: {}",
+                    tokens[2]
+                )))
+            }
+        };
+
+        let field = match tokens[3].as_str() {
+            "real" => MatrixMarketField::Real,
+            "integer" => MatrixMarketField::Integer,
+            "pattern" => MatrixMarketField::Pattern,
+            _ => {
+                return Err(invalid_file_format_error(format!(
+                    "Unsupported Matrix Market field: {}",
+                    tokens[3]
+                )))
+            }
+        };
+
+        let symmetry = match tokens[4].as_str() {
+            "general" => MatrixMarketSymmetry::General,
+            "symmetric" => MatrixMarketSymmetry::Symmetric,
+            "skew-symmetric" => MatrixMarketSymmetry::SkewSymmetric,
+            _ => {
+                return Err(invalid_file_format_error(format!(
+                    "Unsupported Matrix Market symmetry: {}",
+                    tokens[4]
+                )))
+            }
+        };
+
+        if format == MatrixMarketFormat::Array && field == MatrixMarketField::Pattern {
+            return Err(invalid_file_format_error(String::from(
+                "The pattern field is not valid for the array format",
+            )));
+        }
+
+        Ok(Self::new(format, field, symmetry))
+    }
+
+    pub fn to_header_line(&self) -> String {
+        let format = match self.format {
+            MatrixMarketFormat::Coordinate => "coordinate",
+            MatrixMarketFormat::Array => "array",
+        };
+        let field = match self.field {
+            MatrixMarketField::Real => "real",
+            MatrixMarketField::Integer => "integer",
+            MatrixMarketField::Pattern => "pattern",
+        };
+        let symmetry = match self.symmetry {
+            MatrixMarketSymmetry::General => "general",
+            MatrixMarketSymmetry::Symmetric => "symmetric",
+            MatrixMarketSymmetry::SkewSymmetric => "skew-symmetric",
+        };
+        format!("{} matrix {} {} {}", HEADER_BANNER, format, field, symmetry)
+    }
+}
+
+pub(crate) fn invalid_file_format_error(explanation: String) -> SparseLinearAlgebraError {
+    LogicError::new(LogicErrorType::InvalidFileFormat, explanation, None).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_header() {
+        let header =
+            MatrixMarketHeader::parse("%%MatrixMarket matrix coordinate pattern symmetric")
+                .unwrap();
+        assert_eq!(header.format(), MatrixMarketFormat::Coordinate);
+        assert_eq!(header.field(), MatrixMarketField::Pattern);
+        assert_eq!(header.symmetry(), MatrixMarketSymmetry::Symmetric);
+    }
+
+    #[test]
+    fn parse_header_case_insensitively() {
+        let header =
+            MatrixMarketHeader::parse("%%MatrixMarket MATRIX Array Real Skew-Symmetric").unwrap();
+        assert_eq!(header.format(), MatrixMarketFormat::Array);
+        assert_eq!(header.symmetry(), MatrixMarketSymmetry::SkewSymmetric);
+    }
+
+    #[test]
+    fn reject_unsupported_header() {
+        assert!(
+            MatrixMarketHeader::parse("%%MatrixMarket matrix coordinate complex general").is_err()
+        );
+        assert!(MatrixMarketHeader::parse("%%MatrixMarket matrix array pattern general").is_err());
+        assert!(MatrixMarketHeader::parse("1 2 3").is_err());
+    }
+
+    #[test]
+    fn header_round_trip() {
+        let header = MatrixMarketHeader::new(
+            MatrixMarketFormat::Coordinate,
+            MatrixMarketField::Integer,
+            MatrixMarketSymmetry::General,
+        );
+        assert_eq!(
+            header.to_header_line(),
+            "%%MatrixMarket matrix coordinate integer general"
+        );
+        assert_eq!(
+            MatrixMarketHeader::parse(&header.to_header_line()).unwrap(),
+            header
+        );
+    }
+}
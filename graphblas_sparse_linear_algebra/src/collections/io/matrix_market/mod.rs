@@ -0,0 +1,11 @@
+//! Reading and writing of the Matrix Market exchange format, see https://math.nist.gov/MatrixMarket/formats.html
+
+mod header;
+mod read;
+mod value;
+mod write;
+
+pub use header::*;
+pub use read::*;
+pub use value::*;
+pub use write::*;
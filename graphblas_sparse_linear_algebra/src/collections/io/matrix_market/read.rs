@@ -0,0 +1,536 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, Lines};
+use std::path::Path;
+use std::str::SplitWhitespace;
+use std::sync::Arc;
+
+use crate::collections::sparse_matrix::operations::FromMatrixElementList;
+use crate::collections::sparse_matrix::{
+    ColumnIndex, GetMatrixDimensions, MatrixElement, MatrixElementList, RowIndex, Size,
+    SparseMatrix,
+};
+use crate::collections::sparse_vector::operations::FromVectorElementList;
+use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+use crate::context::Context;
+use crate::error::{GraphblasError, GraphblasErrorType, SparseLinearAlgebraError};
+use crate::index::ElementCount;
+use crate::operators::binary_operator::BinaryOperator;
+
+use super::{
+    invalid_file_format_error, MatrixMarketField, MatrixMarketFormat, MatrixMarketHeader,
+    MatrixMarketSymmetry, MatrixMarketValue,
+};
+
+// The number of entries in a file header is not trusted for allocating memory up front
+const MAXIMUM_NUMBER_OF_PREALLOCATED_ENTRIES: ElementCount = 1 << 20;
+
+/// Reads a collection in the Matrix Market exchange format.
+///
+/// Both the coordinate and array formats are supported, with the general, symmetric and skew-symmetric qualifiers.
+/// Symmetric and skew-symmetric files are expanded to all elements.
+/// Elements of a file with the pattern field get the value 1.
+pub trait FromMatrixMarket<T: MatrixMarketValue> {
+    fn from_matrix_market(
+        context: Arc<Context>,
+        reader: impl BufRead,
+        reduction_operator_for_duplicates: &impl BinaryOperator<T>,
+    ) -> Result<Self, SparseLinearAlgebraError>
+    where
+        Self: Sized;
+
+    fn from_matrix_market_file(
+        context: Arc<Context>,
+        path: impl AsRef<Path>,
+        reduction_operator_for_duplicates: &impl BinaryOperator<T>,
+    ) -> Result<Self, SparseLinearAlgebraError>
+    where
+        Self: Sized,
+    {
+        let file = File::open(path)?;
+        Self::from_matrix_market(
+            context,
+            BufReader::new(file),
+            reduction_operator_for_duplicates,
+        )
+    }
+}
+
+impl<T: MatrixMarketValue> FromMatrixMarket<T> for SparseMatrix<T>
+where
+    SparseMatrix<T>: FromMatrixElementList<T>,
+{
+    fn from_matrix_market(
+        context: Arc<Context>,
+        reader: impl BufRead,
+        reduction_operator_for_duplicates: &impl BinaryOperator<T>,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let (size, element_list) = read_matrix_market_elements::<T>(reader)?;
+        SparseMatrix::<T>::from_element_list(
+            context,
+            size,
+            element_list,
+            reduction_operator_for_duplicates,
+        )
+    }
+}
+
+/// The file must describe a matrix with a single column or a single row.
+impl<T: MatrixMarketValue> FromMatrixMarket<T> for SparseVector<T>
+where
+    SparseVector<T>: FromVectorElementList<T>,
+{
+    fn from_matrix_market(
+        context: Arc<Context>,
+        reader: impl BufRead,
+        reduction_operator_for_duplicates: &impl BinaryOperator<T>,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let (size, element_list) = read_matrix_market_elements::<T>(reader)?;
+
+        let (length, indices) = if size.column_width() == 1 {
+            (size.row_height(), element_list.row_indices_ref())
+        } else if size.row_height() == 1 {
+            (size.column_width(), element_list.column_indices_ref())
+        } else {
+            return Err(GraphblasError::new(
+                GraphblasErrorType::DimensionMismatch,
+                format!(
+                    "A vector requires a matrix with a single column or row, found size {:?}",
+                    size
+                ),
+            )
+            .into());
+        };
+
+        let vector_element_list =
+            VectorElementList::from_vectors(indices.to_vec(), element_list.values_ref().to_vec())?;
+        SparseVector::<T>::from_element_list(
+            context,
+            length,
+            vector_element_list,
+            reduction_operator_for_duplicates,
+        )
+    }
+}
+
+fn read_matrix_market_elements<T: MatrixMarketValue>(
+    reader: impl BufRead,
+) -> Result<(Size, MatrixElementList<T>), SparseLinearAlgebraError> {
+    let mut lines = reader.lines();
+
+    let header = match lines.next() {
+        Some(line) => MatrixMarketHeader::parse(&line?)?,
+        None => return Err(invalid_file_format_error(String::from("The file is empty"))),
+    };
+
+    let size_line = match next_data_line(&mut lines)? {
+        Some(line) => line,
+        None => {
+            return Err(invalid_file_format_error(String::from(
+                "The file does not specify a size",
+            )))
+        }
+    };
+    let mut size_tokens = size_line.split_whitespace();
+    let row_height: RowIndex = parse_count(size_tokens.next(), "number of rows")?;
+    let column_width: ColumnIndex = parse_count(size_tokens.next(), "number of columns")?;
+    let size = Size::new(row_height, column_width);
+
+    if header.symmetry() != MatrixMarketSymmetry::General && row_height != column_width {
+        return Err(invalid_file_format_error(format!(
+            "A {:?} matrix must be square, found size {:?}",
+            header.symmetry(),
+            size
+        )));
+    }
+
+    let element_list = match header.format() {
+        MatrixMarketFormat::Coordinate => {
+            let number_of_entries = parse_count(size_tokens.next(), "number of entries")?;
+            check_number_of_entries(&header, &size, number_of_entries)?;
+            read_coordinate_entries(&mut lines, &header, &size, number_of_entries)?
+        }
+        MatrixMarketFormat::Array => read_array_entries(&mut lines, &header, &size)?,
+    };
+
+    Ok((size, element_list))
+}
+
+// The number of entries in the header cannot exceed the number of elements that may be listed
+fn check_number_of_entries(
+    header: &MatrixMarketHeader,
+    size: &Size,
+    number_of_entries: ElementCount,
+) -> Result<(), SparseLinearAlgebraError> {
+    let maximum_number_of_entries = match header.symmetry() {
+        MatrixMarketSymmetry::General => size.row_height().checked_mul(size.column_width()),
+        MatrixMarketSymmetry::Symmetric => size
+            .row_height()
+            .checked_add(1)
+            .and_then(|row_height| row_height.checked_mul(size.row_height()))
+            .map(|number_of_elements| number_of_elements / 2),
+        MatrixMarketSymmetry::SkewSymmetric => size
+            .row_height()
+            .checked_mul(size.row_height().saturating_sub(1))
+            .map(|number_of_elements| number_of_elements / 2),
+    };
+
+    match maximum_number_of_entries {
+        Some(maximum_number_of_entries) if number_of_entries > maximum_number_of_entries => {
+            Err(invalid_file_format_error(format!(
+                "A {:?} matrix of size {:?} cannot list {} entries",
+                header.symmetry(),
+                size,
+                number_of_entries
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn read_coordinate_entries<T: MatrixMarketValue>(
+    lines: &mut Lines<impl BufRead>,
+    header: &MatrixMarketHeader,
+    size: &Size,
+    number_of_entries: ElementCount,
+) -> Result<MatrixElementList<T>, SparseLinearAlgebraError> {
+    let mut element_list = MatrixElementList::<T>::with_capacity(
+        number_of_entries.min(MAXIMUM_NUMBER_OF_PREALLOCATED_ENTRIES),
+    );
+
+    for _ in 0..number_of_entries {
+        let line = match next_data_line(lines)? {
+            Some(line) => line,
+            None => {
+                return Err(invalid_file_format_error(format!(
+                    "Expected {} entries, found {}",
+                    number_of_entries,
+                    element_list.length()
+                )))
+            }
+        };
+        let mut tokens = line.split_whitespace();
+
+        let row_index = parse_one_based_index(tokens.next(), size.row_height(), "row")?;
+        let column_index = parse_one_based_index(tokens.next(), size.column_width(), "column")?;
+        let value = match header.field() {
+            MatrixMarketField::Pattern => T::matrix_market_pattern_value(),
+            _ => parse_value(&mut tokens)?,
+        };
+
+        push_element_with_symmetry(
+            &mut element_list,
+            header.symmetry(),
+            row_index,
+            column_index,
+            value,
+        )?;
+    }
+
+    if next_data_line(lines)?.is_some() {
+        return Err(invalid_file_format_error(format!(
+            "Expected {} entries, found more",
+            number_of_entries
+        )));
+    }
+
+    Ok(element_list)
+}
+
+// Values are listed column by column.
+// For symmetric matrices only the lower triangle is listed, and for skew-symmetric matrices only the strictly lower triangle.
+fn read_array_entries<T: MatrixMarketValue>(
+    lines: &mut Lines<impl BufRead>,
+    header: &MatrixMarketHeader,
+    size: &Size,
+) -> Result<MatrixElementList<T>, SparseLinearAlgebraError> {
+    let mut element_list = MatrixElementList::<T>::new();
+
+    for column_index in 0..size.column_width() {
+        let first_row_index = match header.symmetry() {
+            MatrixMarketSymmetry::General => 0,
+            MatrixMarketSymmetry::Symmetric => column_index,
+            MatrixMarketSymmetry::SkewSymmetric => column_index + 1,
+        };
+
+        for row_index in first_row_index..size.row_height() {
+            let line = match next_data_line(lines)? {
+                Some(line) => line,
+                None => {
+                    return Err(invalid_file_format_error(String::from(
+                        "The file ends before all values of the array are listed",
+                    )))
+                }
+            };
+            let value: T = parse_value(&mut line.split_whitespace())?;
+
+            push_element_with_symmetry(
+                &mut element_list,
+                header.symmetry(),
+                row_index,
+                column_index,
+                value,
+            )?;
+        }
+    }
+
+    if next_data_line(lines)?.is_some() {
+        return Err(invalid_file_format_error(String::from(
+            "The file lists more values than the size of the array",
+        )));
+    }
+
+    Ok(element_list)
+}
+
+fn push_element_with_symmetry<T: MatrixMarketValue>(
+    element_list: &mut MatrixElementList<T>,
+    symmetry: MatrixMarketSymmetry,
+    row_index: RowIndex,
+    column_index: ColumnIndex,
+    value: T,
+) -> Result<(), SparseLinearAlgebraError> {
+    element_list.push_element(MatrixElement::from_triple(row_index, column_index, value));
+
+    if row_index != column_index {
+        match symmetry {
+            MatrixMarketSymmetry::General => {}
+            MatrixMarketSymmetry::Symmetric => element_list
+                .push_element(MatrixElement::from_triple(column_index, row_index, value)),
+            MatrixMarketSymmetry::SkewSymmetric => {
+                element_list.push_element(MatrixElement::from_triple(
+                    column_index,
+                    row_index,
+                    value.negate_matrix_market_value()?,
+                ))
+            }
+        }
+    } else if symmetry == MatrixMarketSymmetry::SkewSymmetric {
+        return Err(invalid_file_format_error(format!(
+            "A skew-symmetric matrix cannot list diagonal element ({}, {})",
+            row_index + 1,
+            column_index + 1
+        )));
+    }
+    Ok(())
+}
+
+// Skips comments and blank lines
+fn next_data_line(
+    lines: &mut Lines<impl BufRead>,
+) -> Result<Option<String>, SparseLinearAlgebraError> {
+    for line in lines {
+        let line = line?;
+        let trimmed_line = line.trim();
+        if !trimmed_line.is_empty() && !trimmed_line.starts_with('%') {
+            return Ok(Some(line));
+        }
+    }
+    Ok(None)
+}
+
+fn parse_count(token: Option<&str>, name: &str) -> Result<ElementCount, SparseLinearAlgebraError> {
+    match token.map(|token| token.parse::<ElementCount>()) {
+        Some(Ok(count)) => Ok(count),
+        _ => Err(invalid_file_format_error(format!(
+            "Expected the {}, found {:?}",
+            name, token
+        ))),
+    }
+}
+
+fn parse_one_based_index(
+    token: Option<&str>,
+    dimension: ElementCount,
+    name: &str,
+) -> Result<ElementCount, SparseLinearAlgebraError> {
+    match token.map(|token| token.parse::<ElementCount>()) {
+        Some(Ok(index)) if index >= 1 && index <= dimension => Ok(index - 1),
+        _ => Err(invalid_file_format_error(format!(
+            "Expected a {} index from 1 to {}, found {:?}",
+            name, dimension, token
+        ))),
+    }
+}
+
+fn parse_value<T: MatrixMarketValue>(
+    tokens: &mut SplitWhitespace,
+) -> Result<T, SparseLinearAlgebraError> {
+    match tokens.next() {
+        Some(token) => T::parse_matrix_market_value(token),
+        None => Err(invalid_file_format_error(String::from(
+            "Expected a value, found the end of the line",
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::collection::Collection;
+    use crate::collections::sparse_matrix::operations::GetSparseMatrixElementValue;
+    use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
+    use crate::collections::sparse_vector::operations::{
+        GetSparseVectorElementValue, GetSparseVectorLength,
+    };
+    use crate::operators::binary_operator::{First, Plus};
+
+    #[test]
+    fn read_general_coordinate_matrix() {
+        let context = Context::init_default().unwrap();
+        let file = "%%MatrixMarket matrix coordinate real general
+% a comment
+3 4 3
+
+1 1 1.5
+3 4 -2
+2 1 1e2
+";
+        let matrix =
+            SparseMatrix::<f64>::from_matrix_market(context, file.as_bytes(), &First::<f64>::new())
+                .unwrap();
+
+        assert_eq!(matrix.size().unwrap(), Size::new(3, 4));
+        assert_eq!(matrix.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(matrix.element_value(&0, &0).unwrap(), Some(1.5));
+        assert_eq!(matrix.element_value(&2, &3).unwrap(), Some(-2.0));
+        assert_eq!(matrix.element_value(&1, &0).unwrap(), Some(100.0));
+    }
+
+    #[test]
+    fn read_duplicates_with_reduction_operator() {
+        let context = Context::init_default().unwrap();
+        let file = "%%MatrixMarket matrix coordinate integer general
+2 2 3
+1 2 3
+1 2 4
+2 2 1
+";
+        let matrix =
+            SparseMatrix::<i32>::from_matrix_market(context, file.as_bytes(), &Plus::<i32>::new())
+                .unwrap();
+
+        assert_eq!(matrix.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(matrix.element_value(&0, &1).unwrap(), Some(7));
+    }
+
+    #[test]
+    fn read_symmetric_pattern_matrix() {
+        let context = Context::init_default().unwrap();
+        let file = "%%MatrixMarket matrix coordinate pattern symmetric
+3 3 3
+1 1
+3 1
+3 2
+";
+        let matrix =
+            SparseMatrix::<u8>::from_matrix_market(context, file.as_bytes(), &First::<u8>::new())
+                .unwrap();
+
+        assert_eq!(matrix.number_of_stored_elements().unwrap(), 5);
+        assert_eq!(matrix.element_value(&0, &0).unwrap(), Some(1));
+        assert_eq!(matrix.element_value(&2, &0).unwrap(), Some(1));
+        assert_eq!(matrix.element_value(&0, &2).unwrap(), Some(1));
+        assert_eq!(matrix.element_value(&1, &2).unwrap(), Some(1));
+        assert_eq!(matrix.element_value(&1, &1).unwrap(), None);
+    }
+
+    #[test]
+    fn read_skew_symmetric_array_matrix() {
+        let context = Context::init_default().unwrap();
+        let file = "%%MatrixMarket matrix array integer skew-symmetric
+3 3
+1
+2
+3
+";
+        let matrix =
+            SparseMatrix::<i64>::from_matrix_market(context, file.as_bytes(), &First::<i64>::new())
+                .unwrap();
+
+        assert_eq!(matrix.number_of_stored_elements().unwrap(), 6);
+        assert_eq!(matrix.element_value(&1, &0).unwrap(), Some(1));
+        assert_eq!(matrix.element_value(&0, &1).unwrap(), Some(-1));
+        assert_eq!(matrix.element_value(&2, &0).unwrap(), Some(2));
+        assert_eq!(matrix.element_value(&2, &1).unwrap(), Some(3));
+        assert_eq!(matrix.element_value(&1, &2).unwrap(), Some(-3));
+    }
+
+    #[test]
+    fn read_general_array_matrix() {
+        let context = Context::init_default().unwrap();
+        let file = "%%MatrixMarket matrix array real general
+2 2
+1
+2
+3
+4
+";
+        let matrix =
+            SparseMatrix::<f32>::from_matrix_market(context, file.as_bytes(), &First::<f32>::new())
+                .unwrap();
+
+        assert_eq!(matrix.number_of_stored_elements().unwrap(), 4);
+        assert_eq!(matrix.element_value(&1, &0).unwrap(), Some(2.0));
+        assert_eq!(matrix.element_value(&0, &1).unwrap(), Some(3.0));
+    }
+
+    #[test]
+    fn read_vector() {
+        let context = Context::init_default().unwrap();
+        let file = "%%MatrixMarket matrix coordinate integer general
+1 5 2
+1 2 7
+1 5 9
+";
+        let vector =
+            SparseVector::<u16>::from_matrix_market(context, file.as_bytes(), &First::<u16>::new())
+                .unwrap();
+
+        assert_eq!(vector.length().unwrap(), 5);
+        assert_eq!(vector.element_value(&1).unwrap(), Some(7));
+        assert_eq!(vector.element_value(&4).unwrap(), Some(9));
+    }
+
+    #[test]
+    fn read_vector_from_matrix() {
+        let context = Context::init_default().unwrap();
+        let file = "%%MatrixMarket matrix coordinate integer general
+2 2 1
+1 2 7
+";
+        let result =
+            SparseVector::<u16>::from_matrix_market(context, file.as_bytes(), &First::<u16>::new());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn read_invalid_files() {
+        let context = Context::init_default().unwrap();
+        let invalid_files = [
+            "",
+            "%%MatrixMarket matrix coordinate real general\n",
+            "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1.0\n",
+            "%%MatrixMarket matrix coordinate real general\n2 2 1\n1 3 1.0\n",
+            "%%MatrixMarket matrix coordinate real general\n2 2 1\n0 1 1.0\n",
+            "%%MatrixMarket matrix coordinate real general\n2 2 1\n1 1 1.0\n2 2 1.0\n",
+            "%%MatrixMarket matrix coordinate integer general\n2 2 1\n1 1 one\n",
+            "%%MatrixMarket matrix coordinate real symmetric\n2 3 0\n",
+            "%%MatrixMarket matrix coordinate real skew-symmetric\n2 2 1\n1 1 1.0\n",
+            "%%MatrixMarket matrix array real general\n2 1\n1.0\n",
+            "%%MatrixMarket matrix coordinate real general\n2 2 5\n",
+            "%%MatrixMarket matrix coordinate real symmetric\n2 2 4\n",
+            "%%MatrixMarket matrix coordinate real general\n2 2 18446744073709551615\n",
+        ];
+
+        for file in invalid_files {
+            let result = SparseMatrix::<f64>::from_matrix_market(
+                context.clone(),
+                file.as_bytes(),
+                &First::<f64>::new(),
+            );
+            assert!(result.is_err(), "{}", file);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::value_type::utilities_to_implement_traits_for_all_value_types::{
+    implement_macro_for_all_floating_point_value_types, implement_macro_for_all_integers,
+};
+use crate::value_type::ValueType;
+
+use super::{invalid_file_format_error, MatrixMarketField};
+
+pub trait MatrixMarketValue: ValueType + Copy + Default + PartialEq {
+    /// The field in which values of this type are written
+    fn matrix_market_field() -> MatrixMarketField;
+    /// The value of elements read from a file with the pattern field
+    fn matrix_market_pattern_value() -> Self;
+    fn parse_matrix_market_value(token: &str) -> Result<Self, SparseLinearAlgebraError>;
+    fn to_matrix_market_value(&self) -> String;
+    /// Used to mirror elements of a skew-symmetric matrix
+    fn negate_matrix_market_value(self) -> Result<Self, SparseLinearAlgebraError>;
+}
+
+macro_rules! implement_matrix_market_value_for_integer {
+    ($value_type:ty) => {
+        impl MatrixMarketValue for $value_type {
+            fn matrix_market_field() -> MatrixMarketField {
+                MatrixMarketField::Integer
+            }
+
+            fn matrix_market_pattern_value() -> Self {
+                1
+            }
+
+            fn parse_matrix_market_value(token: &str) -> Result<Self, SparseLinearAlgebraError> {
+                token.parse::<$value_type>().map_err(|_| {
+                    invalid_file_format_error(format!(
+                        "Cannot parse \"{}\" as {}",
+                        token,
+                        stringify!($value_type)
+                    ))
+                })
+            }
+
+            fn to_matrix_market_value(&self) -> String {
+                self.to_string()
+            }
+
+            fn negate_matrix_market_value(self) -> Result<Self, SparseLinearAlgebraError> {
+                self.checked_neg().ok_or_else(|| {
+                    invalid_file_format_error(format!(
+                        "Cannot negate {} as {}",
+                        self,
+                        stringify!($value_type)
+                    ))
+                })
+            }
+        }
+    };
+}
+implement_macro_for_all_integers!(implement_matrix_market_value_for_integer);
+
+macro_rules! implement_matrix_market_value_for_floating_point {
+    ($value_type:ty) => {
+        impl MatrixMarketValue for $value_type {
+            fn matrix_market_field() -> MatrixMarketField {
+                MatrixMarketField::Real
+            }
+
+            fn matrix_market_pattern_value() -> Self {
+                1.0
+            }
+
+            fn parse_matrix_market_value(token: &str) -> Result<Self, SparseLinearAlgebraError> {
+                token.parse::<$value_type>().map_err(|_| {
+                    invalid_file_format_error(format!(
+                        "Cannot parse \"{}\" as {}",
+                        token,
+                        stringify!($value_type)
+                    ))
+                })
+            }
+
+            fn to_matrix_market_value(&self) -> String {
+                self.to_string()
+            }
+
+            fn negate_matrix_market_value(self) -> Result<Self, SparseLinearAlgebraError> {
+                Ok(-self)
+            }
+        }
+    };
+}
+implement_macro_for_all_floating_point_value_types!(
+    implement_matrix_market_value_for_floating_point
+);
+
+/// Any non-zero value is read as true
+impl MatrixMarketValue for bool {
+    fn matrix_market_field() -> MatrixMarketField {
+        MatrixMarketField::Integer
+    }
+
+    fn matrix_market_pattern_value() -> Self {
+        true
+    }
+
+    fn parse_matrix_market_value(token: &str) -> Result<Self, SparseLinearAlgebraError> {
+        match token.parse::<f64>() {
+            Ok(value) => Ok(value != 0.0),
+            Err(_) => Err(invalid_file_format_error(format!(
+                "Cannot parse \"{}\" as bool",
+                token
+            ))),
+        }
+    }
+
+    fn to_matrix_market_value(&self) -> String {
+        String::from(if *self { "1" } else { "0" })
+    }
+
+    fn negate_matrix_market_value(self) -> Result<Self, SparseLinearAlgebraError> {
+        match self {
+            false => Ok(false),
+            true => Err(invalid_file_format_error(String::from(
+                "Cannot negate true as bool",
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_values() {
+        assert_eq!(i32::parse_matrix_market_value("-12").unwrap(), -12);
+        assert_eq!(f64::parse_matrix_market_value("1.5e3").unwrap(), 1500.0);
+        assert_eq!(f32::parse_matrix_market_value("7").unwrap(), 7.0);
+        assert!(!bool::parse_matrix_market_value("0").unwrap());
+        assert!(bool::parse_matrix_market_value("2.5").unwrap());
+        assert!(u8::parse_matrix_market_value("-1").is_err());
+        assert!(i64::parse_matrix_market_value("1.5").is_err());
+    }
+
+    #[test]
+    fn negate_values() {
+        assert_eq!(i8::negate_matrix_market_value(5).unwrap(), -5);
+        assert_eq!(u16::negate_matrix_market_value(0).unwrap(), 0);
+        assert!(u16::negate_matrix_market_value(3).is_err());
+        assert_eq!(f64::negate_matrix_market_value(2.5).unwrap(), -2.5);
+    }
+
+    #[test]
+    fn write_values() {
+        assert_eq!(0.1f64.to_matrix_market_value(), "0.1");
+        assert_eq!(true.to_matrix_market_value(), "1");
+        assert_eq!((-3isize).to_matrix_market_value(), "-3");
+    }
+}
@@ -0,0 +1,549 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::collections::sparse_matrix::operations::{
+    GetSparseMatrixElementList, GetSparseMatrixSize,
+};
+use crate::collections::sparse_matrix::{GetMatrixDimensions, SparseMatrix};
+use crate::collections::sparse_vector::operations::{
+    GetSparseVectorElementList, GetSparseVectorLength,
+};
+use crate::collections::sparse_vector::SparseVector;
+use crate::error::{GraphblasError, GraphblasErrorType, SparseLinearAlgebraError};
+use crate::index::{ElementCount, ElementIndex};
+
+use super::{
+    invalid_file_format_error, MatrixMarketField, MatrixMarketFormat, MatrixMarketHeader,
+    MatrixMarketSymmetry, MatrixMarketValue,
+};
+
+/// Writes a collection in the Matrix Market exchange format.
+///
+/// Integer and boolean values are written in the integer field, floating point values in the real field.
+/// The array format writes every element, using the default value for elements that are not stored.
+/// A vector is written as a matrix with a single column.
+pub trait ToMatrixMarket {
+    /// Writes with the general qualifier, and the field of the value type
+    fn to_matrix_market(
+        &self,
+        writer: impl Write,
+        format: MatrixMarketFormat,
+    ) -> Result<(), SparseLinearAlgebraError>;
+
+    /// The pattern field writes the coordinates of the stored elements without their values.
+    /// The symmetric and skew-symmetric qualifiers write only the lower triangle,
+    /// and fail if the collection does not have the symmetry.
+    fn to_matrix_market_with_header(
+        &self,
+        writer: impl Write,
+        header: &MatrixMarketHeader,
+    ) -> Result<(), SparseLinearAlgebraError>;
+
+    fn to_matrix_market_file(
+        &self,
+        path: impl AsRef<Path>,
+        format: MatrixMarketFormat,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let file = File::create(path)?;
+        self.to_matrix_market(file, format)
+    }
+
+    fn to_matrix_market_file_with_header(
+        &self,
+        path: impl AsRef<Path>,
+        header: &MatrixMarketHeader,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let file = File::create(path)?;
+        self.to_matrix_market_with_header(file, header)
+    }
+}
+
+impl<T: MatrixMarketValue> ToMatrixMarket for SparseMatrix<T>
+where
+    SparseMatrix<T>: GetSparseMatrixElementList<T>,
+{
+    fn to_matrix_market(
+        &self,
+        writer: impl Write,
+        format: MatrixMarketFormat,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        self.to_matrix_market_with_header(
+            writer,
+            &MatrixMarketHeader::new(
+                format,
+                T::matrix_market_field(),
+                MatrixMarketSymmetry::General,
+            ),
+        )
+    }
+
+    fn to_matrix_market_with_header(
+        &self,
+        writer: impl Write,
+        header: &MatrixMarketHeader,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let size = self.size()?;
+        let element_list = self.element_list()?;
+        write_matrix_market_elements(
+            writer,
+            header,
+            size.row_height(),
+            size.column_width(),
+            element_list.row_indices_ref(),
+            element_list.column_indices_ref(),
+            element_list.values_ref(),
+        )
+    }
+}
+
+impl<T: MatrixMarketValue> ToMatrixMarket for SparseVector<T>
+where
+    SparseVector<T>: GetSparseVectorElementList<T>,
+{
+    fn to_matrix_market(
+        &self,
+        writer: impl Write,
+        format: MatrixMarketFormat,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        self.to_matrix_market_with_header(
+            writer,
+            &MatrixMarketHeader::new(
+                format,
+                T::matrix_market_field(),
+                MatrixMarketSymmetry::General,
+            ),
+        )
+    }
+
+    fn to_matrix_market_with_header(
+        &self,
+        writer: impl Write,
+        header: &MatrixMarketHeader,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let element_list = self.element_list()?;
+        let column_indices = vec![0; element_list.length()];
+        write_matrix_market_elements(
+            writer,
+            header,
+            self.length()?,
+            1,
+            element_list.indices_ref(),
+            &column_indices,
+            element_list.values_ref(),
+        )
+    }
+}
+
+fn write_matrix_market_elements<T: MatrixMarketValue>(
+    writer: impl Write,
+    header: &MatrixMarketHeader,
+    row_height: ElementCount,
+    column_width: ElementCount,
+    row_indices: &[ElementIndex],
+    column_indices: &[ElementIndex],
+    values: &[T],
+) -> Result<(), SparseLinearAlgebraError> {
+    check_header::<T>(header, row_height, column_width)?;
+    let elements = elements_to_list(header.symmetry(), row_indices, column_indices, values)?;
+
+    let mut writer = BufWriter::new(writer);
+    writeln!(writer, "{}", header.to_header_line())?;
+
+    match header.format() {
+        MatrixMarketFormat::Coordinate => {
+            writeln!(writer, "{} {} {}", row_height, column_width, elements.len())?;
+            for (row_index, column_index, value) in elements {
+                match header.field() {
+                    MatrixMarketField::Pattern => {
+                        writeln!(writer, "{} {}", row_index + 1, column_index + 1)?
+                    }
+                    _ => writeln!(
+                        writer,
+                        "{} {} {}",
+                        row_index + 1,
+                        column_index + 1,
+                        value.to_matrix_market_value()
+                    )?,
+                }
+            }
+        }
+        MatrixMarketFormat::Array => {
+            writeln!(writer, "{} {}", row_height, column_width)?;
+            // Values are written column by column
+            let number_of_elements = row_height.checked_mul(column_width).ok_or_else(|| {
+                GraphblasError::new(
+                    GraphblasErrorType::InvalidValue,
+                    format!(
+                        "A matrix of size {} by {} is too large for the array format",
+                        row_height, column_width
+                    ),
+                )
+            })?;
+            let mut dense_values = vec![T::default(); number_of_elements];
+            for (row_index, column_index, value) in elements {
+                dense_values[column_index * row_height + row_index] = value;
+            }
+            for column_index in 0..column_width {
+                // Symmetric arrays list the lower triangle, skew-symmetric arrays the strictly lower triangle
+                let first_row_index = match header.symmetry() {
+                    MatrixMarketSymmetry::General => 0,
+                    MatrixMarketSymmetry::Symmetric => column_index,
+                    MatrixMarketSymmetry::SkewSymmetric => column_index + 1,
+                };
+                for row_index in first_row_index..row_height {
+                    writeln!(
+                        writer,
+                        "{}",
+                        dense_values[column_index * row_height + row_index]
+                            .to_matrix_market_value()
+                    )?;
+                }
+            }
+        }
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+fn check_header<T: MatrixMarketValue>(
+    header: &MatrixMarketHeader,
+    row_height: ElementCount,
+    column_width: ElementCount,
+) -> Result<(), SparseLinearAlgebraError> {
+    if header.field() != MatrixMarketField::Pattern && header.field() != T::matrix_market_field() {
+        return Err(GraphblasError::new(
+            GraphblasErrorType::DomainMismatch,
+            format!(
+                "Values of this type are written in the {:?} field, not in the {:?} field",
+                T::matrix_market_field(),
+                header.field()
+            ),
+        )
+        .into());
+    }
+    if header.format() == MatrixMarketFormat::Array && header.field() == MatrixMarketField::Pattern
+    {
+        return Err(invalid_file_format_error(String::from(
+            "The pattern field is not valid for the array format",
+        )));
+    }
+    if header.symmetry() != MatrixMarketSymmetry::General && row_height != column_width {
+        return Err(GraphblasError::new(
+            GraphblasErrorType::DimensionMismatch,
+            format!(
+                "A {:?} matrix must be square, found size {} by {}",
+                header.symmetry(),
+                row_height,
+                column_width
+            ),
+        )
+        .into());
+    }
+    Ok(())
+}
+
+// Symmetric and skew-symmetric matrices only list the elements in their lower triangle
+fn elements_to_list<T: MatrixMarketValue>(
+    symmetry: MatrixMarketSymmetry,
+    row_indices: &[ElementIndex],
+    column_indices: &[ElementIndex],
+    values: &[T],
+) -> Result<Vec<(ElementIndex, ElementIndex, T)>, SparseLinearAlgebraError> {
+    let elements = row_indices
+        .iter()
+        .zip(column_indices)
+        .zip(values)
+        .map(|((row_index, column_index), value)| (*row_index, *column_index, *value));
+
+    if symmetry == MatrixMarketSymmetry::General {
+        return Ok(elements.collect());
+    }
+
+    let values_by_coordinate: HashMap<(ElementIndex, ElementIndex), T> = elements
+        .clone()
+        .map(|(row_index, column_index, value)| ((row_index, column_index), value))
+        .collect();
+
+    let mut lower_triangle = Vec::new();
+    for (row_index, column_index, value) in elements {
+        if row_index == column_index && symmetry == MatrixMarketSymmetry::SkewSymmetric {
+            return Err(not_symmetric_error(symmetry, row_index, column_index));
+        }
+
+        let mirrored_value = match symmetry {
+            MatrixMarketSymmetry::SkewSymmetric => value.negate_matrix_market_value()?,
+            _ => value,
+        };
+        if values_by_coordinate.get(&(column_index, row_index)) != Some(&mirrored_value) {
+            return Err(not_symmetric_error(symmetry, row_index, column_index));
+        }
+
+        if row_index >= column_index {
+            lower_triangle.push((row_index, column_index, value));
+        }
+    }
+    Ok(lower_triangle)
+}
+
+fn not_symmetric_error(
+    symmetry: MatrixMarketSymmetry,
+    row_index: ElementIndex,
+    column_index: ElementIndex,
+) -> SparseLinearAlgebraError {
+    GraphblasError::new(
+        GraphblasErrorType::InvalidValue,
+        format!(
+            "The matrix is not {:?}, element ({}, {}) is not mirrored",
+            symmetry, row_index, column_index
+        ),
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::io::matrix_market::FromMatrixMarket;
+    use crate::collections::sparse_matrix::operations::FromMatrixElementList;
+    use crate::collections::sparse_matrix::{MatrixElementList, Size};
+    use crate::collections::sparse_vector::operations::FromVectorElementList;
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::context::{Context, GetContext};
+    use crate::operators::binary_operator::First;
+
+    fn test_matrix() -> SparseMatrix<f64> {
+        let context = Context::init_default().unwrap();
+        let element_list = MatrixElementList::<f64>::from_element_vector(vec![
+            (0, 0, 1.5).into(),
+            (2, 1, -2.0).into(),
+            (1, 3, 0.25).into(),
+        ]);
+
+        SparseMatrix::<f64>::from_element_list(
+            context,
+            Size::new(3, 4),
+            element_list,
+            &First::<f64>::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn write_coordinate_matrix() {
+        let matrix = test_matrix();
+
+        let mut output = Vec::new();
+        matrix
+            .to_matrix_market(&mut output, MatrixMarketFormat::Coordinate)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "%%MatrixMarket matrix coordinate real general
+3 4 3
+1 1 1.5
+2 4 0.25
+3 2 -2
+"
+        );
+    }
+
+    #[test]
+    fn write_array_matrix() {
+        let context = Context::init_default().unwrap();
+        let element_list = MatrixElementList::<bool>::from_element_vector(vec![
+            (0, 1, true).into(),
+            (1, 0, false).into(),
+        ]);
+        let matrix = SparseMatrix::<bool>::from_element_list(
+            context,
+            Size::new(2, 2),
+            element_list,
+            &First::<bool>::new(),
+        )
+        .unwrap();
+
+        let mut output = Vec::new();
+        matrix
+            .to_matrix_market(&mut output, MatrixMarketFormat::Array)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "%%MatrixMarket matrix array integer general
+2 2
+0
+0
+1
+0
+"
+        );
+    }
+
+    #[test]
+    fn matrix_round_trip() {
+        let matrix = test_matrix();
+
+        for format in [MatrixMarketFormat::Coordinate, MatrixMarketFormat::Array] {
+            let mut output = Vec::new();
+            matrix.to_matrix_market(&mut output, format).unwrap();
+
+            let read_matrix = SparseMatrix::<f64>::from_matrix_market(
+                matrix.context(),
+                output.as_slice(),
+                &First::<f64>::new(),
+            )
+            .unwrap();
+
+            assert_eq!(read_matrix.size().unwrap(), matrix.size().unwrap());
+            match format {
+                MatrixMarketFormat::Coordinate => {
+                    assert_eq!(
+                        read_matrix.element_list().unwrap(),
+                        matrix.element_list().unwrap()
+                    );
+                }
+                // The array format also lists the elements that are not stored
+                MatrixMarketFormat::Array => {
+                    assert_eq!(read_matrix.element_list().unwrap().length(), 12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn vector_round_trip() {
+        let context = Context::init_default().unwrap();
+        let element_list =
+            VectorElementList::<i32>::from_element_vector(vec![(1, -4).into(), (3, 8).into()]);
+        let vector =
+            SparseVector::<i32>::from_element_list(context.clone(), 5, element_list, &First::new())
+                .unwrap();
+
+        let mut output = Vec::new();
+        vector
+            .to_matrix_market(&mut output, MatrixMarketFormat::Coordinate)
+            .unwrap();
+
+        let read_vector = SparseVector::<i32>::from_matrix_market(
+            context,
+            output.as_slice(),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+
+        assert_eq!(read_vector.length().unwrap(), 5);
+        assert_eq!(
+            read_vector.element_list().unwrap(),
+            vector.element_list().unwrap()
+        );
+    }
+
+    #[test]
+    fn symmetric_pattern_round_trip() {
+        let context = Context::init_default().unwrap();
+        let file = "%%MatrixMarket matrix coordinate pattern symmetric
+3 3 3
+1 1
+3 1
+3 2
+";
+        let matrix =
+            SparseMatrix::<u8>::from_matrix_market(context, file.as_bytes(), &First::<u8>::new())
+                .unwrap();
+
+        let mut output = Vec::new();
+        matrix
+            .to_matrix_market_with_header(
+                &mut output,
+                &MatrixMarketHeader::new(
+                    MatrixMarketFormat::Coordinate,
+                    MatrixMarketField::Pattern,
+                    MatrixMarketSymmetry::Symmetric,
+                ),
+            )
+            .unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), file);
+    }
+
+    #[test]
+    fn skew_symmetric_array_round_trip() {
+        let context = Context::init_default().unwrap();
+        let file = "%%MatrixMarket matrix array integer skew-symmetric
+3 3
+1
+2
+3
+";
+        let matrix =
+            SparseMatrix::<i64>::from_matrix_market(context, file.as_bytes(), &First::<i64>::new())
+                .unwrap();
+
+        let mut output = Vec::new();
+        matrix
+            .to_matrix_market_with_header(
+                &mut output,
+                &MatrixMarketHeader::new(
+                    MatrixMarketFormat::Array,
+                    MatrixMarketField::Integer,
+                    MatrixMarketSymmetry::SkewSymmetric,
+                ),
+            )
+            .unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), file);
+    }
+
+    #[test]
+    fn write_matrix_without_symmetry() {
+        let context = Context::init_default().unwrap();
+        let element_list = MatrixElementList::<f64>::from_element_vector(vec![
+            (0, 1, 1.0).into(),
+            (1, 0, 2.0).into(),
+        ]);
+        let matrix = SparseMatrix::<f64>::from_element_list(
+            context,
+            Size::new(2, 2),
+            element_list,
+            &First::<f64>::new(),
+        )
+        .unwrap();
+        let header = MatrixMarketHeader::new(
+            MatrixMarketFormat::Coordinate,
+            MatrixMarketField::Real,
+            MatrixMarketSymmetry::Symmetric,
+        );
+
+        let mut output = Vec::new();
+        assert!(matrix
+            .to_matrix_market_with_header(&mut output, &header)
+            .is_err());
+    }
+
+    #[test]
+    fn write_and_read_file() {
+        let matrix = test_matrix();
+        let path = std::env::temp_dir().join("graphblas_sparse_linear_algebra_write_and_read.mtx");
+
+        matrix
+            .to_matrix_market_file(&path, MatrixMarketFormat::Coordinate)
+            .unwrap();
+        let read_matrix = SparseMatrix::<f64>::from_matrix_market_file(
+            matrix.context(),
+            &path,
+            &First::<f64>::new(),
+        )
+        .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            read_matrix.element_list().unwrap(),
+            matrix.element_list().unwrap()
+        );
+    }
+}
@@ -0,0 +1 @@
+pub mod matrix_market;
@@ -1,10 +1,15 @@
+mod arithmetic;
 mod collection;
 mod deserializer;
+mod graphblas_buffer;
 mod serializer;
+mod wait;
 
+pub mod io;
 pub mod sparse_matrix;
 pub mod sparse_scalar;
 pub mod sparse_vector;
 
 pub use collection::Collection;
 pub use serializer::*;
+pub use wait::*;
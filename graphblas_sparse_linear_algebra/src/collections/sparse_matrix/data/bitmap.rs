@@ -0,0 +1,67 @@
+use crate::context::MatrixStorageFormat;
+use crate::index::ElementCount;
+use crate::value_type::ValueType;
+
+/// Bitmap data, with a dense array of values and a dense array of presence flags.
+///
+/// Both arrays hold row_height * column_width entries, laid out row by row (ByRow)
+/// or column by column (ByColumn). An element is stored if its bitmap entry is 1,
+/// and absent if it is 0.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BitmapData<T: ValueType> {
+    storage_format: MatrixStorageFormat,
+    bitmap: Vec<i8>,
+    values: Vec<T>,
+    number_of_stored_elements: ElementCount,
+}
+
+impl<T: ValueType> BitmapData<T> {
+    pub fn new(storage_format: MatrixStorageFormat, bitmap: Vec<i8>, values: Vec<T>) -> Self {
+        let number_of_stored_elements = bitmap.iter().filter(|is_stored| **is_stored != 0).count();
+        Self {
+            storage_format,
+            bitmap,
+            values,
+            number_of_stored_elements,
+        }
+    }
+
+    pub(crate) fn from_parts(
+        storage_format: MatrixStorageFormat,
+        bitmap: Vec<i8>,
+        values: Vec<T>,
+        number_of_stored_elements: ElementCount,
+    ) -> Self {
+        Self {
+            storage_format,
+            bitmap,
+            values,
+            number_of_stored_elements,
+        }
+    }
+
+    pub fn storage_format(&self) -> MatrixStorageFormat {
+        self.storage_format
+    }
+
+    pub fn bitmap_ref(&self) -> &[i8] {
+        self.bitmap.as_slice()
+    }
+
+    pub fn values_ref(&self) -> &[T] {
+        self.values.as_slice()
+    }
+
+    pub fn number_of_stored_elements(&self) -> ElementCount {
+        self.number_of_stored_elements
+    }
+
+    pub fn into_parts(self) -> (MatrixStorageFormat, Vec<i8>, Vec<T>, ElementCount) {
+        (
+            self.storage_format,
+            self.bitmap,
+            self.values,
+            self.number_of_stored_elements,
+        )
+    }
+}
@@ -0,0 +1,48 @@
+use crate::collections::sparse_matrix::RowIndex;
+use crate::index::{ElementCount, ElementIndex};
+use crate::value_type::ValueType;
+
+/// Compressed Sparse Column (CSC) data.
+///
+/// The elements of column i are stored at positions column_offsets\[i\]..column_offsets\[i + 1\]
+/// of row_indices and values. Row indices must be unique and sorted within each column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CscData<T: ValueType> {
+    column_offsets: Vec<ElementIndex>,
+    row_indices: Vec<RowIndex>,
+    values: Vec<T>,
+}
+
+impl<T: ValueType> CscData<T> {
+    pub fn new(
+        column_offsets: Vec<ElementIndex>,
+        row_indices: Vec<RowIndex>,
+        values: Vec<T>,
+    ) -> Self {
+        Self {
+            column_offsets,
+            row_indices,
+            values,
+        }
+    }
+
+    pub fn column_offsets_ref(&self) -> &[ElementIndex] {
+        self.column_offsets.as_slice()
+    }
+
+    pub fn row_indices_ref(&self) -> &[RowIndex] {
+        self.row_indices.as_slice()
+    }
+
+    pub fn values_ref(&self) -> &[T] {
+        self.values.as_slice()
+    }
+
+    pub fn number_of_stored_elements(&self) -> ElementCount {
+        self.column_offsets.last().copied().unwrap_or(0)
+    }
+
+    pub fn into_parts(self) -> (Vec<ElementIndex>, Vec<RowIndex>, Vec<T>) {
+        (self.column_offsets, self.row_indices, self.values)
+    }
+}
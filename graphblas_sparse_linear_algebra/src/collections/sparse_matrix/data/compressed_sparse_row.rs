@@ -0,0 +1,48 @@
+use crate::collections::sparse_matrix::ColumnIndex;
+use crate::index::{ElementCount, ElementIndex};
+use crate::value_type::ValueType;
+
+/// Compressed Sparse Row (CSR) data.
+///
+/// The elements of row i are stored at positions row_offsets\[i\]..row_offsets\[i + 1\]
+/// of column_indices and values. Column indices must be unique and sorted within each row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsrData<T: ValueType> {
+    row_offsets: Vec<ElementIndex>,
+    column_indices: Vec<ColumnIndex>,
+    values: Vec<T>,
+}
+
+impl<T: ValueType> CsrData<T> {
+    pub fn new(
+        row_offsets: Vec<ElementIndex>,
+        column_indices: Vec<ColumnIndex>,
+        values: Vec<T>,
+    ) -> Self {
+        Self {
+            row_offsets,
+            column_indices,
+            values,
+        }
+    }
+
+    pub fn row_offsets_ref(&self) -> &[ElementIndex] {
+        self.row_offsets.as_slice()
+    }
+
+    pub fn column_indices_ref(&self) -> &[ColumnIndex] {
+        self.column_indices.as_slice()
+    }
+
+    pub fn values_ref(&self) -> &[T] {
+        self.values.as_slice()
+    }
+
+    pub fn number_of_stored_elements(&self) -> ElementCount {
+        self.row_offsets.last().copied().unwrap_or(0)
+    }
+
+    pub fn into_parts(self) -> (Vec<ElementIndex>, Vec<ColumnIndex>, Vec<T>) {
+        (self.row_offsets, self.column_indices, self.values)
+    }
+}
@@ -0,0 +1,48 @@
+use crate::collections::sparse_matrix::{ColumnIndex, RowIndex};
+use crate::index::ElementCount;
+use crate::value_type::ValueType;
+
+/// Coordinate list (COO) data.
+///
+/// Element i is stored at row_indices\[i\], column_indices\[i\] with value values\[i\].
+/// Elements must be sorted by row index, and by column index within each row, without duplicates.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CooData<T: ValueType> {
+    row_indices: Vec<RowIndex>,
+    column_indices: Vec<ColumnIndex>,
+    values: Vec<T>,
+}
+
+impl<T: ValueType> CooData<T> {
+    pub fn new(
+        row_indices: Vec<RowIndex>,
+        column_indices: Vec<ColumnIndex>,
+        values: Vec<T>,
+    ) -> Self {
+        Self {
+            row_indices,
+            column_indices,
+            values,
+        }
+    }
+
+    pub fn row_indices_ref(&self) -> &[RowIndex] {
+        self.row_indices.as_slice()
+    }
+
+    pub fn column_indices_ref(&self) -> &[ColumnIndex] {
+        self.column_indices.as_slice()
+    }
+
+    pub fn values_ref(&self) -> &[T] {
+        self.values.as_slice()
+    }
+
+    pub fn number_of_stored_elements(&self) -> ElementCount {
+        self.row_indices.len()
+    }
+
+    pub fn into_parts(self) -> (Vec<RowIndex>, Vec<ColumnIndex>, Vec<T>) {
+        (self.row_indices, self.column_indices, self.values)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::context::MatrixStorageFormat;
+use crate::value_type::ValueType;
+
+/// Full data, with a value for every element of the matrix.
+///
+/// values holds row_height * column_width entries, laid out row by row (ByRow)
+/// or column by column (ByColumn).
+#[derive(Clone, Debug, PartialEq)]
+pub struct FullData<T: ValueType> {
+    storage_format: MatrixStorageFormat,
+    values: Vec<T>,
+}
+
+impl<T: ValueType> FullData<T> {
+    pub fn new(storage_format: MatrixStorageFormat, values: Vec<T>) -> Self {
+        Self {
+            storage_format,
+            values,
+        }
+    }
+
+    pub fn storage_format(&self) -> MatrixStorageFormat {
+        self.storage_format
+    }
+
+    pub fn values_ref(&self) -> &[T] {
+        self.values.as_slice()
+    }
+
+    pub fn into_parts(self) -> (MatrixStorageFormat, Vec<T>) {
+        (self.storage_format, self.values)
+    }
+}
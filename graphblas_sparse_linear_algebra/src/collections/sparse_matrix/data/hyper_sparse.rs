@@ -0,0 +1,78 @@
+use crate::context::MatrixStorageFormat;
+use crate::index::{ElementCount, ElementIndex};
+use crate::value_type::ValueType;
+
+/// Hypersparse data, storing only the non-empty rows (ByRow) or columns (ByColumn).
+///
+/// vector_indices lists the indices of the non-empty rows or columns in ascending order.
+/// The elements of the k-th non-empty row or column are stored at positions
+/// offsets\[k\]..offsets\[k + 1\] of indices and values.
+/// Indices must be unique and sorted within each row or column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HyperSparseData<T: ValueType> {
+    storage_format: MatrixStorageFormat,
+    offsets: Vec<ElementIndex>,
+    vector_indices: Vec<ElementIndex>,
+    indices: Vec<ElementIndex>,
+    values: Vec<T>,
+}
+
+impl<T: ValueType> HyperSparseData<T> {
+    pub fn new(
+        storage_format: MatrixStorageFormat,
+        offsets: Vec<ElementIndex>,
+        vector_indices: Vec<ElementIndex>,
+        indices: Vec<ElementIndex>,
+        values: Vec<T>,
+    ) -> Self {
+        Self {
+            storage_format,
+            offsets,
+            vector_indices,
+            indices,
+            values,
+        }
+    }
+
+    pub fn storage_format(&self) -> MatrixStorageFormat {
+        self.storage_format
+    }
+
+    pub fn offsets_ref(&self) -> &[ElementIndex] {
+        self.offsets.as_slice()
+    }
+
+    pub fn vector_indices_ref(&self) -> &[ElementIndex] {
+        self.vector_indices.as_slice()
+    }
+
+    pub fn indices_ref(&self) -> &[ElementIndex] {
+        self.indices.as_slice()
+    }
+
+    pub fn values_ref(&self) -> &[T] {
+        self.values.as_slice()
+    }
+
+    pub fn number_of_stored_elements(&self) -> ElementCount {
+        self.offsets.last().copied().unwrap_or(0)
+    }
+
+    pub fn into_parts(
+        self,
+    ) -> (
+        MatrixStorageFormat,
+        Vec<ElementIndex>,
+        Vec<ElementIndex>,
+        Vec<ElementIndex>,
+        Vec<T>,
+    ) {
+        (
+            self.storage_format,
+            self.offsets,
+            self.vector_indices,
+            self.indices,
+            self.values,
+        )
+    }
+}
@@ -0,0 +1,13 @@
+mod bitmap;
+mod compressed_sparse_column;
+mod compressed_sparse_row;
+mod coordinate_list;
+mod full;
+mod hyper_sparse;
+
+pub use bitmap::*;
+pub use compressed_sparse_column::*;
+pub use compressed_sparse_row::*;
+pub use coordinate_list::*;
+pub use full::*;
+pub use hyper_sparse::*;
@@ -3,6 +3,7 @@ mod element;
 mod size;
 mod sparse_matrix;
 
+pub mod data;
 pub mod operations;
 
 pub use coordinate::*;
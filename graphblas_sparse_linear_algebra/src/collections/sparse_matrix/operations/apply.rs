@@ -0,0 +1,234 @@
+use once_cell::sync::Lazy;
+
+use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
+use crate::collections::sparse_matrix::SparseMatrix;
+use crate::context::GetContext;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::{
+    ApplyBinaryOperator, ApplyIndexUnaryOperator, ApplyUnaryOperator, BinaryOperatorApplier,
+    IndexUnaryOperatorApplier, UnaryOperatorApplier,
+};
+use crate::operators::binary_operator::{Assignment, BinaryOperator};
+use crate::operators::index_unary_operator::IndexUnaryOperator;
+use crate::operators::mask::SelectEntireMatrix;
+use crate::operators::options::{
+    OptionsForOperatorWithMatrixArgument, OptionsForOperatorWithMatrixAsFirstArgument,
+    OptionsForOperatorWithMatrixAsSecondArgument,
+};
+use crate::operators::unary_operator::UnaryOperator;
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OptionsForOperatorWithMatrixArgument> =
+    Lazy::new(OptionsForOperatorWithMatrixArgument::new_default);
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS_WITH_MATRIX_AS_FIRST_ARGUMENT: Lazy<
+    OptionsForOperatorWithMatrixAsFirstArgument,
+> = Lazy::new(OptionsForOperatorWithMatrixAsFirstArgument::new_default);
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS_WITH_MATRIX_AS_SECOND_ARGUMENT: Lazy<
+    OptionsForOperatorWithMatrixAsSecondArgument,
+> = Lazy::new(OptionsForOperatorWithMatrixAsSecondArgument::new_default);
+
+pub trait ApplyUnaryOperatorToSparseMatrix<T: ValueType> {
+    /// Applies the operator to each stored element, and returns the results as a new matrix
+    fn apply(
+        &self,
+        operator: &impl UnaryOperator<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> ApplyUnaryOperatorToSparseMatrix<T> for SparseMatrix<T> {
+    fn apply(
+        &self,
+        operator: &impl UnaryOperator<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<T>::new(self.context(), self.size()?)?;
+
+        UnaryOperatorApplier::new().apply_to_matrix(
+            operator,
+            self,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireMatrix::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(product)
+    }
+}
+
+pub trait ApplyBinaryOperatorToSparseMatrix<T: ValueType> {
+    /// Applies the operator to the scalar and each stored element, and returns the results as a new matrix
+    fn apply_with_left_scalar(
+        &self,
+        left_argument: T,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+
+    /// Applies the operator to each stored element and the scalar, and returns the results as a new matrix
+    fn apply_with_right_scalar(
+        &self,
+        operator: &impl BinaryOperator<T>,
+        right_argument: T,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> ApplyBinaryOperatorToSparseMatrix<T> for SparseMatrix<T>
+where
+    BinaryOperatorApplier: ApplyBinaryOperator<T>,
+{
+    fn apply_with_left_scalar(
+        &self,
+        left_argument: T,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<T>::new(self.context(), self.size()?)?;
+
+        BinaryOperatorApplier::new().apply_with_matrix_as_right_argument(
+            left_argument,
+            operator,
+            self,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireMatrix::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS_WITH_MATRIX_AS_SECOND_ARGUMENT,
+        )?;
+
+        Ok(product)
+    }
+
+    fn apply_with_right_scalar(
+        &self,
+        operator: &impl BinaryOperator<T>,
+        right_argument: T,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<T>::new(self.context(), self.size()?)?;
+
+        BinaryOperatorApplier::new().apply_with_matrix_as_left_argument(
+            self,
+            operator,
+            right_argument,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireMatrix::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS_WITH_MATRIX_AS_FIRST_ARGUMENT,
+        )?;
+
+        Ok(product)
+    }
+}
+
+pub trait ApplyIndexUnaryOperatorToSparseMatrix<T: ValueType> {
+    /// Applies the operator to each stored element and its indices, and returns the results as a new matrix
+    fn apply_index_unary_operator(
+        &self,
+        operator: &impl IndexUnaryOperator<T>,
+        argument: T,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> ApplyIndexUnaryOperatorToSparseMatrix<T> for SparseMatrix<T>
+where
+    IndexUnaryOperatorApplier: ApplyIndexUnaryOperator<T>,
+{
+    fn apply_index_unary_operator(
+        &self,
+        operator: &impl IndexUnaryOperator<T>,
+        argument: T,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<T>::new(self.context(), self.size()?)?;
+
+        IndexUnaryOperatorApplier::new().apply_to_matrix(
+            self,
+            operator,
+            &argument,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireMatrix::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(product)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size};
+    use crate::collections::Collection;
+    use crate::context::Context;
+    use crate::operators::binary_operator::{First, Minus};
+    use crate::operators::index_unary_operator::PlusColumnIndex;
+    use crate::operators::unary_operator::AdditiveInverse;
+
+    #[test]
+    fn apply_unary_operator_into_new_matrix() {
+        let context = Context::init_default().unwrap();
+        let matrix = SparseMatrix::<i16>::from_element_list(
+            context,
+            Size::new(2, 2),
+            MatrixElementList::from_element_vector(vec![(0, 1, 3).into(), (1, 1, -4).into()]),
+            &First::<i16>::new(),
+        )
+        .unwrap();
+
+        let product = matrix.apply(&AdditiveInverse::<i16>::new()).unwrap();
+
+        assert_eq!(product.size().unwrap(), Size::new(2, 2));
+        assert_eq!(product.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(product.element_value(&0, &1).unwrap(), Some(-3));
+        assert_eq!(product.element_value(&1, &1).unwrap(), Some(4));
+        assert_eq!(matrix.element_value(&0, &1).unwrap(), Some(3));
+    }
+
+    #[test]
+    fn apply_binary_operator_with_scalar_into_new_matrix() {
+        let context = Context::init_default().unwrap();
+        let matrix = SparseMatrix::<i32>::from_element_list(
+            context,
+            Size::new(2, 3),
+            MatrixElementList::from_element_vector(vec![(0, 2, 7).into(), (1, 0, -1).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+
+        let product = matrix
+            .apply_with_left_scalar(10, &Minus::<i32>::new())
+            .unwrap();
+        assert_eq!(product.size().unwrap(), Size::new(2, 3));
+        assert_eq!(product.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(product.element_value(&0, &2).unwrap(), Some(3));
+        assert_eq!(product.element_value(&1, &0).unwrap(), Some(11));
+
+        let product = matrix
+            .apply_with_right_scalar(&Minus::<i32>::new(), 10)
+            .unwrap();
+        assert_eq!(product.element_value(&0, &2).unwrap(), Some(-3));
+        assert_eq!(product.element_value(&1, &0).unwrap(), Some(-11));
+    }
+
+    #[test]
+    fn apply_index_unary_operator_into_new_matrix() {
+        let context = Context::init_default().unwrap();
+        let matrix = SparseMatrix::<i64>::from_element_list(
+            context,
+            Size::new(2, 3),
+            MatrixElementList::from_element_vector(vec![(0, 2, 7).into(), (1, 0, -1).into()]),
+            &First::<i64>::new(),
+        )
+        .unwrap();
+
+        let product = matrix
+            .apply_index_unary_operator(&PlusColumnIndex::<i64>::new(), 10)
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(product.element_value(&0, &2).unwrap(), Some(12));
+        assert_eq!(product.element_value(&1, &0).unwrap(), Some(10));
+    }
+}
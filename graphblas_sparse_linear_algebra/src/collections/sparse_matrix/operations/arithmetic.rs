@@ -0,0 +1,239 @@
+use std::ops::{Add, BitAnd, Mul, Neg};
+
+use crate::collections::arithmetic::unwrap_or_panic;
+use crate::collections::sparse_matrix::operations::{
+    ApplyUnaryOperatorToSparseMatrix, ElementWiseSparseMatrixOperations, MultiplySparseMatrix,
+};
+use crate::collections::sparse_matrix::SparseMatrix;
+use crate::collections::sparse_vector::SparseVector;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{Plus, PlusTyped, Times, TimesTyped};
+use crate::operators::semiring::{PlusTimes, PlusTimesTyped};
+use crate::operators::unary_operator::{AdditiveInverse, AdditiveInverseUnaryOperatorTyped};
+use crate::value_type::ValueType;
+
+/// Arithmetic with the default operators for T.
+///
+/// Unlike the std::ops operators, these return a DimensionMismatch error instead of panicking.
+pub trait CheckedSparseMatrixArithmetic<T: ValueType> {
+    /// Element-wise addition of the union of the stored elements
+    fn checked_add(
+        &self,
+        other: &SparseMatrix<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>
+    where
+        T: PlusTyped<T>;
+
+    /// Element-wise multiplication of the intersection of the stored elements
+    fn checked_ewise_mult(
+        &self,
+        other: &SparseMatrix<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>
+    where
+        T: TimesTyped<T>;
+
+    /// Matrix multiplication with the plus-times semiring
+    fn checked_mul(
+        &self,
+        multiplicant: &SparseMatrix<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>
+    where
+        T: PlusTimesTyped<T>;
+
+    /// Matrix-vector multiplication with the plus-times semiring
+    fn checked_mul_vector(
+        &self,
+        multiplicant: &SparseVector<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>
+    where
+        T: PlusTimesTyped<T>;
+
+    fn checked_neg(&self) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>
+    where
+        T: AdditiveInverseUnaryOperatorTyped<T>;
+}
+
+impl<T: ValueType> CheckedSparseMatrixArithmetic<T> for SparseMatrix<T> {
+    fn checked_add(
+        &self,
+        other: &SparseMatrix<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>
+    where
+        T: PlusTyped<T>,
+    {
+        self.ewise_add(other, &Plus::<T>::new())
+    }
+
+    fn checked_ewise_mult(
+        &self,
+        other: &SparseMatrix<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>
+    where
+        T: TimesTyped<T>,
+    {
+        self.ewise_mult(other, &Times::<T>::new())
+    }
+
+    fn checked_mul(
+        &self,
+        multiplicant: &SparseMatrix<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>
+    where
+        T: PlusTimesTyped<T>,
+    {
+        self.mxm(multiplicant, &PlusTimes::<T>::new())
+    }
+
+    fn checked_mul_vector(
+        &self,
+        multiplicant: &SparseVector<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>
+    where
+        T: PlusTimesTyped<T>,
+    {
+        self.mxv(multiplicant, &PlusTimes::<T>::new())
+    }
+
+    fn checked_neg(&self) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>
+    where
+        T: AdditiveInverseUnaryOperatorTyped<T>,
+    {
+        self.apply(&AdditiveInverse::<T>::new())
+    }
+}
+
+/// Panics on a size mismatch, use checked_add to handle it
+impl<T: ValueType + PlusTyped<T>> Add<&SparseMatrix<T>> for &SparseMatrix<T> {
+    type Output = SparseMatrix<T>;
+
+    fn add(self, other: &SparseMatrix<T>) -> SparseMatrix<T> {
+        unwrap_or_panic(self.checked_add(other), "Sparse matrix addition")
+    }
+}
+
+/// Element-wise multiplication, panics on a size mismatch, use checked_ewise_mult to handle it
+impl<T: ValueType + TimesTyped<T>> BitAnd<&SparseMatrix<T>> for &SparseMatrix<T> {
+    type Output = SparseMatrix<T>;
+
+    fn bitand(self, other: &SparseMatrix<T>) -> SparseMatrix<T> {
+        unwrap_or_panic(
+            self.checked_ewise_mult(other),
+            "Sparse matrix element-wise multiplication",
+        )
+    }
+}
+
+/// Matrix multiplication, panics on a size mismatch, use checked_mul to handle it
+impl<T: ValueType + PlusTimesTyped<T>> Mul<&SparseMatrix<T>> for &SparseMatrix<T> {
+    type Output = SparseMatrix<T>;
+
+    fn mul(self, multiplicant: &SparseMatrix<T>) -> SparseMatrix<T> {
+        unwrap_or_panic(
+            self.checked_mul(multiplicant),
+            "Sparse matrix multiplication",
+        )
+    }
+}
+
+/// Matrix-vector multiplication, panics on a size mismatch, use checked_mul_vector to handle it
+impl<T: ValueType + PlusTimesTyped<T>> Mul<&SparseVector<T>> for &SparseMatrix<T> {
+    type Output = SparseVector<T>;
+
+    fn mul(self, multiplicant: &SparseVector<T>) -> SparseVector<T> {
+        unwrap_or_panic(
+            self.checked_mul_vector(multiplicant),
+            "Sparse matrix-vector multiplication",
+        )
+    }
+}
+
+impl<T: ValueType + AdditiveInverseUnaryOperatorTyped<T>> Neg for &SparseMatrix<T> {
+    type Output = SparseMatrix<T>;
+
+    fn neg(self) -> SparseMatrix<T> {
+        unwrap_or_panic(self.checked_neg(), "Sparse matrix negation")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size};
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    fn matrix(elements: Vec<(usize, usize, i32)>) -> SparseMatrix<i32> {
+        SparseMatrix::<i32>::from_element_list(
+            Context::init_default().unwrap(),
+            Size::new(2, 2),
+            MatrixElementList::from_element_vector(
+                elements.into_iter().map(|element| element.into()).collect(),
+            ),
+            &First::<i32>::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn matrix_operators() {
+        let a = matrix(vec![(0, 0, 1), (0, 1, 2), (1, 1, 3)]);
+        let b = matrix(vec![(0, 0, 4), (1, 0, 5)]);
+
+        let sum = &a + &b;
+        assert_eq!(sum.element_value(&0, &0).unwrap(), Some(5));
+        assert_eq!(sum.element_value(&1, &0).unwrap(), Some(5));
+
+        let product = &a * &b;
+        assert_eq!(product.element_value(&0, &0).unwrap(), Some(14));
+        assert_eq!(product.element_value(&1, &0).unwrap(), Some(15));
+        assert_eq!(product.element_value(&0, &1).unwrap(), None);
+
+        let element_wise_product = &a & &b;
+        assert_eq!(element_wise_product.element_value(&0, &0).unwrap(), Some(4));
+        assert_eq!(element_wise_product.element_value(&1, &0).unwrap(), None);
+
+        let negation = -&a;
+        assert_eq!(negation.element_value(&1, &1).unwrap(), Some(-3));
+
+        let vector = SparseVector::<i32>::from_element_list(
+            Context::init_default().unwrap(),
+            2,
+            VectorElementList::from_element_vector(vec![(1, 10).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let product = &a * &vector;
+        assert_eq!(product.element_value(&0).unwrap(), Some(20));
+        assert_eq!(product.element_value(&1).unwrap(), Some(30));
+    }
+
+    #[test]
+    fn checked_operators_return_size_mismatch() {
+        let a = matrix(vec![(0, 0, 1)]);
+        let b =
+            SparseMatrix::<i32>::new(Context::init_default().unwrap(), Size::new(3, 2)).unwrap();
+
+        assert!(a.checked_add(&b).is_err());
+        assert!(a.checked_ewise_mult(&b).is_err());
+        assert!(a.checked_mul(&b).is_err());
+        assert!(b.checked_mul(&a).is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "Sparse matrix multiplication failed")]
+    fn multiplication_operator_panics_on_size_mismatch() {
+        let a = matrix(vec![(0, 0, 1)]);
+        let b =
+            SparseMatrix::<i32>::new(Context::init_default().unwrap(), Size::new(3, 2)).unwrap();
+
+        let _ = &a * &b;
+    }
+}
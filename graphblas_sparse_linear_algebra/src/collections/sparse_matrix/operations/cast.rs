@@ -0,0 +1,140 @@
+use std::ptr;
+
+use once_cell::sync::Lazy;
+use suitesparse_graphblas_sys::GrB_Matrix_apply;
+
+use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
+use crate::collections::sparse_matrix::{GetGraphblasSparseMatrix, SparseMatrix};
+use crate::context::{CallGraphBlasContext, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::options::{GetGraphblasDescriptor, OperatorOptions};
+use crate::operators::unary_operator::{Identity, IdentityUnaryOperatorTyped, UnaryOperator};
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OperatorOptions> =
+    Lazy::new(OperatorOptions::new_default);
+
+/// Converts the stored elements to another value type, following the typecasting rules of SuiteSparse:GraphBLAS.
+///
+/// Conversions between integer types, and between floating point types, follow C.
+/// Floating point values cast to an integer are truncated towards zero and saturate at the bounds of the integer type,
+/// with NaN becoming zero, where C leaves out-of-range conversions undefined.
+/// Any nonzero value, including NaN, becomes true when cast to bool.
+pub trait CastSparseMatrix {
+    fn cast<U: ValueType + IdentityUnaryOperatorTyped<U>>(
+        &self,
+    ) -> Result<SparseMatrix<U>, SparseLinearAlgebraError>;
+
+    /// Replaces the elements of the target, which must have the same size
+    fn cast_into<U: ValueType + IdentityUnaryOperatorTyped<U>>(
+        &self,
+        target: &mut SparseMatrix<U>,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> CastSparseMatrix for SparseMatrix<T> {
+    fn cast<U: ValueType + IdentityUnaryOperatorTyped<U>>(
+        &self,
+    ) -> Result<SparseMatrix<U>, SparseLinearAlgebraError> {
+        let mut target = SparseMatrix::<U>::new(self.context(), self.size()?)?;
+        self.cast_into(&mut target)?;
+        Ok(target)
+    }
+
+    fn cast_into<U: ValueType + IdentityUnaryOperatorTyped<U>>(
+        &self,
+        target: &mut SparseMatrix<U>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        // GraphBLAS typecasts the argument to the domain of the identity operator
+        let identity = Identity::<U>::new();
+
+        self.context_ref().call(
+            || unsafe {
+                GrB_Matrix_apply(
+                    target.graphblas_matrix(),
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                    identity.graphblas_type(),
+                    self.graphblas_matrix(),
+                    DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+                )
+            },
+            unsafe { target.graphblas_matrix_ref() },
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::collection::Collection;
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size};
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    #[test]
+    fn cast_integer_to_floating_point() {
+        let context = Context::init_default().unwrap();
+        let element_list = MatrixElementList::<u8>::from_element_vector(vec![
+            (0, 0, 1).into(),
+            (1, 2, 255).into(),
+        ]);
+        let matrix = SparseMatrix::<u8>::from_element_list(
+            context,
+            Size::new(2, 3),
+            element_list,
+            &First::<u8>::new(),
+        )
+        .unwrap();
+
+        let cast_matrix = matrix.cast::<f64>().unwrap();
+
+        assert_eq!(cast_matrix.size().unwrap(), Size::new(2, 3));
+        assert_eq!(cast_matrix.element_value(&0, &0).unwrap(), Some(1.0));
+        assert_eq!(cast_matrix.element_value(&1, &2).unwrap(), Some(255.0));
+    }
+
+    #[test]
+    fn cast_floating_point_to_integer_and_bool() {
+        let context = Context::init_default().unwrap();
+        let element_list = MatrixElementList::<f32>::from_element_vector(vec![
+            (0, 0, 2.7).into(),
+            (0, 1, -2.7).into(),
+            (1, 0, 0.0).into(),
+            (1, 1, 1000.0).into(),
+        ]);
+        let matrix = SparseMatrix::<f32>::from_element_list(
+            context.clone(),
+            Size::new(2, 2),
+            element_list,
+            &First::<f32>::new(),
+        )
+        .unwrap();
+
+        let integer_matrix = matrix.cast::<i8>().unwrap();
+        assert_eq!(integer_matrix.element_value(&0, &0).unwrap(), Some(2));
+        assert_eq!(integer_matrix.element_value(&0, &1).unwrap(), Some(-2));
+        assert_eq!(integer_matrix.element_value(&1, &0).unwrap(), Some(0));
+        assert_eq!(integer_matrix.element_value(&1, &1).unwrap(), Some(127));
+
+        let mut bool_matrix = SparseMatrix::<bool>::new(context, Size::new(2, 2)).unwrap();
+        matrix.cast_into(&mut bool_matrix).unwrap();
+        assert_eq!(bool_matrix.number_of_stored_elements().unwrap(), 4);
+        assert_eq!(bool_matrix.element_value(&0, &1).unwrap(), Some(true));
+        assert_eq!(bool_matrix.element_value(&1, &0).unwrap(), Some(false));
+    }
+
+    #[test]
+    fn cast_into_matrix_with_different_size() {
+        let context = Context::init_default().unwrap();
+        let matrix = SparseMatrix::<i32>::new(context.clone(), Size::new(2, 2)).unwrap();
+        let mut target = SparseMatrix::<i64>::new(context, Size::new(3, 2)).unwrap();
+
+        assert!(matrix.cast_into(&mut target).is_err());
+    }
+}
@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use once_cell::sync::Lazy;
+use suitesparse_graphblas_sys::GxB_Matrix_concat;
+
+use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
+use crate::collections::sparse_matrix::{
+    ColumnIndex, GetGraphblasSparseMatrix, RowIndex, Size, SparseMatrix,
+};
+use crate::context::{CallGraphBlasContext, Context};
+use crate::error::{GraphblasError, GraphblasErrorType, SparseLinearAlgebraError};
+use crate::graphblas_bindings::GrB_Matrix;
+use crate::index::IndexConversion;
+use crate::operators::options::{GetGraphblasDescriptor, OperatorOptions};
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OperatorOptions> =
+    Lazy::new(OperatorOptions::new_default);
+
+/// Assembles a matrix from a grid of tiles, given as a vector of tile rows.
+///
+/// All tiles in a tile row must have the same row height,
+/// and all tiles in a tile column must have the same column width.
+/// All tiles must have value type T, tiles of another value type can be typecast first.
+pub trait ConcatenateSparseMatrices<T: ValueType> {
+    fn from_tiles(
+        context: Arc<Context>,
+        tiles: &[Vec<SparseMatrix<T>>],
+    ) -> Result<Self, SparseLinearAlgebraError>
+    where
+        Self: Sized;
+}
+
+impl<T: ValueType> ConcatenateSparseMatrices<T> for SparseMatrix<T> {
+    fn from_tiles(
+        context: Arc<Context>,
+        tiles: &[Vec<SparseMatrix<T>>],
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let number_of_tile_rows = tiles.len();
+        let number_of_tile_columns = match tiles.first() {
+            Some(first_tile_row) if !first_tile_row.is_empty() => first_tile_row.len(),
+            _ => {
+                return Err(GraphblasError::new(
+                    GraphblasErrorType::InvalidValue,
+                    String::from("At least one tile is required"),
+                )
+                .into())
+            }
+        };
+
+        let mut graphblas_tiles: Vec<GrB_Matrix> =
+            Vec::with_capacity(number_of_tile_rows * number_of_tile_columns);
+        for tile_row in tiles {
+            if tile_row.len() != number_of_tile_columns {
+                return Err(GraphblasError::new(
+                    GraphblasErrorType::DimensionMismatch,
+                    format!(
+                        "Each tile row must have {} tiles, found a tile row with {} tiles",
+                        number_of_tile_columns,
+                        tile_row.len()
+                    ),
+                )
+                .into());
+            }
+            for tile in tile_row {
+                graphblas_tiles.push(unsafe { tile.graphblas_matrix() });
+            }
+        }
+
+        // GraphBLAS verifies that the sizes of the other tiles are consistent
+        let mut row_height: RowIndex = 0;
+        for tile_row in tiles {
+            row_height += tile_row[0].row_height()?;
+        }
+        let mut column_width: ColumnIndex = 0;
+        for tile in tiles[0].iter() {
+            column_width += tile.column_width()?;
+        }
+        let matrix = SparseMatrix::<T>::new(context.clone(), Size::new(row_height, column_width))?;
+
+        let number_of_tile_rows = number_of_tile_rows.to_graphblas_index()?;
+        let number_of_tile_columns = number_of_tile_columns.to_graphblas_index()?;
+
+        context.call(
+            || unsafe {
+                GxB_Matrix_concat(
+                    matrix.graphblas_matrix(),
+                    graphblas_tiles.as_ptr(),
+                    number_of_tile_rows,
+                    number_of_tile_columns,
+                    DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+                )
+            },
+            unsafe { matrix.graphblas_matrix_ref() },
+        )?;
+
+        Ok(matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::collection::Collection;
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::MatrixElementList;
+    use crate::operators::binary_operator::First;
+
+    fn tile(
+        context: &Arc<Context>,
+        size: Size,
+        elements: Vec<(RowIndex, ColumnIndex, i32)>,
+    ) -> SparseMatrix<i32> {
+        let element_list = MatrixElementList::<i32>::from_element_vector(
+            elements.into_iter().map(|element| element.into()).collect(),
+        );
+        SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            size,
+            element_list,
+            &First::<i32>::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn concatenate_tiles() {
+        let context = Context::init_default().unwrap();
+
+        let tiles = vec![
+            vec![
+                tile(&context, Size::new(2, 1), vec![(0, 0, 1)]),
+                tile(&context, Size::new(2, 3), vec![(1, 2, 2)]),
+            ],
+            vec![
+                tile(&context, Size::new(1, 1), vec![(0, 0, 3)]),
+                tile(&context, Size::new(1, 3), vec![(0, 1, 4)]),
+            ],
+        ];
+
+        let matrix = SparseMatrix::<i32>::from_tiles(context.clone(), &tiles).unwrap();
+
+        assert_eq!(matrix.size().unwrap(), Size::new(3, 4));
+        assert_eq!(matrix.number_of_stored_elements().unwrap(), 4);
+        assert_eq!(matrix.element_value(&0, &0).unwrap(), Some(1));
+        assert_eq!(matrix.element_value(&1, &3).unwrap(), Some(2));
+        assert_eq!(matrix.element_value(&2, &0).unwrap(), Some(3));
+        assert_eq!(matrix.element_value(&2, &2).unwrap(), Some(4));
+    }
+
+    #[test]
+    fn concatenate_tiles_with_inconsistent_sizes() {
+        let context = Context::init_default().unwrap();
+
+        let tiles = vec![
+            vec![
+                tile(&context, Size::new(2, 1), vec![]),
+                tile(&context, Size::new(1, 3), vec![]),
+            ],
+            vec![
+                tile(&context, Size::new(1, 1), vec![]),
+                tile(&context, Size::new(1, 3), vec![]),
+            ],
+        ];
+        assert!(SparseMatrix::<i32>::from_tiles(context.clone(), &tiles).is_err());
+
+        let ragged_tiles = vec![
+            vec![tile(&context, Size::new(1, 1), vec![])],
+            vec![
+                tile(&context, Size::new(1, 1), vec![]),
+                tile(&context, Size::new(1, 1), vec![]),
+            ],
+        ];
+        assert!(SparseMatrix::<i32>::from_tiles(context.clone(), &ragged_tiles).is_err());
+
+        assert!(SparseMatrix::<i32>::from_tiles(context.clone(), &[]).is_err());
+    }
+}
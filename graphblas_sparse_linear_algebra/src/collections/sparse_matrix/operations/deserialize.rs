@@ -2,14 +2,63 @@ use std::ffi::c_void;
 use std::ptr;
 use std::{mem::MaybeUninit, sync::Arc};
 
-use suitesparse_graphblas_sys::{GrB_Index, GrB_Matrix, GrB_Matrix_deserialize};
+use suitesparse_graphblas_sys::{GrB_Index, GrB_Matrix, GrB_Matrix_deserialize, GrB_Type};
 
+use crate::collections::sparse_matrix::SparseMatrix;
 use crate::index::IndexConversion;
+use crate::value_type::ValueType;
 use crate::{context::Context, error::SparseLinearAlgebraError};
 
 pub unsafe fn deserialize_suitesparse_graphblas_sparse_matrix(
     context: &Arc<Context>,
     serialized_suitesparse_graphblas_sparse_matrix: &[u8],
+) -> Result<GrB_Matrix, SparseLinearAlgebraError> {
+    deserialize_suitesparse_graphblas_sparse_matrix_with_value_type(
+        context,
+        serialized_suitesparse_graphblas_sparse_matrix,
+        ptr::null_mut(),
+    )
+}
+
+/// Deserializes a matrix serialized by any of the serializers.
+///
+/// Unlike deserialize_suitesparse_graphblas_sparse_matrix(), this also supports matrices of custom value types.
+/// Fails if the serialized matrix has a different value type.
+///
+/// # Safety
+/// GraphBLAS identifies a custom value type by its name and size only, and copies the serialized values as they are.
+/// For a custom value type, every serialized value must therefore be a valid T,
+/// e.g. because the matrix was serialized by this library from a SparseMatrix<T>.
+pub trait DeserializeSparseMatrix<T: ValueType> {
+    /// # Safety
+    /// See the safety requirements of DeserializeSparseMatrix.
+    unsafe fn deserialize(
+        context: Arc<Context>,
+        serialized_sparse_matrix: &[u8],
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> DeserializeSparseMatrix<T> for SparseMatrix<T> {
+    unsafe fn deserialize(
+        context: Arc<Context>,
+        serialized_sparse_matrix: &[u8],
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        unsafe {
+            let graphblas_matrix = deserialize_suitesparse_graphblas_sparse_matrix_with_value_type(
+                &context,
+                serialized_sparse_matrix,
+                T::graphblas_type_in_context(&context)?,
+            )?;
+            SparseMatrix::from_graphblas_matrix(context, graphblas_matrix)
+        }
+    }
+}
+
+// A null value type makes GraphBLAS use the built-in value type of the serialized matrix
+unsafe fn deserialize_suitesparse_graphblas_sparse_matrix_with_value_type(
+    context: &Arc<Context>,
+    serialized_suitesparse_graphblas_sparse_matrix: &[u8],
+    value_type: GrB_Type,
 ) -> Result<GrB_Matrix, SparseLinearAlgebraError> {
     let mut suitesparse_graphblas_sparse_matrix: MaybeUninit<GrB_Matrix> = MaybeUninit::uninit();
     let raw_pointer_to_serialized_suitesparse_graphblas_sparse_matrix: *const c_void =
@@ -22,7 +71,7 @@ pub unsafe fn deserialize_suitesparse_graphblas_sparse_matrix(
     context.call_without_detailed_error_information(|| unsafe {
         GrB_Matrix_deserialize(
             suitesparse_graphblas_sparse_matrix.as_mut_ptr(),
-            ptr::null_mut(),
+            value_type,
             raw_pointer_to_serialized_suitesparse_graphblas_sparse_matrix,
             size_of_serialized_suitesparse_graphblas_sparse_matrix,
         )
@@ -0,0 +1,228 @@
+use once_cell::sync::Lazy;
+
+use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
+use crate::collections::sparse_matrix::SparseMatrix;
+use crate::collections::sparse_scalar::{SetScalarValue, SparseScalar};
+use crate::context::GetContext;
+use crate::error::{GraphblasError, GraphblasErrorType, SparseLinearAlgebraError};
+use crate::operators::binary_operator::{Assignment, BinaryOperator};
+use crate::operators::element_wise_addition::{
+    ApplyElementWiseMatrixAdditionBinaryOperator, ElementWiseMatrixAdditionBinaryOperator,
+};
+use crate::operators::element_wise_multiplication::{
+    ApplyElementWiseMatrixMultiplicationBinaryOperator,
+    ElementWiseMatrixMultiplicationBinaryOperator,
+};
+use crate::operators::element_wise_union::{
+    ApplyElementWiseMatrixUnion, ElementWiseMatrixUnionOperator, ElementWiseUnionArgument,
+};
+use crate::operators::mask::SelectEntireMatrix;
+use crate::operators::options::OptionsForOperatorWithMatrixArguments;
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OptionsForOperatorWithMatrixArguments> =
+    Lazy::new(OptionsForOperatorWithMatrixArguments::new_default);
+
+/// Combines two matrices of equal size into a new matrix.
+///
+/// Returns a DimensionMismatch error before calling GraphBLAS if the sizes differ.
+pub trait ElementWiseSparseMatrixOperations<T: ValueType> {
+    /// Applies the operator to the union of the stored elements
+    fn ewise_add(
+        &self,
+        other: &SparseMatrix<T>,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+
+    /// Applies the operator to the intersection of the stored elements
+    fn ewise_mult(
+        &self,
+        other: &SparseMatrix<T>,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> ElementWiseSparseMatrixOperations<T> for SparseMatrix<T> {
+    fn ewise_add(
+        &self,
+        other: &SparseMatrix<T>,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let mut product = new_product_of_equal_size(self, other)?;
+
+        ElementWiseMatrixAdditionBinaryOperator::new().apply(
+            self,
+            operator,
+            other,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireMatrix::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(product)
+    }
+
+    fn ewise_mult(
+        &self,
+        other: &SparseMatrix<T>,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let mut product = new_product_of_equal_size(self, other)?;
+
+        ElementWiseMatrixMultiplicationBinaryOperator::new().apply(
+            self,
+            operator,
+            other,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireMatrix::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(product)
+    }
+}
+
+/// Combines two matrices of equal size into a new matrix, applying the operator to the union of the stored elements.
+///
+/// Where only one matrix stores an element, the fill value of the other matrix takes the place of its missing element,
+/// e.g. zero fill values to compute A - B.
+/// Returns a DimensionMismatch error before calling GraphBLAS if the sizes differ.
+pub trait ElementWiseSparseMatrixUnion<T: ValueType> {
+    fn ewise_union(
+        &self,
+        fill_value: T,
+        other: &SparseMatrix<T>,
+        other_fill_value: T,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> ElementWiseSparseMatrixUnion<T> for SparseMatrix<T>
+where
+    SparseScalar<T>: SetScalarValue<T>,
+{
+    fn ewise_union(
+        &self,
+        fill_value: T,
+        other: &SparseMatrix<T>,
+        other_fill_value: T,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let mut product = new_product_of_equal_size(self, other)?;
+
+        let mut left_argument_fill_value = SparseScalar::<T>::new(self.context())?;
+        left_argument_fill_value.set_value(fill_value)?;
+        let mut right_argument_fill_value = SparseScalar::<T>::new(self.context())?;
+        right_argument_fill_value.set_value(other_fill_value)?;
+
+        ElementWiseMatrixUnionOperator::new().apply(
+            &ElementWiseUnionArgument::new(self, &left_argument_fill_value),
+            operator,
+            &ElementWiseUnionArgument::new(other, &right_argument_fill_value),
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireMatrix::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(product)
+    }
+}
+
+fn new_product_of_equal_size<T: ValueType>(
+    first: &SparseMatrix<T>,
+    second: &SparseMatrix<T>,
+) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+    let size = first.size()?;
+    let other_size = second.size()?;
+    if size != other_size {
+        return Err(GraphblasError::new(
+            GraphblasErrorType::DimensionMismatch,
+            format!(
+                "Element-wise operations require matrices of equal size, found {:?} and {:?}",
+                size, other_size
+            ),
+        )
+        .into());
+    }
+    SparseMatrix::<T>::new(first.context(), size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size};
+    use crate::collections::Collection;
+    use crate::context::Context;
+    use crate::operators::binary_operator::{First, Minus, Plus, Times};
+
+    #[test]
+    fn element_wise_operations_into_new_matrix() {
+        let context = Context::init_default().unwrap();
+        let first = SparseMatrix::<f32>::from_element_list(
+            context.clone(),
+            Size::new(2, 2),
+            MatrixElementList::from_element_vector(vec![(0, 0, 2.0).into(), (1, 1, 3.0).into()]),
+            &First::<f32>::new(),
+        )
+        .unwrap();
+        let second = SparseMatrix::<f32>::from_element_list(
+            context.clone(),
+            Size::new(2, 2),
+            MatrixElementList::from_element_vector(vec![(0, 0, 4.0).into(), (0, 1, 5.0).into()]),
+            &First::<f32>::new(),
+        )
+        .unwrap();
+
+        let sum = first.ewise_add(&second, &Plus::<f32>::new()).unwrap();
+        assert_eq!(sum.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(sum.element_value(&0, &0).unwrap(), Some(6.0));
+        assert_eq!(sum.element_value(&1, &1).unwrap(), Some(3.0));
+
+        let product = first.ewise_mult(&second, &Times::<f32>::new()).unwrap();
+        assert_eq!(product.number_of_stored_elements().unwrap(), 1);
+        assert_eq!(product.element_value(&0, &0).unwrap(), Some(8.0));
+
+        let other_size = SparseMatrix::<f32>::new(context, Size::new(2, 3)).unwrap();
+        assert!(first.ewise_add(&other_size, &Plus::<f32>::new()).is_err());
+    }
+
+    #[test]
+    fn element_wise_union_into_new_matrix() {
+        let context = Context::init_default().unwrap();
+        let first = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            Size::new(2, 2),
+            MatrixElementList::from_element_vector(vec![(0, 0, 2).into(), (1, 1, 3).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let second = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            Size::new(2, 2),
+            MatrixElementList::from_element_vector(vec![(0, 0, 4).into(), (0, 1, 5).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+
+        let difference = first
+            .ewise_union(0, &second, 0, &Minus::<i32>::new())
+            .unwrap();
+        assert_eq!(difference.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(difference.element_value(&0, &0).unwrap(), Some(-2));
+        assert_eq!(difference.element_value(&0, &1).unwrap(), Some(-5));
+        assert_eq!(difference.element_value(&1, &1).unwrap(), Some(3));
+        assert_eq!(difference.element_value(&1, &0).unwrap(), None);
+
+        let other_size = SparseMatrix::<i32>::new(context, Size::new(3, 2)).unwrap();
+        assert!(first
+            .ewise_union(0, &other_size, 0, &Minus::<i32>::new())
+            .is_err());
+    }
+}
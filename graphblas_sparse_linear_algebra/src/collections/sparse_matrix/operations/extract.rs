@@ -0,0 +1,170 @@
+use once_cell::sync::Lazy;
+
+use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
+use crate::collections::sparse_matrix::{GetMatrixDimensions, Size, SparseMatrix};
+use crate::collections::sparse_vector::SparseVector;
+use crate::context::GetContext;
+use crate::error::SparseLinearAlgebraError;
+use crate::index::{ElementIndex, ElementIndexSelector};
+use crate::operators::binary_operator::Assignment;
+use crate::operators::extract::{
+    ExtractMatrixColumn, ExtractMatrixRow, ExtractSubMatrix, MatrixColumnExtractor,
+    MatrixRowExtractor, SubMatrixExtractor,
+};
+use crate::operators::mask::{SelectEntireMatrix, SelectEntireVector};
+use crate::operators::options::OptionsForOperatorWithMatrixArgument;
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OptionsForOperatorWithMatrixArgument> =
+    Lazy::new(OptionsForOperatorWithMatrixArgument::new_default);
+
+/// Extracts into a new collection, which has the size of the selection.
+pub trait ExtractFromSparseMatrix<T: ValueType> {
+    fn extract_sub_matrix(
+        &self,
+        rows_to_extract: &ElementIndexSelector,
+        columns_to_extract: &ElementIndexSelector,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+
+    fn extract_row(
+        &self,
+        row_index_to_extract: ElementIndex,
+        columns_to_extract: &ElementIndexSelector,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>;
+
+    fn extract_column(
+        &self,
+        column_index_to_extract: ElementIndex,
+        rows_to_extract: &ElementIndexSelector,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> ExtractFromSparseMatrix<T> for SparseMatrix<T> {
+    fn extract_sub_matrix(
+        &self,
+        rows_to_extract: &ElementIndexSelector,
+        columns_to_extract: &ElementIndexSelector,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let size = self.size()?;
+        let mut sub_matrix = SparseMatrix::<T>::new(
+            self.context(),
+            Size::new(
+                rows_to_extract.number_of_selected_elements(size.row_height())?,
+                columns_to_extract.number_of_selected_elements(size.column_width())?,
+            ),
+        )?;
+
+        SubMatrixExtractor::new().apply(
+            self,
+            rows_to_extract,
+            columns_to_extract,
+            &Assignment::<T>::new(),
+            &mut sub_matrix,
+            &SelectEntireMatrix::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(sub_matrix)
+    }
+
+    fn extract_row(
+        &self,
+        row_index_to_extract: ElementIndex,
+        columns_to_extract: &ElementIndexSelector,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut row = SparseVector::<T>::new(
+            self.context(),
+            columns_to_extract.number_of_selected_elements(self.column_width()?)?,
+        )?;
+
+        MatrixRowExtractor::new().apply(
+            self,
+            row_index_to_extract,
+            columns_to_extract,
+            &Assignment::<T>::new(),
+            &mut row,
+            &SelectEntireVector::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(row)
+    }
+
+    fn extract_column(
+        &self,
+        column_index_to_extract: ElementIndex,
+        rows_to_extract: &ElementIndexSelector,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut column = SparseVector::<T>::new(
+            self.context(),
+            rows_to_extract.number_of_selected_elements(self.row_height()?)?,
+        )?;
+
+        MatrixColumnExtractor::new().apply(
+            self,
+            column_index_to_extract,
+            rows_to_extract,
+            &Assignment::<T>::new(),
+            &mut column,
+            &SelectEntireVector::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::MatrixElementList;
+    use crate::collections::sparse_vector::operations::{
+        GetSparseVectorElementValue, GetSparseVectorLength,
+    };
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    #[test]
+    fn extract_into_new_collections() {
+        let context = Context::init_default().unwrap();
+        let matrix = SparseMatrix::<u8>::from_element_list(
+            context,
+            Size::new(3, 4),
+            MatrixElementList::from_element_vector(vec![
+                (0, 1, 1).into(),
+                (1, 3, 2).into(),
+                (2, 0, 3).into(),
+                (2, 3, 4).into(),
+            ]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+
+        let sub_matrix = matrix
+            .extract_sub_matrix(
+                &ElementIndexSelector::Index(&[2, 1]),
+                &ElementIndexSelector::Index(&[3]),
+            )
+            .unwrap();
+        assert_eq!(sub_matrix.size().unwrap(), Size::new(2, 1));
+        assert_eq!(sub_matrix.element_value(&0, &0).unwrap(), Some(4));
+        assert_eq!(sub_matrix.element_value(&1, &0).unwrap(), Some(2));
+
+        let row = matrix.extract_row(2, &ElementIndexSelector::All).unwrap();
+        assert_eq!(row.length().unwrap(), 4);
+        assert_eq!(row.element_value(&0).unwrap(), Some(3));
+        assert_eq!(row.element_value(&1).unwrap(), None);
+        assert_eq!(row.element_value(&3).unwrap(), Some(4));
+
+        let column = matrix
+            .extract_column(3, &ElementIndexSelector::Index(&[1, 2]))
+            .unwrap();
+        assert_eq!(column.length().unwrap(), 2);
+        assert_eq!(column.element_value(&0).unwrap(), Some(2));
+        assert_eq!(column.element_value(&1).unwrap(), Some(4));
+    }
+}
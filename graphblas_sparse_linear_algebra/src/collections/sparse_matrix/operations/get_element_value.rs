@@ -1,3 +1,4 @@
+use std::ffi::c_void;
 use std::mem::MaybeUninit;
 
 use crate::collections::sparse_matrix::sparse_matrix::GetGraphblasSparseMatrix;
@@ -15,14 +16,14 @@ use crate::{
     error::SparseLinearAlgebraError,
     value_type::{
         utilities_to_implement_traits_for_all_value_types::implement_macro_for_all_value_types_and_graphblas_function,
-        ValueType,
+        CustomValueType, ValueType,
     },
 };
 
 use crate::graphblas_bindings::{
     GrB_Matrix_extractElement_BOOL, GrB_Matrix_extractElement_FP32, GrB_Matrix_extractElement_FP64,
     GrB_Matrix_extractElement_INT16, GrB_Matrix_extractElement_INT32,
-    GrB_Matrix_extractElement_INT64, GrB_Matrix_extractElement_INT8,
+    GrB_Matrix_extractElement_INT64, GrB_Matrix_extractElement_INT8, GrB_Matrix_extractElement_UDT,
     GrB_Matrix_extractElement_UINT16, GrB_Matrix_extractElement_UINT32,
     GrB_Matrix_extractElement_UINT64, GrB_Matrix_extractElement_UINT8,
 };
@@ -183,3 +184,72 @@ implement_macro_for_all_value_types_and_graphblas_function!(
     implement_get_element_value,
     GrB_Matrix_extractElement
 );
+
+impl<T: CustomValueType> GetSparseMatrixElementValueTyped<T> for T {
+    fn element_value(
+        matrix: &SparseMatrix<T>,
+        row_index: &RowIndex,
+        column_index: &ColumnIndex,
+    ) -> Result<Option<T>, SparseLinearAlgebraError> {
+        let mut value: MaybeUninit<T> = MaybeUninit::uninit();
+        let row_index_to_get = row_index.as_graphblas_index()?;
+        let column_index_to_get = column_index.as_graphblas_index()?;
+
+        let result = matrix.context_ref().call(
+            || unsafe {
+                GrB_Matrix_extractElement_UDT(
+                    value.as_mut_ptr() as *mut c_void,
+                    matrix.graphblas_matrix(),
+                    row_index_to_get,
+                    column_index_to_get,
+                )
+            },
+            unsafe { &matrix.graphblas_matrix() },
+        );
+
+        match result {
+            Ok(_) => Ok(Some(unsafe { value.assume_init() })),
+            Err(error) => match error.error_type() {
+                SparseLinearAlgebraErrorType::LogicErrorType(LogicErrorType::GraphBlas(
+                    GraphblasErrorType::NoValue,
+                )) => Ok(None),
+                _ => Err(error),
+            },
+        }
+    }
+
+    fn element_value_or_default(
+        matrix: &SparseMatrix<T>,
+        row_index: &RowIndex,
+        column_index: &ColumnIndex,
+    ) -> Result<T, SparseLinearAlgebraError> {
+        Ok(<T as GetSparseMatrixElementValueTyped<T>>::element_value(
+            matrix,
+            row_index,
+            column_index,
+        )?
+        .unwrap_or_default())
+    }
+
+    fn element_value_at_coordinate(
+        matrix: &SparseMatrix<T>,
+        coordinate: &impl GetCoordinateIndices,
+    ) -> Result<Option<T>, SparseLinearAlgebraError> {
+        <T as GetSparseMatrixElementValueTyped<T>>::element_value(
+            matrix,
+            coordinate.row_index_ref(),
+            coordinate.column_index_ref(),
+        )
+    }
+
+    fn element_value_or_default_at_coordinate(
+        matrix: &SparseMatrix<T>,
+        coordinate: &impl GetCoordinateIndices,
+    ) -> Result<T, SparseLinearAlgebraError> {
+        <T as GetSparseMatrixElementValueTyped<T>>::element_value_or_default(
+            matrix,
+            coordinate.row_index_ref(),
+            coordinate.column_index_ref(),
+        )
+    }
+}
@@ -1,26 +1,54 @@
+mod apply;
+mod arithmetic;
+mod cast;
+mod concatenate;
 mod create;
 mod drop_element;
 // mod sort_matrix;
 mod deserialize;
+mod element_wise;
+mod extract;
 mod get_element;
 mod get_element_list;
 mod get_element_value;
 mod get_size;
 mod is_element;
+mod multiply;
+mod pack;
+mod reduce;
 mod resize;
+mod select;
 mod serialize;
 mod set_element;
 mod sort;
+mod split;
+mod storage_options;
+mod transpose;
+mod unpack;
 
+pub use apply::*;
+pub use arithmetic::*;
+pub use cast::*;
+pub use concatenate::*;
 pub use create::*;
 pub use deserialize::*;
 pub use drop_element::*;
+pub use element_wise::*;
+pub use extract::*;
 pub use get_element::*;
 pub use get_element_list::*;
 pub use get_element_value::*;
 pub use get_size::*;
 pub use is_element::*;
+pub use multiply::*;
+pub use pack::*;
+pub use reduce::*;
 pub use resize::*;
+pub use select::*;
 pub use serialize::*;
 pub use set_element::*;
 pub use sort::SortSparseMatrix;
+pub use split::*;
+pub use storage_options::*;
+pub use transpose::*;
+pub use unpack::*;
@@ -0,0 +1,283 @@
+use once_cell::sync::Lazy;
+
+use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
+use crate::collections::sparse_matrix::{GetMatrixDimensions, Size, SparseMatrix};
+use crate::collections::sparse_vector::operations::GetSparseVectorLength;
+use crate::collections::sparse_vector::SparseVector;
+use crate::context::GetContext;
+use crate::error::{GraphblasError, GraphblasErrorType, SparseLinearAlgebraError};
+use crate::operators::binary_operator::{Assignment, BinaryOperator};
+use crate::operators::kronecker_product::{
+    BinaryOperatorKroneckerProduct, BinaryOperatorKroneckerProductOperator,
+};
+use crate::operators::mask::{SelectEntireMatrix, SelectEntireVector};
+use crate::operators::multiplication::{
+    MatrixMultiplicationOperator, MatrixVectorMultiplicationOperator, MultiplyMatrices,
+    MultiplyMatrixByVector,
+};
+use crate::operators::options::{
+    OptionsForOperatorWithMatrixArguments, OptionsForOperatorWithMatrixAsFirstArgument,
+};
+use crate::operators::semiring::Semiring;
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OptionsForOperatorWithMatrixArguments> =
+    Lazy::new(OptionsForOperatorWithMatrixArguments::new_default);
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS_WITH_MATRIX_AS_FIRST_ARGUMENT: Lazy<
+    OptionsForOperatorWithMatrixAsFirstArgument,
+> = Lazy::new(OptionsForOperatorWithMatrixAsFirstArgument::new_default);
+
+/// Multiplies into a new collection, which has the size and value type of the product.
+///
+/// Returns a DimensionMismatch error before calling GraphBLAS if the sizes are incompatible.
+pub trait MultiplySparseMatrix<T: ValueType> {
+    fn mxm(
+        &self,
+        multiplicant: &SparseMatrix<T>,
+        operator: &impl Semiring<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+
+    fn mxv(
+        &self,
+        multiplicant: &SparseVector<T>,
+        operator: &impl Semiring<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>;
+
+    /// The Kronecker product, which has a block for each element of self, with the operator applied to the element
+    /// and each element of the multiplicant
+    fn kronecker(
+        &self,
+        multiplicant: &SparseMatrix<T>,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> MultiplySparseMatrix<T> for SparseMatrix<T> {
+    fn mxm(
+        &self,
+        multiplicant: &SparseMatrix<T>,
+        operator: &impl Semiring<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let multiplier_size = self.size()?;
+        let multiplicant_size = multiplicant.size()?;
+        if multiplier_size.column_width() != multiplicant_size.row_height() {
+            return Err(GraphblasError::new(
+                GraphblasErrorType::DimensionMismatch,
+                format!(
+                    "Cannot multiply a matrix of size {:?} by a matrix of size {:?}",
+                    multiplier_size, multiplicant_size
+                ),
+            )
+            .into());
+        }
+
+        let mut product = SparseMatrix::<T>::new(
+            self.context(),
+            Size::new(
+                multiplier_size.row_height(),
+                multiplicant_size.column_width(),
+            ),
+        )?;
+
+        MatrixMultiplicationOperator::new().apply(
+            self,
+            operator,
+            multiplicant,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireMatrix::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(product)
+    }
+
+    fn mxv(
+        &self,
+        multiplicant: &SparseVector<T>,
+        operator: &impl Semiring<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let multiplier_size = self.size()?;
+        let multiplicant_length = multiplicant.length()?;
+        if multiplier_size.column_width() != multiplicant_length {
+            return Err(GraphblasError::new(
+                GraphblasErrorType::DimensionMismatch,
+                format!(
+                    "Cannot multiply a matrix of size {:?} by a vector of length {}",
+                    multiplier_size, multiplicant_length
+                ),
+            )
+            .into());
+        }
+
+        let mut product = SparseVector::<T>::new(self.context(), multiplier_size.row_height())?;
+
+        MatrixVectorMultiplicationOperator::new().apply(
+            self,
+            operator,
+            multiplicant,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireVector::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS_WITH_MATRIX_AS_FIRST_ARGUMENT,
+        )?;
+
+        Ok(product)
+    }
+
+    fn kronecker(
+        &self,
+        multiplicant: &SparseMatrix<T>,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let multiplier_size = self.size()?;
+        let multiplicant_size = multiplicant.size()?;
+        let product_size = match (
+            multiplier_size
+                .row_height()
+                .checked_mul(multiplicant_size.row_height()),
+            multiplier_size
+                .column_width()
+                .checked_mul(multiplicant_size.column_width()),
+        ) {
+            (Some(row_height), Some(column_width)) => Size::new(row_height, column_width),
+            _ => {
+                return Err(GraphblasError::new(
+                    GraphblasErrorType::InvalidValue,
+                    format!(
+                        "The Kronecker product of a matrix of size {:?} and a matrix of size {:?} overflows",
+                        multiplier_size, multiplicant_size
+                    ),
+                )
+                .into())
+            }
+        };
+
+        let mut product = SparseMatrix::<T>::new(self.context(), product_size)?;
+
+        BinaryOperatorKroneckerProductOperator::new().apply(
+            self,
+            operator,
+            multiplicant,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireMatrix::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(product)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::MatrixElementList;
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::context::Context;
+    use crate::error::{LogicErrorType, SparseLinearAlgebraErrorType};
+    use crate::operators::binary_operator::{First, Times};
+    use crate::operators::semiring::PlusTimes;
+
+    #[test]
+    fn multiply_into_new_collections() {
+        let context = Context::init_default().unwrap();
+
+        let multiplier = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            Size::new(2, 3),
+            MatrixElementList::from_element_vector(vec![
+                (0, 0, 1).into(),
+                (0, 2, 2).into(),
+                (1, 1, 3).into(),
+            ]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            Size::new(3, 1),
+            MatrixElementList::from_element_vector(vec![(0, 0, 4).into(), (2, 0, 5).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+
+        let product = multiplier
+            .mxm(&multiplicant, &PlusTimes::<i32>::new())
+            .unwrap();
+        assert_eq!(product.size().unwrap(), Size::new(2, 1));
+        assert_eq!(product.element_value(&0, &0).unwrap(), Some(14));
+        assert_eq!(product.element_value(&1, &0).unwrap(), None);
+
+        let vector = SparseVector::<i32>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::from_element_vector(vec![(1, 2).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let product = multiplier.mxv(&vector, &PlusTimes::<i32>::new()).unwrap();
+        assert_eq!(product.length().unwrap(), 2);
+        assert_eq!(product.element_value(&1).unwrap(), Some(6));
+    }
+
+    #[test]
+    fn reject_incompatible_sizes() {
+        let context = Context::init_default().unwrap();
+
+        let multiplier = SparseMatrix::<f64>::new(context.clone(), Size::new(2, 3)).unwrap();
+        let multiplicant = SparseMatrix::<f64>::new(context.clone(), Size::new(2, 3)).unwrap();
+
+        match multiplier.mxm(&multiplicant, &PlusTimes::<f64>::new()) {
+            Err(error) => assert_eq!(
+                error.error_type(),
+                SparseLinearAlgebraErrorType::LogicErrorType(LogicErrorType::GraphBlas(
+                    GraphblasErrorType::DimensionMismatch
+                ))
+            ),
+            Ok(_) => panic!("Expected a DimensionMismatch error"),
+        }
+
+        let vector = SparseVector::<f64>::new(context, 2).unwrap();
+        assert!(multiplier.mxv(&vector, &PlusTimes::<f64>::new()).is_err());
+    }
+
+    #[test]
+    fn kronecker_product_into_new_matrix() {
+        let context = Context::init_default().unwrap();
+
+        let multiplier = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            Size::new(2, 2),
+            MatrixElementList::from_element_vector(vec![(0, 0, 2).into(), (1, 1, 3).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            Size::new(1, 3),
+            MatrixElementList::from_element_vector(vec![(0, 0, 1).into(), (0, 2, 5).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+
+        let product = multiplier
+            .kronecker(&multiplicant, &Times::<i32>::new())
+            .unwrap();
+
+        assert_eq!(product.size().unwrap(), Size::new(2, 6));
+        assert_eq!(product.element_value(&0, &0).unwrap(), Some(2));
+        assert_eq!(product.element_value(&0, &2).unwrap(), Some(10));
+        assert_eq!(product.element_value(&1, &3).unwrap(), Some(3));
+        assert_eq!(product.element_value(&1, &5).unwrap(), Some(15));
+        assert_eq!(product.element_value(&0, &3).unwrap(), None);
+    }
+}
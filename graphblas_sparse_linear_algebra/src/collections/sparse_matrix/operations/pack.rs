@@ -0,0 +1,470 @@
+use std::ffi::c_void;
+use std::mem;
+
+use suitesparse_graphblas_sys::{
+    GxB_Matrix_pack_BitmapC, GxB_Matrix_pack_BitmapR, GxB_Matrix_pack_CSC, GxB_Matrix_pack_CSR,
+    GxB_Matrix_pack_FullC, GxB_Matrix_pack_FullR, GxB_Matrix_pack_HyperCSC,
+    GxB_Matrix_pack_HyperCSR,
+};
+
+use crate::collections::graphblas_buffer::{
+    allocated_size_in_bytes, check_buffer_length, ensure_allocation, number_of_dense_elements,
+};
+use crate::collections::sparse_matrix::data::{
+    BitmapData, CooData, CscData, CsrData, FullData, HyperSparseData,
+};
+use crate::collections::sparse_matrix::operations::sparse_matrix_size;
+use crate::collections::sparse_matrix::{
+    GetGraphblasSparseMatrix, GetMatrixDimensions, SparseMatrix,
+};
+use crate::context::{CallGraphBlasContext, GetContext, MatrixStorageFormat};
+use crate::error::{GraphblasError, GraphblasErrorType, SparseLinearAlgebraError};
+use crate::graphblas_bindings::GrB_Index;
+use crate::index::IndexConversion;
+use crate::operators::options::GetOperatorOptions;
+use crate::value_type::ValueType;
+
+/// Replaces the elements of a matrix by handing ownership of the data vectors to GraphBLAS, without copying.
+///
+/// The size of the matrix is preserved, and the data must describe a matrix of that size.
+///
+/// # Safety
+/// GraphBLAS releases the data vectors with its own deallocator.
+/// This requires the Rust global allocator to be the system allocator, which is the default.
+/// Only the lengths of the data vectors are checked, unless the options have extended descriptor options
+/// that do not trust the input, see ExtendedDescriptorOptions::with_trusted_input.
+/// Without that check, the content must be valid for the matrix size, e.g. indices must be in bounds, unique and sorted.
+pub trait PackSparseMatrix<T: ValueType> {
+    /// # Safety
+    /// See the safety requirements of PackSparseMatrix.
+    unsafe fn pack_csr(
+        &mut self,
+        data: CsrData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+    /// # Safety
+    /// See the safety requirements of PackSparseMatrix.
+    unsafe fn pack_csc(
+        &mut self,
+        data: CscData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+    /// GraphBLAS does not store coordinate lists. The row indices are compressed into row offsets in O(n) time,
+    /// the column indices and values are handed over without copying.
+    ///
+    /// # Safety
+    /// See the safety requirements of PackSparseMatrix.
+    unsafe fn pack_coo(
+        &mut self,
+        data: CooData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+    /// # Safety
+    /// See the safety requirements of PackSparseMatrix.
+    unsafe fn pack_hyper_sparse(
+        &mut self,
+        data: HyperSparseData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+    /// # Safety
+    /// See the safety requirements of PackSparseMatrix.
+    unsafe fn pack_bitmap(
+        &mut self,
+        data: BitmapData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+    /// # Safety
+    /// See the safety requirements of PackSparseMatrix.
+    unsafe fn pack_full(
+        &mut self,
+        data: FullData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> PackSparseMatrix<T> for SparseMatrix<T> {
+    unsafe fn pack_csr(
+        &mut self,
+        data: CsrData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let size = sparse_matrix_size(self)?;
+        let (mut row_offsets, mut column_indices, mut values) = data.into_parts();
+
+        check_buffer_length("row_offsets", row_offsets.len(), size.row_height() + 1)?;
+        let number_of_stored_elements = row_offsets[size.row_height()];
+        check_buffer_length(
+            "column_indices",
+            column_indices.len(),
+            number_of_stored_elements,
+        )?;
+        check_buffer_length("values", values.len(), number_of_stored_elements)?;
+
+        ensure_allocation(&mut row_offsets);
+        ensure_allocation(&mut column_indices);
+        ensure_allocation(&mut values);
+
+        let row_offsets_size = allocated_size_in_bytes(&row_offsets)?;
+        let column_indices_size = allocated_size_in_bytes(&column_indices)?;
+        let values_size = allocated_size_in_bytes(&values)?;
+
+        let mut row_offsets_pointer = row_offsets.as_mut_ptr() as *mut GrB_Index;
+        let mut column_indices_pointer = column_indices.as_mut_ptr() as *mut GrB_Index;
+        let mut values_pointer = values.as_mut_ptr() as *mut c_void;
+
+        let graphblas_descriptor = options.graphblas_descriptor_for_call()?;
+        self.context_ref().call(
+            || unsafe {
+                GxB_Matrix_pack_CSR(
+                    self.graphblas_matrix(),
+                    &mut row_offsets_pointer,
+                    &mut column_indices_pointer,
+                    &mut values_pointer,
+                    row_offsets_size,
+                    column_indices_size,
+                    values_size,
+                    false,
+                    false,
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { &self.graphblas_matrix() },
+        )?;
+
+        // GraphBLAS now owns the memory
+        mem::forget(row_offsets);
+        mem::forget(column_indices);
+        mem::forget(values);
+        Ok(())
+    }
+
+    unsafe fn pack_csc(
+        &mut self,
+        data: CscData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let size = sparse_matrix_size(self)?;
+        let (mut column_offsets, mut row_indices, mut values) = data.into_parts();
+
+        check_buffer_length(
+            "column_offsets",
+            column_offsets.len(),
+            size.column_width() + 1,
+        )?;
+        let number_of_stored_elements = column_offsets[size.column_width()];
+        check_buffer_length("row_indices", row_indices.len(), number_of_stored_elements)?;
+        check_buffer_length("values", values.len(), number_of_stored_elements)?;
+
+        ensure_allocation(&mut column_offsets);
+        ensure_allocation(&mut row_indices);
+        ensure_allocation(&mut values);
+
+        let column_offsets_size = allocated_size_in_bytes(&column_offsets)?;
+        let row_indices_size = allocated_size_in_bytes(&row_indices)?;
+        let values_size = allocated_size_in_bytes(&values)?;
+
+        let mut column_offsets_pointer = column_offsets.as_mut_ptr() as *mut GrB_Index;
+        let mut row_indices_pointer = row_indices.as_mut_ptr() as *mut GrB_Index;
+        let mut values_pointer = values.as_mut_ptr() as *mut c_void;
+
+        let graphblas_descriptor = options.graphblas_descriptor_for_call()?;
+        self.context_ref().call(
+            || unsafe {
+                GxB_Matrix_pack_CSC(
+                    self.graphblas_matrix(),
+                    &mut column_offsets_pointer,
+                    &mut row_indices_pointer,
+                    &mut values_pointer,
+                    column_offsets_size,
+                    row_indices_size,
+                    values_size,
+                    false,
+                    false,
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { &self.graphblas_matrix() },
+        )?;
+
+        // GraphBLAS now owns the memory
+        mem::forget(column_offsets);
+        mem::forget(row_indices);
+        mem::forget(values);
+        Ok(())
+    }
+
+    unsafe fn pack_coo(
+        &mut self,
+        data: CooData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let size = sparse_matrix_size(self)?;
+        let (row_indices, column_indices, values) = data.into_parts();
+
+        let mut row_offsets = vec![0; size.row_height() + 1];
+        for row_index in row_indices.iter() {
+            if *row_index >= size.row_height() {
+                return Err(GraphblasError::new(
+                    GraphblasErrorType::IndexOutOfBounds,
+                    format!(
+                        "Row index {} is out of bounds for a matrix with row height {}",
+                        row_index,
+                        size.row_height()
+                    ),
+                )
+                .into());
+            }
+            row_offsets[row_index + 1] += 1;
+        }
+        for row_index in 0..size.row_height() {
+            row_offsets[row_index + 1] += row_offsets[row_index];
+        }
+
+        self.pack_csr(CsrData::new(row_offsets, column_indices, values), options)
+    }
+
+    unsafe fn pack_hyper_sparse(
+        &mut self,
+        data: HyperSparseData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let (storage_format, mut offsets, mut vector_indices, mut indices, mut values) =
+            data.into_parts();
+
+        let number_of_vectors = vector_indices.len();
+        check_buffer_length("offsets", offsets.len(), number_of_vectors + 1)?;
+        let number_of_stored_elements = offsets[number_of_vectors];
+        check_buffer_length("indices", indices.len(), number_of_stored_elements)?;
+        check_buffer_length("values", values.len(), number_of_stored_elements)?;
+
+        ensure_allocation(&mut offsets);
+        ensure_allocation(&mut vector_indices);
+        ensure_allocation(&mut indices);
+        ensure_allocation(&mut values);
+
+        let offsets_size = allocated_size_in_bytes(&offsets)?;
+        let vector_indices_size = allocated_size_in_bytes(&vector_indices)?;
+        let indices_size = allocated_size_in_bytes(&indices)?;
+        let values_size = allocated_size_in_bytes(&values)?;
+        let number_of_vectors = number_of_vectors.to_graphblas_index()?;
+
+        let mut offsets_pointer = offsets.as_mut_ptr() as *mut GrB_Index;
+        let mut vector_indices_pointer = vector_indices.as_mut_ptr() as *mut GrB_Index;
+        let mut indices_pointer = indices.as_mut_ptr() as *mut GrB_Index;
+        let mut values_pointer = values.as_mut_ptr() as *mut c_void;
+
+        let pack_hyper_sparse = match storage_format {
+            MatrixStorageFormat::ByRow => GxB_Matrix_pack_HyperCSR,
+            MatrixStorageFormat::ByColumn => GxB_Matrix_pack_HyperCSC,
+        };
+
+        let graphblas_descriptor = options.graphblas_descriptor_for_call()?;
+        self.context_ref().call(
+            || unsafe {
+                pack_hyper_sparse(
+                    self.graphblas_matrix(),
+                    &mut offsets_pointer,
+                    &mut vector_indices_pointer,
+                    &mut indices_pointer,
+                    &mut values_pointer,
+                    offsets_size,
+                    vector_indices_size,
+                    indices_size,
+                    values_size,
+                    false,
+                    number_of_vectors,
+                    false,
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { &self.graphblas_matrix() },
+        )?;
+
+        // GraphBLAS now owns the memory
+        mem::forget(offsets);
+        mem::forget(vector_indices);
+        mem::forget(indices);
+        mem::forget(values);
+        Ok(())
+    }
+
+    unsafe fn pack_bitmap(
+        &mut self,
+        data: BitmapData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let size = sparse_matrix_size(self)?;
+        let (storage_format, mut bitmap, mut values, number_of_stored_elements) = data.into_parts();
+
+        let number_of_elements = number_of_dense_elements(&size)?;
+        check_buffer_length("bitmap", bitmap.len(), number_of_elements)?;
+        check_buffer_length("values", values.len(), number_of_elements)?;
+
+        ensure_allocation(&mut bitmap);
+        ensure_allocation(&mut values);
+
+        let bitmap_size = allocated_size_in_bytes(&bitmap)?;
+        let values_size = allocated_size_in_bytes(&values)?;
+        let number_of_stored_elements = number_of_stored_elements.to_graphblas_index()?;
+
+        let mut bitmap_pointer = bitmap.as_mut_ptr();
+        let mut values_pointer = values.as_mut_ptr() as *mut c_void;
+
+        let pack_bitmap = match storage_format {
+            MatrixStorageFormat::ByRow => GxB_Matrix_pack_BitmapR,
+            MatrixStorageFormat::ByColumn => GxB_Matrix_pack_BitmapC,
+        };
+
+        let graphblas_descriptor = options.graphblas_descriptor_for_call()?;
+        self.context_ref().call(
+            || unsafe {
+                pack_bitmap(
+                    self.graphblas_matrix(),
+                    &mut bitmap_pointer,
+                    &mut values_pointer,
+                    bitmap_size,
+                    values_size,
+                    false,
+                    number_of_stored_elements,
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { &self.graphblas_matrix() },
+        )?;
+
+        // GraphBLAS now owns the memory
+        mem::forget(bitmap);
+        mem::forget(values);
+        Ok(())
+    }
+
+    unsafe fn pack_full(
+        &mut self,
+        data: FullData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let size = sparse_matrix_size(self)?;
+        let (storage_format, mut values) = data.into_parts();
+
+        check_buffer_length("values", values.len(), number_of_dense_elements(&size)?)?;
+
+        ensure_allocation(&mut values);
+        let values_size = allocated_size_in_bytes(&values)?;
+        let mut values_pointer = values.as_mut_ptr() as *mut c_void;
+
+        let pack_full = match storage_format {
+            MatrixStorageFormat::ByRow => GxB_Matrix_pack_FullR,
+            MatrixStorageFormat::ByColumn => GxB_Matrix_pack_FullC,
+        };
+
+        let graphblas_descriptor = options.graphblas_descriptor_for_call()?;
+        self.context_ref().call(
+            || unsafe {
+                pack_full(
+                    self.graphblas_matrix(),
+                    &mut values_pointer,
+                    values_size,
+                    false,
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { &self.graphblas_matrix() },
+        )?;
+
+        // GraphBLAS now owns the memory
+        mem::forget(values);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::collection::Collection;
+    use crate::collections::sparse_matrix::operations::{
+        GetSparseMatrixElementValue, UnpackSparseMatrix,
+    };
+    use crate::context::Context;
+    use crate::operators::options::{
+        ExtendedDescriptorOptions, OperatorOptions, WithExtendedDescriptorOptions,
+    };
+
+    #[test]
+    fn pack_csr() {
+        let context = Context::init_default().unwrap();
+        let mut matrix = SparseMatrix::<i32>::new(context.clone(), (3, 3).into()).unwrap();
+
+        let data = CsrData::new(vec![0, 2, 2, 3], vec![0, 2, 1], vec![1, 2, 3]);
+        unsafe { matrix.pack_csr(data, &OperatorOptions::new_default()) }.unwrap();
+
+        assert_eq!(matrix.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(matrix.element_value(&0, &2).unwrap(), Some(2));
+        assert_eq!(matrix.element_value(&2, &1).unwrap(), Some(3));
+        assert_eq!(matrix.element_value(&1, &1).unwrap(), None);
+    }
+
+    #[test]
+    fn pack_csr_with_invalid_row_offsets() {
+        let context = Context::init_default().unwrap();
+        let mut matrix = SparseMatrix::<i32>::new(context.clone(), (3, 3).into()).unwrap();
+
+        let data = CsrData::new(vec![0, 2], vec![0, 2], vec![1, 2]);
+        assert!(unsafe { matrix.pack_csr(data, &OperatorOptions::new_default()) }.is_err());
+    }
+
+    #[test]
+    fn pack_csr_with_column_index_out_of_bounds_and_untrusted_input() {
+        let context = Context::init_default().unwrap();
+        let mut matrix = SparseMatrix::<i32>::new(context.clone(), (3, 3).into()).unwrap();
+        let options = OperatorOptions::new_default()
+            .with_extended_descriptor_options(
+                &context,
+                &ExtendedDescriptorOptions::new_default().with_trusted_input(false),
+            )
+            .unwrap();
+
+        let data = CsrData::new(vec![0, 2, 2, 3], vec![0, 3, 1], vec![1, 2, 3]);
+        assert!(unsafe { matrix.pack_csr(data, &options) }.is_err());
+    }
+
+    #[test]
+    fn pack_coo() {
+        let context = Context::init_default().unwrap();
+        let mut matrix = SparseMatrix::<i32>::new(context.clone(), (3, 3).into()).unwrap();
+
+        let data = CooData::new(vec![0, 0, 2], vec![0, 2, 1], vec![1, 2, 3]);
+        unsafe { matrix.pack_coo(data, &OperatorOptions::new_default()) }.unwrap();
+
+        assert_eq!(matrix.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(matrix.element_value(&0, &2).unwrap(), Some(2));
+        assert_eq!(matrix.element_value(&2, &1).unwrap(), Some(3));
+        assert_eq!(matrix.element_value(&1, &1).unwrap(), None);
+    }
+
+    #[test]
+    fn pack_coo_with_row_index_out_of_bounds() {
+        let context = Context::init_default().unwrap();
+        let mut matrix = SparseMatrix::<i32>::new(context.clone(), (3, 3).into()).unwrap();
+
+        let data = CooData::new(vec![0, 3], vec![0, 2], vec![1, 2]);
+        assert!(unsafe { matrix.pack_coo(data, &OperatorOptions::new_default()) }.is_err());
+    }
+
+    #[test]
+    fn pack_and_unpack_full() {
+        let context = Context::init_default().unwrap();
+        let mut matrix = SparseMatrix::<f64>::new(context.clone(), (2, 3).into()).unwrap();
+
+        let data = FullData::new(
+            MatrixStorageFormat::ByRow,
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        );
+        unsafe { matrix.pack_full(data.clone(), &OperatorOptions::new_default()) }.unwrap();
+
+        assert_eq!(matrix.element_value(&1, &0).unwrap(), Some(4.0));
+
+        let unpacked_data = unsafe { matrix.unpack_full(MatrixStorageFormat::ByRow) }.unwrap();
+        assert_eq!(unpacked_data, data);
+    }
+}
@@ -0,0 +1,164 @@
+use once_cell::sync::Lazy;
+
+use crate::collections::sparse_matrix::SparseMatrix;
+use crate::collections::sparse_scalar::{GetScalarValue, SparseScalar};
+use crate::context::GetContext;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{
+    Assignment, BinaryOperator, LogicalAnd, LogicalOr, Max, MaxTyped, Min, MinTyped, Plus,
+    PlusTyped, Times, TimesTyped,
+};
+use crate::operators::options::OptionsForOperatorWithMatrixArgument;
+use crate::operators::reduce::{BinaryOperatorReducer, BinaryOperatorScalarReducer};
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OptionsForOperatorWithMatrixArgument> =
+    Lazy::new(OptionsForOperatorWithMatrixArgument::new_default);
+
+/// Reduces all stored elements to a single value.
+///
+/// Each reduction returns None if the matrix has no stored elements.
+pub trait ReduceSparseMatrix<T: ValueType + Default> {
+    fn sum(&self) -> Result<Option<T>, SparseLinearAlgebraError>
+    where
+        T: PlusTyped<T>;
+    fn product(&self) -> Result<Option<T>, SparseLinearAlgebraError>
+    where
+        T: TimesTyped<T>;
+    fn min(&self) -> Result<Option<T>, SparseLinearAlgebraError>
+    where
+        T: MinTyped<T>;
+    fn max(&self) -> Result<Option<T>, SparseLinearAlgebraError>
+    where
+        T: MaxTyped<T>;
+
+    /// Whether any stored element is true, after casting the elements to bool
+    fn any(&self) -> Result<Option<bool>, SparseLinearAlgebraError>;
+    /// Whether all stored elements are true, after casting the elements to bool
+    fn all(&self) -> Result<Option<bool>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType + Default> ReduceSparseMatrix<T> for SparseMatrix<T>
+where
+    SparseScalar<T>: GetScalarValue<T>,
+{
+    fn sum(&self) -> Result<Option<T>, SparseLinearAlgebraError>
+    where
+        T: PlusTyped<T>,
+    {
+        reduce_to_value(self, &Plus::<T>::new())
+    }
+
+    fn product(&self) -> Result<Option<T>, SparseLinearAlgebraError>
+    where
+        T: TimesTyped<T>,
+    {
+        reduce_to_value(self, &Times::<T>::new())
+    }
+
+    fn min(&self) -> Result<Option<T>, SparseLinearAlgebraError>
+    where
+        T: MinTyped<T>,
+    {
+        reduce_to_value(self, &Min::<T>::new())
+    }
+
+    fn max(&self) -> Result<Option<T>, SparseLinearAlgebraError>
+    where
+        T: MaxTyped<T>,
+    {
+        reduce_to_value(self, &Max::<T>::new())
+    }
+
+    fn any(&self) -> Result<Option<bool>, SparseLinearAlgebraError> {
+        reduce_to_value::<T, bool>(self, &LogicalOr::<bool>::new())
+    }
+
+    fn all(&self) -> Result<Option<bool>, SparseLinearAlgebraError> {
+        reduce_to_value::<T, bool>(self, &LogicalAnd::<bool>::new())
+    }
+}
+
+fn reduce_to_value<T: ValueType, U: ValueType + Default>(
+    matrix: &SparseMatrix<T>,
+    operator: &impl BinaryOperator<U>,
+) -> Result<Option<U>, SparseLinearAlgebraError>
+where
+    SparseScalar<U>: GetScalarValue<U>,
+{
+    let mut product = SparseScalar::<U>::new(matrix.context())?;
+
+    // GraphBLAS typecasts the stored elements to the domain of the operator
+    BinaryOperatorReducer::new().matrix_to_scalar(
+        operator,
+        matrix,
+        &Assignment::<U>::new(),
+        &mut product,
+        &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+    )?;
+
+    product.get_value()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::FromMatrixElementList;
+    use crate::collections::sparse_matrix::{MatrixElementList, Size};
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    #[test]
+    fn reduce_matrix_to_values() {
+        let context = Context::init_default().unwrap();
+        let element_list = MatrixElementList::<i32>::from_element_vector(vec![
+            (0, 0, 3).into(),
+            (1, 2, -2).into(),
+            (2, 1, 5).into(),
+        ]);
+        let matrix = SparseMatrix::<i32>::from_element_list(
+            context,
+            Size::new(3, 3),
+            element_list,
+            &First::<i32>::new(),
+        )
+        .unwrap();
+
+        assert_eq!(matrix.sum().unwrap(), Some(6));
+        assert_eq!(matrix.product().unwrap(), Some(-30));
+        assert_eq!(matrix.min().unwrap(), Some(-2));
+        assert_eq!(matrix.max().unwrap(), Some(5));
+        assert_eq!(matrix.any().unwrap(), Some(true));
+        assert_eq!(matrix.all().unwrap(), Some(true));
+    }
+
+    #[test]
+    fn reduce_boolean_matrix() {
+        let context = Context::init_default().unwrap();
+        let element_list = MatrixElementList::<bool>::from_element_vector(vec![
+            (0, 1, true).into(),
+            (1, 0, false).into(),
+        ]);
+        let matrix = SparseMatrix::<bool>::from_element_list(
+            context,
+            Size::new(2, 2),
+            element_list,
+            &First::<bool>::new(),
+        )
+        .unwrap();
+
+        assert_eq!(matrix.any().unwrap(), Some(true));
+        assert_eq!(matrix.all().unwrap(), Some(false));
+    }
+
+    #[test]
+    fn reduce_empty_matrix() {
+        let context = Context::init_default().unwrap();
+        let matrix = SparseMatrix::<f64>::new(context, Size::new(4, 2)).unwrap();
+
+        assert_eq!(matrix.sum().unwrap(), None);
+        assert_eq!(matrix.max().unwrap(), None);
+        assert_eq!(matrix.any().unwrap(), None);
+    }
+}
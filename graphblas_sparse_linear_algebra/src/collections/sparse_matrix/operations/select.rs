@@ -0,0 +1,85 @@
+use once_cell::sync::Lazy;
+
+use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
+use crate::collections::sparse_matrix::SparseMatrix;
+use crate::context::GetContext;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::Assignment;
+use crate::operators::index_unary_operator::IndexUnaryOperator;
+use crate::operators::mask::SelectEntireMatrix;
+use crate::operators::options::OptionsForOperatorWithMatrixArgument;
+use crate::operators::select::{MatrixSelector, SelectFromMatrix};
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OptionsForOperatorWithMatrixArgument> =
+    Lazy::new(OptionsForOperatorWithMatrixArgument::new_default);
+
+pub trait SelectFromSparseMatrix<T: ValueType> {
+    /// Returns a new matrix with the stored elements for which the selector is true
+    fn select(
+        &self,
+        selector: &impl IndexUnaryOperator<T>,
+        selector_argument: T,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> SelectFromSparseMatrix<T> for SparseMatrix<T>
+where
+    MatrixSelector: SelectFromMatrix<T>,
+{
+    fn select(
+        &self,
+        selector: &impl IndexUnaryOperator<T>,
+        selector_argument: T,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<T>::new(self.context(), self.size()?)?;
+
+        MatrixSelector::new().apply(
+            selector,
+            selector_argument,
+            self,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireMatrix::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(product)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size};
+    use crate::collections::Collection;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+    use crate::operators::index_unary_operator::IsValueGreaterThan;
+
+    #[test]
+    fn select_into_new_matrix() {
+        let context = Context::init_default().unwrap();
+        let matrix = SparseMatrix::<u8>::from_element_list(
+            context,
+            Size::new(2, 2),
+            MatrixElementList::from_element_vector(vec![
+                (0, 0, 1).into(),
+                (0, 1, 5).into(),
+                (1, 0, 9).into(),
+            ]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+
+        let selection = matrix.select(&IsValueGreaterThan::<u8>::new(), 4).unwrap();
+
+        assert_eq!(selection.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(selection.element_value(&0, &0).unwrap(), None);
+        assert_eq!(selection.element_value(&1, &0).unwrap(), Some(9));
+    }
+}
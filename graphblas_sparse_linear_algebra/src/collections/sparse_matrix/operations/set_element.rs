@@ -1,3 +1,5 @@
+use std::ffi::c_void;
+
 use crate::collections::sparse_matrix::element::GetMatrixElementCoordinate;
 use crate::collections::sparse_matrix::element::GetMatrixElementValue;
 use crate::collections::sparse_matrix::ColumnIndex;
@@ -6,11 +8,11 @@ use crate::context::CallGraphBlasContext;
 use crate::graphblas_bindings::{
     GrB_Matrix_setElement_BOOL, GrB_Matrix_setElement_FP32, GrB_Matrix_setElement_FP64,
     GrB_Matrix_setElement_INT16, GrB_Matrix_setElement_INT32, GrB_Matrix_setElement_INT64,
-    GrB_Matrix_setElement_INT8, GrB_Matrix_setElement_UINT16, GrB_Matrix_setElement_UINT32,
-    GrB_Matrix_setElement_UINT64, GrB_Matrix_setElement_UINT8,
+    GrB_Matrix_setElement_INT8, GrB_Matrix_setElement_UDT, GrB_Matrix_setElement_UINT16,
+    GrB_Matrix_setElement_UINT32, GrB_Matrix_setElement_UINT64, GrB_Matrix_setElement_UINT8,
 };
 use crate::index::IndexConversion;
-use crate::value_type::ConvertScalar;
+use crate::value_type::{ConvertScalar, CustomValueType};
 use crate::{
     collections::sparse_matrix::{GetGraphblasSparseMatrix, SparseMatrix},
     error::SparseLinearAlgebraError,
@@ -110,3 +112,39 @@ implement_1_type_macro_for_all_value_types_and_typed_graphblas_function_with_imp
     implement_set_element_typed,
     GrB_Matrix_setElement
 );
+
+impl<T: CustomValueType> SetSparseMatrixElementTyped<T> for T {
+    fn set_graphblas_matrix_value(
+        matrix: &mut impl GetGraphblasSparseMatrix,
+        row_index: RowIndex,
+        column_index: ColumnIndex,
+        mut value: T,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let row_index_to_set = row_index.as_graphblas_index()?;
+        let column_index_to_set = column_index.as_graphblas_index()?;
+        matrix.context_ref().call(
+            || unsafe {
+                GrB_Matrix_setElement_UDT(
+                    matrix.graphblas_matrix(),
+                    &mut value as *mut T as *mut c_void,
+                    row_index_to_set,
+                    column_index_to_set,
+                )
+            },
+            unsafe { &matrix.graphblas_matrix() },
+        )?;
+        Ok(())
+    }
+
+    fn set_graphblas_matrix_element(
+        matrix: &mut impl GetGraphblasSparseMatrix,
+        element: impl GetMatrixElementCoordinate + GetMatrixElementValue<T>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        T::set_graphblas_matrix_value(
+            matrix,
+            element.row_index(),
+            element.column_index(),
+            element.value(),
+        )
+    }
+}
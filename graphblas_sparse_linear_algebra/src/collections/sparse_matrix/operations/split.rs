@@ -0,0 +1,180 @@
+use std::mem::MaybeUninit;
+
+use once_cell::sync::Lazy;
+use suitesparse_graphblas_sys::{GrB_Matrix_free, GxB_Matrix_split};
+
+use crate::collections::sparse_matrix::{
+    ColumnIndex, GetGraphblasSparseMatrix, RowIndex, SparseMatrix,
+};
+use crate::context::{CallGraphBlasContext, GetContext};
+use crate::error::{GraphblasError, GraphblasErrorType, SparseLinearAlgebraError};
+use crate::graphblas_bindings::{GrB_Index, GrB_Matrix};
+use crate::index::IndexConversion;
+use crate::operators::options::{GetGraphblasDescriptor, OperatorOptions};
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OperatorOptions> =
+    Lazy::new(OperatorOptions::new_default);
+
+/// Splits a matrix into a grid of tiles, returned as a vector of tile rows.
+///
+/// The tile row heights must sum to the row height of the matrix,
+/// and the tile column widths must sum to the column width of the matrix.
+pub trait SplitSparseMatrix<T: ValueType> {
+    fn split(
+        &self,
+        tile_row_heights: &[RowIndex],
+        tile_column_widths: &[ColumnIndex],
+    ) -> Result<Vec<Vec<SparseMatrix<T>>>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> SplitSparseMatrix<T> for SparseMatrix<T> {
+    fn split(
+        &self,
+        tile_row_heights: &[RowIndex],
+        tile_column_widths: &[ColumnIndex],
+    ) -> Result<Vec<Vec<SparseMatrix<T>>>, SparseLinearAlgebraError> {
+        if tile_row_heights.is_empty() || tile_column_widths.is_empty() {
+            return Err(GraphblasError::new(
+                GraphblasErrorType::InvalidValue,
+                String::from("At least one tile row and one tile column are required"),
+            )
+            .into());
+        }
+
+        let graphblas_tile_row_heights = to_graphblas_indices(tile_row_heights)?;
+        let graphblas_tile_column_widths = to_graphblas_indices(tile_column_widths)?;
+        let number_of_tile_rows = tile_row_heights.len().to_graphblas_index()?;
+        let number_of_tile_columns = tile_column_widths.len().to_graphblas_index()?;
+
+        let number_of_tiles = tile_row_heights.len() * tile_column_widths.len();
+        let mut graphblas_tiles: Vec<MaybeUninit<GrB_Matrix>> = Vec::with_capacity(number_of_tiles);
+        graphblas_tiles.resize_with(number_of_tiles, MaybeUninit::uninit);
+
+        self.context_ref().call(
+            || unsafe {
+                GxB_Matrix_split(
+                    graphblas_tiles.as_mut_ptr() as *mut GrB_Matrix,
+                    number_of_tile_rows,
+                    number_of_tile_columns,
+                    graphblas_tile_row_heights.as_ptr(),
+                    graphblas_tile_column_widths.as_ptr(),
+                    self.graphblas_matrix(),
+                    DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+                )
+            },
+            unsafe { self.graphblas_matrix_ref() },
+        )?;
+
+        // Every tile is owned by a SparseMatrix as soon as possible, such that no tile leaks on an error
+        let mut graphblas_tiles = graphblas_tiles
+            .into_iter()
+            .map(|graphblas_tile| unsafe { graphblas_tile.assume_init() });
+        let mut tiles = Vec::with_capacity(number_of_tiles);
+        while let Some(graphblas_tile) = graphblas_tiles.next() {
+            match unsafe {
+                SparseMatrix::<T>::from_graphblas_matrix(self.context(), graphblas_tile)
+            } {
+                Ok(tile) => tiles.push(tile),
+                Err(error) => {
+                    for mut graphblas_tile in std::iter::once(graphblas_tile).chain(graphblas_tiles)
+                    {
+                        let _ =
+                            self.context_ref()
+                                .call_without_detailed_error_information(|| unsafe {
+                                    GrB_Matrix_free(&mut graphblas_tile)
+                                });
+                    }
+                    return Err(error);
+                }
+            }
+        }
+
+        let mut tiles = tiles.into_iter();
+        Ok((0..tile_row_heights.len())
+            .map(|_| tiles.by_ref().take(tile_column_widths.len()).collect())
+            .collect())
+    }
+}
+
+fn to_graphblas_indices(indices: &[RowIndex]) -> Result<Vec<GrB_Index>, SparseLinearAlgebraError> {
+    let mut graphblas_indices = Vec::with_capacity(indices.len());
+    for index in indices {
+        graphblas_indices.push(index.to_graphblas_index()?);
+    }
+    Ok(graphblas_indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::collection::Collection;
+    use crate::collections::sparse_matrix::operations::{
+        ConcatenateSparseMatrices, FromMatrixElementList, GetSparseMatrixElementList,
+        GetSparseMatrixElementValue, GetSparseMatrixSize,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size};
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    fn test_matrix() -> SparseMatrix<f32> {
+        let context = Context::init_default().unwrap();
+        let element_list = MatrixElementList::<f32>::from_element_vector(vec![
+            (0, 0, 1.0).into(),
+            (1, 3, 2.0).into(),
+            (2, 0, 3.0).into(),
+            (4, 2, 4.0).into(),
+        ]);
+        SparseMatrix::<f32>::from_element_list(
+            context,
+            Size::new(5, 4),
+            element_list,
+            &First::<f32>::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn split_into_tiles() {
+        let matrix = test_matrix();
+
+        let tiles = matrix.split(&[2, 3], &[1, 3]).unwrap();
+
+        assert_eq!(tiles.len(), 2);
+        assert_eq!(tiles[0].len(), 2);
+        assert_eq!(tiles[0][0].size().unwrap(), Size::new(2, 1));
+        assert_eq!(tiles[0][1].size().unwrap(), Size::new(2, 3));
+        assert_eq!(tiles[1][0].size().unwrap(), Size::new(3, 1));
+        assert_eq!(tiles[1][1].size().unwrap(), Size::new(3, 3));
+
+        assert_eq!(tiles[0][0].element_value(&0, &0).unwrap(), Some(1.0));
+        assert_eq!(tiles[0][1].element_value(&1, &2).unwrap(), Some(2.0));
+        assert_eq!(tiles[1][0].element_value(&0, &0).unwrap(), Some(3.0));
+        assert_eq!(tiles[1][1].element_value(&2, &1).unwrap(), Some(4.0));
+        assert_eq!(tiles[1][1].number_of_stored_elements().unwrap(), 1);
+    }
+
+    #[test]
+    fn split_and_concatenate() {
+        let matrix = test_matrix();
+
+        let tiles = matrix.split(&[1, 1, 3], &[4]).unwrap();
+        let concatenated_matrix =
+            SparseMatrix::<f32>::from_tiles(matrix.context(), &tiles).unwrap();
+
+        assert_eq!(concatenated_matrix.size().unwrap(), matrix.size().unwrap());
+        assert_eq!(
+            concatenated_matrix.element_list().unwrap(),
+            matrix.element_list().unwrap()
+        );
+    }
+
+    #[test]
+    fn split_with_invalid_partition() {
+        let matrix = test_matrix();
+
+        assert!(matrix.split(&[2, 2], &[4]).is_err());
+        assert!(matrix.split(&[5], &[]).is_err());
+    }
+}
@@ -0,0 +1,231 @@
+use std::mem::MaybeUninit;
+
+use suitesparse_graphblas_sys::{
+    GxB_Format_Value, GxB_Matrix_Option_get_FP64, GxB_Matrix_Option_get_INT32,
+    GxB_Matrix_Option_set_FP64, GxB_Matrix_Option_set_INT32, GxB_Option_Field,
+    GxB_Option_Field_GxB_BITMAP_SWITCH, GxB_Option_Field_GxB_FORMAT,
+    GxB_Option_Field_GxB_HYPER_SWITCH, GxB_Option_Field_GxB_SPARSITY_CONTROL,
+    GxB_Option_Field_GxB_SPARSITY_STATUS,
+};
+
+use crate::collections::sparse_matrix::{GetGraphblasSparseMatrix, SparseMatrix};
+use crate::context::{
+    CallGraphBlasContext, MatrixStorageFormat, SparsityControl, SparsityStructure,
+};
+use crate::error::SparseLinearAlgebraError;
+use crate::value_type::ValueType;
+
+/// Queries how GraphBLAS stores a matrix.
+pub trait GetSparseMatrixStorageOptions {
+    fn storage_format(&self) -> Result<MatrixStorageFormat, SparseLinearAlgebraError>;
+    fn sparsity_control(&self) -> Result<SparsityControl, SparseLinearAlgebraError>;
+    /// The structure in which the matrix is currently stored
+    fn sparsity_structure(&self) -> Result<SparsityStructure, SparseLinearAlgebraError>;
+    fn hyper_switch(&self) -> Result<f64, SparseLinearAlgebraError>;
+    fn bitmap_switch(&self) -> Result<f64, SparseLinearAlgebraError>;
+}
+
+/// Controls how GraphBLAS stores a matrix, overriding the global settings of the Context.
+///
+/// The elements of the matrix are unaffected, only their representation changes.
+pub trait SetSparseMatrixStorageOptions {
+    fn set_storage_format(
+        &mut self,
+        storage_format: MatrixStorageFormat,
+    ) -> Result<(), SparseLinearAlgebraError>;
+    fn set_sparsity_control(
+        &mut self,
+        sparsity_control: SparsityControl,
+    ) -> Result<(), SparseLinearAlgebraError>;
+    /// The matrix becomes hypersparse when the fraction of non-empty rows or columns is below the hyper switch,
+    /// and sparse when it exceeds twice the hyper switch.
+    /// A negative value prevents, and a value above 1 enforces, a hypersparse structure.
+    fn set_hyper_switch(&mut self, hyper_switch: f64) -> Result<(), SparseLinearAlgebraError>;
+    /// The matrix becomes bitmap when the fraction of stored elements exceeds the bitmap switch,
+    /// and sparse when it drops below half the bitmap switch.
+    fn set_bitmap_switch(&mut self, bitmap_switch: f64) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> GetSparseMatrixStorageOptions for SparseMatrix<T> {
+    fn storage_format(&self) -> Result<MatrixStorageFormat, SparseLinearAlgebraError> {
+        let format = get_int32_option(self, GxB_Option_Field_GxB_FORMAT)?;
+        MatrixStorageFormat::from_graphblas_format_value(format as GxB_Format_Value)
+    }
+
+    fn sparsity_control(&self) -> Result<SparsityControl, SparseLinearAlgebraError> {
+        let graphblas_flags = get_int32_option(self, GxB_Option_Field_GxB_SPARSITY_CONTROL)?;
+        Ok(SparsityControl::from_graphblas_flags(graphblas_flags))
+    }
+
+    fn sparsity_structure(&self) -> Result<SparsityStructure, SparseLinearAlgebraError> {
+        let status = get_int32_option(self, GxB_Option_Field_GxB_SPARSITY_STATUS)?;
+        SparsityStructure::from_graphblas_sparsity_status(status)
+    }
+
+    fn hyper_switch(&self) -> Result<f64, SparseLinearAlgebraError> {
+        get_fp64_option(self, GxB_Option_Field_GxB_HYPER_SWITCH)
+    }
+
+    fn bitmap_switch(&self) -> Result<f64, SparseLinearAlgebraError> {
+        get_fp64_option(self, GxB_Option_Field_GxB_BITMAP_SWITCH)
+    }
+}
+
+impl<T: ValueType> SetSparseMatrixStorageOptions for SparseMatrix<T> {
+    fn set_storage_format(
+        &mut self,
+        storage_format: MatrixStorageFormat,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let format: GxB_Format_Value = storage_format.into();
+        set_int32_option(self, GxB_Option_Field_GxB_FORMAT, format as i32)
+    }
+
+    fn set_sparsity_control(
+        &mut self,
+        sparsity_control: SparsityControl,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        set_int32_option(
+            self,
+            GxB_Option_Field_GxB_SPARSITY_CONTROL,
+            sparsity_control.to_graphblas_flags(),
+        )
+    }
+
+    fn set_hyper_switch(&mut self, hyper_switch: f64) -> Result<(), SparseLinearAlgebraError> {
+        set_fp64_option(self, GxB_Option_Field_GxB_HYPER_SWITCH, hyper_switch)
+    }
+
+    fn set_bitmap_switch(&mut self, bitmap_switch: f64) -> Result<(), SparseLinearAlgebraError> {
+        set_fp64_option(self, GxB_Option_Field_GxB_BITMAP_SWITCH, bitmap_switch)
+    }
+}
+
+fn get_int32_option(
+    matrix: &impl GetGraphblasSparseMatrix,
+    field: GxB_Option_Field,
+) -> Result<i32, SparseLinearAlgebraError> {
+    let mut value: MaybeUninit<i32> = MaybeUninit::uninit();
+    matrix.context_ref().call(
+        || unsafe {
+            GxB_Matrix_Option_get_INT32(matrix.graphblas_matrix(), field, value.as_mut_ptr())
+        },
+        unsafe { matrix.graphblas_matrix_ref() },
+    )?;
+    Ok(unsafe { value.assume_init() })
+}
+
+fn get_fp64_option(
+    matrix: &impl GetGraphblasSparseMatrix,
+    field: GxB_Option_Field,
+) -> Result<f64, SparseLinearAlgebraError> {
+    let mut value: MaybeUninit<f64> = MaybeUninit::uninit();
+    matrix.context_ref().call(
+        || unsafe {
+            GxB_Matrix_Option_get_FP64(matrix.graphblas_matrix(), field, value.as_mut_ptr())
+        },
+        unsafe { matrix.graphblas_matrix_ref() },
+    )?;
+    Ok(unsafe { value.assume_init() })
+}
+
+fn set_int32_option(
+    matrix: &mut impl GetGraphblasSparseMatrix,
+    field: GxB_Option_Field,
+    value: i32,
+) -> Result<(), SparseLinearAlgebraError> {
+    matrix.context_ref().call(
+        || unsafe { GxB_Matrix_Option_set_INT32(matrix.graphblas_matrix(), field, value) },
+        unsafe { matrix.graphblas_matrix_ref() },
+    )?;
+    Ok(())
+}
+
+fn set_fp64_option(
+    matrix: &mut impl GetGraphblasSparseMatrix,
+    field: GxB_Option_Field,
+    value: f64,
+) -> Result<(), SparseLinearAlgebraError> {
+    matrix.context_ref().call(
+        || unsafe { GxB_Matrix_Option_set_FP64(matrix.graphblas_matrix(), field, value) },
+        unsafe { matrix.graphblas_matrix_ref() },
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementList,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size};
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    fn test_matrix() -> SparseMatrix<u32> {
+        let context = Context::init_default().unwrap();
+        let element_list =
+            MatrixElementList::<u32>::from_element_vector(vec![(0, 0, 1).into(), (3, 2, 2).into()]);
+        SparseMatrix::<u32>::from_element_list(
+            context,
+            Size::new(10, 10),
+            element_list,
+            &First::<u32>::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn set_storage_format() {
+        let mut matrix = test_matrix();
+        let element_list = matrix.element_list().unwrap();
+
+        assert_eq!(matrix.storage_format().unwrap(), MatrixStorageFormat::ByRow);
+
+        matrix
+            .set_storage_format(MatrixStorageFormat::ByColumn)
+            .unwrap();
+        assert_eq!(
+            matrix.storage_format().unwrap(),
+            MatrixStorageFormat::ByColumn
+        );
+        assert_eq!(matrix.element_list().unwrap(), element_list);
+    }
+
+    #[test]
+    fn set_sparsity_control() {
+        let mut matrix = test_matrix();
+
+        matrix
+            .set_sparsity_control(SparsityControl::new(&[SparsityStructure::Bitmap]))
+            .unwrap();
+        assert_eq!(
+            matrix.sparsity_control().unwrap().allowed_structures(),
+            vec![SparsityStructure::Bitmap]
+        );
+        assert_eq!(
+            matrix.sparsity_structure().unwrap(),
+            SparsityStructure::Bitmap
+        );
+
+        matrix
+            .set_sparsity_control(SparsityControl::new(&[SparsityStructure::HyperSparse]))
+            .unwrap();
+        assert_eq!(
+            matrix.sparsity_structure().unwrap(),
+            SparsityStructure::HyperSparse
+        );
+    }
+
+    #[test]
+    fn set_switches() {
+        let mut matrix = test_matrix();
+
+        matrix.set_hyper_switch(0.25).unwrap();
+        assert_eq!(matrix.hyper_switch().unwrap(), 0.25);
+
+        matrix.set_bitmap_switch(0.5).unwrap();
+        assert_eq!(matrix.bitmap_switch().unwrap(), 0.5);
+    }
+}
@@ -0,0 +1,70 @@
+use once_cell::sync::Lazy;
+
+use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
+use crate::collections::sparse_matrix::{GetMatrixDimensions, Size, SparseMatrix};
+use crate::context::GetContext;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::Assignment;
+use crate::operators::mask::SelectEntireMatrix;
+use crate::operators::options::OptionsForOperatorWithMatrixArgument;
+use crate::operators::transpose::{MatrixTranspose, TransposeMatrix};
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OptionsForOperatorWithMatrixArgument> =
+    Lazy::new(OptionsForOperatorWithMatrixArgument::new_default);
+
+pub trait TransposeSparseMatrix<T: ValueType> {
+    /// Returns the transpose as a new matrix
+    fn transposed(&self) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> TransposeSparseMatrix<T> for SparseMatrix<T> {
+    fn transposed(&self) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let size = self.size()?;
+        let mut transpose = SparseMatrix::<T>::new(
+            self.context(),
+            Size::new(size.column_width(), size.row_height()),
+        )?;
+
+        MatrixTranspose::new().apply(
+            self,
+            &Assignment::<T>::new(),
+            &mut transpose,
+            &SelectEntireMatrix::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(transpose)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::MatrixElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    #[test]
+    fn transpose_into_new_matrix() {
+        let context = Context::init_default().unwrap();
+        let matrix = SparseMatrix::<u8>::from_element_list(
+            context,
+            Size::new(2, 3),
+            MatrixElementList::from_element_vector(vec![(0, 2, 1).into(), (1, 0, 2).into()]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+
+        let transpose = matrix.transposed().unwrap();
+
+        assert_eq!(transpose.size().unwrap(), Size::new(3, 2));
+        assert_eq!(transpose.element_value(&2, &0).unwrap(), Some(1));
+        assert_eq!(transpose.element_value(&0, &1).unwrap(), Some(2));
+        assert_eq!(transpose.element_value(&0, &2).unwrap(), None);
+    }
+}
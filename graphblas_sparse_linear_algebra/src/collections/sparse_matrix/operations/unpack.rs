@@ -0,0 +1,443 @@
+use std::ffi::c_void;
+use std::ptr;
+
+use once_cell::sync::Lazy;
+use suitesparse_graphblas_sys::{
+    GxB_Matrix_unpack_BitmapC, GxB_Matrix_unpack_BitmapR, GxB_Matrix_unpack_CSC,
+    GxB_Matrix_unpack_CSR, GxB_Matrix_unpack_FullC, GxB_Matrix_unpack_FullR,
+    GxB_Matrix_unpack_HyperCSC, GxB_Matrix_unpack_HyperCSR,
+};
+
+use crate::collections::graphblas_buffer::{number_of_dense_elements, GraphblasBuffer};
+use crate::collections::sparse_matrix::data::{
+    BitmapData, CooData, CscData, CsrData, FullData, HyperSparseData,
+};
+use crate::collections::sparse_matrix::operations::sparse_matrix_size;
+use crate::collections::sparse_matrix::{
+    GetGraphblasSparseMatrix, GetMatrixDimensions, SparseMatrix,
+};
+use crate::context::{CallGraphBlasContext, GetContext, MatrixStorageFormat};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::GrB_Index;
+use crate::index::{ElementCount, ElementIndex, IndexConversion};
+use crate::operators::options::{GetGraphblasDescriptor, OperatorOptions};
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OperatorOptions> =
+    Lazy::new(OperatorOptions::new_default);
+
+/// Takes ownership of the data of a matrix from GraphBLAS, without copying.
+///
+/// The matrix keeps its size, and has no stored elements afterwards.
+/// GraphBLAS converts the matrix to the requested format first if required, which may take more than O(1) time.
+///
+/// # Safety
+/// The data vectors are allocated by GraphBLAS.
+/// This requires the Rust global allocator to be the system allocator, which is the default.
+pub trait UnpackSparseMatrix<T: ValueType> {
+    /// # Safety
+    /// See the safety requirements of UnpackSparseMatrix.
+    unsafe fn unpack_csr(&mut self) -> Result<CsrData<T>, SparseLinearAlgebraError>;
+    /// # Safety
+    /// See the safety requirements of UnpackSparseMatrix.
+    unsafe fn unpack_csc(&mut self) -> Result<CscData<T>, SparseLinearAlgebraError>;
+    /// GraphBLAS does not store coordinate lists. The row offsets are expanded into row indices in O(n) time,
+    /// the column indices and values are taken over without copying.
+    ///
+    /// # Safety
+    /// See the safety requirements of UnpackSparseMatrix.
+    unsafe fn unpack_coo(&mut self) -> Result<CooData<T>, SparseLinearAlgebraError>;
+    /// # Safety
+    /// See the safety requirements of UnpackSparseMatrix.
+    unsafe fn unpack_hyper_sparse(
+        &mut self,
+        storage_format: MatrixStorageFormat,
+    ) -> Result<HyperSparseData<T>, SparseLinearAlgebraError>;
+    /// The values of absent elements are T::default().
+    ///
+    /// # Safety
+    /// See the safety requirements of UnpackSparseMatrix.
+    unsafe fn unpack_bitmap(
+        &mut self,
+        storage_format: MatrixStorageFormat,
+    ) -> Result<BitmapData<T>, SparseLinearAlgebraError>;
+    /// Fails if not every element of the matrix is stored.
+    ///
+    /// # Safety
+    /// See the safety requirements of UnpackSparseMatrix.
+    unsafe fn unpack_full(
+        &mut self,
+        storage_format: MatrixStorageFormat,
+    ) -> Result<FullData<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType + Default> UnpackSparseMatrix<T> for SparseMatrix<T> {
+    unsafe fn unpack_csr(&mut self) -> Result<CsrData<T>, SparseLinearAlgebraError> {
+        let size = sparse_matrix_size(self)?;
+
+        let mut row_offsets_pointer: *mut GrB_Index = ptr::null_mut();
+        let mut column_indices_pointer: *mut GrB_Index = ptr::null_mut();
+        let mut values_pointer: *mut c_void = ptr::null_mut();
+        let mut row_offsets_size: GrB_Index = 0;
+        let mut column_indices_size: GrB_Index = 0;
+        let mut values_size: GrB_Index = 0;
+
+        // Passing null for iso and jumbled requests non-iso values and sorted indices
+        self.context_ref().call(
+            || unsafe {
+                GxB_Matrix_unpack_CSR(
+                    self.graphblas_matrix(),
+                    &mut row_offsets_pointer,
+                    &mut column_indices_pointer,
+                    &mut values_pointer,
+                    &mut row_offsets_size,
+                    &mut column_indices_size,
+                    &mut values_size,
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                    DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+                )
+            },
+            unsafe { &self.graphblas_matrix() },
+        )?;
+
+        let row_offsets =
+            GraphblasBuffer::new(row_offsets_pointer as *mut ElementIndex, row_offsets_size);
+        let column_indices = GraphblasBuffer::new(
+            column_indices_pointer as *mut ElementIndex,
+            column_indices_size,
+        );
+        let values = GraphblasBuffer::new(values_pointer as *mut T, values_size);
+
+        let row_offsets = row_offsets.into_vec(size.row_height() + 1)?;
+        let number_of_stored_elements = row_offsets[size.row_height()];
+        let column_indices = column_indices.into_vec(number_of_stored_elements)?;
+        let values = values.into_vec(number_of_stored_elements)?;
+
+        Ok(CsrData::new(row_offsets, column_indices, values))
+    }
+
+    unsafe fn unpack_csc(&mut self) -> Result<CscData<T>, SparseLinearAlgebraError> {
+        let size = sparse_matrix_size(self)?;
+
+        let mut column_offsets_pointer: *mut GrB_Index = ptr::null_mut();
+        let mut row_indices_pointer: *mut GrB_Index = ptr::null_mut();
+        let mut values_pointer: *mut c_void = ptr::null_mut();
+        let mut column_offsets_size: GrB_Index = 0;
+        let mut row_indices_size: GrB_Index = 0;
+        let mut values_size: GrB_Index = 0;
+
+        // Passing null for iso and jumbled requests non-iso values and sorted indices
+        self.context_ref().call(
+            || unsafe {
+                GxB_Matrix_unpack_CSC(
+                    self.graphblas_matrix(),
+                    &mut column_offsets_pointer,
+                    &mut row_indices_pointer,
+                    &mut values_pointer,
+                    &mut column_offsets_size,
+                    &mut row_indices_size,
+                    &mut values_size,
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                    DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+                )
+            },
+            unsafe { &self.graphblas_matrix() },
+        )?;
+
+        let column_offsets = GraphblasBuffer::new(
+            column_offsets_pointer as *mut ElementIndex,
+            column_offsets_size,
+        );
+        let row_indices =
+            GraphblasBuffer::new(row_indices_pointer as *mut ElementIndex, row_indices_size);
+        let values = GraphblasBuffer::new(values_pointer as *mut T, values_size);
+
+        let column_offsets = column_offsets.into_vec(size.column_width() + 1)?;
+        let number_of_stored_elements = column_offsets[size.column_width()];
+        let row_indices = row_indices.into_vec(number_of_stored_elements)?;
+        let values = values.into_vec(number_of_stored_elements)?;
+
+        Ok(CscData::new(column_offsets, row_indices, values))
+    }
+
+    unsafe fn unpack_coo(&mut self) -> Result<CooData<T>, SparseLinearAlgebraError> {
+        let (row_offsets, column_indices, values) = self.unpack_csr()?.into_parts();
+
+        let mut row_indices = Vec::with_capacity(column_indices.len());
+        for (row_index, row_offset_range) in row_offsets.windows(2).enumerate() {
+            row_indices.resize(row_offset_range[1], row_index);
+        }
+
+        Ok(CooData::new(row_indices, column_indices, values))
+    }
+
+    unsafe fn unpack_hyper_sparse(
+        &mut self,
+        storage_format: MatrixStorageFormat,
+    ) -> Result<HyperSparseData<T>, SparseLinearAlgebraError> {
+        let mut offsets_pointer: *mut GrB_Index = ptr::null_mut();
+        let mut vector_indices_pointer: *mut GrB_Index = ptr::null_mut();
+        let mut indices_pointer: *mut GrB_Index = ptr::null_mut();
+        let mut values_pointer: *mut c_void = ptr::null_mut();
+        let mut offsets_size: GrB_Index = 0;
+        let mut vector_indices_size: GrB_Index = 0;
+        let mut indices_size: GrB_Index = 0;
+        let mut values_size: GrB_Index = 0;
+        let mut number_of_vectors: GrB_Index = 0;
+
+        let unpack_hyper_sparse = match storage_format {
+            MatrixStorageFormat::ByRow => GxB_Matrix_unpack_HyperCSR,
+            MatrixStorageFormat::ByColumn => GxB_Matrix_unpack_HyperCSC,
+        };
+
+        // Passing null for iso and jumbled requests non-iso values and sorted indices
+        self.context_ref().call(
+            || unsafe {
+                unpack_hyper_sparse(
+                    self.graphblas_matrix(),
+                    &mut offsets_pointer,
+                    &mut vector_indices_pointer,
+                    &mut indices_pointer,
+                    &mut values_pointer,
+                    &mut offsets_size,
+                    &mut vector_indices_size,
+                    &mut indices_size,
+                    &mut values_size,
+                    ptr::null_mut(),
+                    &mut number_of_vectors,
+                    ptr::null_mut(),
+                    DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+                )
+            },
+            unsafe { &self.graphblas_matrix() },
+        )?;
+
+        let offsets = GraphblasBuffer::new(offsets_pointer as *mut ElementIndex, offsets_size);
+        let vector_indices = GraphblasBuffer::new(
+            vector_indices_pointer as *mut ElementIndex,
+            vector_indices_size,
+        );
+        let indices = GraphblasBuffer::new(indices_pointer as *mut ElementIndex, indices_size);
+        let values = GraphblasBuffer::new(values_pointer as *mut T, values_size);
+
+        let number_of_vectors = ElementCount::from_graphblas_index(number_of_vectors)?;
+        let offsets = offsets.into_vec(number_of_vectors + 1)?;
+        let number_of_stored_elements = offsets[number_of_vectors];
+        let vector_indices = vector_indices.into_vec(number_of_vectors)?;
+        let indices = indices.into_vec(number_of_stored_elements)?;
+        let values = values.into_vec(number_of_stored_elements)?;
+
+        Ok(HyperSparseData::new(
+            storage_format,
+            offsets,
+            vector_indices,
+            indices,
+            values,
+        ))
+    }
+
+    unsafe fn unpack_bitmap(
+        &mut self,
+        storage_format: MatrixStorageFormat,
+    ) -> Result<BitmapData<T>, SparseLinearAlgebraError> {
+        let size = sparse_matrix_size(self)?;
+
+        let mut bitmap_pointer: *mut i8 = ptr::null_mut();
+        let mut values_pointer: *mut c_void = ptr::null_mut();
+        let mut bitmap_size: GrB_Index = 0;
+        let mut values_size: GrB_Index = 0;
+        let mut number_of_stored_elements: GrB_Index = 0;
+
+        let unpack_bitmap = match storage_format {
+            MatrixStorageFormat::ByRow => GxB_Matrix_unpack_BitmapR,
+            MatrixStorageFormat::ByColumn => GxB_Matrix_unpack_BitmapC,
+        };
+
+        // Passing null for iso requests non-iso values
+        self.context_ref().call(
+            || unsafe {
+                unpack_bitmap(
+                    self.graphblas_matrix(),
+                    &mut bitmap_pointer,
+                    &mut values_pointer,
+                    &mut bitmap_size,
+                    &mut values_size,
+                    ptr::null_mut(),
+                    &mut number_of_stored_elements,
+                    DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+                )
+            },
+            unsafe { &self.graphblas_matrix() },
+        )?;
+
+        let bitmap = GraphblasBuffer::new(bitmap_pointer, bitmap_size);
+        let values = GraphblasBuffer::new(values_pointer as *mut T, values_size);
+
+        let bitmap = bitmap.into_vec(number_of_dense_elements(&size)?)?;
+        let values = values.into_vec_with_bitmap(&bitmap)?;
+
+        Ok(BitmapData::from_parts(
+            storage_format,
+            bitmap,
+            values,
+            ElementCount::from_graphblas_index(number_of_stored_elements)?,
+        ))
+    }
+
+    unsafe fn unpack_full(
+        &mut self,
+        storage_format: MatrixStorageFormat,
+    ) -> Result<FullData<T>, SparseLinearAlgebraError> {
+        let size = sparse_matrix_size(self)?;
+
+        let mut values_pointer: *mut c_void = ptr::null_mut();
+        let mut values_size: GrB_Index = 0;
+
+        let unpack_full = match storage_format {
+            MatrixStorageFormat::ByRow => GxB_Matrix_unpack_FullR,
+            MatrixStorageFormat::ByColumn => GxB_Matrix_unpack_FullC,
+        };
+
+        // Passing null for iso requests non-iso values
+        self.context_ref().call(
+            || unsafe {
+                unpack_full(
+                    self.graphblas_matrix(),
+                    &mut values_pointer,
+                    &mut values_size,
+                    ptr::null_mut(),
+                    DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+                )
+            },
+            unsafe { &self.graphblas_matrix() },
+        )?;
+
+        let values = GraphblasBuffer::new(values_pointer as *mut T, values_size)
+            .into_vec(number_of_dense_elements(&size)?)?;
+
+        Ok(FullData::new(storage_format, values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::collection::Collection;
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementList, PackSparseMatrix,
+    };
+    use crate::collections::sparse_matrix::MatrixElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    fn test_matrix(context: &std::sync::Arc<Context>) -> SparseMatrix<i32> {
+        let element_list = MatrixElementList::<i32>::from_element_vector(vec![
+            (0, 1, 1).into(),
+            (0, 3, 2).into(),
+            (2, 2, 3).into(),
+            (3, 0, 4).into(),
+        ]);
+
+        SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            (4, 5).into(),
+            element_list,
+            &First::<i32>::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn unpack_and_pack_csr() {
+        let context = Context::init_default().unwrap();
+        let mut matrix = test_matrix(&context);
+        let expected_element_list = matrix.element_list().unwrap();
+
+        let data = unsafe { matrix.unpack_csr() }.unwrap();
+        assert_eq!(data.row_offsets_ref(), &[0, 2, 2, 3, 4]);
+        assert_eq!(data.column_indices_ref(), &[1, 3, 2, 0]);
+        assert_eq!(data.values_ref(), &[1, 2, 3, 4]);
+        assert_eq!(matrix.number_of_stored_elements().unwrap(), 0);
+
+        unsafe { matrix.pack_csr(data, &OperatorOptions::new_default()) }.unwrap();
+        assert_eq!(matrix.element_list().unwrap(), expected_element_list);
+    }
+
+    #[test]
+    fn unpack_and_pack_csc() {
+        let context = Context::init_default().unwrap();
+        let mut matrix = test_matrix(&context);
+        let expected_element_list = matrix.element_list().unwrap();
+
+        let data = unsafe { matrix.unpack_csc() }.unwrap();
+        assert_eq!(data.column_offsets_ref(), &[0, 1, 2, 3, 4, 4]);
+        assert_eq!(data.row_indices_ref(), &[3, 0, 2, 0]);
+        assert_eq!(data.values_ref(), &[4, 1, 3, 2]);
+
+        unsafe { matrix.pack_csc(data, &OperatorOptions::new_default()) }.unwrap();
+        assert_eq!(matrix.element_list().unwrap(), expected_element_list);
+    }
+
+    #[test]
+    fn unpack_and_pack_coo() {
+        let context = Context::init_default().unwrap();
+        let mut matrix = test_matrix(&context);
+        let expected_element_list = matrix.element_list().unwrap();
+
+        let data = unsafe { matrix.unpack_coo() }.unwrap();
+        assert_eq!(data.row_indices_ref(), &[0, 0, 2, 3]);
+        assert_eq!(data.column_indices_ref(), &[1, 3, 2, 0]);
+        assert_eq!(data.values_ref(), &[1, 2, 3, 4]);
+        assert_eq!(matrix.number_of_stored_elements().unwrap(), 0);
+
+        unsafe { matrix.pack_coo(data, &OperatorOptions::new_default()) }.unwrap();
+        assert_eq!(matrix.element_list().unwrap(), expected_element_list);
+    }
+
+    #[test]
+    fn unpack_and_pack_hyper_sparse() {
+        let context = Context::init_default().unwrap();
+        let mut matrix = test_matrix(&context);
+        let expected_element_list = matrix.element_list().unwrap();
+
+        let data = unsafe { matrix.unpack_hyper_sparse(MatrixStorageFormat::ByRow) }.unwrap();
+        assert_eq!(data.vector_indices_ref(), &[0, 2, 3]);
+        assert_eq!(data.offsets_ref(), &[0, 2, 3, 4]);
+        assert_eq!(data.indices_ref(), &[1, 3, 2, 0]);
+
+        unsafe { matrix.pack_hyper_sparse(data, &OperatorOptions::new_default()) }.unwrap();
+        assert_eq!(matrix.element_list().unwrap(), expected_element_list);
+    }
+
+    #[test]
+    fn unpack_and_pack_bitmap() {
+        let context = Context::init_default().unwrap();
+        let mut matrix = test_matrix(&context);
+        let expected_element_list = matrix.element_list().unwrap();
+
+        let data = unsafe { matrix.unpack_bitmap(MatrixStorageFormat::ByColumn) }.unwrap();
+        assert_eq!(data.number_of_stored_elements(), 4);
+        assert_eq!(data.bitmap_ref().len(), 20);
+        assert_eq!(data.bitmap_ref()[3], 1);
+        assert_eq!(data.values_ref()[3], 4);
+        for (is_stored, value) in data.bitmap_ref().iter().zip(data.values_ref()) {
+            if *is_stored == 0 {
+                assert_eq!(*value, 0);
+            }
+        }
+
+        unsafe { matrix.pack_bitmap(data, &OperatorOptions::new_default()) }.unwrap();
+        assert_eq!(matrix.element_list().unwrap(), expected_element_list);
+    }
+
+    #[test]
+    fn unpack_full_from_sparse_matrix() {
+        let context = Context::init_default().unwrap();
+        let mut matrix = test_matrix(&context);
+
+        assert!(unsafe { matrix.unpack_full(MatrixStorageFormat::ByRow) }.is_err());
+    }
+}
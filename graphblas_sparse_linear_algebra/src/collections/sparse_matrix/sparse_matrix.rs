@@ -1,22 +1,28 @@
 use std::marker::{PhantomData, Send, Sync};
 use std::mem::MaybeUninit;
+use std::ptr;
 use std::sync::Arc;
 
 use suitesparse_graphblas_sys::GrB_Type;
 
 use crate::collections::collection::Collection;
+use crate::collections::{pending_work_from_graphblas_report, PendingWork, WaitMode};
 use crate::error::SparseLinearAlgebraError;
 use crate::graphblas_bindings::{
     GrB_Index, GrB_Matrix, GrB_Matrix_clear, GrB_Matrix_dup, GrB_Matrix_free, GrB_Matrix_new,
     GrB_Matrix_nvals,
 };
-use crate::operators::mask::MatrixMask;
+use crate::graphblas_bindings::{GrB_Matrix_wait, GxB_Matrix_fprint, GxB_Print_Level_GxB_SUMMARY};
+use crate::operators::mask::{GetMaskOptions, MatrixMask};
 
 use super::element::MatrixElementList;
 use super::size::{GetMatrixDimensions, Size};
 
 use crate::context::GetContext;
-use crate::context::{CallGraphBlasContext, Context};
+use crate::context::{
+    deregister_graphblas_collection, graphblas_matrix_memory_usage, register_graphblas_collection,
+    CallGraphBlasContext, Context, GraphblasCollectionType,
+};
 
 use crate::collections::sparse_matrix::operations::GetSparseMatrixElementList;
 use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
@@ -69,7 +75,10 @@ pub unsafe fn new_graphblas_matrix(
 
 impl<T: ValueType> SparseMatrix<T> {
     pub fn new(context: Arc<Context>, size: Size) -> Result<Self, SparseLinearAlgebraError> {
-        let matrix = unsafe { new_graphblas_matrix(&context, size, T::to_graphblas_type()) }?;
+        let matrix = unsafe {
+            new_graphblas_matrix(&context, size, T::graphblas_type_in_context(&context)?)
+        }?;
+        register_graphblas_collection(&context, matrix as usize, GraphblasCollectionType::Matrix);
 
         return Ok(SparseMatrix {
             context,
@@ -82,17 +91,13 @@ impl<T: ValueType> SparseMatrix<T> {
         context: Arc<Context>,
         matrix: GrB_Matrix,
     ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        register_graphblas_collection(&context, matrix as usize, GraphblasCollectionType::Matrix);
         Ok(SparseMatrix {
             context: context,
             matrix,
             value_type: PhantomData,
         })
     }
-
-    // TODO
-    // fn from_matrices(matrices: Vec<SparseMatrix<T>, >) -> Result<Self, SparseLinearAlgebraError> {
-
-    // }
 }
 
 impl<T: ValueType> GetContext for SparseMatrix<T> {
@@ -120,6 +125,24 @@ impl<T: ValueType> Collection for SparseMatrix<T> {
         let number_of_values = unsafe { number_of_values.assume_init() };
         Ok(ElementCount::from_graphblas_index(number_of_values)?)
     }
+
+    fn memory_usage_in_bytes(&self) -> Result<usize, SparseLinearAlgebraError> {
+        graphblas_matrix_memory_usage(&self.context, self.matrix)
+    }
+
+    fn wait(&mut self, mode: WaitMode) -> Result<(), SparseLinearAlgebraError> {
+        self.context.call(
+            || unsafe { GrB_Matrix_wait(self.matrix, mode.into()) },
+            &self.matrix,
+        )?;
+        Ok(())
+    }
+
+    fn pending_work(&self) -> Result<PendingWork, SparseLinearAlgebraError> {
+        pending_work_from_graphblas_report(&self.context, |file| unsafe {
+            GxB_Matrix_fprint(self.matrix, ptr::null(), GxB_Print_Level_GxB_SUMMARY, file)
+        })
+    }
 }
 
 pub trait GetGraphblasSparseMatrix: GetContext {
@@ -144,6 +167,7 @@ impl<T: ValueType> GetGraphblasSparseMatrix for SparseMatrix<T> {
 
 impl<T: ValueType> Drop for SparseMatrix<T> {
     fn drop(&mut self) -> () {
+        deregister_graphblas_collection(&self.context, self.matrix as usize);
         let _ = self
             .context
             .call_without_detailed_error_information(|| unsafe {
@@ -154,12 +178,12 @@ impl<T: ValueType> Drop for SparseMatrix<T> {
 
 impl<T: ValueType> Clone for SparseMatrix<T> {
     fn clone(&self) -> Self {
-        SparseMatrix {
-            context: self.context.clone(),
-            matrix: unsafe {
-                clone_graphblas_matrix(self.context_ref(), self.graphblas_matrix_ref()).unwrap()
-            },
-            value_type: PhantomData,
+        unsafe {
+            SparseMatrix::from_graphblas_matrix(
+                self.context.clone(),
+                clone_graphblas_matrix(self.context_ref(), self.graphblas_matrix_ref()).unwrap(),
+            )
+            .unwrap()
         }
     }
 }
@@ -213,6 +237,8 @@ macro_rules! implement_display {
 
 implement_macro_for_all_value_types!(implement_display);
 
+impl<T: ValueType> GetMaskOptions for SparseMatrix<T> {}
+
 impl<T: ValueType> MatrixMask for SparseMatrix<T> {
     unsafe fn graphblas_matrix(&self) -> GrB_Matrix {
         GetGraphblasSparseMatrix::graphblas_matrix(self)
@@ -233,6 +259,7 @@ mod tests {
     };
     use crate::collections::sparse_vector::operations::FromVectorElementList;
     use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::context::{MatrixStorageFormat, Mode};
 
     use crate::error::{GraphblasErrorType, LogicErrorType, SparseLinearAlgebraErrorType};
     use crate::operators::binary_operator::First;
@@ -440,6 +467,69 @@ mod tests {
         assert_eq!(sparse_matrix.number_of_stored_elements().unwrap(), 1)
     }
 
+    #[test]
+    fn wait_for_pending_work() {
+        let context = Context::init(Mode::NonBlocking, MatrixStorageFormat::ByRow).unwrap();
+
+        let mut sparse_matrix = SparseMatrix::<i32>::new(context, Size::new(10, 5)).unwrap();
+        sparse_matrix
+            .set_element(MatrixElement::from_triple(1, 2, 3))
+            .unwrap();
+        sparse_matrix
+            .set_element(MatrixElement::from_triple(4, 1, 2))
+            .unwrap();
+
+        let pending_work = sparse_matrix.pending_work().unwrap();
+        assert_eq!(pending_work.number_of_pending_elements(), 2);
+        assert!(!pending_work.is_finished());
+
+        sparse_matrix.wait(WaitMode::Materialize).unwrap();
+        assert!(sparse_matrix.pending_work().unwrap().is_finished());
+        assert_eq!(sparse_matrix.number_of_stored_elements().unwrap(), 2);
+
+        sparse_matrix
+            .drop_element_with_coordinate(Coordinate::new(1, 2))
+            .unwrap();
+        assert_eq!(sparse_matrix.pending_work().unwrap().number_of_zombies(), 1);
+
+        sparse_matrix.wait(WaitMode::Complete).unwrap();
+        assert_eq!(sparse_matrix.number_of_stored_elements().unwrap(), 1);
+        assert!(sparse_matrix.pending_work().unwrap().is_finished());
+        assert_eq!(sparse_matrix.element_value(&4, &1).unwrap(), Some(2));
+    }
+
+    #[test]
+    fn pending_elements_and_zombies_at_once() {
+        let context = Context::init(Mode::NonBlocking, MatrixStorageFormat::ByRow).unwrap();
+
+        let mut sparse_matrix = SparseMatrix::<i32>::new(context, Size::new(10, 5)).unwrap();
+        sparse_matrix
+            .set_element(MatrixElement::from_triple(1, 2, 3))
+            .unwrap();
+        sparse_matrix
+            .set_element(MatrixElement::from_triple(4, 1, 2))
+            .unwrap();
+        sparse_matrix.wait(WaitMode::Materialize).unwrap();
+
+        sparse_matrix
+            .drop_element_with_coordinate(Coordinate::new(1, 2))
+            .unwrap();
+        sparse_matrix
+            .set_element(MatrixElement::from_triple(7, 3, 5))
+            .unwrap();
+        sparse_matrix
+            .set_element(MatrixElement::from_triple(8, 0, 6))
+            .unwrap();
+
+        let pending_work = sparse_matrix.pending_work().unwrap();
+        assert_eq!(pending_work.number_of_pending_elements(), 2);
+        assert_eq!(pending_work.number_of_zombies(), 1);
+
+        sparse_matrix.wait(WaitMode::Materialize).unwrap();
+        assert!(sparse_matrix.pending_work().unwrap().is_finished());
+        assert_eq!(sparse_matrix.number_of_stored_elements().unwrap(), 3);
+    }
+
     #[test]
     fn get_element_from_matrix() {
         let context = Context::init_default().unwrap();
@@ -0,0 +1,137 @@
+use std::mem::MaybeUninit;
+
+use suitesparse_graphblas_sys::{
+    GrB_Scalar, GrB_Scalar_extractElement_BOOL, GrB_Scalar_extractElement_FP32,
+    GrB_Scalar_extractElement_FP64, GrB_Scalar_extractElement_INT16,
+    GrB_Scalar_extractElement_INT32, GrB_Scalar_extractElement_INT64,
+    GrB_Scalar_extractElement_INT8, GrB_Scalar_extractElement_UINT16,
+    GrB_Scalar_extractElement_UINT32, GrB_Scalar_extractElement_UINT64,
+    GrB_Scalar_extractElement_UINT8,
+};
+
+use crate::collections::collection::Collection;
+use crate::collections::sparse_scalar::{GraphblasSparseScalarTrait, SetScalarValue, SparseScalar};
+use crate::context::{CallGraphBlasContext, GetContext};
+use crate::error::{
+    GraphblasErrorType, LogicErrorType, SparseLinearAlgebraError, SparseLinearAlgebraErrorType,
+};
+use crate::value_type::utilities_to_implement_traits_for_all_value_types::implement_1_type_macro_for_all_value_types_and_typed_graphblas_function_with_implementation_type;
+use crate::value_type::{ConvertScalar, ValueType};
+
+/// Converts the value to another value type, following the typecasting rules of SuiteSparse:GraphBLAS.
+///
+/// Conversions between integer types, and between floating point types, follow C.
+/// Floating point values cast to an integer are truncated towards zero and saturate at the bounds of the integer type,
+/// with NaN becoming zero, where C leaves out-of-range conversions undefined.
+/// Any nonzero value, including NaN, becomes true when cast to bool.
+pub trait CastSparseScalar {
+    fn cast<U: CastScalarValue>(&self) -> Result<SparseScalar<U>, SparseLinearAlgebraError>
+    where
+        SparseScalar<U>: SetScalarValue<U>;
+
+    /// Replaces the value of the target
+    fn cast_into<U: CastScalarValue>(
+        &self,
+        target: &mut SparseScalar<U>,
+    ) -> Result<(), SparseLinearAlgebraError>
+    where
+        SparseScalar<U>: SetScalarValue<U>;
+}
+
+impl<T: ValueType> CastSparseScalar for SparseScalar<T> {
+    fn cast<U: CastScalarValue>(&self) -> Result<SparseScalar<U>, SparseLinearAlgebraError>
+    where
+        SparseScalar<U>: SetScalarValue<U>,
+    {
+        let mut target = SparseScalar::<U>::new(self.context())?;
+        self.cast_into(&mut target)?;
+        Ok(target)
+    }
+
+    fn cast_into<U: CastScalarValue>(
+        &self,
+        target: &mut SparseScalar<U>,
+    ) -> Result<(), SparseLinearAlgebraError>
+    where
+        SparseScalar<U>: SetScalarValue<U>,
+    {
+        match U::cast_value(self)? {
+            Some(value) => target.set_value(value),
+            None => target.clear(),
+        }
+    }
+}
+
+/// Value types that GraphBLAS can typecast the value of a scalar of any built-in value type to
+pub trait CastScalarValue: ValueType + Sized {
+    fn cast_value<T: ValueType>(
+        scalar: &SparseScalar<T>,
+    ) -> Result<Option<Self>, SparseLinearAlgebraError>;
+}
+
+macro_rules! implement_cast_scalar_value {
+    ($value_type:ty, $graphblas_implementation_type:ty, $extract_element_function:ident) => {
+        impl CastScalarValue for $value_type {
+            fn cast_value<T: ValueType>(
+                scalar: &SparseScalar<T>,
+            ) -> Result<Option<Self>, SparseLinearAlgebraError> {
+                // GraphBLAS typecasts the stored value to the type of the extract function
+                let mut value: MaybeUninit<$graphblas_implementation_type> = MaybeUninit::uninit();
+                let graphblas_scalar: GrB_Scalar = unsafe { scalar.graphblas_scalar() };
+
+                let result = scalar.context_ref().call(
+                    || unsafe { $extract_element_function(value.as_mut_ptr(), graphblas_scalar) },
+                    unsafe { scalar.graphblas_scalar_ref() },
+                );
+
+                match result {
+                    Ok(_) => {
+                        let value = unsafe { value.assume_init() };
+                        Ok(Some(<$graphblas_implementation_type>::to_type(value)?))
+                    }
+                    Err(error) => match error.error_type() {
+                        SparseLinearAlgebraErrorType::LogicErrorType(
+                            LogicErrorType::GraphBlas(GraphblasErrorType::NoValue),
+                        ) => Ok(None),
+                        _ => Err(error),
+                    },
+                }
+            }
+        }
+    };
+}
+
+implement_1_type_macro_for_all_value_types_and_typed_graphblas_function_with_implementation_type!(
+    implement_cast_scalar_value,
+    GrB_Scalar_extractElement
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_scalar::GetScalarValue;
+    use crate::context::Context;
+
+    #[test]
+    fn cast_scalar() {
+        let context = Context::init_default().unwrap();
+
+        let mut scalar = SparseScalar::<f32>::new(context.clone()).unwrap();
+        scalar.set_value(-7.9).unwrap();
+
+        assert_eq!(scalar.cast::<i64>().unwrap().get_value().unwrap(), Some(-7));
+        assert_eq!(
+            scalar.cast::<bool>().unwrap().get_value().unwrap(),
+            Some(true)
+        );
+
+        let mut target = SparseScalar::<u8>::new(context.clone()).unwrap();
+        target.set_value(3).unwrap();
+        SparseScalar::<f32>::new(context)
+            .unwrap()
+            .cast_into(&mut target)
+            .unwrap();
+        assert_eq!(target.number_of_stored_elements().unwrap(), 0);
+    }
+}
@@ -1,3 +1,5 @@
+mod cast;
 mod sparse_scalar;
 
+pub use cast::*;
 pub use sparse_scalar::{GetScalarValue, GraphblasSparseScalarTrait, SetScalarValue, SparseScalar};
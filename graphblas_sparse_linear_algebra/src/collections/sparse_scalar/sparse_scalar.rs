@@ -1,11 +1,17 @@
+use std::ffi::c_void;
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
+use std::ptr;
 use std::sync::Arc;
 
 use suitesparse_graphblas_sys::GrB_Info;
 
 use crate::collections::collection::Collection;
-use crate::context::{CallGraphBlasContext, Context, GetContext};
+use crate::collections::{pending_work_from_graphblas_report, PendingWork, WaitMode};
+use crate::context::{
+    deregister_graphblas_collection, graphblas_scalar_memory_usage, register_graphblas_collection,
+    CallGraphBlasContext, Context, GetContext, GraphblasCollectionType,
+};
 use crate::error::{
     GraphblasErrorType, LogicErrorType, SparseLinearAlgebraError, SparseLinearAlgebraErrorType,
 };
@@ -13,21 +19,23 @@ use crate::graphblas_bindings::{
     GrB_Index, GrB_Scalar, GrB_Scalar_clear, GrB_Scalar_dup, GrB_Scalar_extractElement_BOOL,
     GrB_Scalar_extractElement_FP32, GrB_Scalar_extractElement_FP64,
     GrB_Scalar_extractElement_INT16, GrB_Scalar_extractElement_INT32,
-    GrB_Scalar_extractElement_INT64, GrB_Scalar_extractElement_INT8,
+    GrB_Scalar_extractElement_INT64, GrB_Scalar_extractElement_INT8, GrB_Scalar_extractElement_UDT,
     GrB_Scalar_extractElement_UINT16, GrB_Scalar_extractElement_UINT32,
     GrB_Scalar_extractElement_UINT64, GrB_Scalar_extractElement_UINT8, GrB_Scalar_free,
     GrB_Scalar_new, GrB_Scalar_nvals, GrB_Scalar_setElement_BOOL, GrB_Scalar_setElement_FP32,
     GrB_Scalar_setElement_FP64, GrB_Scalar_setElement_INT16, GrB_Scalar_setElement_INT32,
-    GrB_Scalar_setElement_INT64, GrB_Scalar_setElement_INT8, GrB_Scalar_setElement_UINT16,
-    GrB_Scalar_setElement_UINT32, GrB_Scalar_setElement_UINT64, GrB_Scalar_setElement_UINT8,
+    GrB_Scalar_setElement_INT64, GrB_Scalar_setElement_INT8, GrB_Scalar_setElement_UDT,
+    GrB_Scalar_setElement_UINT16, GrB_Scalar_setElement_UINT32, GrB_Scalar_setElement_UINT64,
+    GrB_Scalar_setElement_UINT8,
 };
+use crate::graphblas_bindings::{GrB_Scalar_wait, GxB_Print_Level_GxB_SUMMARY, GxB_Scalar_fprint};
 use crate::index::{ElementCount, ElementIndex, IndexConversion};
 use crate::value_type::utilities_to_implement_traits_for_all_value_types::{
     implement_1_type_macro_for_all_value_types_and_typed_graphblas_function_with_implementation_type,
     implement_macro_for_all_value_types,
 };
-use crate::value_type::ConvertScalar;
 use crate::value_type::ValueType;
+use crate::value_type::{ConvertScalar, CustomValueType};
 
 #[derive(Debug)]
 pub struct SparseScalar<T: ValueType> {
@@ -46,12 +54,14 @@ unsafe impl<T: ValueType> Sync for SparseScalar<T> {}
 impl<T: ValueType> SparseScalar<T> {
     pub fn new(context: Arc<Context>) -> Result<Self, SparseLinearAlgebraError> {
         let mut scalar: MaybeUninit<GrB_Scalar> = MaybeUninit::uninit();
+        let value_type = <T>::graphblas_type_in_context(&context)?;
 
         context.call_without_detailed_error_information(|| unsafe {
-            GrB_Scalar_new(scalar.as_mut_ptr(), <T>::to_graphblas_type())
+            GrB_Scalar_new(scalar.as_mut_ptr(), value_type)
         })?;
 
         let scalar = unsafe { scalar.assume_init() };
+        register_graphblas_collection(&context, scalar as usize, GraphblasCollectionType::Scalar);
         return Ok(SparseScalar {
             context,
             scalar,
@@ -93,25 +103,13 @@ macro_rules! sparse_scalar_from_scalar {
 }
 implement_macro_for_all_value_types!(sparse_scalar_from_scalar);
 
-// impl<T: ValueType + CustomValueType> SparseScalar<T> {
-//     pub fn new_custom_type(
-//         value_type: Arc<RegisteredCustomValueType<T>>,
-//     ) -> Result<Self, SparseLinearAlgebraError> {
-//         let mut scalar: MaybeUninit<GxB_Scalar> = MaybeUninit::uninit();
-//         let context = value_type.context();
-
-//         context.call(|| unsafe {
-//             GxB_Scalar_new(scalar.as_mut_ptr(), value_type.to_graphblas_type())
-//         })?;
-
-//         let scalar = unsafe { scalar.assume_init() };
-//         return Ok(SparseScalar {
-//             context,
-//             scalar,
-//             value_type: PhantomData,
-//         });
-//     }
-// }
+impl<T: CustomValueType> SparseScalar<T> {
+    pub fn from_value(context: Arc<Context>, value: T) -> Result<Self, SparseLinearAlgebraError> {
+        let mut sparse_scalar = SparseScalar::new(context)?;
+        sparse_scalar.set_value(value)?;
+        Ok(sparse_scalar)
+    }
+}
 
 impl<T: ValueType> GetContext for SparseScalar<T> {
     fn context(&self) -> Arc<Context> {
@@ -139,10 +137,29 @@ impl<T: ValueType> Collection for SparseScalar<T> {
         let number_of_values = unsafe { number_of_values.assume_init() };
         Ok(ElementIndex::from_graphblas_index(number_of_values)?)
     }
+
+    fn memory_usage_in_bytes(&self) -> Result<usize, SparseLinearAlgebraError> {
+        graphblas_scalar_memory_usage(&self.context, self.scalar)
+    }
+
+    fn wait(&mut self, mode: WaitMode) -> Result<(), SparseLinearAlgebraError> {
+        self.context.call(
+            || unsafe { GrB_Scalar_wait(self.scalar, mode.into()) },
+            &self.scalar,
+        )?;
+        Ok(())
+    }
+
+    fn pending_work(&self) -> Result<PendingWork, SparseLinearAlgebraError> {
+        pending_work_from_graphblas_report(&self.context, |file| unsafe {
+            GxB_Scalar_fprint(self.scalar, ptr::null(), GxB_Print_Level_GxB_SUMMARY, file)
+        })
+    }
 }
 
 impl<T: ValueType> Drop for SparseScalar<T> {
     fn drop(&mut self) -> () {
+        deregister_graphblas_collection(&self.context, self.scalar as usize);
         let _ = self
             .context
             .call_without_detailed_error_information(|| unsafe {
@@ -161,9 +178,16 @@ impl<T: ValueType> Clone for SparseScalar<T> {
             )
             .unwrap();
 
+        let scalar = unsafe { scalar_copy.assume_init() };
+        register_graphblas_collection(
+            &self.context,
+            scalar as usize,
+            GraphblasCollectionType::Scalar,
+        );
+
         SparseScalar {
             context: self.context.clone(),
-            scalar: unsafe { scalar_copy.assume_init() },
+            scalar,
             value_type: PhantomData,
         }
     }
@@ -349,6 +373,45 @@ implement_1_type_macro_for_all_value_types_and_typed_graphblas_function_with_imp
     GrB_Scalar_extractElement
 );
 
+impl<T: CustomValueType> SetScalarValue<T> for SparseScalar<T> {
+    fn set_value(&mut self, mut value: T) -> Result<(), SparseLinearAlgebraError> {
+        self.context.call(
+            || unsafe {
+                GrB_Scalar_setElement_UDT(self.scalar, &mut value as *mut T as *mut c_void)
+            },
+            &self.scalar,
+        )?;
+        Ok(())
+    }
+}
+
+impl<T: CustomValueType> GetScalarValue<T> for SparseScalar<T> {
+    fn get_value(&self) -> Result<Option<T>, SparseLinearAlgebraError> {
+        let mut value: MaybeUninit<T> = MaybeUninit::uninit();
+
+        let result = self.context.call(
+            || unsafe {
+                GrB_Scalar_extractElement_UDT(value.as_mut_ptr() as *mut c_void, self.scalar)
+            },
+            &self.scalar,
+        );
+
+        match result {
+            Ok(_) => Ok(Some(unsafe { value.assume_init() })),
+            Err(error) => match error.error_type() {
+                SparseLinearAlgebraErrorType::LogicErrorType(LogicErrorType::GraphBlas(
+                    GraphblasErrorType::NoValue,
+                )) => Ok(None),
+                _ => Err(error),
+            },
+        }
+    }
+
+    fn get_value_or_default(&self) -> Result<T, SparseLinearAlgebraError> {
+        Ok(self.get_value()?.unwrap_or_default())
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -0,0 +1,51 @@
+use crate::index::ElementCount;
+use crate::value_type::ValueType;
+
+/// Bitmap data, with a dense array of values and a dense array of presence flags.
+///
+/// An element is stored if its bitmap entry is 1, and absent if it is 0.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BitmapVectorData<T: ValueType> {
+    bitmap: Vec<i8>,
+    values: Vec<T>,
+    number_of_stored_elements: ElementCount,
+}
+
+impl<T: ValueType> BitmapVectorData<T> {
+    pub fn new(bitmap: Vec<i8>, values: Vec<T>) -> Self {
+        let number_of_stored_elements = bitmap.iter().filter(|is_stored| **is_stored != 0).count();
+        Self {
+            bitmap,
+            values,
+            number_of_stored_elements,
+        }
+    }
+
+    pub(crate) fn from_parts(
+        bitmap: Vec<i8>,
+        values: Vec<T>,
+        number_of_stored_elements: ElementCount,
+    ) -> Self {
+        Self {
+            bitmap,
+            values,
+            number_of_stored_elements,
+        }
+    }
+
+    pub fn bitmap_ref(&self) -> &[i8] {
+        self.bitmap.as_slice()
+    }
+
+    pub fn values_ref(&self) -> &[T] {
+        self.values.as_slice()
+    }
+
+    pub fn number_of_stored_elements(&self) -> ElementCount {
+        self.number_of_stored_elements
+    }
+
+    pub fn into_parts(self) -> (Vec<i8>, Vec<T>, ElementCount) {
+        (self.bitmap, self.values, self.number_of_stored_elements)
+    }
+}
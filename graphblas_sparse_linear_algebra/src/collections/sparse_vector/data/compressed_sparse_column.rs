@@ -0,0 +1,33 @@
+use crate::index::{ElementCount, ElementIndex};
+use crate::value_type::ValueType;
+
+/// Sparse data, listing the index and value of each stored element.
+///
+/// Indices must be unique and sorted in ascending order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseVectorData<T: ValueType> {
+    indices: Vec<ElementIndex>,
+    values: Vec<T>,
+}
+
+impl<T: ValueType> SparseVectorData<T> {
+    pub fn new(indices: Vec<ElementIndex>, values: Vec<T>) -> Self {
+        Self { indices, values }
+    }
+
+    pub fn indices_ref(&self) -> &[ElementIndex] {
+        self.indices.as_slice()
+    }
+
+    pub fn values_ref(&self) -> &[T] {
+        self.values.as_slice()
+    }
+
+    pub fn number_of_stored_elements(&self) -> ElementCount {
+        self.indices.len()
+    }
+
+    pub fn into_parts(self) -> (Vec<ElementIndex>, Vec<T>) {
+        (self.indices, self.values)
+    }
+}
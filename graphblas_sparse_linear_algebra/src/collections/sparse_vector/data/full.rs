@@ -1,4 +1,4 @@
-use crate::value_types::value_type::ValueType;
+use crate::value_type::ValueType;
 
 pub trait FullVectorDataTrait<T: ValueType> {
     fn values(self) -> Vec<T>;
@@ -8,7 +8,8 @@ pub trait FullVectorDataTrait<T: ValueType> {
     fn values_mut_slice(&mut self) -> &mut [T];
 }
 
-#[derive(Clone, Debug)]
+/// Full data, with a value for every element of the vector.
+#[derive(Clone, Debug, PartialEq)]
 #[repr(C)]
 pub struct FullVectorData<T: ValueType> {
     values: Vec<T>,
@@ -1,5 +1,7 @@
+mod bitmap;
+mod compressed_sparse_column;
 mod full;
-mod vector_data;
 
+pub use bitmap::BitmapVectorData;
+pub use compressed_sparse_column::SparseVectorData;
 pub use full::{FullVectorData, FullVectorDataTrait};
-pub use vector_data::VectorData;
@@ -1,4 +1,4 @@
-// pub mod data;
+pub mod data;
 mod element;
 mod sparse_vector;
 
@@ -0,0 +1,216 @@
+use once_cell::sync::Lazy;
+
+use crate::collections::sparse_vector::operations::GetSparseVectorLength;
+use crate::collections::sparse_vector::SparseVector;
+use crate::context::GetContext;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::{
+    ApplyBinaryOperator, ApplyIndexUnaryOperator, ApplyUnaryOperator, BinaryOperatorApplier,
+    IndexUnaryOperatorApplier, UnaryOperatorApplier,
+};
+use crate::operators::binary_operator::{Assignment, BinaryOperator};
+use crate::operators::index_unary_operator::IndexUnaryOperator;
+use crate::operators::mask::SelectEntireVector;
+use crate::operators::options::OperatorOptions;
+use crate::operators::unary_operator::UnaryOperator;
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OperatorOptions> =
+    Lazy::new(OperatorOptions::new_default);
+
+pub trait ApplyUnaryOperatorToSparseVector<T: ValueType> {
+    /// Applies the operator to each stored element, and returns the results as a new vector
+    fn apply(
+        &self,
+        operator: &impl UnaryOperator<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> ApplyUnaryOperatorToSparseVector<T> for SparseVector<T> {
+    fn apply(
+        &self,
+        operator: &impl UnaryOperator<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::<T>::new(self.context(), self.length()?)?;
+
+        UnaryOperatorApplier::new().apply_to_vector(
+            operator,
+            self,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireVector::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(product)
+    }
+}
+
+pub trait ApplyBinaryOperatorToSparseVector<T: ValueType> {
+    /// Applies the operator to the scalar and each stored element, and returns the results as a new vector
+    fn apply_with_left_scalar(
+        &self,
+        left_argument: T,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>;
+
+    /// Applies the operator to each stored element and the scalar, and returns the results as a new vector
+    fn apply_with_right_scalar(
+        &self,
+        operator: &impl BinaryOperator<T>,
+        right_argument: T,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> ApplyBinaryOperatorToSparseVector<T> for SparseVector<T>
+where
+    BinaryOperatorApplier: ApplyBinaryOperator<T>,
+{
+    fn apply_with_left_scalar(
+        &self,
+        left_argument: T,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::<T>::new(self.context(), self.length()?)?;
+
+        BinaryOperatorApplier::new().apply_with_vector_as_right_argument(
+            left_argument,
+            operator,
+            self,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireVector::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(product)
+    }
+
+    fn apply_with_right_scalar(
+        &self,
+        operator: &impl BinaryOperator<T>,
+        right_argument: T,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::<T>::new(self.context(), self.length()?)?;
+
+        BinaryOperatorApplier::new().apply_with_vector_as_left_argument(
+            self,
+            operator,
+            right_argument,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireVector::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(product)
+    }
+}
+
+pub trait ApplyIndexUnaryOperatorToSparseVector<T: ValueType> {
+    /// Applies the operator to each stored element and its index, and returns the results as a new vector
+    fn apply_index_unary_operator(
+        &self,
+        operator: &impl IndexUnaryOperator<T>,
+        argument: T,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> ApplyIndexUnaryOperatorToSparseVector<T> for SparseVector<T>
+where
+    IndexUnaryOperatorApplier: ApplyIndexUnaryOperator<T>,
+{
+    fn apply_index_unary_operator(
+        &self,
+        operator: &impl IndexUnaryOperator<T>,
+        argument: T,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::<T>::new(self.context(), self.length()?)?;
+
+        IndexUnaryOperatorApplier::new().apply_to_vector(
+            self,
+            operator,
+            &argument,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireVector::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(product)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::{First, Times};
+    use crate::operators::index_unary_operator::PlusRowIndex;
+    use crate::operators::unary_operator::AdditiveInverse;
+
+    #[test]
+    fn apply_unary_operator_into_new_vector() {
+        let context = Context::init_default().unwrap();
+        let vector = SparseVector::<f64>::from_element_list(
+            context,
+            4,
+            VectorElementList::from_element_vector(vec![(1, 1.5).into(), (3, -2.0).into()]),
+            &First::<f64>::new(),
+        )
+        .unwrap();
+
+        let product = vector.apply(&AdditiveInverse::<f64>::new()).unwrap();
+
+        assert_eq!(product.length().unwrap(), 4);
+        assert_eq!(product.element_value(&1).unwrap(), Some(-1.5));
+        assert_eq!(product.element_value(&3).unwrap(), Some(2.0));
+        assert_eq!(product.element_value(&0).unwrap(), None);
+    }
+
+    #[test]
+    fn apply_binary_operator_with_scalar_into_new_vector() {
+        let context = Context::init_default().unwrap();
+        let vector = SparseVector::<f64>::from_element_list(
+            context,
+            4,
+            VectorElementList::from_element_vector(vec![(1, 1.5).into(), (3, -2.0).into()]),
+            &First::<f64>::new(),
+        )
+        .unwrap();
+
+        let product = vector
+            .apply_with_right_scalar(&Times::<f64>::new(), 2.0)
+            .unwrap();
+
+        assert_eq!(product.length().unwrap(), 4);
+        assert_eq!(product.element_value(&1).unwrap(), Some(3.0));
+        assert_eq!(product.element_value(&3).unwrap(), Some(-4.0));
+        assert_eq!(product.element_value(&0).unwrap(), None);
+    }
+
+    #[test]
+    fn apply_index_unary_operator_into_new_vector() {
+        let context = Context::init_default().unwrap();
+        let vector = SparseVector::<i64>::from_element_list(
+            context,
+            4,
+            VectorElementList::from_element_vector(vec![(1, 5).into(), (3, 6).into()]),
+            &First::<i64>::new(),
+        )
+        .unwrap();
+
+        let product = vector
+            .apply_index_unary_operator(&PlusRowIndex::<i64>::new(), 100)
+            .unwrap();
+
+        assert_eq!(product.element_value(&1).unwrap(), Some(101));
+        assert_eq!(product.element_value(&3).unwrap(), Some(103));
+        assert_eq!(product.element_value(&0).unwrap(), None);
+    }
+}
@@ -0,0 +1,143 @@
+use std::ops::{Add, BitAnd, Neg};
+
+use crate::collections::arithmetic::unwrap_or_panic;
+use crate::collections::sparse_vector::operations::{
+    ApplyUnaryOperatorToSparseVector, ElementWiseSparseVectorOperations,
+};
+use crate::collections::sparse_vector::SparseVector;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{Plus, PlusTyped, Times, TimesTyped};
+use crate::operators::unary_operator::{AdditiveInverse, AdditiveInverseUnaryOperatorTyped};
+use crate::value_type::ValueType;
+
+/// Arithmetic with the default operators for T.
+///
+/// Unlike the std::ops operators, these return a DimensionMismatch error instead of panicking.
+pub trait CheckedSparseVectorArithmetic<T: ValueType> {
+    /// Element-wise addition of the union of the stored elements
+    fn checked_add(
+        &self,
+        other: &SparseVector<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>
+    where
+        T: PlusTyped<T>;
+
+    /// Element-wise multiplication of the intersection of the stored elements
+    fn checked_ewise_mult(
+        &self,
+        other: &SparseVector<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>
+    where
+        T: TimesTyped<T>;
+
+    fn checked_neg(&self) -> Result<SparseVector<T>, SparseLinearAlgebraError>
+    where
+        T: AdditiveInverseUnaryOperatorTyped<T>;
+}
+
+impl<T: ValueType> CheckedSparseVectorArithmetic<T> for SparseVector<T> {
+    fn checked_add(
+        &self,
+        other: &SparseVector<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>
+    where
+        T: PlusTyped<T>,
+    {
+        self.ewise_add(other, &Plus::<T>::new())
+    }
+
+    fn checked_ewise_mult(
+        &self,
+        other: &SparseVector<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>
+    where
+        T: TimesTyped<T>,
+    {
+        self.ewise_mult(other, &Times::<T>::new())
+    }
+
+    fn checked_neg(&self) -> Result<SparseVector<T>, SparseLinearAlgebraError>
+    where
+        T: AdditiveInverseUnaryOperatorTyped<T>,
+    {
+        self.apply(&AdditiveInverse::<T>::new())
+    }
+}
+
+/// Panics on a length mismatch, use checked_add to handle it
+impl<T: ValueType + PlusTyped<T>> Add<&SparseVector<T>> for &SparseVector<T> {
+    type Output = SparseVector<T>;
+
+    fn add(self, other: &SparseVector<T>) -> SparseVector<T> {
+        unwrap_or_panic(self.checked_add(other), "Sparse vector addition")
+    }
+}
+
+/// Element-wise multiplication, panics on a length mismatch, use checked_ewise_mult to handle it
+impl<T: ValueType + TimesTyped<T>> BitAnd<&SparseVector<T>> for &SparseVector<T> {
+    type Output = SparseVector<T>;
+
+    fn bitand(self, other: &SparseVector<T>) -> SparseVector<T> {
+        unwrap_or_panic(
+            self.checked_ewise_mult(other),
+            "Sparse vector element-wise multiplication",
+        )
+    }
+}
+
+impl<T: ValueType + AdditiveInverseUnaryOperatorTyped<T>> Neg for &SparseVector<T> {
+    type Output = SparseVector<T>;
+
+    fn neg(self) -> SparseVector<T> {
+        unwrap_or_panic(self.checked_neg(), "Sparse vector negation")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    fn vector(length: usize, elements: Vec<(usize, f32)>) -> SparseVector<f32> {
+        SparseVector::<f32>::from_element_list(
+            Context::init_default().unwrap(),
+            length,
+            VectorElementList::from_element_vector(
+                elements.into_iter().map(|element| element.into()).collect(),
+            ),
+            &First::<f32>::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn vector_operators() {
+        let u = vector(3, vec![(0, 1.0), (2, 2.0)]);
+        let v = vector(3, vec![(2, 3.0)]);
+
+        let sum = &u + &v;
+        assert_eq!(sum.element_value(&0).unwrap(), Some(1.0));
+        assert_eq!(sum.element_value(&2).unwrap(), Some(5.0));
+
+        let element_wise_product = &u & &v;
+        assert_eq!(element_wise_product.element_value(&0).unwrap(), None);
+        assert_eq!(element_wise_product.element_value(&2).unwrap(), Some(6.0));
+
+        let negation = -&u;
+        assert_eq!(negation.element_value(&0).unwrap(), Some(-1.0));
+
+        assert!(u.checked_add(&vector(4, vec![])).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Sparse vector addition failed")]
+    fn addition_operator_panics_on_length_mismatch() {
+        let _ = &vector(3, vec![]) + &vector(2, vec![]);
+    }
+}
@@ -0,0 +1,124 @@
+use std::ptr;
+
+use once_cell::sync::Lazy;
+use suitesparse_graphblas_sys::GrB_Vector_apply;
+
+use crate::collections::sparse_vector::operations::GetSparseVectorLength;
+use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
+use crate::context::{CallGraphBlasContext, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::options::{GetGraphblasDescriptor, OperatorOptions};
+use crate::operators::unary_operator::{Identity, IdentityUnaryOperatorTyped, UnaryOperator};
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OperatorOptions> =
+    Lazy::new(OperatorOptions::new_default);
+
+/// Converts the stored elements to another value type, following the typecasting rules of SuiteSparse:GraphBLAS.
+///
+/// Conversions between integer types, and between floating point types, follow C.
+/// Floating point values cast to an integer are truncated towards zero and saturate at the bounds of the integer type,
+/// with NaN becoming zero, where C leaves out-of-range conversions undefined.
+/// Any nonzero value, including NaN, becomes true when cast to bool.
+pub trait CastSparseVector {
+    fn cast<U: ValueType + IdentityUnaryOperatorTyped<U>>(
+        &self,
+    ) -> Result<SparseVector<U>, SparseLinearAlgebraError>;
+
+    /// Replaces the elements of the target, which must have the same length
+    fn cast_into<U: ValueType + IdentityUnaryOperatorTyped<U>>(
+        &self,
+        target: &mut SparseVector<U>,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> CastSparseVector for SparseVector<T> {
+    fn cast<U: ValueType + IdentityUnaryOperatorTyped<U>>(
+        &self,
+    ) -> Result<SparseVector<U>, SparseLinearAlgebraError> {
+        let mut target = SparseVector::<U>::new(self.context(), self.length()?)?;
+        self.cast_into(&mut target)?;
+        Ok(target)
+    }
+
+    fn cast_into<U: ValueType + IdentityUnaryOperatorTyped<U>>(
+        &self,
+        target: &mut SparseVector<U>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        // GraphBLAS typecasts the argument to the domain of the identity operator
+        let identity = Identity::<U>::new();
+
+        self.context_ref().call(
+            || unsafe {
+                GrB_Vector_apply(
+                    target.graphblas_vector(),
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                    identity.graphblas_type(),
+                    self.graphblas_vector(),
+                    DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+                )
+            },
+            unsafe { target.graphblas_vector_ref() },
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::collection::Collection;
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    #[test]
+    fn cast_integer_to_bool() {
+        let context = Context::init_default().unwrap();
+        let element_list = VectorElementList::<i32>::from_element_vector(vec![
+            (0, -3).into(),
+            (2, 0).into(),
+            (4, 1).into(),
+        ]);
+        let vector =
+            SparseVector::<i32>::from_element_list(context, 5, element_list, &First::<i32>::new())
+                .unwrap();
+
+        let bool_vector = vector.cast::<bool>().unwrap();
+
+        assert_eq!(bool_vector.length().unwrap(), 5);
+        assert_eq!(bool_vector.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(bool_vector.element_value(&0).unwrap(), Some(true));
+        assert_eq!(bool_vector.element_value(&2).unwrap(), Some(false));
+        assert_eq!(bool_vector.element_value(&4).unwrap(), Some(true));
+    }
+
+    #[test]
+    fn cast_floating_point_into_unsigned_integer() {
+        let context = Context::init_default().unwrap();
+        let element_list = VectorElementList::<f64>::from_element_vector(vec![
+            (0, 9.99).into(),
+            (1, -1.5).into(),
+            (2, f64::NAN).into(),
+        ]);
+        let vector = SparseVector::<f64>::from_element_list(
+            context.clone(),
+            3,
+            element_list,
+            &First::<f64>::new(),
+        )
+        .unwrap();
+
+        let mut target = SparseVector::<u16>::new(context, 3).unwrap();
+        vector.cast_into(&mut target).unwrap();
+
+        assert_eq!(target.element_value(&0).unwrap(), Some(9));
+        assert_eq!(target.element_value(&1).unwrap(), Some(0));
+        assert_eq!(target.element_value(&2).unwrap(), Some(0));
+    }
+}
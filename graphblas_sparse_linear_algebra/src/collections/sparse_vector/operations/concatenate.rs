@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use once_cell::sync::Lazy;
+use suitesparse_graphblas_sys::GxB_Matrix_concat;
+
+use crate::collections::sparse_vector::operations::GetSparseVectorLength;
+use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
+use crate::context::{CallGraphBlasContext, Context};
+use crate::error::{GraphblasError, GraphblasErrorType, SparseLinearAlgebraError};
+use crate::graphblas_bindings::GrB_Matrix;
+use crate::index::{ElementCount, IndexConversion};
+use crate::operators::options::{GetGraphblasDescriptor, OperatorOptions};
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OperatorOptions> =
+    Lazy::new(OperatorOptions::new_default);
+
+/// Assembles a vector from consecutive tiles.
+///
+/// All tiles must have value type T, tiles of another value type can be typecast first.
+pub trait ConcatenateSparseVectors<T: ValueType> {
+    fn from_tiles(
+        context: Arc<Context>,
+        tiles: &[SparseVector<T>],
+    ) -> Result<Self, SparseLinearAlgebraError>
+    where
+        Self: Sized;
+}
+
+impl<T: ValueType> ConcatenateSparseVectors<T> for SparseVector<T> {
+    fn from_tiles(
+        context: Arc<Context>,
+        tiles: &[SparseVector<T>],
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        if tiles.is_empty() {
+            return Err(GraphblasError::new(
+                GraphblasErrorType::InvalidValue,
+                String::from("At least one tile is required"),
+            )
+            .into());
+        }
+
+        let mut length: ElementCount = 0;
+        for tile in tiles {
+            length += tile.length()?;
+        }
+        let vector = SparseVector::<T>::new(context.clone(), length)?;
+
+        // A GrB_Vector is a GrB_Matrix with a single column, and may be used as such
+        let graphblas_tiles: Vec<GrB_Matrix> = tiles
+            .iter()
+            .map(|tile| unsafe { tile.graphblas_vector() } as GrB_Matrix)
+            .collect();
+        let number_of_tiles = tiles.len().to_graphblas_index()?;
+
+        context.call(
+            || unsafe {
+                GxB_Matrix_concat(
+                    vector.graphblas_vector() as GrB_Matrix,
+                    graphblas_tiles.as_ptr(),
+                    number_of_tiles,
+                    1,
+                    DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+                )
+            },
+            unsafe { vector.graphblas_vector_ref() },
+        )?;
+
+        Ok(vector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::collection::Collection;
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::index::ElementIndex;
+    use crate::operators::binary_operator::First;
+
+    fn tile(
+        context: &Arc<Context>,
+        length: ElementCount,
+        elements: Vec<(ElementIndex, u64)>,
+    ) -> SparseVector<u64> {
+        let element_list = VectorElementList::<u64>::from_element_vector(
+            elements.into_iter().map(|element| element.into()).collect(),
+        );
+        SparseVector::<u64>::from_element_list(
+            context.clone(),
+            length,
+            element_list,
+            &First::<u64>::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn concatenate_tiles() {
+        let context = Context::init_default().unwrap();
+
+        let tiles = vec![
+            tile(&context, 3, vec![(0, 1), (2, 2)]),
+            tile(&context, 1, vec![]),
+            tile(&context, 2, vec![(1, 3)]),
+        ];
+
+        let vector = SparseVector::<u64>::from_tiles(context.clone(), &tiles).unwrap();
+
+        assert_eq!(vector.length().unwrap(), 6);
+        assert_eq!(vector.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(vector.element_value(&0).unwrap(), Some(1));
+        assert_eq!(vector.element_value(&2).unwrap(), Some(2));
+        assert_eq!(vector.element_value(&5).unwrap(), Some(3));
+    }
+
+    #[test]
+    fn concatenate_without_tiles() {
+        let context = Context::init_default().unwrap();
+
+        assert!(SparseVector::<u64>::from_tiles(context, &[]).is_err());
+    }
+}
@@ -2,14 +2,63 @@ use std::ffi::c_void;
 use std::ptr;
 use std::{mem::MaybeUninit, sync::Arc};
 
-use suitesparse_graphblas_sys::{GrB_Index, GrB_Vector, GxB_Vector_deserialize};
+use suitesparse_graphblas_sys::{GrB_Index, GrB_Type, GrB_Vector, GxB_Vector_deserialize};
 
+use crate::collections::sparse_vector::SparseVector;
 use crate::index::IndexConversion;
+use crate::value_type::ValueType;
 use crate::{context::Context, error::SparseLinearAlgebraError};
 
 pub unsafe fn deserialize_suitesparse_graphblas_sparse_vector(
     context: &Arc<Context>,
     serialized_suitesparse_graphblas_sparse_vector: &[u8],
+) -> Result<GrB_Vector, SparseLinearAlgebraError> {
+    deserialize_suitesparse_graphblas_sparse_vector_with_value_type(
+        context,
+        serialized_suitesparse_graphblas_sparse_vector,
+        ptr::null_mut(),
+    )
+}
+
+/// Deserializes a vector serialized by any of the serializers.
+///
+/// Unlike deserialize_suitesparse_graphblas_sparse_vector(), this also supports vectors of custom value types.
+/// Fails if the serialized vector has a different value type.
+///
+/// # Safety
+/// GraphBLAS identifies a custom value type by its name and size only, and copies the serialized values as they are.
+/// For a custom value type, every serialized value must therefore be a valid T,
+/// e.g. because the vector was serialized by this library from a SparseVector<T>.
+pub trait DeserializeSparseVector<T: ValueType> {
+    /// # Safety
+    /// See the safety requirements of DeserializeSparseVector.
+    unsafe fn deserialize(
+        context: Arc<Context>,
+        serialized_sparse_vector: &[u8],
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> DeserializeSparseVector<T> for SparseVector<T> {
+    unsafe fn deserialize(
+        context: Arc<Context>,
+        serialized_sparse_vector: &[u8],
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        unsafe {
+            let graphblas_vector = deserialize_suitesparse_graphblas_sparse_vector_with_value_type(
+                &context,
+                serialized_sparse_vector,
+                T::graphblas_type_in_context(&context)?,
+            )?;
+            SparseVector::from_graphblas_vector(context, graphblas_vector)
+        }
+    }
+}
+
+// A null value type makes GraphBLAS use the built-in value type of the serialized vector
+unsafe fn deserialize_suitesparse_graphblas_sparse_vector_with_value_type(
+    context: &Arc<Context>,
+    serialized_suitesparse_graphblas_sparse_vector: &[u8],
+    value_type: GrB_Type,
 ) -> Result<GrB_Vector, SparseLinearAlgebraError> {
     let mut suitesparse_graphblas_sparse_vector: MaybeUninit<GrB_Vector> = MaybeUninit::uninit();
     let raw_pointer_to_serialized_suitesparse_graphblas_sparse_vector: *const c_void =
@@ -22,7 +71,7 @@ pub unsafe fn deserialize_suitesparse_graphblas_sparse_vector(
     context.call_without_detailed_error_information(|| unsafe {
         GxB_Vector_deserialize(
             suitesparse_graphblas_sparse_vector.as_mut_ptr(),
-            ptr::null_mut(),
+            value_type,
             raw_pointer_to_serialized_suitesparse_graphblas_sparse_vector,
             size_of_serialized_suitesparse_graphblas_sparse_vector,
             ptr::null_mut(), // TODO: Could set the (max) number of threads
@@ -0,0 +1,227 @@
+use once_cell::sync::Lazy;
+
+use crate::collections::sparse_scalar::{SetScalarValue, SparseScalar};
+use crate::collections::sparse_vector::operations::GetSparseVectorLength;
+use crate::collections::sparse_vector::SparseVector;
+use crate::context::GetContext;
+use crate::error::{GraphblasError, GraphblasErrorType, SparseLinearAlgebraError};
+use crate::operators::binary_operator::{Assignment, BinaryOperator};
+use crate::operators::element_wise_addition::{
+    ApplyElementWiseVectorAdditionBinaryOperator, ElementWiseVectorAdditionBinaryOperator,
+};
+use crate::operators::element_wise_multiplication::{
+    ApplyElementWiseVectorMultiplicationBinaryOperator,
+    ElementWiseVectorMultiplicationBinaryOperator,
+};
+use crate::operators::element_wise_union::{
+    ApplyElementWiseVectorUnion, ElementWiseUnionArgument, ElementWiseVectorUnionOperator,
+};
+use crate::operators::mask::SelectEntireVector;
+use crate::operators::options::OperatorOptions;
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OperatorOptions> =
+    Lazy::new(OperatorOptions::new_default);
+
+/// Combines two vectors of equal length into a new vector.
+///
+/// Returns a DimensionMismatch error before calling GraphBLAS if the lengths differ.
+pub trait ElementWiseSparseVectorOperations<T: ValueType> {
+    /// Applies the operator to the union of the stored elements
+    fn ewise_add(
+        &self,
+        other: &SparseVector<T>,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>;
+
+    /// Applies the operator to the intersection of the stored elements
+    fn ewise_mult(
+        &self,
+        other: &SparseVector<T>,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> ElementWiseSparseVectorOperations<T> for SparseVector<T> {
+    fn ewise_add(
+        &self,
+        other: &SparseVector<T>,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut product = new_product_of_equal_length(self, other)?;
+
+        ElementWiseVectorAdditionBinaryOperator::new().apply(
+            self,
+            operator,
+            other,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireVector::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(product)
+    }
+
+    fn ewise_mult(
+        &self,
+        other: &SparseVector<T>,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut product = new_product_of_equal_length(self, other)?;
+
+        ElementWiseVectorMultiplicationBinaryOperator::new().apply(
+            self,
+            operator,
+            other,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireVector::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(product)
+    }
+}
+
+/// Combines two vectors of equal length into a new vector, applying the operator to the union of the stored elements.
+///
+/// Where only one vector stores an element, the fill value of the other vector takes the place of its missing element,
+/// e.g. zero fill values to compute u - v.
+/// Returns a DimensionMismatch error before calling GraphBLAS if the lengths differ.
+pub trait ElementWiseSparseVectorUnion<T: ValueType> {
+    fn ewise_union(
+        &self,
+        fill_value: T,
+        other: &SparseVector<T>,
+        other_fill_value: T,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> ElementWiseSparseVectorUnion<T> for SparseVector<T>
+where
+    SparseScalar<T>: SetScalarValue<T>,
+{
+    fn ewise_union(
+        &self,
+        fill_value: T,
+        other: &SparseVector<T>,
+        other_fill_value: T,
+        operator: &impl BinaryOperator<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut product = new_product_of_equal_length(self, other)?;
+
+        let mut left_argument_fill_value = SparseScalar::<T>::new(self.context())?;
+        left_argument_fill_value.set_value(fill_value)?;
+        let mut right_argument_fill_value = SparseScalar::<T>::new(self.context())?;
+        right_argument_fill_value.set_value(other_fill_value)?;
+
+        ElementWiseVectorUnionOperator::new().apply(
+            &ElementWiseUnionArgument::new(self, &left_argument_fill_value),
+            operator,
+            &ElementWiseUnionArgument::new(other, &right_argument_fill_value),
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireVector::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(product)
+    }
+}
+
+fn new_product_of_equal_length<T: ValueType>(
+    first: &SparseVector<T>,
+    second: &SparseVector<T>,
+) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+    let length = first.length()?;
+    let other_length = second.length()?;
+    if length != other_length {
+        return Err(GraphblasError::new(
+            GraphblasErrorType::DimensionMismatch,
+            format!(
+                "Element-wise operations require vectors of equal length, found {} and {}",
+                length, other_length
+            ),
+        )
+        .into());
+    }
+    SparseVector::<T>::new(first.context(), length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::collections::Collection;
+    use crate::context::Context;
+    use crate::operators::binary_operator::{First, Max, Min, Times};
+
+    #[test]
+    fn element_wise_operations_into_new_vector() {
+        let context = Context::init_default().unwrap();
+        let first = SparseVector::<u16>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::from_element_vector(vec![(0, 2).into(), (1, 9).into()]),
+            &First::<u16>::new(),
+        )
+        .unwrap();
+        let second = SparseVector::<u16>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::from_element_vector(vec![(1, 4).into(), (2, 7).into()]),
+            &First::<u16>::new(),
+        )
+        .unwrap();
+
+        let union = first.ewise_add(&second, &Max::<u16>::new()).unwrap();
+        assert_eq!(union.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(union.element_value(&1).unwrap(), Some(9));
+        assert_eq!(union.element_value(&2).unwrap(), Some(7));
+
+        let intersection = first.ewise_mult(&second, &Min::<u16>::new()).unwrap();
+        assert_eq!(intersection.number_of_stored_elements().unwrap(), 1);
+        assert_eq!(intersection.element_value(&1).unwrap(), Some(4));
+
+        let longer = SparseVector::<u16>::new(context, 4).unwrap();
+        assert!(first.ewise_mult(&longer, &Min::<u16>::new()).is_err());
+    }
+
+    #[test]
+    fn element_wise_union_into_new_vector() {
+        let context = Context::init_default().unwrap();
+        let first = SparseVector::<f32>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::from_element_vector(vec![(0, 2.0).into(), (1, 3.0).into()]),
+            &First::<f32>::new(),
+        )
+        .unwrap();
+        let second = SparseVector::<f32>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::from_element_vector(vec![(1, 4.0).into(), (2, 5.0).into()]),
+            &First::<f32>::new(),
+        )
+        .unwrap();
+
+        let product = first
+            .ewise_union(1.0, &second, 10.0, &Times::<f32>::new())
+            .unwrap();
+        assert_eq!(product.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product.element_value(&0).unwrap(), Some(20.0));
+        assert_eq!(product.element_value(&1).unwrap(), Some(12.0));
+        assert_eq!(product.element_value(&2).unwrap(), Some(5.0));
+
+        let longer = SparseVector::<f32>::new(context, 4).unwrap();
+        assert!(first
+            .ewise_union(1.0, &longer, 1.0, &Times::<f32>::new())
+            .is_err());
+    }
+}
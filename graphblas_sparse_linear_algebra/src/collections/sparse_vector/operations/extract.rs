@@ -0,0 +1,83 @@
+use once_cell::sync::Lazy;
+
+use crate::collections::sparse_vector::operations::GetSparseVectorLength;
+use crate::collections::sparse_vector::SparseVector;
+use crate::context::GetContext;
+use crate::error::SparseLinearAlgebraError;
+use crate::index::ElementIndexSelector;
+use crate::operators::binary_operator::Assignment;
+use crate::operators::extract::{ExtractSubVector, SubVectorExtractor};
+use crate::operators::mask::SelectEntireVector;
+use crate::operators::options::OperatorOptions;
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OperatorOptions> =
+    Lazy::new(OperatorOptions::new_default);
+
+pub trait ExtractFromSparseVector<T: ValueType> {
+    /// Extracts into a new vector, which has the length of the selection
+    fn extract_sub_vector(
+        &self,
+        indices_to_extract: &ElementIndexSelector,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> ExtractFromSparseVector<T> for SparseVector<T> {
+    fn extract_sub_vector(
+        &self,
+        indices_to_extract: &ElementIndexSelector,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut sub_vector = SparseVector::<T>::new(
+            self.context(),
+            indices_to_extract.number_of_selected_elements(self.length()?)?,
+        )?;
+
+        SubVectorExtractor::new().apply(
+            self,
+            indices_to_extract,
+            &Assignment::<T>::new(),
+            &mut sub_vector,
+            &SelectEntireVector::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(sub_vector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    #[test]
+    fn extract_sub_vector_into_new_vector() {
+        let context = Context::init_default().unwrap();
+        let vector = SparseVector::<i8>::from_element_list(
+            context,
+            6,
+            VectorElementList::from_element_vector(vec![
+                (1, -1).into(),
+                (2, 2).into(),
+                (5, 7).into(),
+            ]),
+            &First::<i8>::new(),
+        )
+        .unwrap();
+
+        let sub_vector = vector
+            .extract_sub_vector(&ElementIndexSelector::Range(1..4))
+            .unwrap();
+
+        assert_eq!(sub_vector.length().unwrap(), 3);
+        assert_eq!(sub_vector.element_value(&0).unwrap(), Some(-1));
+        assert_eq!(sub_vector.element_value(&1).unwrap(), Some(2));
+        assert_eq!(sub_vector.element_value(&2).unwrap(), None);
+    }
+}
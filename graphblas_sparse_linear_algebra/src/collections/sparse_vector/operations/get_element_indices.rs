@@ -9,7 +9,7 @@ use crate::graphblas_bindings::GrB_Index;
 use crate::graphblas_bindings::{
     GrB_Vector_extractTuples_BOOL, GrB_Vector_extractTuples_FP32, GrB_Vector_extractTuples_FP64,
     GrB_Vector_extractTuples_INT16, GrB_Vector_extractTuples_INT32, GrB_Vector_extractTuples_INT64,
-    GrB_Vector_extractTuples_INT8, GrB_Vector_extractTuples_UINT16,
+    GrB_Vector_extractTuples_INT8, GrB_Vector_extractTuples_UDT, GrB_Vector_extractTuples_UINT16,
     GrB_Vector_extractTuples_UINT32, GrB_Vector_extractTuples_UINT64,
     GrB_Vector_extractTuples_UINT8,
 };
@@ -19,7 +19,7 @@ use crate::{
     index::ElementIndex,
     value_type::{
         utilities_to_implement_traits_for_all_value_types::implement_1_type_macro_for_all_value_types_and_typed_graphblas_function_with_implementation_type,
-        ValueType,
+        CustomValueType, ValueType,
     },
 };
 
@@ -88,3 +88,54 @@ implement_1_type_macro_for_all_value_types_and_typed_graphblas_function_with_imp
     implement_get_element_indices,
     GrB_Vector_extractTuples
 );
+
+impl<T: CustomValueType> GetSparseVectorElementIndicesTyped<T> for T {
+    fn element_indices(
+        vector: &SparseVector<T>,
+    ) -> Result<Vec<ElementIndex>, SparseLinearAlgebraError> {
+        let number_of_stored_elements = vector.number_of_stored_elements()?;
+
+        let mut graphblas_indices: Vec<GrB_Index> = Vec::with_capacity(number_of_stored_elements);
+
+        let mut number_of_stored_and_returned_elements =
+            number_of_stored_elements.as_graphblas_index()?;
+
+        vector.context_ref().call(
+            || unsafe {
+                GrB_Vector_extractTuples_UDT(
+                    graphblas_indices.as_mut_ptr(),
+                    std::ptr::null_mut(),
+                    &mut number_of_stored_and_returned_elements,
+                    vector.graphblas_vector(),
+                )
+            },
+            unsafe { &vector.graphblas_vector() },
+        )?;
+
+        let length_of_element_list =
+            ElementIndex::from_graphblas_index(number_of_stored_and_returned_elements)?;
+
+        unsafe {
+            if length_of_element_list == number_of_stored_elements {
+                graphblas_indices.set_len(length_of_element_list);
+            } else {
+                let err: SparseLinearAlgebraError = GraphblasError::new(
+                    GraphblasErrorType::IndexOutOfBounds,
+                    format!(
+                        "matrix.number_of_stored_elements {} unequal to length of returned values {}",
+                        number_of_stored_elements, length_of_element_list
+                    ),
+                )
+                .into();
+                return Err(err);
+            }
+        };
+
+        let mut indices: Vec<ElementIndex> = Vec::with_capacity(length_of_element_list);
+
+        for index in graphblas_indices.into_iter() {
+            indices.push(ElementIndex::from_graphblas_index(index)?);
+        }
+        Ok(indices)
+    }
+}
@@ -1,7 +1,7 @@
 use suitesparse_graphblas_sys::{
     GrB_Vector_extractElement_BOOL, GrB_Vector_extractElement_FP32, GrB_Vector_extractElement_FP64,
     GrB_Vector_extractElement_INT16, GrB_Vector_extractElement_INT32,
-    GrB_Vector_extractElement_INT64, GrB_Vector_extractElement_INT8,
+    GrB_Vector_extractElement_INT64, GrB_Vector_extractElement_INT8, GrB_Vector_extractElement_UDT,
     GrB_Vector_extractElement_UINT16, GrB_Vector_extractElement_UINT32,
     GrB_Vector_extractElement_UINT64, GrB_Vector_extractElement_UINT8,
 };
@@ -20,10 +20,11 @@ use crate::{
     index::ElementIndex,
     value_type::{
         utilities_to_implement_traits_for_all_value_types::implement_1_type_macro_for_all_value_types_and_typed_graphblas_function_with_implementation_type,
-        ValueType,
+        CustomValueType, ValueType,
     },
 };
 use core::mem::MaybeUninit;
+use std::ffi::c_void;
 
 pub trait GetSparseVectorElementValue<T: ValueType + Default> {
     fn element_value(&self, index: &ElementIndex) -> Result<Option<T>, SparseLinearAlgebraError>;
@@ -106,3 +107,44 @@ implement_1_type_macro_for_all_value_types_and_typed_graphblas_function_with_imp
     implement_get_element_value_for_built_in_type,
     GrB_Vector_extractElement
 );
+
+impl<T: CustomValueType> GetSparseVectorElementValueTyped<T> for T {
+    fn element_value(
+        vector: &SparseVector<T>,
+        index: &ElementIndex,
+    ) -> Result<Option<T>, SparseLinearAlgebraError> {
+        let mut value: MaybeUninit<T> = MaybeUninit::uninit();
+        let index_to_get = index.to_graphblas_index()?;
+
+        let result = vector.context_ref().call(
+            || unsafe {
+                GrB_Vector_extractElement_UDT(
+                    value.as_mut_ptr() as *mut c_void,
+                    vector.graphblas_vector(),
+                    index_to_get,
+                )
+            },
+            unsafe { &vector.graphblas_vector() },
+        );
+
+        match result {
+            Ok(_) => Ok(Some(unsafe { value.assume_init() })),
+            Err(error) => match error.error_type() {
+                SparseLinearAlgebraErrorType::LogicErrorType(LogicErrorType::GraphBlas(
+                    GraphblasErrorType::NoValue,
+                )) => Ok(None),
+                _ => Err(error),
+            },
+        }
+    }
+
+    fn element_value_or_default(
+        vector: &SparseVector<T>,
+        index: &ElementIndex,
+    ) -> Result<T, SparseLinearAlgebraError> {
+        Ok(
+            <T as GetSparseVectorElementValueTyped<T>>::element_value(vector, index)?
+                .unwrap_or_default(),
+        )
+    }
+}
@@ -1,3 +1,5 @@
+use std::ffi::c_void;
+
 use crate::collections::collection::Collection;
 use crate::collections::sparse_vector::sparse_vector::GetGraphblasSparseVector;
 use crate::collections::sparse_vector::SparseVector;
@@ -9,14 +11,14 @@ use crate::error::SparseLinearAlgebraError;
 use crate::graphblas_bindings::{
     GrB_Vector_extractTuples_BOOL, GrB_Vector_extractTuples_FP32, GrB_Vector_extractTuples_FP64,
     GrB_Vector_extractTuples_INT16, GrB_Vector_extractTuples_INT32, GrB_Vector_extractTuples_INT64,
-    GrB_Vector_extractTuples_INT8, GrB_Vector_extractTuples_UINT16,
+    GrB_Vector_extractTuples_INT8, GrB_Vector_extractTuples_UDT, GrB_Vector_extractTuples_UINT16,
     GrB_Vector_extractTuples_UINT32, GrB_Vector_extractTuples_UINT64,
     GrB_Vector_extractTuples_UINT8,
 };
 use crate::index::ElementIndex;
 use crate::index::IndexConversion;
 use crate::value_type::utilities_to_implement_traits_for_all_value_types::implement_1_type_macro_for_all_value_types_and_typed_graphblas_function_with_implementation_type;
-use crate::value_type::{ConvertVector, ValueType};
+use crate::value_type::{ConvertVector, CustomValueType, ValueType};
 
 pub trait GetSparseVectorElementValues<T: ValueType> {
     fn element_values(&self) -> Result<Vec<T>, SparseLinearAlgebraError>;
@@ -77,3 +79,47 @@ implement_1_type_macro_for_all_value_types_and_typed_graphblas_function_with_imp
     implement_get_element_values,
     GrB_Vector_extractTuples
 );
+
+impl<T: CustomValueType> GetSparseVectorElementValuesTyped<T> for T {
+    fn element_values(vector: &SparseVector<T>) -> Result<Vec<T>, SparseLinearAlgebraError> {
+        let number_of_stored_elements = vector.number_of_stored_elements()?;
+
+        let mut values: Vec<T> = Vec::with_capacity(number_of_stored_elements);
+
+        let mut number_of_stored_and_returned_elements =
+            number_of_stored_elements.as_graphblas_index()?;
+
+        vector.context_ref().call(
+            || unsafe {
+                GrB_Vector_extractTuples_UDT(
+                    std::ptr::null_mut(),
+                    values.as_mut_ptr() as *mut c_void,
+                    &mut number_of_stored_and_returned_elements,
+                    vector.graphblas_vector(),
+                )
+            },
+            unsafe { &vector.graphblas_vector() },
+        )?;
+
+        let length_of_element_list =
+            ElementIndex::from_graphblas_index(number_of_stored_and_returned_elements)?;
+
+        unsafe {
+            if length_of_element_list == number_of_stored_elements {
+                values.set_len(length_of_element_list);
+            } else {
+                let err: SparseLinearAlgebraError = GraphblasError::new(
+                    GraphblasErrorType::IndexOutOfBounds,
+                    format!(
+                        "matrix.number_of_stored_elements {} unequal to length of returned values {}",
+                        number_of_stored_elements, length_of_element_list
+                    ),
+                )
+                .into();
+                return Err(err);
+            }
+        };
+
+        Ok(values)
+    }
+}
@@ -1,6 +1,12 @@
+mod apply;
+mod arithmetic;
+mod cast;
+mod concatenate;
 mod create;
 mod deserialize;
 mod drop_element;
+mod element_wise;
+mod extract;
 mod get_element;
 mod get_element_indices;
 mod get_element_list;
@@ -8,14 +14,27 @@ mod get_element_value;
 mod get_element_values;
 mod get_length;
 mod is_element;
+mod multiply;
+mod pack;
+mod reduce;
 mod resize;
+mod select;
 mod serialize;
 mod set_element;
 mod sort;
+mod split;
+mod storage_options;
+mod unpack;
 
+pub use apply::*;
+pub use arithmetic::*;
+pub use cast::*;
+pub use concatenate::*;
 pub use create::*;
 pub use deserialize::*;
 pub use drop_element::*;
+pub use element_wise::*;
+pub use extract::*;
 pub use get_element::*;
 pub use get_element_indices::*;
 pub use get_element_list::*;
@@ -23,7 +42,14 @@ pub use get_element_value::*;
 pub use get_element_values::*;
 pub use get_length::*;
 pub use is_element::*;
+pub use multiply::*;
+pub use pack::*;
+pub use reduce::*;
 pub use resize::*;
+pub use select::*;
 pub use serialize::*;
 pub use set_element::*;
 pub use sort::SortSparseVector;
+pub use split::*;
+pub use storage_options::*;
+pub use unpack::*;
@@ -0,0 +1,108 @@
+use once_cell::sync::Lazy;
+
+use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
+use crate::collections::sparse_matrix::{GetMatrixDimensions, SparseMatrix};
+use crate::collections::sparse_vector::operations::GetSparseVectorLength;
+use crate::collections::sparse_vector::SparseVector;
+use crate::context::GetContext;
+use crate::error::{GraphblasError, GraphblasErrorType, SparseLinearAlgebraError};
+use crate::operators::binary_operator::Assignment;
+use crate::operators::mask::SelectEntireVector;
+use crate::operators::multiplication::{
+    MultiplyVectorByMatrix, VectorMatrixMultiplicationOperator,
+};
+use crate::operators::options::OptionsForOperatorWithMatrixAsSecondArgument;
+use crate::operators::semiring::Semiring;
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OptionsForOperatorWithMatrixAsSecondArgument> =
+    Lazy::new(OptionsForOperatorWithMatrixAsSecondArgument::new_default);
+
+pub trait MultiplySparseVector<T: ValueType> {
+    /// Multiplies into a new vector with the length of the column width of the multiplicant.
+    ///
+    /// Returns a DimensionMismatch error before calling GraphBLAS if the sizes are incompatible.
+    fn vxm(
+        &self,
+        multiplicant: &SparseMatrix<T>,
+        operator: &impl Semiring<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> MultiplySparseVector<T> for SparseVector<T> {
+    fn vxm(
+        &self,
+        multiplicant: &SparseMatrix<T>,
+        operator: &impl Semiring<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let multiplier_length = self.length()?;
+        let multiplicant_size = multiplicant.size()?;
+        if multiplier_length != multiplicant_size.row_height() {
+            return Err(GraphblasError::new(
+                GraphblasErrorType::DimensionMismatch,
+                format!(
+                    "Cannot multiply a vector of length {} by a matrix of size {:?}",
+                    multiplier_length, multiplicant_size
+                ),
+            )
+            .into());
+        }
+
+        let mut product = SparseVector::<T>::new(self.context(), multiplicant_size.column_width())?;
+
+        VectorMatrixMultiplicationOperator::new().apply(
+            self,
+            operator,
+            multiplicant,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireVector::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(product)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::FromMatrixElementList;
+    use crate::collections::sparse_matrix::{MatrixElementList, Size};
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+    use crate::operators::semiring::PlusTimes;
+
+    #[test]
+    fn multiply_into_new_vector() {
+        let context = Context::init_default().unwrap();
+        let vector = SparseVector::<i64>::from_element_list(
+            context.clone(),
+            2,
+            VectorElementList::from_element_vector(vec![(0, 2).into(), (1, 3).into()]),
+            &First::<i64>::new(),
+        )
+        .unwrap();
+        let matrix = SparseMatrix::<i64>::from_element_list(
+            context.clone(),
+            Size::new(2, 3),
+            MatrixElementList::from_element_vector(vec![(0, 2, 5).into(), (1, 2, 7).into()]),
+            &First::<i64>::new(),
+        )
+        .unwrap();
+
+        let product = vector.vxm(&matrix, &PlusTimes::<i64>::new()).unwrap();
+
+        assert_eq!(product.length().unwrap(), 3);
+        assert_eq!(product.element_value(&0).unwrap(), None);
+        assert_eq!(product.element_value(&2).unwrap(), Some(31));
+
+        let too_short = SparseVector::<i64>::new(context, 1).unwrap();
+        assert!(too_short.vxm(&matrix, &PlusTimes::<i64>::new()).is_err());
+    }
+}
@@ -0,0 +1,178 @@
+use std::ffi::c_void;
+use std::mem;
+
+use suitesparse_graphblas_sys::{
+    GxB_Vector_pack_Bitmap, GxB_Vector_pack_CSC, GxB_Vector_pack_Full,
+};
+
+use crate::collections::graphblas_buffer::{
+    allocated_size_in_bytes, check_buffer_length, ensure_allocation,
+};
+use crate::collections::sparse_vector::data::{
+    BitmapVectorData, FullVectorData, FullVectorDataTrait, SparseVectorData,
+};
+use crate::collections::sparse_vector::operations::sparse_vector_length;
+use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
+use crate::context::{CallGraphBlasContext, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::GrB_Index;
+use crate::index::IndexConversion;
+use crate::operators::options::GetOperatorOptions;
+use crate::value_type::ValueType;
+
+/// Replaces the elements of a vector by handing ownership of the data vectors to GraphBLAS, without copying.
+///
+/// The length of the vector is preserved, and the data must describe a vector of that length.
+///
+/// # Safety
+/// GraphBLAS releases the data vectors with its own deallocator.
+/// This requires the Rust global allocator to be the system allocator, which is the default.
+/// Only the lengths of the data vectors are checked, unless the options have extended descriptor options
+/// that do not trust the input, see ExtendedDescriptorOptions::with_trusted_input.
+/// Without that check, the content must be valid for the vector length, e.g. indices must be in bounds, unique and sorted.
+pub trait PackSparseVector<T: ValueType> {
+    /// # Safety
+    /// See the safety requirements of PackSparseVector.
+    unsafe fn pack_sparse(
+        &mut self,
+        data: SparseVectorData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+    /// # Safety
+    /// See the safety requirements of PackSparseVector.
+    unsafe fn pack_bitmap(
+        &mut self,
+        data: BitmapVectorData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+    /// # Safety
+    /// See the safety requirements of PackSparseVector.
+    unsafe fn pack_full(
+        &mut self,
+        data: FullVectorData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> PackSparseVector<T> for SparseVector<T> {
+    unsafe fn pack_sparse(
+        &mut self,
+        data: SparseVectorData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let (mut indices, mut values) = data.into_parts();
+
+        let number_of_stored_elements = indices.len();
+        check_buffer_length("values", values.len(), number_of_stored_elements)?;
+
+        ensure_allocation(&mut indices);
+        ensure_allocation(&mut values);
+
+        let indices_size = allocated_size_in_bytes(&indices)?;
+        let values_size = allocated_size_in_bytes(&values)?;
+        let number_of_stored_elements = number_of_stored_elements.to_graphblas_index()?;
+
+        let mut indices_pointer = indices.as_mut_ptr() as *mut GrB_Index;
+        let mut values_pointer = values.as_mut_ptr() as *mut c_void;
+
+        let graphblas_descriptor = options.graphblas_descriptor_for_call()?;
+        self.context_ref().call(
+            || unsafe {
+                GxB_Vector_pack_CSC(
+                    self.graphblas_vector(),
+                    &mut indices_pointer,
+                    &mut values_pointer,
+                    indices_size,
+                    values_size,
+                    false,
+                    number_of_stored_elements,
+                    false,
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { &self.graphblas_vector() },
+        )?;
+
+        // GraphBLAS now owns the memory
+        mem::forget(indices);
+        mem::forget(values);
+        Ok(())
+    }
+
+    unsafe fn pack_bitmap(
+        &mut self,
+        data: BitmapVectorData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let length = sparse_vector_length(self)?;
+        let (mut bitmap, mut values, number_of_stored_elements) = data.into_parts();
+
+        check_buffer_length("bitmap", bitmap.len(), length)?;
+        check_buffer_length("values", values.len(), length)?;
+
+        ensure_allocation(&mut bitmap);
+        ensure_allocation(&mut values);
+
+        let bitmap_size = allocated_size_in_bytes(&bitmap)?;
+        let values_size = allocated_size_in_bytes(&values)?;
+        let number_of_stored_elements = number_of_stored_elements.to_graphblas_index()?;
+
+        let mut bitmap_pointer = bitmap.as_mut_ptr();
+        let mut values_pointer = values.as_mut_ptr() as *mut c_void;
+
+        let graphblas_descriptor = options.graphblas_descriptor_for_call()?;
+        self.context_ref().call(
+            || unsafe {
+                GxB_Vector_pack_Bitmap(
+                    self.graphblas_vector(),
+                    &mut bitmap_pointer,
+                    &mut values_pointer,
+                    bitmap_size,
+                    values_size,
+                    false,
+                    number_of_stored_elements,
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { &self.graphblas_vector() },
+        )?;
+
+        // GraphBLAS now owns the memory
+        mem::forget(bitmap);
+        mem::forget(values);
+        Ok(())
+    }
+
+    unsafe fn pack_full(
+        &mut self,
+        data: FullVectorData<T>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let length = sparse_vector_length(self)?;
+        let mut values = data.values();
+
+        check_buffer_length("values", values.len(), length)?;
+
+        ensure_allocation(&mut values);
+        let values_size = allocated_size_in_bytes(&values)?;
+        let mut values_pointer = values.as_mut_ptr() as *mut c_void;
+
+        let graphblas_descriptor = options.graphblas_descriptor_for_call()?;
+        self.context_ref().call(
+            || unsafe {
+                GxB_Vector_pack_Full(
+                    self.graphblas_vector(),
+                    &mut values_pointer,
+                    values_size,
+                    false,
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { &self.graphblas_vector() },
+        )?;
+
+        // GraphBLAS now owns the memory
+        mem::forget(values);
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use once_cell::sync::Lazy;
+
+use crate::collections::sparse_scalar::{GetScalarValue, SparseScalar};
+use crate::collections::sparse_vector::SparseVector;
+use crate::context::GetContext;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{
+    Assignment, BinaryOperator, LogicalAnd, LogicalOr, Max, MaxTyped, Min, MinTyped, Plus,
+    PlusTyped, Times, TimesTyped,
+};
+use crate::operators::options::OperatorOptions;
+use crate::operators::reduce::{BinaryOperatorReducer, BinaryOperatorScalarReducer};
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OperatorOptions> =
+    Lazy::new(OperatorOptions::new_default);
+
+/// Reduces all stored elements to a single value.
+///
+/// Each reduction returns None if the vector has no stored elements.
+pub trait ReduceSparseVector<T: ValueType + Default> {
+    fn sum(&self) -> Result<Option<T>, SparseLinearAlgebraError>
+    where
+        T: PlusTyped<T>;
+    fn product(&self) -> Result<Option<T>, SparseLinearAlgebraError>
+    where
+        T: TimesTyped<T>;
+    fn min(&self) -> Result<Option<T>, SparseLinearAlgebraError>
+    where
+        T: MinTyped<T>;
+    fn max(&self) -> Result<Option<T>, SparseLinearAlgebraError>
+    where
+        T: MaxTyped<T>;
+
+    /// Whether any stored element is true, after casting the elements to bool
+    fn any(&self) -> Result<Option<bool>, SparseLinearAlgebraError>;
+    /// Whether all stored elements are true, after casting the elements to bool
+    fn all(&self) -> Result<Option<bool>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType + Default> ReduceSparseVector<T> for SparseVector<T>
+where
+    SparseScalar<T>: GetScalarValue<T>,
+{
+    fn sum(&self) -> Result<Option<T>, SparseLinearAlgebraError>
+    where
+        T: PlusTyped<T>,
+    {
+        reduce_to_value(self, &Plus::<T>::new())
+    }
+
+    fn product(&self) -> Result<Option<T>, SparseLinearAlgebraError>
+    where
+        T: TimesTyped<T>,
+    {
+        reduce_to_value(self, &Times::<T>::new())
+    }
+
+    fn min(&self) -> Result<Option<T>, SparseLinearAlgebraError>
+    where
+        T: MinTyped<T>,
+    {
+        reduce_to_value(self, &Min::<T>::new())
+    }
+
+    fn max(&self) -> Result<Option<T>, SparseLinearAlgebraError>
+    where
+        T: MaxTyped<T>,
+    {
+        reduce_to_value(self, &Max::<T>::new())
+    }
+
+    fn any(&self) -> Result<Option<bool>, SparseLinearAlgebraError> {
+        reduce_to_value::<T, bool>(self, &LogicalOr::<bool>::new())
+    }
+
+    fn all(&self) -> Result<Option<bool>, SparseLinearAlgebraError> {
+        reduce_to_value::<T, bool>(self, &LogicalAnd::<bool>::new())
+    }
+}
+
+fn reduce_to_value<T: ValueType, U: ValueType + Default>(
+    vector: &SparseVector<T>,
+    operator: &impl BinaryOperator<U>,
+) -> Result<Option<U>, SparseLinearAlgebraError>
+where
+    SparseScalar<U>: GetScalarValue<U>,
+{
+    let mut product = SparseScalar::<U>::new(vector.context())?;
+
+    // GraphBLAS typecasts the stored elements to the domain of the operator
+    BinaryOperatorReducer::new().vector_to_scalar(
+        operator,
+        vector,
+        &Assignment::<U>::new(),
+        &mut product,
+        &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+    )?;
+
+    product.get_value()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_vector::operations::FromVectorElementList;
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    #[test]
+    fn reduce_vector_to_values() {
+        let context = Context::init_default().unwrap();
+        let element_list = VectorElementList::<f32>::from_element_vector(vec![
+            (1, 1.5).into(),
+            (3, 0.0).into(),
+            (4, -4.0).into(),
+        ]);
+        let vector =
+            SparseVector::<f32>::from_element_list(context, 6, element_list, &First::<f32>::new())
+                .unwrap();
+
+        assert_eq!(vector.sum().unwrap(), Some(-2.5));
+        assert_eq!(vector.product().unwrap(), Some(0.0));
+        assert_eq!(vector.min().unwrap(), Some(-4.0));
+        assert_eq!(vector.max().unwrap(), Some(1.5));
+        assert_eq!(vector.any().unwrap(), Some(true));
+        assert_eq!(vector.all().unwrap(), Some(false));
+    }
+
+    #[test]
+    fn reduce_empty_vector() {
+        let context = Context::init_default().unwrap();
+        let vector = SparseVector::<u16>::new(context, 3).unwrap();
+
+        assert_eq!(vector.product().unwrap(), None);
+        assert_eq!(vector.min().unwrap(), None);
+        assert_eq!(vector.all().unwrap(), None);
+    }
+}
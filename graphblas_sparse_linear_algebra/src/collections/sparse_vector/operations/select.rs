@@ -0,0 +1,86 @@
+use once_cell::sync::Lazy;
+
+use crate::collections::sparse_vector::operations::GetSparseVectorLength;
+use crate::collections::sparse_vector::SparseVector;
+use crate::context::GetContext;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::Assignment;
+use crate::operators::index_unary_operator::IndexUnaryOperator;
+use crate::operators::mask::SelectEntireVector;
+use crate::operators::options::OperatorOptions;
+use crate::operators::select::{SelectFromVector, VectorSelector};
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OperatorOptions> =
+    Lazy::new(OperatorOptions::new_default);
+
+pub trait SelectFromSparseVector<T: ValueType> {
+    /// Returns a new vector with the stored elements for which the selector is true
+    fn select(
+        &self,
+        selector: &impl IndexUnaryOperator<T>,
+        selector_argument: T,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> SelectFromSparseVector<T> for SparseVector<T>
+where
+    VectorSelector: SelectFromVector<T>,
+{
+    fn select(
+        &self,
+        selector: &impl IndexUnaryOperator<T>,
+        selector_argument: T,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::<T>::new(self.context(), self.length()?)?;
+
+        VectorSelector::new().apply(
+            selector,
+            selector_argument,
+            self,
+            &Assignment::<T>::new(),
+            &mut product,
+            &SelectEntireVector::new(self.context()),
+            &*DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS,
+        )?;
+
+        Ok(product)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::collections::Collection;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+    use crate::operators::index_unary_operator::IsValueLessThan;
+
+    #[test]
+    fn select_into_new_vector() {
+        let context = Context::init_default().unwrap();
+        let vector = SparseVector::<i8>::from_element_list(
+            context,
+            5,
+            VectorElementList::from_element_vector(vec![
+                (0, -3).into(),
+                (2, 4).into(),
+                (4, 0).into(),
+            ]),
+            &First::<i8>::new(),
+        )
+        .unwrap();
+
+        let selection = vector.select(&IsValueLessThan::<i8>::new(), 1).unwrap();
+
+        assert_eq!(selection.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(selection.element_value(&0).unwrap(), Some(-3));
+        assert_eq!(selection.element_value(&2).unwrap(), None);
+        assert_eq!(selection.element_value(&4).unwrap(), Some(0));
+    }
+}
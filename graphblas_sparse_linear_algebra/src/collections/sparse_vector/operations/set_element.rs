@@ -1,3 +1,5 @@
+use std::ffi::c_void;
+
 use crate::collections::sparse_vector::sparse_vector::GetGraphblasSparseVector;
 use crate::collections::sparse_vector::GetVectorElementIndex;
 use crate::collections::sparse_vector::GetVectorElementValue;
@@ -5,7 +7,7 @@ use crate::collections::sparse_vector::SparseVector;
 use crate::context::CallGraphBlasContext;
 use crate::index::ElementIndex;
 use crate::index::IndexConversion;
-use crate::value_type::ConvertScalar;
+use crate::value_type::{ConvertScalar, CustomValueType};
 use crate::{
     error::SparseLinearAlgebraError,
     value_type::{
@@ -17,8 +19,8 @@ use crate::{
 use crate::graphblas_bindings::{
     GrB_Vector_setElement_BOOL, GrB_Vector_setElement_FP32, GrB_Vector_setElement_FP64,
     GrB_Vector_setElement_INT16, GrB_Vector_setElement_INT32, GrB_Vector_setElement_INT64,
-    GrB_Vector_setElement_INT8, GrB_Vector_setElement_UINT16, GrB_Vector_setElement_UINT32,
-    GrB_Vector_setElement_UINT64, GrB_Vector_setElement_UINT8,
+    GrB_Vector_setElement_INT8, GrB_Vector_setElement_UDT, GrB_Vector_setElement_UINT16,
+    GrB_Vector_setElement_UINT32, GrB_Vector_setElement_UINT64, GrB_Vector_setElement_UINT8,
 };
 
 pub trait SetSparseVectorElement<T: ValueType> {
@@ -102,3 +104,31 @@ implement_1_type_macro_for_all_value_types_and_typed_graphblas_function_with_imp
     implement_set_element_for_built_in_type,
     GrB_Vector_setElement
 );
+
+impl<T: CustomValueType> SetSparseVectorElementTyped<T> for T {
+    fn set_graphblas_vector_element(
+        vector: &mut impl GetGraphblasSparseVector,
+        element: impl GetVectorElementIndex + GetVectorElementValue<T>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        T::set_graphblas_vector_value(vector, element.index(), element.value())
+    }
+
+    fn set_graphblas_vector_value(
+        vector: &mut impl GetGraphblasSparseVector,
+        index: ElementIndex,
+        mut value: T,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let index_to_set = index.to_graphblas_index()?;
+        vector.context_ref().call(
+            || unsafe {
+                GrB_Vector_setElement_UDT(
+                    vector.graphblas_vector(),
+                    &mut value as *mut T as *mut c_void,
+                    index_to_set,
+                )
+            },
+            unsafe { &vector.graphblas_vector() },
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use std::ptr;
+
+use once_cell::sync::Lazy;
+use suitesparse_graphblas_sys::GrB_Vector_extract;
+
+use crate::collections::sparse_vector::operations::GetSparseVectorLength;
+use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
+use crate::context::{CallGraphBlasContext, GetContext};
+use crate::error::{GraphblasError, GraphblasErrorType, SparseLinearAlgebraError};
+use crate::graphblas_bindings::GrB_Index;
+use crate::index::{ElementCount, ElementIndex, IndexConversion};
+use crate::operators::options::{GetGraphblasDescriptor, OperatorOptions};
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OperatorOptions> =
+    Lazy::new(OperatorOptions::new_default);
+
+/// Splits a vector into consecutive tiles.
+///
+/// The tile lengths must sum to the length of the vector.
+pub trait SplitSparseVector<T: ValueType> {
+    fn split(
+        &self,
+        tile_lengths: &[ElementCount],
+    ) -> Result<Vec<SparseVector<T>>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> SplitSparseVector<T> for SparseVector<T> {
+    fn split(
+        &self,
+        tile_lengths: &[ElementCount],
+    ) -> Result<Vec<SparseVector<T>>, SparseLinearAlgebraError> {
+        let length = self.length()?;
+        let sum_of_tile_lengths: ElementCount = tile_lengths.iter().sum();
+        if tile_lengths.is_empty() || sum_of_tile_lengths != length {
+            return Err(GraphblasError::new(
+                GraphblasErrorType::DimensionMismatch,
+                format!(
+                    "The tile lengths must sum to the vector length {}, found {:?}",
+                    length, tile_lengths
+                ),
+            )
+            .into());
+        }
+
+        let mut tiles = Vec::with_capacity(tile_lengths.len());
+        let mut first_index: ElementIndex = 0;
+        for tile_length in tile_lengths {
+            let tile = SparseVector::<T>::new(self.context(), *tile_length)?;
+
+            let mut indices: Vec<GrB_Index> = Vec::with_capacity(*tile_length);
+            for index in first_index..first_index + tile_length {
+                indices.push(index.to_graphblas_index()?);
+            }
+            let number_of_indices = tile_length.to_graphblas_index()?;
+
+            self.context_ref().call(
+                || unsafe {
+                    GrB_Vector_extract(
+                        tile.graphblas_vector(),
+                        ptr::null_mut(),
+                        ptr::null_mut(),
+                        self.graphblas_vector(),
+                        indices.as_ptr(),
+                        number_of_indices,
+                        DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+                    )
+                },
+                unsafe { tile.graphblas_vector_ref() },
+            )?;
+
+            tiles.push(tile);
+            first_index += tile_length;
+        }
+        Ok(tiles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::collection::Collection;
+    use crate::collections::sparse_vector::operations::{
+        ConcatenateSparseVectors, FromVectorElementList, GetSparseVectorElementList,
+        GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    fn test_vector() -> SparseVector<i8> {
+        let context = Context::init_default().unwrap();
+        let element_list = VectorElementList::<i8>::from_element_vector(vec![
+            (0, 1).into(),
+            (3, 2).into(),
+            (4, 3).into(),
+        ]);
+        SparseVector::<i8>::from_element_list(context, 7, element_list, &First::<i8>::new())
+            .unwrap()
+    }
+
+    #[test]
+    fn split_into_tiles() {
+        let vector = test_vector();
+
+        let tiles = vector.split(&[3, 2, 2]).unwrap();
+
+        assert_eq!(tiles.len(), 3);
+        assert_eq!(tiles[0].length().unwrap(), 3);
+        assert_eq!(tiles[0].element_value(&0).unwrap(), Some(1));
+        assert_eq!(tiles[1].element_value(&0).unwrap(), Some(2));
+        assert_eq!(tiles[1].element_value(&1).unwrap(), Some(3));
+        assert_eq!(tiles[2].number_of_stored_elements().unwrap(), 0);
+    }
+
+    #[test]
+    fn split_and_concatenate() {
+        let vector = test_vector();
+
+        let tiles = vector.split(&[1, 6]).unwrap();
+        let concatenated_vector = SparseVector::<i8>::from_tiles(vector.context(), &tiles).unwrap();
+
+        assert_eq!(concatenated_vector.length().unwrap(), 7);
+        assert_eq!(
+            concatenated_vector.element_list().unwrap(),
+            vector.element_list().unwrap()
+        );
+    }
+
+    #[test]
+    fn split_with_invalid_partition() {
+        let vector = test_vector();
+
+        assert!(vector.split(&[3, 3]).is_err());
+        assert!(vector.split(&[]).is_err());
+    }
+}
@@ -0,0 +1,156 @@
+use std::mem::MaybeUninit;
+
+use suitesparse_graphblas_sys::{
+    GxB_Option_Field, GxB_Option_Field_GxB_BITMAP_SWITCH, GxB_Option_Field_GxB_SPARSITY_CONTROL,
+    GxB_Option_Field_GxB_SPARSITY_STATUS, GxB_Vector_Option_get_FP64, GxB_Vector_Option_get_INT32,
+    GxB_Vector_Option_set_FP64, GxB_Vector_Option_set_INT32,
+};
+
+use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
+use crate::context::{CallGraphBlasContext, GetContext, SparsityControl, SparsityStructure};
+use crate::error::SparseLinearAlgebraError;
+use crate::value_type::ValueType;
+
+/// Queries how GraphBLAS stores a vector.
+///
+/// A vector is always stored by column, and never as hypersparse.
+pub trait GetSparseVectorStorageOptions {
+    fn sparsity_control(&self) -> Result<SparsityControl, SparseLinearAlgebraError>;
+    /// The structure in which the vector is currently stored
+    fn sparsity_structure(&self) -> Result<SparsityStructure, SparseLinearAlgebraError>;
+    fn bitmap_switch(&self) -> Result<f64, SparseLinearAlgebraError>;
+}
+
+/// Controls how GraphBLAS stores a vector, overriding the global settings of the Context.
+///
+/// The elements of the vector are unaffected, only their representation changes.
+pub trait SetSparseVectorStorageOptions {
+    fn set_sparsity_control(
+        &mut self,
+        sparsity_control: SparsityControl,
+    ) -> Result<(), SparseLinearAlgebraError>;
+    /// The vector becomes bitmap when the fraction of stored elements exceeds the bitmap switch,
+    /// and sparse when it drops below half the bitmap switch.
+    fn set_bitmap_switch(&mut self, bitmap_switch: f64) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType> GetSparseVectorStorageOptions for SparseVector<T> {
+    fn sparsity_control(&self) -> Result<SparsityControl, SparseLinearAlgebraError> {
+        let graphblas_flags = get_int32_option(self, GxB_Option_Field_GxB_SPARSITY_CONTROL)?;
+        Ok(SparsityControl::from_graphblas_flags(graphblas_flags))
+    }
+
+    fn sparsity_structure(&self) -> Result<SparsityStructure, SparseLinearAlgebraError> {
+        let status = get_int32_option(self, GxB_Option_Field_GxB_SPARSITY_STATUS)?;
+        SparsityStructure::from_graphblas_sparsity_status(status)
+    }
+
+    fn bitmap_switch(&self) -> Result<f64, SparseLinearAlgebraError> {
+        let mut value: MaybeUninit<f64> = MaybeUninit::uninit();
+        self.context_ref().call(
+            || unsafe {
+                GxB_Vector_Option_get_FP64(
+                    self.graphblas_vector(),
+                    GxB_Option_Field_GxB_BITMAP_SWITCH,
+                    value.as_mut_ptr(),
+                )
+            },
+            unsafe { self.graphblas_vector_ref() },
+        )?;
+        Ok(unsafe { value.assume_init() })
+    }
+}
+
+impl<T: ValueType> SetSparseVectorStorageOptions for SparseVector<T> {
+    fn set_sparsity_control(
+        &mut self,
+        sparsity_control: SparsityControl,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        self.context_ref().call(
+            || unsafe {
+                GxB_Vector_Option_set_INT32(
+                    self.graphblas_vector(),
+                    GxB_Option_Field_GxB_SPARSITY_CONTROL,
+                    sparsity_control.to_graphblas_flags(),
+                )
+            },
+            unsafe { self.graphblas_vector_ref() },
+        )?;
+        Ok(())
+    }
+
+    fn set_bitmap_switch(&mut self, bitmap_switch: f64) -> Result<(), SparseLinearAlgebraError> {
+        self.context_ref().call(
+            || unsafe {
+                GxB_Vector_Option_set_FP64(
+                    self.graphblas_vector(),
+                    GxB_Option_Field_GxB_BITMAP_SWITCH,
+                    bitmap_switch,
+                )
+            },
+            unsafe { self.graphblas_vector_ref() },
+        )?;
+        Ok(())
+    }
+}
+
+fn get_int32_option(
+    vector: &impl GetGraphblasSparseVector,
+    field: GxB_Option_Field,
+) -> Result<i32, SparseLinearAlgebraError> {
+    let mut value: MaybeUninit<i32> = MaybeUninit::uninit();
+    vector.context_ref().call(
+        || unsafe {
+            GxB_Vector_Option_get_INT32(vector.graphblas_vector(), field, value.as_mut_ptr())
+        },
+        unsafe { vector.graphblas_vector_ref() },
+    )?;
+    Ok(unsafe { value.assume_init() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementList,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    #[test]
+    fn pin_vector_to_bitmap() {
+        let context = Context::init_default().unwrap();
+        let element_list =
+            VectorElementList::<f64>::from_element_vector(vec![(2, 1.0).into(), (7, 2.0).into()]);
+        let mut vector = SparseVector::<f64>::from_element_list(
+            context,
+            100,
+            element_list.clone(),
+            &First::<f64>::new(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            vector.sparsity_structure().unwrap(),
+            SparsityStructure::Sparse
+        );
+
+        vector
+            .set_sparsity_control(SparsityControl::new(&[SparsityStructure::Bitmap]))
+            .unwrap();
+        assert_eq!(
+            vector.sparsity_control().unwrap().allowed_structures(),
+            vec![SparsityStructure::Bitmap]
+        );
+        assert_eq!(
+            vector.sparsity_structure().unwrap(),
+            SparsityStructure::Bitmap
+        );
+        assert_eq!(vector.element_list().unwrap(), element_list);
+
+        vector.set_bitmap_switch(0.75).unwrap();
+        assert_eq!(vector.bitmap_switch().unwrap(), 0.75);
+    }
+}
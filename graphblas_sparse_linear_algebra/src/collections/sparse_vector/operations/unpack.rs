@@ -0,0 +1,280 @@
+use std::ffi::c_void;
+use std::ptr;
+
+use once_cell::sync::Lazy;
+use suitesparse_graphblas_sys::{
+    GxB_Vector_unpack_Bitmap, GxB_Vector_unpack_CSC, GxB_Vector_unpack_Full,
+};
+
+use crate::collections::graphblas_buffer::GraphblasBuffer;
+use crate::collections::sparse_vector::data::{BitmapVectorData, FullVectorData, SparseVectorData};
+use crate::collections::sparse_vector::operations::sparse_vector_length;
+use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
+use crate::context::{CallGraphBlasContext, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::GrB_Index;
+use crate::index::{ElementCount, ElementIndex, IndexConversion};
+use crate::operators::options::{GetGraphblasDescriptor, OperatorOptions};
+use crate::value_type::ValueType;
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OperatorOptions> =
+    Lazy::new(OperatorOptions::new_default);
+
+/// Takes ownership of the data of a vector from GraphBLAS, without copying.
+///
+/// The vector keeps its length, and has no stored elements afterwards.
+/// GraphBLAS converts the vector to the requested format first if required, which may take more than O(1) time.
+///
+/// # Safety
+/// The data vectors are allocated by GraphBLAS.
+/// This requires the Rust global allocator to be the system allocator, which is the default.
+pub trait UnpackSparseVector<T: ValueType> {
+    /// # Safety
+    /// See the safety requirements of UnpackSparseVector.
+    unsafe fn unpack_sparse(&mut self) -> Result<SparseVectorData<T>, SparseLinearAlgebraError>;
+    /// The values of absent elements are T::default().
+    ///
+    /// # Safety
+    /// See the safety requirements of UnpackSparseVector.
+    unsafe fn unpack_bitmap(&mut self) -> Result<BitmapVectorData<T>, SparseLinearAlgebraError>;
+    /// Fails if not every element of the vector is stored.
+    ///
+    /// # Safety
+    /// See the safety requirements of UnpackSparseVector.
+    unsafe fn unpack_full(&mut self) -> Result<FullVectorData<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType + Clone + Default> UnpackSparseVector<T> for SparseVector<T> {
+    unsafe fn unpack_sparse(&mut self) -> Result<SparseVectorData<T>, SparseLinearAlgebraError> {
+        let mut indices_pointer: *mut GrB_Index = ptr::null_mut();
+        let mut values_pointer: *mut c_void = ptr::null_mut();
+        let mut indices_size: GrB_Index = 0;
+        let mut values_size: GrB_Index = 0;
+        let mut number_of_stored_elements: GrB_Index = 0;
+
+        // Passing null for iso and jumbled requests non-iso values and sorted indices
+        self.context_ref().call(
+            || unsafe {
+                GxB_Vector_unpack_CSC(
+                    self.graphblas_vector(),
+                    &mut indices_pointer,
+                    &mut values_pointer,
+                    &mut indices_size,
+                    &mut values_size,
+                    ptr::null_mut(),
+                    &mut number_of_stored_elements,
+                    ptr::null_mut(),
+                    DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+                )
+            },
+            unsafe { &self.graphblas_vector() },
+        )?;
+
+        let indices = GraphblasBuffer::new(indices_pointer as *mut ElementIndex, indices_size);
+        let values = GraphblasBuffer::new(values_pointer as *mut T, values_size);
+
+        let number_of_stored_elements =
+            ElementCount::from_graphblas_index(number_of_stored_elements)?;
+        let indices = indices.into_vec(number_of_stored_elements)?;
+        let values = values.into_vec(number_of_stored_elements)?;
+
+        Ok(SparseVectorData::new(indices, values))
+    }
+
+    unsafe fn unpack_bitmap(&mut self) -> Result<BitmapVectorData<T>, SparseLinearAlgebraError> {
+        let length = sparse_vector_length(self)?;
+
+        let mut bitmap_pointer: *mut i8 = ptr::null_mut();
+        let mut values_pointer: *mut c_void = ptr::null_mut();
+        let mut bitmap_size: GrB_Index = 0;
+        let mut values_size: GrB_Index = 0;
+        let mut number_of_stored_elements: GrB_Index = 0;
+
+        // Passing null for iso requests non-iso values
+        self.context_ref().call(
+            || unsafe {
+                GxB_Vector_unpack_Bitmap(
+                    self.graphblas_vector(),
+                    &mut bitmap_pointer,
+                    &mut values_pointer,
+                    &mut bitmap_size,
+                    &mut values_size,
+                    ptr::null_mut(),
+                    &mut number_of_stored_elements,
+                    DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+                )
+            },
+            unsafe { &self.graphblas_vector() },
+        )?;
+
+        let bitmap = GraphblasBuffer::new(bitmap_pointer, bitmap_size);
+        let values = GraphblasBuffer::new(values_pointer as *mut T, values_size);
+
+        let bitmap = bitmap.into_vec(length)?;
+        let values = values.into_vec_with_bitmap(&bitmap)?;
+
+        Ok(BitmapVectorData::from_parts(
+            bitmap,
+            values,
+            ElementCount::from_graphblas_index(number_of_stored_elements)?,
+        ))
+    }
+
+    unsafe fn unpack_full(&mut self) -> Result<FullVectorData<T>, SparseLinearAlgebraError> {
+        let length = sparse_vector_length(self)?;
+
+        let mut values_pointer: *mut c_void = ptr::null_mut();
+        let mut values_size: GrB_Index = 0;
+
+        // Passing null for iso requests non-iso values
+        self.context_ref().call(
+            || unsafe {
+                GxB_Vector_unpack_Full(
+                    self.graphblas_vector(),
+                    &mut values_pointer,
+                    &mut values_size,
+                    ptr::null_mut(),
+                    DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+                )
+            },
+            unsafe { &self.graphblas_vector() },
+        )?;
+
+        let values =
+            GraphblasBuffer::new(values_pointer as *mut T, values_size).into_vec(length)?;
+
+        Ok(FullVectorData::from_vector(values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::collection::Collection;
+    use crate::collections::sparse_vector::data::FullVectorDataTrait;
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementList, GetSparseVectorElementValue,
+        PackSparseVector,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    fn test_vector(context: &std::sync::Arc<Context>) -> SparseVector<i16> {
+        let element_list = VectorElementList::<i16>::from_element_vector(vec![
+            (1, 1).into(),
+            (4, 2).into(),
+            (6, 3).into(),
+        ]);
+
+        SparseVector::<i16>::from_element_list(
+            context.clone(),
+            8,
+            element_list,
+            &First::<i16>::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn pack_full() {
+        let context = Context::init_default().unwrap();
+        let mut vector = SparseVector::<i16>::new(context.clone(), 4).unwrap();
+
+        let data = FullVectorData::<i16>::from_vector(vec![0, 1, 2, 3]);
+        unsafe { vector.pack_full(data, &OperatorOptions::new_default()) }.unwrap();
+
+        assert_eq!(vector.number_of_stored_elements().unwrap(), 4);
+        assert_eq!(vector.element_value(&2).unwrap(), Some(2));
+    }
+
+    #[test]
+    fn pack_full_with_insufficient_values() {
+        let context = Context::init_default().unwrap();
+        let mut vector = SparseVector::<i16>::new(context.clone(), 4).unwrap();
+
+        let data = FullVectorData::<i16>::from_vector(vec![0, 1, 2]);
+        assert!(unsafe { vector.pack_full(data, &OperatorOptions::new_default()) }.is_err());
+    }
+
+    #[test]
+    fn pack_and_unpack_full() {
+        let context = Context::init_default().unwrap();
+        let mut vector = SparseVector::<f32>::new(context.clone(), 5).unwrap();
+
+        let data = FullVectorData::<f32>::from_vector(vec![0.5, 1.0, 1.5, 2.0, 2.5]);
+        unsafe { vector.pack_full(data.clone(), &OperatorOptions::new_default()) }.unwrap();
+
+        let unpacked_data = unsafe { vector.unpack_full() }.unwrap();
+        assert_eq!(unpacked_data.values_ref(), data.values_ref());
+        assert_eq!(vector.number_of_stored_elements().unwrap(), 0);
+    }
+
+    #[test]
+    fn unpack_full_from_sparse_vector() {
+        let context = Context::init_default().unwrap();
+        let mut vector = test_vector(&context);
+
+        assert!(unsafe { vector.unpack_full() }.is_err());
+    }
+
+    #[test]
+    fn unpack_and_pack_sparse() {
+        let context = Context::init_default().unwrap();
+        let mut vector = test_vector(&context);
+        let expected_element_list = vector.element_list().unwrap();
+
+        let data = unsafe { vector.unpack_sparse() }.unwrap();
+        assert_eq!(data.indices_ref(), &[1, 4, 6]);
+        assert_eq!(data.values_ref(), &[1, 2, 3]);
+        assert_eq!(vector.number_of_stored_elements().unwrap(), 0);
+
+        unsafe { vector.pack_sparse(data, &OperatorOptions::new_default()) }.unwrap();
+        assert_eq!(vector.element_list().unwrap(), expected_element_list);
+    }
+
+    #[test]
+    fn pack_empty_sparse_data() {
+        let context = Context::init_default().unwrap();
+        let mut vector = test_vector(&context);
+
+        unsafe {
+            vector.pack_sparse(
+                SparseVectorData::new(vec![], vec![]),
+                &OperatorOptions::new_default(),
+            )
+        }
+        .unwrap();
+        assert_eq!(vector.number_of_stored_elements().unwrap(), 0);
+    }
+
+    #[test]
+    fn unpack_and_pack_bitmap() {
+        let context = Context::init_default().unwrap();
+        let mut vector = test_vector(&context);
+        let expected_element_list = vector.element_list().unwrap();
+
+        let data = unsafe { vector.unpack_bitmap() }.unwrap();
+        assert_eq!(data.bitmap_ref(), &[0, 1, 0, 0, 1, 0, 1, 0]);
+        assert_eq!(data.number_of_stored_elements(), 3);
+        assert_eq!(data.values_ref()[4], 2);
+        assert_eq!(data.values_ref()[0], 0);
+
+        unsafe { vector.pack_bitmap(data, &OperatorOptions::new_default()) }.unwrap();
+        assert_eq!(vector.element_list().unwrap(), expected_element_list);
+    }
+
+    #[test]
+    fn pack_bitmap() {
+        let context = Context::init_default().unwrap();
+        let mut vector = SparseVector::<u8>::new(context.clone(), 3).unwrap();
+
+        let data = BitmapVectorData::new(vec![1, 0, 1], vec![7, 0, 9]);
+        unsafe { vector.pack_bitmap(data, &OperatorOptions::new_default()) }.unwrap();
+
+        assert_eq!(vector.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(vector.element_value(&1).unwrap(), None);
+        assert_eq!(vector.element_value(&2).unwrap(), Some(9));
+    }
+}
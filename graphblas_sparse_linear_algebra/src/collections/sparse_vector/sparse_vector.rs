@@ -1,6 +1,7 @@
 use std::cmp::min;
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
+use std::ptr;
 use std::sync::Arc;
 
 use once_cell::sync::Lazy;
@@ -13,17 +14,22 @@ use crate::collections::sparse_scalar::{GraphblasSparseScalarTrait, SparseScalar
 use crate::collections::sparse_vector::operations::GetSparseVectorElementList;
 use crate::collections::sparse_vector::operations::GetSparseVectorLength;
 use crate::collections::sparse_vector::VectorElementList;
+use crate::collections::{pending_work_from_graphblas_report, PendingWork, WaitMode};
 use crate::context::CallGraphBlasContext;
-use crate::context::{Context, GetContext};
+use crate::context::{
+    deregister_graphblas_collection, graphblas_vector_memory_usage, register_graphblas_collection,
+    Context, GetContext, GraphblasCollectionType,
+};
 use crate::error::SparseLinearAlgebraError;
 use crate::graphblas_bindings::{
     GrB_Index, GrB_Vector, GrB_Vector_clear, GrB_Vector_dup, GrB_Vector_free, GrB_Vector_new,
     GrB_Vector_nvals,
 };
+use crate::graphblas_bindings::{GrB_Vector_wait, GxB_Print_Level_GxB_SUMMARY, GxB_Vector_fprint};
 use crate::index::{
     DiagonalIndex, DiagonalIndexConversion, ElementCount, ElementIndex, IndexConversion,
 };
-use crate::operators::mask::VectorMask;
+use crate::operators::mask::{GetMaskOptions, VectorMask};
 use crate::operators::options::{GetGraphblasDescriptor, OperatorOptions};
 use crate::value_type::utilities_to_implement_traits_for_all_value_types::implement_macro_for_all_value_types;
 use crate::value_type::ValueType;
@@ -67,7 +73,10 @@ impl<T: ValueType> SparseVector<T> {
         context: Arc<Context>,
         length: ElementCount,
     ) -> Result<Self, SparseLinearAlgebraError> {
-        let vector = unsafe { new_graphblas_vector(&context, length, T::to_graphblas_type())? };
+        let vector = unsafe {
+            new_graphblas_vector(&context, length, T::graphblas_type_in_context(&context)?)?
+        };
+        register_graphblas_collection(&context, vector as usize, GraphblasCollectionType::Vector);
         return Ok(SparseVector {
             context,
             vector,
@@ -79,6 +88,7 @@ impl<T: ValueType> SparseVector<T> {
         context: Arc<Context>,
         vector: GrB_Vector,
     ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        register_graphblas_collection(&context, vector as usize, GraphblasCollectionType::Vector);
         Ok(SparseVector {
             context: context.clone(),
             vector,
@@ -187,30 +197,6 @@ macro_rules! implement_from_value {
 }
 implement_macro_for_all_value_types!(implement_from_value);
 
-// impl<T: ValueType + CustomValueType> SparseVector<T> {
-//     pub fn new_custom_type(
-//         // context: Arc<Context>,
-//         value_type: Arc<RegisteredCustomValueType<T>>,
-//         length: ElementIndex,
-//     ) -> Result<Self, SparseLinearAlgebraError> {
-//         let mut vector: MaybeUninit<GrB_Vector> = MaybeUninit::uninit();
-//         let context = value_type.context();
-
-//         let length = length.to_graphblas_index()?;
-
-//         context.call(|| unsafe {
-//             GrB_Vector_new(vector.as_mut_ptr(), value_type.to_graphblas_type(), length)
-//         })?;
-
-//         let vector = unsafe { vector.assume_init() };
-//         return Ok(SparseVector {
-//             context,
-//             vector,
-//             value_type: PhantomData,
-//         });
-//     }
-// }
-
 impl<T: ValueType> GetContext for SparseVector<T> {
     fn context(&self) -> Arc<Context> {
         self.context.clone()
@@ -236,6 +222,24 @@ impl<T: ValueType> Collection for SparseVector<T> {
         let number_of_values = unsafe { number_of_values.assume_init() };
         Ok(ElementCount::from_graphblas_index(number_of_values)?)
     }
+
+    fn memory_usage_in_bytes(&self) -> Result<usize, SparseLinearAlgebraError> {
+        graphblas_vector_memory_usage(&self.context, self.vector)
+    }
+
+    fn wait(&mut self, mode: WaitMode) -> Result<(), SparseLinearAlgebraError> {
+        self.context.call(
+            || unsafe { GrB_Vector_wait(self.vector, mode.into()) },
+            &self.vector,
+        )?;
+        Ok(())
+    }
+
+    fn pending_work(&self) -> Result<PendingWork, SparseLinearAlgebraError> {
+        pending_work_from_graphblas_report(&self.context, |file| unsafe {
+            GxB_Vector_fprint(self.vector, ptr::null(), GxB_Print_Level_GxB_SUMMARY, file)
+        })
+    }
 }
 
 pub trait GetGraphblasSparseVector: GetContext {
@@ -258,6 +262,7 @@ impl<T: ValueType> GetGraphblasSparseVector for SparseVector<T> {
 
 impl<T: ValueType> Drop for SparseVector<T> {
     fn drop(&mut self) -> () {
+        deregister_graphblas_collection(&self.context, self.vector as usize);
         let _ = self
             .context
             .call_without_detailed_error_information(|| unsafe {
@@ -268,12 +273,12 @@ impl<T: ValueType> Drop for SparseVector<T> {
 
 impl<T: ValueType> Clone for SparseVector<T> {
     fn clone(&self) -> Self {
-        SparseVector {
-            context: self.context.clone(),
-            vector: unsafe {
-                clone_graphblas_vector(self.context_ref(), self.graphblas_vector_ref()).unwrap()
-            },
-            value_type: PhantomData,
+        unsafe {
+            SparseVector::from_graphblas_vector(
+                self.context.clone(),
+                clone_graphblas_vector(self.context_ref(), self.graphblas_vector_ref()).unwrap(),
+            )
+            .unwrap()
         }
     }
 }
@@ -324,52 +329,7 @@ macro_rules! implement_dispay {
 }
 implement_macro_for_all_value_types!(implement_dispay);
 
-// impl SetElement<i128> for SparseVector<i128> {
-//     fn set_element(
-//         &mut self,
-//         element: VectorElement<i128>,
-//     ) -> Result<(), SparseLinearAlgebraError> {
-//         let index_to_set = element.index().to_graphblas_index()?;
-//         // https://users.rust-lang.org/t/cast-c-int-to-mut-c-void/44766/3
-//         let value: *mut c_void = &mut element.value() as *mut i128 as *mut c_void; // https://stackoverflow.com/questions/24191249/working-with-c-void-in-an-ffi
-//         self.context.call(|| unsafe {
-//             GrB_Vector_setElement_UDT(self.vector,  value, index_to_set)
-//         })?;
-//         Ok(())
-//     }
-// }
-
-// implement_set_element_for_custom_type!(i128);
-// implement_set_element_for_custom_type!(u128);
-
-// macro_rules! implement_get_element_for_custom_type {
-//     ($value_type:ty) => {
-//         impl GetVectorElement<$value_type> for SparseVector<$value_type> {
-//             fn get_element(
-//                 &self,
-//                 index: ElementIndex,
-//             ) -> Result<VectorElement<$value_type>, SparseLinearAlgebraError> {
-//                 let mut value: MaybeUninit<$value_type> = MaybeUninit::uninit();
-//                 let pointer_to_value: *mut c_void = &mut value as *mut _ as *mut c_void; // https://stackoverflow.com/questions/24191249/working-with-c-void-in-an-ffi
-//                 let index_to_get = index.to_graphblas_index()?;
-
-//                 self.context.call(
-//                     || unsafe {
-//                         GrB_Vector_extractElement_UDT(pointer_to_value, self.vector, index_to_get)
-//                     },
-//                     &self.vector,
-//                 )?;
-
-//                 let value = unsafe { value.assume_init() };
-
-//                 Ok(VectorElement::new(index, value))
-//             }
-//         }
-//     };
-// }
-
-// implement_get_element_for_custom_type!(i128);
-// implement_get_element_for_custom_type!(u128);
+impl<T: ValueType> GetMaskOptions for SparseVector<T> {}
 
 impl<T: ValueType> VectorMask for SparseVector<T> {
     unsafe fn graphblas_vector(&self) -> GrB_Vector {
@@ -581,6 +541,28 @@ mod tests {
         assert_eq!(vector.number_of_stored_elements().unwrap(), 3);
     }
 
+    #[test]
+    fn wait_for_pending_work() {
+        let context = Context::init_default().unwrap();
+
+        let mut sparse_vector = SparseVector::<u8>::new(context, 10).unwrap();
+        sparse_vector
+            .set_element(VectorElement::from_pair(1, 2))
+            .unwrap();
+
+        assert_eq!(
+            sparse_vector
+                .pending_work()
+                .unwrap()
+                .number_of_pending_elements(),
+            1
+        );
+
+        sparse_vector.wait(WaitMode::Materialize).unwrap();
+        assert!(sparse_vector.pending_work().unwrap().is_finished());
+        assert_eq!(sparse_vector.number_of_stored_elements().unwrap(), 1);
+    }
+
     #[test]
     fn set_element_in_vector() {
         let context = Context::init_default().unwrap();
@@ -0,0 +1,244 @@
+//! In Mode::NonBlocking, GraphBLAS may defer work until its result is needed.
+//!
+//! Operators, e.g. element-wise addition, multiplication or apply, may return before their output is fully computed.
+//! Setting elements of a collection adds pending elements, and dropping elements leaves zombies,
+//! which are assembled or removed in a single pass later on.
+//! Some operators also leave the indices of their output unsorted, i.e. jumbled.
+//!
+//! Operations that read a collection, e.g. getting its element list, number of stored elements, an element value,
+//! iterating over it, serializing, or unpacking it, complete the required work first.
+//! Call Collection::wait() to complete the work at a chosen point instead,
+//! for example before timing an operation, or before sharing a collection across threads.
+//! In Mode::Blocking, every operation completes before it returns, and wait() has no effect.
+
+use std::ffi::c_void;
+use std::io;
+
+use suitesparse_graphblas_sys::{
+    fclose, fflush, fread, rewind, tmpfile, GrB_Info, GrB_WaitMode, GrB_WaitMode_GrB_COMPLETE,
+    GrB_WaitMode_GrB_MATERIALIZE, FILE,
+};
+
+use crate::context::Context;
+use crate::error::{SparseLinearAlgebraError, SystemError, SystemErrorType};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WaitMode {
+    /// Completes all computations of the collection,
+    /// such that it can be used by another thread, though it may still have pending work.
+    Complete,
+    /// Completes all computations, and finishes all pending work of the collection.
+    Materialize,
+}
+
+impl From<WaitMode> for GrB_WaitMode {
+    fn from(mode: WaitMode) -> Self {
+        match mode {
+            WaitMode::Complete => GrB_WaitMode_GrB_COMPLETE,
+            WaitMode::Materialize => GrB_WaitMode_GrB_MATERIALIZE,
+        }
+    }
+}
+
+/// Deferred work of a collection, which is finished by Collection::wait() with WaitMode::Materialize.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PendingWork {
+    number_of_pending_elements: usize,
+    number_of_zombies: usize,
+    is_jumbled: bool,
+}
+
+impl PendingWork {
+    /// Elements that were set, but not yet assembled into the collection
+    pub fn number_of_pending_elements(&self) -> usize {
+        self.number_of_pending_elements
+    }
+
+    /// Elements that were dropped, but not yet removed from the collection
+    pub fn number_of_zombies(&self) -> usize {
+        self.number_of_zombies
+    }
+
+    /// Whether the indices of the stored elements are not yet sorted
+    pub fn is_jumbled(&self) -> bool {
+        self.is_jumbled
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.number_of_pending_elements == 0 && self.number_of_zombies == 0 && !self.is_jumbled
+    }
+}
+
+// GrB_get and GxB_*_Option_get have no field for pending elements, zombies or jumbled indices.
+// GraphBLAS reports them only in the summary printed by GxB_*_fprint,
+// which is printed to a temporary file, and parsed.
+pub(crate) fn pending_work_from_graphblas_report(
+    context: &Context,
+    mut print_report: impl FnMut(*mut FILE) -> GrB_Info,
+) -> Result<PendingWork, SparseLinearAlgebraError> {
+    let file = unsafe { tmpfile() };
+    if file.is_null() {
+        return Err(io::Error::last_os_error().into());
+    }
+
+    let report = context
+        .call_without_detailed_error_information(|| print_report(file))
+        .map(|_| unsafe { read_file_from_start(file) });
+    unsafe { fclose(file) };
+
+    parse_pending_work(&report?)
+}
+
+unsafe fn read_file_from_start(file: *mut FILE) -> String {
+    fflush(file);
+    rewind(file);
+
+    let mut content = Vec::new();
+    let mut buffer = [0u8; 4096];
+    loop {
+        let number_of_bytes_read = fread(
+            buffer.as_mut_ptr() as *mut c_void,
+            1,
+            buffer.len() as _,
+            file,
+        ) as usize;
+        if number_of_bytes_read == 0 {
+            break;
+        }
+        content.extend_from_slice(&buffer[..number_of_bytes_read]);
+    }
+    String::from_utf8_lossy(&content).into_owned()
+}
+
+// The report starts with a header like "5x5 GraphBLAS double matrix, sparse (jumbled) by row",
+// where "(jumbled)" marks unsorted indices.
+// A line like "pending tuples: 3 max pending: 256 zombies: 1" follows when there is pending work.
+fn parse_pending_work(report: &str) -> Result<PendingWork, SparseLinearAlgebraError> {
+    let mut lines = report
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty());
+
+    let header = match lines.next() {
+        Some(header) if is_report_header(header) => header,
+        _ => {
+            return Err(unexpected_report(
+                "a header like \"5x5 GraphBLAS double matrix, sparse by row\"",
+                report,
+            ))
+        }
+    };
+    // The storage format follows the last comma, the name of a custom value type may contain anything
+    let is_jumbled = header
+        .rsplit(", ")
+        .next()
+        .is_some_and(|format| format.split_whitespace().any(|token| token == "(jumbled)"));
+
+    let pending_lines: Vec<&str> = lines
+        .filter(|line| line.starts_with("pending tuples:"))
+        .collect();
+    let (number_of_pending_elements, number_of_zombies) = match pending_lines.as_slice() {
+        [] => (0, 0),
+        [line] => parse_pending_line(line).ok_or_else(|| {
+            unexpected_report(
+                "a line like \"pending tuples: 3 max pending: 256 zombies: 1\"",
+                report,
+            )
+        })?,
+        _ => {
+            return Err(unexpected_report(
+                "a single line with pending tuples",
+                report,
+            ))
+        }
+    };
+
+    Ok(PendingWork {
+        number_of_pending_elements,
+        number_of_zombies,
+        is_jumbled,
+    })
+}
+
+fn is_report_header(line: &str) -> bool {
+    let mut tokens = line.split_whitespace();
+    let is_size = tokens.next().is_some_and(|size| {
+        size.split_once('x').is_some_and(|(rows, columns)| {
+            rows.parse::<u64>().is_ok() && columns.parse::<u64>().is_ok()
+        })
+    });
+    is_size && tokens.next() == Some("GraphBLAS")
+}
+
+fn parse_pending_line(line: &str) -> Option<(usize, usize)> {
+    match line.split_whitespace().collect::<Vec<&str>>().as_slice() {
+        ["pending", "tuples:", number_of_pending_elements, "max", "pending:", _, "zombies:", number_of_zombies] => {
+            Some((
+                number_of_pending_elements.parse().ok()?,
+                number_of_zombies.parse().ok()?,
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn unexpected_report(expected: &str, report: &str) -> SparseLinearAlgebraError {
+    SystemError::new(
+        SystemErrorType::Other,
+        format!(
+            "Expected {} in the report printed by GraphBLAS:\n{}",
+            expected, report
+        ),
+        None,
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_report_with_pending_work() {
+        let report = "
+  5x5 GraphBLAS double matrix, sparse (jumbled) by row
+  A, 3 entries, memory: 1.2 KB
+  pending tuples: 2 max pending: 256 zombies: 1
+";
+        let pending_work = parse_pending_work(report).unwrap();
+        assert_eq!(pending_work.number_of_pending_elements(), 2);
+        assert_eq!(pending_work.number_of_zombies(), 1);
+        assert!(pending_work.is_jumbled());
+        assert!(!pending_work.is_finished());
+    }
+
+    #[test]
+    fn parse_report_without_pending_work() {
+        let report = "
+  5x5 GraphBLAS double matrix, sparse by row
+  A, 3 entries, memory: 1.2 KB
+";
+        assert!(parse_pending_work(report).unwrap().is_finished());
+    }
+
+    #[test]
+    fn only_the_storage_format_marks_jumbled_indices() {
+        let report = "
+  5x5 GraphBLAS (jumbled) matrix, sparse by row
+  jumbled, 3 entries, memory: 1.2 KB
+";
+        assert!(!parse_pending_work(report).unwrap().is_jumbled());
+    }
+
+    #[test]
+    fn reject_unexpected_report() {
+        assert!(parse_pending_work("").is_err());
+        assert!(parse_pending_work("GraphBLAS matrix\n").is_err());
+
+        let report = "
+  5x5 GraphBLAS double matrix, sparse by row
+  pending tuples: many max pending: 256 zombies: 1
+";
+        assert!(parse_pending_work(report).is_err());
+    }
+}
@@ -1,13 +1,14 @@
 use suitesparse_graphblas_sys::{
-    GxB_Format_Value, GxB_Format_Value_GxB_BY_COL, GxB_Format_Value_GxB_BY_ROW,
-    GxB_Global_Option_set, GxB_Option_Field_GxB_FORMAT,
+    GxB_AUTO_SPARSITY, GxB_BITMAP, GxB_FULL, GxB_Format_Value, GxB_Format_Value_GxB_BY_COL,
+    GxB_Format_Value_GxB_BY_ROW, GxB_Global_Option_set, GxB_HYPERSPARSE,
+    GxB_Option_Field_GxB_FORMAT, GxB_SPARSE,
 };
 
-use crate::error::SparseLinearAlgebraError;
+use crate::error::{GraphblasError, GraphblasErrorType, SparseLinearAlgebraError};
 
 use super::Context;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum MatrixStorageFormat {
     ByRow,
     ByColumn,
@@ -22,6 +23,118 @@ impl Into<GxB_Format_Value> for MatrixStorageFormat {
     }
 }
 
+impl MatrixStorageFormat {
+    pub(crate) fn from_graphblas_format_value(
+        value: GxB_Format_Value,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        if value == GxB_Format_Value_GxB_BY_ROW {
+            Ok(MatrixStorageFormat::ByRow)
+        } else if value == GxB_Format_Value_GxB_BY_COL {
+            Ok(MatrixStorageFormat::ByColumn)
+        } else {
+            Err(GraphblasError::new(
+                GraphblasErrorType::InvalidValue,
+                format!("Unknown storage format: {}", value),
+            )
+            .into())
+        }
+    }
+}
+
+/// The data structure in which the elements of a matrix or vector are stored
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SparsityStructure {
+    /// Stores only the non-empty rows or columns
+    HyperSparse,
+    /// Compressed sparse row or column
+    Sparse,
+    /// Stores a value and a presence flag for every element
+    Bitmap,
+    /// Stores a value for every element, all elements must be present
+    Full,
+}
+
+impl SparsityStructure {
+    fn to_graphblas_flag(self) -> i32 {
+        (match self {
+            SparsityStructure::HyperSparse => GxB_HYPERSPARSE,
+            SparsityStructure::Sparse => GxB_SPARSE,
+            SparsityStructure::Bitmap => GxB_BITMAP,
+            SparsityStructure::Full => GxB_FULL,
+        }) as i32
+    }
+
+    pub(crate) fn from_graphblas_sparsity_status(
+        value: i32,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        for structure in ALL_SPARSITY_STRUCTURES {
+            if value == structure.to_graphblas_flag() {
+                return Ok(structure);
+            }
+        }
+        Err(GraphblasError::new(
+            GraphblasErrorType::InvalidValue,
+            format!("Unknown sparsity status: {}", value),
+        )
+        .into())
+    }
+}
+
+const ALL_SPARSITY_STRUCTURES: [SparsityStructure; 4] = [
+    SparsityStructure::HyperSparse,
+    SparsityStructure::Sparse,
+    SparsityStructure::Bitmap,
+    SparsityStructure::Full,
+];
+
+/// The sparsity structures GraphBLAS may choose from to store a matrix or vector.
+///
+/// GraphBLAS switches between the allowed structures based on the number of stored elements,
+/// the hyper switch and the bitmap switch.
+/// A full structure is only used when all elements are present,
+/// and vectors are never stored as hypersparse.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SparsityControl {
+    graphblas_flags: i32,
+}
+
+impl SparsityControl {
+    /// An empty selection of structures is equivalent to allowing all structures
+    pub fn new(allowed_structures: &[SparsityStructure]) -> Self {
+        let mut graphblas_flags = 0;
+        for structure in allowed_structures {
+            graphblas_flags |= structure.to_graphblas_flag();
+        }
+        Self { graphblas_flags }
+    }
+
+    /// Allows all structures, which is the default
+    pub fn automatic() -> Self {
+        Self {
+            graphblas_flags: GxB_AUTO_SPARSITY as i32,
+        }
+    }
+
+    pub fn allows(&self, structure: SparsityStructure) -> bool {
+        self.graphblas_flags == 0 || self.graphblas_flags & structure.to_graphblas_flag() != 0
+    }
+
+    pub fn allowed_structures(&self) -> Vec<SparsityStructure> {
+        ALL_SPARSITY_STRUCTURES
+            .into_iter()
+            .filter(|structure| self.allows(*structure))
+            .collect()
+    }
+
+    pub(crate) fn from_graphblas_flags(graphblas_flags: i32) -> Self {
+        Self { graphblas_flags }
+    }
+
+    pub(crate) fn to_graphblas_flags(self) -> i32 {
+        self.graphblas_flags
+    }
+}
+
 pub(crate) trait SetMatrixFormat {
     fn set_matrix_format(
         &mut self,
@@ -46,4 +159,24 @@ impl SetMatrixFormat for Context {
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparsity_control() {
+        let control =
+            SparsityControl::new(&[SparsityStructure::Bitmap, SparsityStructure::HyperSparse]);
+
+        assert!(control.allows(SparsityStructure::Bitmap));
+        assert!(control.allows(SparsityStructure::HyperSparse));
+        assert!(!control.allows(SparsityStructure::Full));
+        assert_eq!(
+            control.allowed_structures(),
+            vec![SparsityStructure::HyperSparse, SparsityStructure::Bitmap]
+        );
+        assert_eq!(
+            SparsityControl::new(&[]).allowed_structures(),
+            SparsityControl::automatic().allowed_structures()
+        );
+    }
+}
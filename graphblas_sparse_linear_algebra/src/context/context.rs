@@ -5,7 +5,7 @@
 use core::mem::MaybeUninit;
 use std::ffi::{c_char, CStr};
 use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
-use std::sync::{Arc, Mutex, MutexGuard};
+use std::sync::{Arc, Mutex, MutexGuard, RwLockReadGuard};
 
 use once_cell::sync::Lazy;
 use suitesparse_graphblas_sys::{
@@ -45,6 +45,9 @@ use crate::error::SparseLinearAlgebraError;
 use crate::error::{GraphblasError, GraphblasErrorType};
 use crate::error::{SystemError, SystemErrorType};
 
+use crate::value_type::RegisteredCustomValueTypes;
+
+use super::memory_usage::LiveGraphblasCollections;
 use super::{MatrixStorageFormat, SetMatrixFormat};
 
 /*
@@ -142,11 +145,11 @@ impl Context {
         // println!("number_of_ready_contexts before starting: {:?}",number_of_ready_contexts.load(Ordering::SeqCst));
         if number_of_ready_contexts.load(Ordering::SeqCst) == 0 {
             let status = initialize(mode, number_of_ready_contexts)?;
-            *self = Context::Ready(Ready { mode });
+            *self = Context::Ready(Ready::new(mode));
             Ok(status)
         } else {
             number_of_ready_contexts.fetch_add(1, Ordering::SeqCst);
-            *self = Context::Ready(Ready { mode });
+            *self = Context::Ready(Ready::new(mode));
             Ok(Status::Success)
         }
     }
@@ -169,8 +172,28 @@ impl Context {
     where
         F: FnMut() -> GrB_Info,
     {
+        let _graphblas_call = self.lock_for_graphblas_call();
         call_graphblas_implementation_without_detailed_error_information(function_to_call)
     }
+
+    pub(crate) fn live_graphblas_collections(&self) -> Option<&LiveGraphblasCollections> {
+        match self {
+            Context::Ready(ready) => Some(&ready.live_graphblas_collections),
+            Context::NotReady(_) => None,
+        }
+    }
+
+    pub(crate) fn registered_custom_value_types(&self) -> Option<&RegisteredCustomValueTypes> {
+        match self {
+            Context::Ready(ready) => Some(&ready.registered_custom_value_types),
+            Context::NotReady(_) => None,
+        }
+    }
+
+    fn lock_for_graphblas_call(&self) -> Option<RwLockReadGuard<'_, ()>> {
+        self.live_graphblas_collections()
+            .and_then(LiveGraphblasCollections::lock_for_graphblas_call)
+    }
 }
 
 fn initialize(
@@ -203,13 +226,30 @@ pub trait CallGraphBlasContext<T> {
 #[derive(Debug, PartialEq)]
 pub struct NotReady {}
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 pub struct Ready {
     mode: Mode,
+    live_graphblas_collections: LiveGraphblasCollections,
+    registered_custom_value_types: RegisteredCustomValueTypes,
     // version: Version
 }
 
+// The registries of a context are not part of its identity
+impl PartialEq for Ready {
+    fn eq(&self, other: &Self) -> bool {
+        self.mode == other.mode
+    }
+}
+
 impl Ready {
+    fn new(mode: Mode) -> Self {
+        Self {
+            mode,
+            live_graphblas_collections: LiveGraphblasCollections::default(),
+            registered_custom_value_types: RegisteredCustomValueTypes::default(),
+        }
+    }
+
     fn call_without_detailed_error_information<F>(
         &self,
         function_to_call: F,
@@ -242,6 +282,7 @@ impl Ready {
 
 impl Drop for Ready {
     fn drop(&mut self) -> () {
+        self.registered_custom_value_types.free();
         let number_of_ready_contexts = NUMBER_OF_READY_CONTEXTS.lock().unwrap();
         if number_of_ready_contexts.load(Ordering::SeqCst) == 0 {
             self.finalize_context().unwrap();
@@ -266,7 +307,11 @@ macro_rules! implement_CallGraphBlasContext {
                         [<generate_closure_to_retrieve_detailed_error_message_ $graphblas_type>](reference_to_debug_info);
                     // thread::sleep(time::Duration::from_secs(2));
                     // let _is_graphblas_busy = IS_GRAPHBLAS_BUSY.lock().unwrap();
-                    graphblas_result(function_to_call(), get_detailed_error_information)
+                    let graphblas_info = {
+                        let _graphblas_call = self.lock_for_graphblas_call();
+                        function_to_call()
+                    };
+                    graphblas_result(graphblas_info, get_detailed_error_information)
                 }
             }
 
@@ -315,7 +360,7 @@ implement_CallGraphBlasContext!(GrB_Monoid, GrB_Monoid_error);
 implement_CallGraphBlasContext!(GrB_IndexUnaryOp, GrB_IndexUnaryOp_error);
 // implement_CallGraphBlasContext!(GxB_Iterator, GxB_Iterator_error);
 
-fn graphblas_result<F>(
+pub(crate) fn graphblas_result<F>(
     grb_info: GrB_Info,
     get_detailed_error_information: F,
 ) -> Result<Status, SparseLinearAlgebraError>
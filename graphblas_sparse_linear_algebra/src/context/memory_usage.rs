@@ -0,0 +1,306 @@
+use std::collections::HashMap;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard};
+
+use suitesparse_graphblas_sys::{
+    GrB_Info, GrB_Matrix, GrB_Scalar, GrB_Vector, GxB_Matrix_memoryUsage, GxB_Scalar_memoryUsage,
+    GxB_Vector_memoryUsage,
+};
+
+use crate::error::SparseLinearAlgebraError;
+
+use super::{graphblas_result, Context};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum GraphblasCollectionType {
+    Matrix,
+    Vector,
+    Scalar,
+}
+
+// Collections are keyed by the address of their GraphBLAS object,
+// and are removed before their GraphBLAS object is freed, such that every address refers to a live collection.
+// Raw pointers are not Send, hence addresses are stored as usize.
+#[derive(Debug, Default)]
+pub(crate) struct LiveGraphblasCollections {
+    // Creating and dropping collections does not lock the registry until the context tracks its collections
+    is_tracking: AtomicBool,
+    collections: Mutex<HashMap<usize, GraphblasCollectionType>>,
+    // While tracking, GraphBLAS calls through the context share this lock, and the summary holds it exclusively
+    graphblas_calls: RwLock<()>,
+}
+
+impl LiveGraphblasCollections {
+    // The registry only holds addresses, so it remains consistent if a thread panicked while holding the lock
+    fn collections(&self) -> MutexGuard<'_, HashMap<usize, GraphblasCollectionType>> {
+        self.collections
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub(crate) fn lock_for_graphblas_call(&self) -> Option<RwLockReadGuard<'_, ()>> {
+        if self.is_tracking.load(Ordering::Acquire) {
+            Some(
+                self.graphblas_calls
+                    .read()
+                    .unwrap_or_else(PoisonError::into_inner),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+pub(crate) fn register_graphblas_collection(
+    context: &Context,
+    address: usize,
+    collection_type: GraphblasCollectionType,
+) {
+    if let Some(live_collections) = context.live_graphblas_collections() {
+        if live_collections.is_tracking.load(Ordering::Acquire) {
+            live_collections
+                .collections()
+                .insert(address, collection_type);
+        }
+    }
+}
+
+/// Must be called before the GraphBLAS object is freed
+pub(crate) fn deregister_graphblas_collection(context: &Context, address: usize) {
+    if let Some(live_collections) = context.live_graphblas_collections() {
+        if live_collections.is_tracking.load(Ordering::Acquire) {
+            live_collections.collections().remove(&address);
+        }
+    }
+}
+
+pub(crate) fn graphblas_matrix_memory_usage(
+    context: &Context,
+    matrix: GrB_Matrix,
+) -> Result<usize, SparseLinearAlgebraError> {
+    let mut memory_usage: MaybeUninit<usize> = MaybeUninit::uninit();
+    context.call_without_detailed_error_information(|| unsafe {
+        GxB_Matrix_memoryUsage(memory_usage.as_mut_ptr(), matrix)
+    })?;
+    Ok(unsafe { memory_usage.assume_init() })
+}
+
+pub(crate) fn graphblas_vector_memory_usage(
+    context: &Context,
+    vector: GrB_Vector,
+) -> Result<usize, SparseLinearAlgebraError> {
+    let mut memory_usage: MaybeUninit<usize> = MaybeUninit::uninit();
+    context.call_without_detailed_error_information(|| unsafe {
+        GxB_Vector_memoryUsage(memory_usage.as_mut_ptr(), vector)
+    })?;
+    Ok(unsafe { memory_usage.assume_init() })
+}
+
+pub(crate) fn graphblas_scalar_memory_usage(
+    context: &Context,
+    scalar: GrB_Scalar,
+) -> Result<usize, SparseLinearAlgebraError> {
+    let mut memory_usage: MaybeUninit<usize> = MaybeUninit::uninit();
+    context.call_without_detailed_error_information(|| unsafe {
+        GxB_Scalar_memoryUsage(memory_usage.as_mut_ptr(), scalar)
+    })?;
+    Ok(unsafe { memory_usage.assume_init() })
+}
+
+/// Number and memory usage of the collections alive in the process
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryUsageSummary {
+    number_of_matrices: usize,
+    number_of_vectors: usize,
+    number_of_scalars: usize,
+    matrix_memory_usage_in_bytes: usize,
+    vector_memory_usage_in_bytes: usize,
+    scalar_memory_usage_in_bytes: usize,
+}
+
+impl MemoryUsageSummary {
+    pub fn number_of_matrices(&self) -> usize {
+        self.number_of_matrices
+    }
+
+    pub fn number_of_vectors(&self) -> usize {
+        self.number_of_vectors
+    }
+
+    pub fn number_of_scalars(&self) -> usize {
+        self.number_of_scalars
+    }
+
+    pub fn matrix_memory_usage_in_bytes(&self) -> usize {
+        self.matrix_memory_usage_in_bytes
+    }
+
+    pub fn vector_memory_usage_in_bytes(&self) -> usize {
+        self.vector_memory_usage_in_bytes
+    }
+
+    pub fn scalar_memory_usage_in_bytes(&self) -> usize {
+        self.scalar_memory_usage_in_bytes
+    }
+
+    pub fn total_memory_usage_in_bytes(&self) -> usize {
+        self.matrix_memory_usage_in_bytes
+            + self.vector_memory_usage_in_bytes
+            + self.scalar_memory_usage_in_bytes
+    }
+}
+
+impl Context {
+    /// Tracks the SparseMatrix, SparseVector and SparseScalar instances created with this context from now on,
+    /// for memory_usage_summary().
+    ///
+    /// Tracking cannot be disabled. Once the context tracks its collections,
+    /// creating and dropping collections, and GraphBLAS calls through the context, synchronize on a lock of the context.
+    pub fn track_live_collections(&self) {
+        if let Some(live_collections) = self.live_graphblas_collections() {
+            live_collections.is_tracking.store(true, Ordering::Release);
+        }
+    }
+
+    /// Summarizes the memory used by the SparseMatrix, SparseVector and SparseScalar instances of this context
+    /// that are alive, and that were created after calling track_live_collections().
+    ///
+    /// GraphBLAS objects owned by operators, or created directly through the GraphBLAS bindings, are not included.
+    /// Dropping a tracked collection, and GraphBLAS calls through this context, wait until the summary is complete.
+    pub fn memory_usage_summary(&self) -> Result<MemoryUsageSummary, SparseLinearAlgebraError> {
+        let mut summary = MemoryUsageSummary::default();
+        let live_collections = match self.live_graphblas_collections() {
+            Some(live_collections) => live_collections,
+            None => return Ok(summary),
+        };
+
+        // Holding the registry prevents tracked collections from being freed,
+        // and holding the call lock prevents them from being modified through this context
+        let collections = live_collections.collections();
+        let _graphblas_calls = live_collections
+            .graphblas_calls
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        for (address, collection_type) in collections.iter() {
+            match collection_type {
+                GraphblasCollectionType::Matrix => {
+                    summary.number_of_matrices += 1;
+                    summary.matrix_memory_usage_in_bytes += memory_usage(|memory_usage| unsafe {
+                        GxB_Matrix_memoryUsage(memory_usage, *address as GrB_Matrix)
+                    })?;
+                }
+                GraphblasCollectionType::Vector => {
+                    summary.number_of_vectors += 1;
+                    summary.vector_memory_usage_in_bytes += memory_usage(|memory_usage| unsafe {
+                        GxB_Vector_memoryUsage(memory_usage, *address as GrB_Vector)
+                    })?;
+                }
+                GraphblasCollectionType::Scalar => {
+                    summary.number_of_scalars += 1;
+                    summary.scalar_memory_usage_in_bytes += memory_usage(|memory_usage| unsafe {
+                        GxB_Scalar_memoryUsage(memory_usage, *address as GrB_Scalar)
+                    })?;
+                }
+            }
+        }
+        Ok(summary)
+    }
+}
+
+// Calls GraphBLAS directly, because the summary holds the lock that calls through the context take
+fn memory_usage(
+    get_memory_usage: impl FnOnce(*mut usize) -> GrB_Info,
+) -> Result<usize, SparseLinearAlgebraError> {
+    let mut memory_usage: MaybeUninit<usize> = MaybeUninit::uninit();
+    graphblas_result(get_memory_usage(memory_usage.as_mut_ptr()), || {
+        String::from("Something went wrong while reading the memory usage of a collection.")
+    })?;
+    Ok(unsafe { memory_usage.assume_init() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::FromMatrixElementList;
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::collections::sparse_scalar::SparseScalar;
+    use crate::collections::sparse_vector::SparseVector;
+    use crate::collections::Collection;
+    use crate::operators::binary_operator::First;
+
+    #[test]
+    fn memory_usage_of_collections() {
+        let context = Context::init_default().unwrap();
+        context.track_live_collections();
+
+        let empty_matrix =
+            SparseMatrix::<f64>::new(context.clone(), Size::new(1000, 1000)).unwrap();
+        let element_list = MatrixElementList::<f64>::from_element_vector(
+            (0..1000).map(|index| (index, index, 1.0).into()).collect(),
+        );
+        let matrix = SparseMatrix::<f64>::from_element_list(
+            context.clone(),
+            Size::new(1000, 1000),
+            element_list,
+            &First::<f64>::new(),
+        )
+        .unwrap();
+        let vector = SparseVector::<u8>::new(context.clone(), 10).unwrap();
+        let scalar = SparseScalar::<i32>::new(context.clone()).unwrap();
+
+        assert!(
+            matrix.memory_usage_in_bytes().unwrap() > empty_matrix.memory_usage_in_bytes().unwrap()
+        );
+        assert!(vector.memory_usage_in_bytes().unwrap() > 0);
+        assert!(scalar.memory_usage_in_bytes().unwrap() > 0);
+
+        let summary = context.memory_usage_summary().unwrap();
+        assert_eq!(summary.number_of_matrices(), 2);
+        assert_eq!(summary.number_of_vectors(), 1);
+        assert_eq!(summary.number_of_scalars(), 1);
+        assert!(
+            summary.total_memory_usage_in_bytes()
+                >= summary.matrix_memory_usage_in_bytes() + summary.vector_memory_usage_in_bytes()
+        );
+    }
+
+    #[test]
+    fn summarize_live_collections_of_the_context() {
+        let context = Context::init_default().unwrap();
+        let other_context = Context::init_default().unwrap();
+        context.track_live_collections();
+        other_context.track_live_collections();
+
+        let untracked_matrix =
+            SparseMatrix::<f64>::new(Context::init_default().unwrap(), Size::new(10, 10)).unwrap();
+        let matrix = SparseMatrix::<f64>::new(context.clone(), Size::new(10, 10)).unwrap();
+        let dropped_matrix = SparseMatrix::<f64>::new(context.clone(), Size::new(10, 10)).unwrap();
+        let other_vector = SparseVector::<u8>::new(other_context.clone(), 10).unwrap();
+        drop(dropped_matrix);
+
+        let summary = context.memory_usage_summary().unwrap();
+        assert_eq!(summary.number_of_matrices(), 1);
+        assert_eq!(summary.number_of_vectors(), 0);
+        assert_eq!(
+            summary.matrix_memory_usage_in_bytes(),
+            matrix.memory_usage_in_bytes().unwrap()
+        );
+
+        let other_summary = other_context.memory_usage_summary().unwrap();
+        assert_eq!(other_summary.number_of_matrices(), 0);
+        assert_eq!(other_summary.number_of_vectors(), 1);
+
+        drop(other_vector);
+        drop(untracked_matrix);
+        assert_eq!(
+            other_context
+                .memory_usage_summary()
+                .unwrap()
+                .number_of_vectors(),
+            0
+        );
+    }
+}
@@ -1,5 +1,8 @@
 mod configuration;
 mod context;
+mod memory_usage;
 
 pub use configuration::*;
+pub(crate) use context::graphblas_result;
 pub use context::{CallGraphBlasContext, Context, GetContext, Mode, NotReady, Ready, Status};
+pub use memory_usage::*;
@@ -27,6 +27,7 @@ pub enum LogicErrorType {
     // ContextAlreadyInitialized,
     IndexOutOfBounds,
     UnsafeTypeConversion,
+    InvalidFileFormat,
     Other,
 }
 
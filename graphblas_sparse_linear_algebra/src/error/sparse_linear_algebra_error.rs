@@ -70,6 +70,12 @@ impl From<LogicError> for SparseLinearAlgebraError {
     }
 }
 
+impl From<std::io::Error> for SparseLinearAlgebraError {
+    fn from(error: std::io::Error) -> Self {
+        SparseLinearAlgebraError::SystemError(error.into())
+    }
+}
+
 impl From<std::fmt::Error> for SparseLinearAlgebraError {
     fn from(error: std::fmt::Error) -> Self {
         SparseLinearAlgebraError::OtherError(error.into())
@@ -1,5 +1,6 @@
 use std::error::Error;
 use std::fmt;
+use std::io;
 use std::num::TryFromIntError;
 use std::{error, sync::PoisonError};
 
@@ -16,6 +17,7 @@ pub struct SystemError {
 pub enum SystemErrorSource {
     GraphBLAS(GraphblasError),
     IntegerConversionError(TryFromIntError),
+    InputOutput(io::Error),
     PoisonedData,
 }
 
@@ -30,6 +32,7 @@ pub enum SystemErrorType {
     UnsupportedArchitecture,
     PoisonedData,
     IntegerConversionFailed,
+    InputOutput,
     Other,
 }
 
@@ -60,6 +63,7 @@ impl error::Error for SystemError {
             Some(ref error) => match error {
                 SystemErrorSource::GraphBLAS(error) => Some(error),
                 SystemErrorSource::IntegerConversionError(error) => Some(error),
+                SystemErrorSource::InputOutput(error) => Some(error),
                 SystemErrorSource::PoisonedData => None,
             },
             None => None,
@@ -101,6 +105,16 @@ impl From<TryFromIntError> for SystemError {
     }
 }
 
+impl From<io::Error> for SystemError {
+    fn from(error: io::Error) -> Self {
+        Self {
+            error_type: SystemErrorType::InputOutput,
+            explanation: String::new(),
+            source: Some(SystemErrorSource::InputOutput(error)),
+        }
+    }
+}
+
 impl<T> From<PoisonError<T>> for SystemError {
     fn from(error: PoisonError<T>) -> Self {
         Self {
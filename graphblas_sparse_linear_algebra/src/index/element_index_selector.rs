@@ -1,47 +1,275 @@
-use crate::error::SparseLinearAlgebraError;
-use crate::graphblas_bindings::{GrB_ALL, GrB_Index};
+use std::borrow::Cow;
+use std::ops::Range;
+
+use crate::error::{GraphblasError, GraphblasErrorType, SparseLinearAlgebraError};
+use crate::graphblas_bindings::{GrB_ALL, GrB_Index, GxB_BEGIN, GxB_END, GxB_INC};
 use crate::index::{ElementIndex, IndexConversion};
 
-#[derive(Debug)]
+// GraphBLAS.h defines these as macros of INT64_MAX, which bindgen does not translate
+const GRAPHBLAS_RANGE: GrB_Index = i64::MAX as GrB_Index;
+const GRAPHBLAS_STRIDE: GrB_Index = (i64::MAX - 1) as GrB_Index;
+const GRAPHBLAS_BACKWARDS: GrB_Index = (i64::MAX - 2) as GrB_Index;
+
+/// Selects the indices of elements to extract or insert into.
+///
+/// Range, Stride and Backwards do not allocate an index list, and exclude their end, like a Rust range.
+#[derive(Debug, Clone)]
 pub enum ElementIndexSelector<'a> {
-    Index(&'a Vec<ElementIndex>),
+    Index(&'a [ElementIndex]),
     All,
+    /// Selects start, start+1, ..., end-1
+    Range(Range<ElementIndex>),
+    /// Selects start, start+step, ... while below end. Step must be larger than zero.
+    Stride(ElementIndex, ElementIndex, ElementIndex),
+    /// Selects start, start-step, ... while above end. Step must be larger than zero.
+    Backwards(ElementIndex, ElementIndex, ElementIndex),
+}
+
+pub(crate) struct ElementIndexSelectorGraphblasType<'a> {
+    indices: GraphblasIndices<'a>,
+    number_of_indices: GrB_Index,
 }
 
-pub(crate) enum ElementIndexSelectorGraphblasType {
-    Index(Vec<GrB_Index>),
-    All(*const GrB_Index),
+enum GraphblasIndices<'a> {
+    List(Cow<'a, [GrB_Index]>),
+    All,
+    Colon([GrB_Index; 3]),
+}
+
+impl<'a> ElementIndexSelectorGraphblasType<'a> {
+    /// The pointer is valid as long as self is
+    pub(crate) fn as_ptr(&self) -> *const GrB_Index {
+        match &self.indices {
+            GraphblasIndices::List(indices) => indices.as_ptr(),
+            GraphblasIndices::All => unsafe { GrB_ALL },
+            GraphblasIndices::Colon(colon) => colon.as_ptr(),
+        }
+    }
+
+    /// Is a special value for Range, Stride and Backwards
+    pub(crate) fn number_of_indices(&self) -> GrB_Index {
+        self.number_of_indices
+    }
+
+    fn from_list(indices: Cow<'a, [GrB_Index]>) -> Result<Self, SparseLinearAlgebraError> {
+        let number_of_indices = indices.len().to_graphblas_index()?;
+        Ok(Self {
+            indices: GraphblasIndices::List(indices),
+            number_of_indices,
+        })
+    }
+
+    fn empty() -> Result<Self, SparseLinearAlgebraError> {
+        Self::from_list(Cow::Borrowed(&[]))
+    }
+
+    fn from_colon(
+        begin: ElementIndex,
+        end: ElementIndex,
+        increment: ElementIndex,
+        number_of_indices: GrB_Index,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let mut colon: [GrB_Index; 3] = [0; 3];
+        colon[GxB_BEGIN as usize] = begin.to_graphblas_index()?;
+        colon[GxB_END as usize] = end.to_graphblas_index()?;
+        colon[GxB_INC as usize] = increment.to_graphblas_index()?;
+
+        Ok(Self {
+            indices: GraphblasIndices::Colon(colon),
+            number_of_indices,
+        })
+    }
 }
 
 impl<'a> ElementIndexSelector<'a> {
+    /// All selects number_of_elements_for_all elements
     pub(crate) fn to_graphblas_type(
         &self,
-    ) -> Result<ElementIndexSelectorGraphblasType, SparseLinearAlgebraError> {
+        number_of_elements_for_all: ElementIndex,
+    ) -> Result<ElementIndexSelectorGraphblasType<'a>, SparseLinearAlgebraError> {
         match self {
-            ElementIndexSelector::Index(columns) => {
-                let indices: Result<Vec<GrB_Index>, SparseLinearAlgebraError> = columns
-                    .into_iter()
-                    .map(|index| index.to_graphblas_index())
-                    .collect();
-
-                match indices {
-                    Ok(indices) => Ok(ElementIndexSelectorGraphblasType::Index(indices)),
-                    Err(error) => Err(error.into()),
+            ElementIndexSelector::Index(indices) => {
+                ElementIndexSelectorGraphblasType::from_list(graphblas_index_list(indices)?)
+            }
+            ElementIndexSelector::All => Ok(ElementIndexSelectorGraphblasType {
+                indices: GraphblasIndices::All,
+                number_of_indices: number_of_elements_for_all.to_graphblas_index()?,
+            }),
+            // GraphBLAS includes the end of a colon expression
+            ElementIndexSelector::Range(range) => {
+                if range.is_empty() {
+                    ElementIndexSelectorGraphblasType::empty()
+                } else {
+                    ElementIndexSelectorGraphblasType::from_colon(
+                        range.start,
+                        range.end - 1,
+                        1,
+                        GRAPHBLAS_RANGE,
+                    )
                 }
             }
-            ElementIndexSelector::All => {
-                Ok(unsafe { ElementIndexSelectorGraphblasType::All(GrB_ALL) })
+            ElementIndexSelector::Stride(start, end, step) => {
+                check_step(*step)?;
+                if start >= end {
+                    ElementIndexSelectorGraphblasType::empty()
+                } else {
+                    ElementIndexSelectorGraphblasType::from_colon(
+                        *start,
+                        *end - 1,
+                        *step,
+                        GRAPHBLAS_STRIDE,
+                    )
+                }
+            }
+            ElementIndexSelector::Backwards(start, end, step) => {
+                check_step(*step)?;
+                if start <= end {
+                    ElementIndexSelectorGraphblasType::empty()
+                } else {
+                    ElementIndexSelectorGraphblasType::from_colon(
+                        *start,
+                        *end + 1,
+                        *step,
+                        GRAPHBLAS_BACKWARDS,
+                    )
+                }
             }
         }
     }
 
-    pub(crate) fn number_of_selected_elements(
+    /// All selects number_of_elements_for_all elements
+    pub fn number_of_selected_elements(
         &self,
         number_elements_for_all: ElementIndex,
     ) -> Result<ElementIndex, SparseLinearAlgebraError> {
         match self {
             ElementIndexSelector::Index(indices) => Ok(indices.len()),
             ElementIndexSelector::All => Ok(number_elements_for_all),
+            ElementIndexSelector::Range(range) => Ok(range.len()),
+            ElementIndexSelector::Stride(start, end, step) => {
+                check_step(*step)?;
+                Ok((*start..*end).step_by(*step).len())
+            }
+            ElementIndexSelector::Backwards(start, end, step) => {
+                check_step(*step)?;
+                let start_exclusive_end = start.checked_add(1).ok_or_else(|| {
+                    GraphblasError::new(
+                        GraphblasErrorType::IndexOutOfBounds,
+                        format!(
+                            "The start {} of a backwards index selector is out of bounds",
+                            start
+                        ),
+                    )
+                })?;
+                // An end beyond the start selects nothing
+                Ok((end.saturating_add(1)..start_exclusive_end)
+                    .rev()
+                    .step_by(*step)
+                    .len())
+            }
         }
     }
 }
+
+fn check_step(step: ElementIndex) -> Result<(), SparseLinearAlgebraError> {
+    if step == 0 {
+        return Err(GraphblasError::new(
+            GraphblasErrorType::InvalidValue,
+            String::from("The step of an index selector must be larger than zero"),
+        )
+        .into());
+    }
+    Ok(())
+}
+
+// ElementIndex and GrB_Index share a layout on 64-bit platforms, so the list can be borrowed
+#[cfg(target_pointer_width = "64")]
+fn graphblas_index_list(
+    indices: &[ElementIndex],
+) -> Result<Cow<'_, [GrB_Index]>, SparseLinearAlgebraError> {
+    Ok(Cow::Borrowed(unsafe {
+        std::slice::from_raw_parts(indices.as_ptr() as *const GrB_Index, indices.len())
+    }))
+}
+
+#[cfg(not(target_pointer_width = "64"))]
+fn graphblas_index_list(
+    indices: &[ElementIndex],
+) -> Result<Cow<'_, [GrB_Index]>, SparseLinearAlgebraError> {
+    let indices: Result<Vec<GrB_Index>, SparseLinearAlgebraError> = indices
+        .into_iter()
+        .map(|index| index.to_graphblas_index())
+        .collect();
+    Ok(Cow::Owned(indices?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borrow_index_list() {
+        let indices: Vec<ElementIndex> = vec![3, 1, 4];
+        let selector = ElementIndexSelector::Index(&indices);
+
+        let graphblas_selector = selector.to_graphblas_type(10).unwrap();
+        assert_eq!(graphblas_selector.number_of_indices(), 3);
+        assert_eq!(
+            graphblas_selector.as_ptr() as usize,
+            indices.as_ptr() as usize
+        );
+    }
+
+    #[test]
+    fn range_to_inclusive_colon() {
+        let graphblas_selector = ElementIndexSelector::Range(1000..2000)
+            .to_graphblas_type(10_000)
+            .unwrap();
+
+        assert_eq!(graphblas_selector.number_of_indices(), GRAPHBLAS_RANGE);
+        let colon = unsafe { std::slice::from_raw_parts(graphblas_selector.as_ptr(), 2) };
+        assert_eq!(colon, &[1000, 1999]);
+    }
+
+    #[test]
+    fn number_of_selected_elements() {
+        assert_eq!(
+            ElementIndexSelector::Range(2..5)
+                .number_of_selected_elements(10)
+                .unwrap(),
+            3
+        );
+        assert_eq!(
+            ElementIndexSelector::Stride(1, 8, 3)
+                .number_of_selected_elements(10)
+                .unwrap(),
+            3
+        );
+        assert_eq!(
+            ElementIndexSelector::Backwards(9, 0, 4)
+                .number_of_selected_elements(10)
+                .unwrap(),
+            3
+        );
+        assert_eq!(
+            ElementIndexSelector::Backwards(0, 0, 1)
+                .number_of_selected_elements(10)
+                .unwrap(),
+            0
+        );
+        assert!(ElementIndexSelector::Stride(0, 5, 0)
+            .number_of_selected_elements(10)
+            .is_err());
+        assert!(ElementIndexSelector::Backwards(ElementIndex::MAX, 0, 1)
+            .number_of_selected_elements(10)
+            .is_err());
+    }
+
+    #[test]
+    fn empty_range() {
+        let graphblas_selector = ElementIndexSelector::Range(4..4)
+            .to_graphblas_type(10)
+            .unwrap();
+        assert_eq!(graphblas_selector.number_of_indices(), 0);
+    }
+}
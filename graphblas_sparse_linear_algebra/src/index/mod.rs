@@ -5,4 +5,3 @@ mod element_index_selector;
 pub use diagonal_index::{DiagonalIndex, DiagonalIndexConversion, GraphblasDiagionalIndex};
 pub use element_index::{ElementCount, ElementIndex, IndexConversion};
 pub use element_index_selector::ElementIndexSelector;
-pub(crate) use element_index_selector::ElementIndexSelectorGraphblasType;
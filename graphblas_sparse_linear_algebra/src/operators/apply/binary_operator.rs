@@ -118,6 +118,7 @@ macro_rules! implement_apply_binary_operator {
                 let context = product.context_ref();
                 let second_argument = second_argument.to_type()?;
 
+                let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
                 context.call(
                     || unsafe {
                         $graphblas_function_1(
@@ -127,7 +128,7 @@ macro_rules! implement_apply_binary_operator {
                             operator.graphblas_type(),
                             first_argument.graphblas_vector(),
                             second_argument,
-                            options.graphblas_descriptor(),
+                            graphblas_descriptor.graphblas_descriptor(),
                         )
                     },
                     unsafe { &product.graphblas_vector() },
@@ -149,6 +150,7 @@ macro_rules! implement_apply_binary_operator {
                 let context = product.context_ref();
                 let first_argument = first_argument.to_type()?;
 
+                let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
                 context.call(
                     || unsafe {
                         $graphblas_function_2(
@@ -158,7 +160,7 @@ macro_rules! implement_apply_binary_operator {
                             operator.graphblas_type(),
                             first_argument,
                             second_argument.graphblas_vector(),
-                            options.graphblas_descriptor(),
+                            graphblas_descriptor.graphblas_descriptor(),
                         )
                     },
                     unsafe { &product.graphblas_vector() },
@@ -180,6 +182,7 @@ macro_rules! implement_apply_binary_operator {
                 let context = product.context_ref();
                 let second_argument = second_argument.to_type()?;
 
+                let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
                 context.call(
                     || unsafe {
                         $graphblas_function_3(
@@ -189,7 +192,7 @@ macro_rules! implement_apply_binary_operator {
                             operator.graphblas_type(),
                             first_argument.graphblas_matrix(),
                             second_argument,
-                            options.graphblas_descriptor(),
+                            graphblas_descriptor.graphblas_descriptor(),
                         )
                     },
                     unsafe { &product.graphblas_matrix() },
@@ -211,6 +214,7 @@ macro_rules! implement_apply_binary_operator {
                 let context = product.context_ref();
                 let first_argument = first_argument.to_type()?;
 
+                let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
                 context.call(
                     || unsafe {
                         $graphblas_function_4(
@@ -220,7 +224,7 @@ macro_rules! implement_apply_binary_operator {
                             operator.graphblas_type(),
                             first_argument,
                             second_argument.graphblas_matrix(),
-                            options.graphblas_descriptor(),
+                            graphblas_descriptor.graphblas_descriptor(),
                         )
                     },
                     unsafe { &product.graphblas_matrix() },
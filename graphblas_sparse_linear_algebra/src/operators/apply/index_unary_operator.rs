@@ -80,6 +80,7 @@ macro_rules! implement_apply_index_binary_operator {
                 let context = product.context_ref();
                 let argument = argument.clone().to_type()?;
 
+                let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
                 context.call(
                     || unsafe {
                         $graphblas_function_1(
@@ -89,7 +90,7 @@ macro_rules! implement_apply_index_binary_operator {
                             operator.graphblas_type(),
                             vector.graphblas_vector(),
                             argument,
-                            options.graphblas_descriptor(),
+                            graphblas_descriptor.graphblas_descriptor(),
                         )
                     },
                     unsafe { &product.graphblas_vector() },
@@ -111,6 +112,7 @@ macro_rules! implement_apply_index_binary_operator {
                 let context = product.context_ref();
                 let argument = argument.clone().to_type()?;
 
+                let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
                 context.call(
                     || unsafe {
                         $graphblas_function_2(
@@ -120,7 +122,7 @@ macro_rules! implement_apply_index_binary_operator {
                             operator.graphblas_type(),
                             matrix.graphblas_matrix(),
                             argument,
-                            options.graphblas_descriptor(),
+                            graphblas_descriptor.graphblas_descriptor(),
                         )
                     },
                     unsafe { &product.graphblas_matrix() },
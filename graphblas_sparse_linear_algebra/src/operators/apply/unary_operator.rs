@@ -64,6 +64,7 @@ impl<EvaluationDomain: ValueType> ApplyUnaryOperator<EvaluationDomain> for Unary
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = argument.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Vector_apply(
@@ -72,7 +73,7 @@ impl<EvaluationDomain: ValueType> ApplyUnaryOperator<EvaluationDomain> for Unary
                     accumulator.accumulator_graphblas_type(),
                     operator.graphblas_type(),
                     argument.graphblas_vector(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_vector() },
@@ -92,6 +93,7 @@ impl<EvaluationDomain: ValueType> ApplyUnaryOperator<EvaluationDomain> for Unary
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = argument.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Matrix_apply(
@@ -100,7 +102,7 @@ impl<EvaluationDomain: ValueType> ApplyUnaryOperator<EvaluationDomain> for Unary
                     accumulator.accumulator_graphblas_type(),
                     operator.graphblas_type(),
                     argument.graphblas_matrix(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_matrix() },
@@ -81,6 +81,7 @@ impl<EvaluationDomain: ValueType> ApplyBinaryOperatorWithSparseScalar<Evaluation
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Vector_apply_BinaryOp2nd_Scalar(
@@ -90,7 +91,7 @@ impl<EvaluationDomain: ValueType> ApplyBinaryOperatorWithSparseScalar<Evaluation
                     operator.graphblas_type(),
                     first_argument.graphblas_vector(),
                     second_argument.graphblas_scalar(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_vector() },
@@ -111,6 +112,7 @@ impl<EvaluationDomain: ValueType> ApplyBinaryOperatorWithSparseScalar<Evaluation
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Vector_apply_BinaryOp1st_Scalar(
@@ -120,7 +122,7 @@ impl<EvaluationDomain: ValueType> ApplyBinaryOperatorWithSparseScalar<Evaluation
                     operator.graphblas_type(),
                     first_argument.graphblas_scalar(),
                     second_argument.graphblas_vector(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_vector() },
@@ -141,6 +143,7 @@ impl<EvaluationDomain: ValueType> ApplyBinaryOperatorWithSparseScalar<Evaluation
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Matrix_apply_BinaryOp2nd_Scalar(
@@ -150,7 +153,7 @@ impl<EvaluationDomain: ValueType> ApplyBinaryOperatorWithSparseScalar<Evaluation
                     operator.graphblas_type(),
                     first_argument.graphblas_matrix(),
                     second_argument.graphblas_scalar(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_matrix() },
@@ -171,6 +174,7 @@ impl<EvaluationDomain: ValueType> ApplyBinaryOperatorWithSparseScalar<Evaluation
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Matrix_apply_BinaryOp1st_Scalar(
@@ -180,7 +184,7 @@ impl<EvaluationDomain: ValueType> ApplyBinaryOperatorWithSparseScalar<Evaluation
                     operator.graphblas_type(),
                     first_argument.graphblas_scalar(),
                     second_argument.graphblas_matrix(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_matrix() },
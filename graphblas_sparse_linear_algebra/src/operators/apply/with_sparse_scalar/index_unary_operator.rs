@@ -54,6 +54,7 @@ impl<EvaluationDomain: ValueType> ApplyIndexUnaryOperatorWithSparseScalar<Evalua
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = argument.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Vector_apply_IndexOp_Scalar(
@@ -63,7 +64,7 @@ impl<EvaluationDomain: ValueType> ApplyIndexUnaryOperatorWithSparseScalar<Evalua
                     operator.graphblas_type(),
                     vector.graphblas_vector(),
                     argument.graphblas_scalar(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_vector() },
@@ -84,6 +85,7 @@ impl<EvaluationDomain: ValueType> ApplyIndexUnaryOperatorWithSparseScalar<Evalua
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = argument.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Matrix_apply_IndexOp_Scalar(
@@ -93,7 +95,7 @@ impl<EvaluationDomain: ValueType> ApplyIndexUnaryOperatorWithSparseScalar<Evalua
                     operator.graphblas_type(),
                     matrix.graphblas_matrix(),
                     argument.graphblas_scalar(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_matrix() },
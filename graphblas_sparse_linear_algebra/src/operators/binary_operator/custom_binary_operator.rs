@@ -0,0 +1,195 @@
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::sync::Arc;
+
+use crate::context::{Context, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::{GrB_BinaryOp, GrB_BinaryOp_free, GrB_BinaryOp_new};
+use crate::value_type::ValueType;
+
+use super::{AccumulatorBinaryOperator, BinaryOperator};
+
+/// Computes the product from the two arguments. GraphBLAS typecasts the arguments and product as required.
+///
+/// Implement it for a unit struct, and pass that struct to CustomBinaryOperator::new().
+/// GraphBLAS calls the function from C, so a panic aborts the process.
+pub trait BinaryOperatorFunction<Argument, Product> {
+    fn product(first_argument: &Argument, second_argument: &Argument) -> Product;
+}
+
+// GraphBLAS may pass the same pointer for the product and an argument,
+// so both arguments are read before the product is written.
+unsafe extern "C" fn call_binary_operator_function<
+    Argument,
+    Product,
+    Function: BinaryOperatorFunction<Argument, Product>,
+>(
+    product: *mut c_void,
+    first_argument: *const c_void,
+    second_argument: *const c_void,
+) {
+    let first_argument = ptr::read_unaligned(first_argument as *const Argument);
+    let second_argument = ptr::read_unaligned(second_argument as *const Argument);
+    ptr::write_unaligned(
+        product as *mut Product,
+        Function::product(&first_argument, &second_argument),
+    );
+}
+
+/// A binary operator defined by a Rust function.
+///
+/// The GraphBLAS operator is freed when the CustomBinaryOperator is dropped.
+#[derive(Debug)]
+pub struct CustomBinaryOperator<Argument: ValueType, Product: ValueType = Argument> {
+    context: Arc<Context>,
+    graphblas_operator: GrB_BinaryOp,
+    _argument_type: PhantomData<Argument>,
+    _product_type: PhantomData<Product>,
+}
+
+// Implemented methods do not provide mutable access to GraphBLAS operators or options.
+// Code review must consider that no mtable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl<Argument: ValueType, Product: ValueType> Send
+    for CustomBinaryOperator<Argument, Product>
+{
+}
+unsafe impl<Argument: ValueType, Product: ValueType> Sync
+    for CustomBinaryOperator<Argument, Product>
+{
+}
+
+impl<Argument: ValueType, Product: ValueType> CustomBinaryOperator<Argument, Product> {
+    pub fn new<Function: BinaryOperatorFunction<Argument, Product>>(
+        context: Arc<Context>,
+        _function: Function,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let mut graphblas_operator: MaybeUninit<GrB_BinaryOp> = MaybeUninit::uninit();
+
+        let product_type = Product::graphblas_type_in_context(&context)?;
+        let argument_type = Argument::graphblas_type_in_context(&context)?;
+
+        context.call_without_detailed_error_information(|| unsafe {
+            GrB_BinaryOp_new(
+                graphblas_operator.as_mut_ptr(),
+                Some(call_binary_operator_function::<Argument, Product, Function>),
+                product_type,
+                argument_type,
+                argument_type,
+            )
+        })?;
+
+        Ok(Self {
+            context,
+            graphblas_operator: unsafe { graphblas_operator.assume_init() },
+            _argument_type: PhantomData,
+            _product_type: PhantomData,
+        })
+    }
+}
+
+impl<Argument: ValueType, Product: ValueType> BinaryOperator<Argument>
+    for CustomBinaryOperator<Argument, Product>
+{
+    fn graphblas_type(&self) -> GrB_BinaryOp {
+        self.graphblas_operator
+    }
+}
+
+impl<Argument: ValueType, Product: ValueType> AccumulatorBinaryOperator<Argument>
+    for CustomBinaryOperator<Argument, Product>
+{
+    fn accumulator_graphblas_type(&self) -> GrB_BinaryOp {
+        self.graphblas_operator
+    }
+}
+
+impl<Argument: ValueType, Product: ValueType> GetContext
+    for CustomBinaryOperator<Argument, Product>
+{
+    fn context(&self) -> Arc<Context> {
+        self.context.clone()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        &self.context
+    }
+}
+
+impl<Argument: ValueType, Product: ValueType> Drop for CustomBinaryOperator<Argument, Product> {
+    fn drop(&mut self) {
+        let _ = self
+            .context
+            .call_without_detailed_error_information(|| unsafe {
+                GrB_BinaryOp_free(&mut self.graphblas_operator)
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::collections::Collection;
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::element_wise_addition::{
+        ApplyElementWiseMatrixAdditionBinaryOperator, ElementWiseMatrixAdditionBinaryOperator,
+    };
+    use crate::operators::mask::SelectEntireMatrix;
+    use crate::operators::options::OptionsForOperatorWithMatrixArguments;
+
+    struct AbsoluteDifference;
+
+    impl BinaryOperatorFunction<f32, f32> for AbsoluteDifference {
+        fn product(first_argument: &f32, second_argument: &f32) -> f32 {
+            (first_argument - second_argument).abs()
+        }
+    }
+
+    #[test]
+    fn element_wise_addition_with_custom_binary_operator() {
+        let context = Context::init_default().unwrap();
+
+        let left = SparseMatrix::<f32>::from_element_list(
+            context.clone(),
+            Size::new(2, 2),
+            MatrixElementList::from_element_vector(vec![(0, 0, 1.0).into(), (1, 1, 2.5).into()]),
+            &First::<f32>::new(),
+        )
+        .unwrap();
+        let right = SparseMatrix::<f32>::from_element_list(
+            context.clone(),
+            Size::new(2, 2),
+            MatrixElementList::from_element_vector(vec![(0, 0, 4.0).into(), (1, 0, 3.0).into()]),
+            &First::<f32>::new(),
+        )
+        .unwrap();
+        let mut product = SparseMatrix::<f32>::new(context.clone(), Size::new(2, 2)).unwrap();
+
+        let distance =
+            CustomBinaryOperator::<f32>::new(context.clone(), AbsoluteDifference).unwrap();
+        ElementWiseMatrixAdditionBinaryOperator::new()
+            .apply(
+                &left,
+                &distance,
+                &right,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product.element_value(&0, &0).unwrap(), Some(3.0));
+        // Element-wise addition takes the value of elements stored in only one of the arguments
+        assert_eq!(product.element_value(&1, &0).unwrap(), Some(3.0));
+        assert_eq!(product.element_value(&1, &1).unwrap(), Some(2.5));
+    }
+}
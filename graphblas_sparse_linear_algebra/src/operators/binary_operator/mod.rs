@@ -1,3 +1,5 @@
 mod binary_operator;
+mod custom_binary_operator;
 
 pub use binary_operator::*;
+pub use custom_binary_operator::*;
@@ -54,6 +54,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseMatrixAdditionSemiring<Evaluat
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Matrix_eWiseAdd_Semiring(
@@ -63,7 +64,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseMatrixAdditionSemiring<Evaluat
                     operator.graphblas_type(),
                     multiplier.graphblas_matrix(),
                     multiplicant.graphblas_matrix(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_matrix() },
@@ -115,6 +116,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseMatrixAdditionMonoidOperator<E
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Matrix_eWiseAdd_Monoid(
@@ -124,7 +126,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseMatrixAdditionMonoidOperator<E
                     operator.graphblas_type(),
                     multiplier.graphblas_matrix(),
                     multiplicant.graphblas_matrix(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_matrix() },
@@ -177,6 +179,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseMatrixAdditionBinaryOperator<E
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Matrix_eWiseAdd_BinaryOp(
@@ -186,7 +189,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseMatrixAdditionBinaryOperator<E
                     operator.graphblas_type(),
                     multiplier.graphblas_matrix(),
                     multiplicant.graphblas_matrix(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_matrix() },
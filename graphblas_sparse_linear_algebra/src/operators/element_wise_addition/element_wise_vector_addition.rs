@@ -54,6 +54,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseVectorAdditionSemiringOperator
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Vector_eWiseAdd_Semiring(
@@ -63,7 +64,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseVectorAdditionSemiringOperator
                     operator.graphblas_type(),
                     multiplier.graphblas_vector(),
                     multiplicant.graphblas_vector(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_vector() },
@@ -116,6 +117,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseVectorAdditionMonoidOperator<E
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Vector_eWiseAdd_Monoid(
@@ -125,7 +127,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseVectorAdditionMonoidOperator<E
                     operator.graphblas_type(),
                     multiplier.graphblas_vector(),
                     multiplicant.graphblas_vector(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_vector() },
@@ -178,6 +180,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseVectorAdditionBinaryOperator<E
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Vector_eWiseAdd_BinaryOp(
@@ -187,7 +190,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseVectorAdditionBinaryOperator<E
                     operator.graphblas_type(),
                     multiplier.graphblas_vector(),
                     multiplicant.graphblas_vector(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_vector() },
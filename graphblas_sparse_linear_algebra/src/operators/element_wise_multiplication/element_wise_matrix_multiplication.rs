@@ -54,6 +54,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseMatrixMultiplicationSemiring<E
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Matrix_eWiseMult_Semiring(
@@ -63,7 +64,7 @@ impl<EvaluationDomain: ValueType> ApplyElementWiseMatrixMultiplicationSemiring<E
                     operator.graphblas_type(),
                     multiplier.graphblas_matrix(),
                     multiplicant.graphblas_matrix(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_matrix() },
@@ -116,6 +117,7 @@ impl<EvaluationDomain: ValueType>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Matrix_eWiseMult_Monoid(
@@ -125,7 +127,7 @@ impl<EvaluationDomain: ValueType>
                     operator.graphblas_type(),
                     multiplier.graphblas_matrix(),
                     multiplicant.graphblas_matrix(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_matrix() },
@@ -179,6 +181,7 @@ impl<EvaluationDomain: ValueType>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Matrix_eWiseMult_BinaryOp(
@@ -188,7 +191,7 @@ impl<EvaluationDomain: ValueType>
                     operator.graphblas_type(),
                     multiplier.graphblas_matrix(),
                     multiplicant.graphblas_matrix(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_matrix() },
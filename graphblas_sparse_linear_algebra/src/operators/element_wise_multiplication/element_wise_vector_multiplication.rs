@@ -55,6 +55,7 @@ impl<EvaluationDomain: ValueType>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Vector_eWiseMult_Semiring(
@@ -64,7 +65,7 @@ impl<EvaluationDomain: ValueType>
                     operator.graphblas_type(),
                     multiplier.graphblas_vector(),
                     multiplicant.graphblas_vector(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_vector() },
@@ -118,6 +119,7 @@ impl<EvaluationDomain: ValueType>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Vector_eWiseMult_Monoid(
@@ -127,7 +129,7 @@ impl<EvaluationDomain: ValueType>
                     operator.graphblas_type(),
                     multiplier.graphblas_vector(),
                     multiplicant.graphblas_vector(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_vector() },
@@ -181,6 +183,7 @@ impl<EvaluationDomain: ValueType>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Vector_eWiseMult_BinaryOp(
@@ -190,7 +193,7 @@ impl<EvaluationDomain: ValueType>
                     operator.graphblas_type(),
                     multiplier.graphblas_vector(),
                     multiplicant.graphblas_vector(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { &product.graphblas_vector() },
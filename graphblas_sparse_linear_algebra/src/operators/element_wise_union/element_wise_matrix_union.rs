@@ -0,0 +1,179 @@
+use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
+use crate::collections::sparse_scalar::GraphblasSparseScalarTrait;
+use crate::context::CallGraphBlasContext;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{AccumulatorBinaryOperator, BinaryOperator};
+use crate::operators::mask::MatrixMask;
+use crate::operators::options::GetOptionsForOperatorWithMatrixArguments;
+use crate::value_type::ValueType;
+
+use crate::graphblas_bindings::GxB_Matrix_eWiseUnion;
+
+use super::ElementWiseUnionArgument;
+
+// Implemented methods do not provide mutable access to GraphBLAS operators or options.
+// Code review must consider that no mtable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl Sync for ElementWiseMatrixUnionOperator {}
+unsafe impl Send for ElementWiseMatrixUnionOperator {}
+
+#[derive(Debug, Clone)]
+pub struct ElementWiseMatrixUnionOperator {}
+
+impl ElementWiseMatrixUnionOperator {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for ElementWiseMatrixUnionOperator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Applies the operator to the union of the stored elements of both arguments.
+///
+/// Unlike element-wise addition, the operator is also applied where only one argument stores an element.
+/// The fill value of the other argument is used in place of its missing element, e.g. a zero to compute A - B.
+/// The fill values must not be empty.
+pub trait ApplyElementWiseMatrixUnion<EvaluationDomain: ValueType> {
+    fn apply(
+        &self,
+        left_argument: &ElementWiseUnionArgument<
+            impl GetGraphblasSparseMatrix,
+            impl GraphblasSparseScalarTrait,
+        >,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        right_argument: &ElementWiseUnionArgument<
+            impl GetGraphblasSparseMatrix,
+            impl GraphblasSparseScalarTrait,
+        >,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl GetGraphblasSparseMatrix,
+        mask: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArguments,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> ApplyElementWiseMatrixUnion<EvaluationDomain>
+    for ElementWiseMatrixUnionOperator
+{
+    fn apply(
+        &self,
+        left_argument: &ElementWiseUnionArgument<
+            impl GetGraphblasSparseMatrix,
+            impl GraphblasSparseScalarTrait,
+        >,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        right_argument: &ElementWiseUnionArgument<
+            impl GetGraphblasSparseMatrix,
+            impl GraphblasSparseScalarTrait,
+        >,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl GetGraphblasSparseMatrix,
+        mask: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArguments,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let context = product.context_ref();
+
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
+        context.call(
+            || unsafe {
+                GxB_Matrix_eWiseUnion(
+                    product.graphblas_matrix(),
+                    mask.graphblas_matrix(),
+                    accumulator.accumulator_graphblas_type(),
+                    operator.graphblas_type(),
+                    left_argument.argument().graphblas_matrix(),
+                    left_argument.fill_value().graphblas_scalar(),
+                    right_argument.argument().graphblas_matrix(),
+                    right_argument.fill_value().graphblas_scalar(),
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { &product.graphblas_matrix() },
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::collections::sparse_scalar::SparseScalar;
+    use crate::collections::Collection;
+    use crate::context::Context;
+    use crate::operators::binary_operator::{Assignment, First, Minus};
+    use crate::operators::mask::SelectEntireMatrix;
+    use crate::operators::options::OptionsForOperatorWithMatrixArguments;
+
+    #[test]
+    fn test_sparse_difference() {
+        let context = Context::init_default().unwrap();
+        let size = Size::new(2, 2);
+
+        let left_argument = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            size,
+            MatrixElementList::from_element_vector(vec![(0, 0, 5).into(), (0, 1, 3).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let right_argument = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            size,
+            MatrixElementList::from_element_vector(vec![(0, 0, 2).into(), (1, 1, 4).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let zero = SparseScalar::<i32>::from_value(context.clone(), 0).unwrap();
+        let mut product = SparseMatrix::<i32>::new(context.clone(), size).unwrap();
+
+        ElementWiseMatrixUnionOperator::new()
+            .apply(
+                &ElementWiseUnionArgument::new(&left_argument, &zero),
+                &Minus::<i32>::new(),
+                &ElementWiseUnionArgument::new(&right_argument, &zero),
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product.element_value(&0, &0).unwrap(), Some(3));
+        assert_eq!(product.element_value(&0, &1).unwrap(), Some(3));
+        assert_eq!(product.element_value(&1, &1).unwrap(), Some(-4));
+        assert_eq!(product.element_value(&1, &0).unwrap(), None);
+    }
+
+    #[test]
+    fn test_union_with_empty_fill_value() {
+        let context = Context::init_default().unwrap();
+        let size = Size::new(2, 2);
+
+        let argument = SparseMatrix::<i32>::new(context.clone(), size).unwrap();
+        let empty_fill_value = SparseScalar::<i32>::new(context.clone()).unwrap();
+        let mut product = SparseMatrix::<i32>::new(context.clone(), size).unwrap();
+
+        let result = ElementWiseMatrixUnionOperator::new().apply(
+            &ElementWiseUnionArgument::new(&argument, &empty_fill_value),
+            &Minus::<i32>::new(),
+            &ElementWiseUnionArgument::new(&argument, &empty_fill_value),
+            &Assignment::new(),
+            &mut product,
+            &SelectEntireMatrix::new(context),
+            &OptionsForOperatorWithMatrixArguments::new_default(),
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,23 @@
+/// An argument of an element-wise union, with the fill value used in place of its missing elements.
+#[derive(Debug, Clone, Copy)]
+pub struct ElementWiseUnionArgument<'a, Argument, FillValue> {
+    argument: &'a Argument,
+    fill_value: &'a FillValue,
+}
+
+impl<'a, Argument, FillValue> ElementWiseUnionArgument<'a, Argument, FillValue> {
+    pub fn new(argument: &'a Argument, fill_value: &'a FillValue) -> Self {
+        Self {
+            argument,
+            fill_value,
+        }
+    }
+
+    pub fn argument(&self) -> &'a Argument {
+        self.argument
+    }
+
+    pub fn fill_value(&self) -> &'a FillValue {
+        self.fill_value
+    }
+}
@@ -0,0 +1,171 @@
+use crate::collections::sparse_scalar::GraphblasSparseScalarTrait;
+use crate::collections::sparse_vector::GetGraphblasSparseVector;
+use crate::context::CallGraphBlasContext;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{AccumulatorBinaryOperator, BinaryOperator};
+use crate::operators::mask::VectorMask;
+use crate::operators::options::GetOperatorOptions;
+use crate::value_type::ValueType;
+
+use crate::graphblas_bindings::GxB_Vector_eWiseUnion;
+
+use super::ElementWiseUnionArgument;
+
+// Implemented methods do not provide mutable access to GraphBLAS operators or options.
+// Code review must consider that no mtable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl Sync for ElementWiseVectorUnionOperator {}
+unsafe impl Send for ElementWiseVectorUnionOperator {}
+
+#[derive(Debug, Clone)]
+pub struct ElementWiseVectorUnionOperator {}
+
+impl ElementWiseVectorUnionOperator {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for ElementWiseVectorUnionOperator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Applies the operator to the union of the stored elements of both arguments.
+///
+/// Unlike element-wise addition, the operator is also applied where only one argument stores an element.
+/// The fill value of the other argument is used in place of its missing element, e.g. a zero to compute u - v.
+/// The fill values must not be empty.
+pub trait ApplyElementWiseVectorUnion<EvaluationDomain: ValueType> {
+    fn apply(
+        &self,
+        left_argument: &ElementWiseUnionArgument<
+            impl GetGraphblasSparseVector,
+            impl GraphblasSparseScalarTrait,
+        >,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        right_argument: &ElementWiseUnionArgument<
+            impl GetGraphblasSparseVector,
+            impl GraphblasSparseScalarTrait,
+        >,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl GetGraphblasSparseVector,
+        mask: &impl VectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> ApplyElementWiseVectorUnion<EvaluationDomain>
+    for ElementWiseVectorUnionOperator
+{
+    fn apply(
+        &self,
+        left_argument: &ElementWiseUnionArgument<
+            impl GetGraphblasSparseVector,
+            impl GraphblasSparseScalarTrait,
+        >,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        right_argument: &ElementWiseUnionArgument<
+            impl GetGraphblasSparseVector,
+            impl GraphblasSparseScalarTrait,
+        >,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl GetGraphblasSparseVector,
+        mask: &impl VectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let context = product.context_ref();
+
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
+        context.call(
+            || unsafe {
+                GxB_Vector_eWiseUnion(
+                    product.graphblas_vector(),
+                    mask.graphblas_vector(),
+                    accumulator.accumulator_graphblas_type(),
+                    operator.graphblas_type(),
+                    left_argument.argument().graphblas_vector(),
+                    left_argument.fill_value().graphblas_scalar(),
+                    right_argument.argument().graphblas_vector(),
+                    right_argument.fill_value().graphblas_scalar(),
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { &product.graphblas_vector() },
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_scalar::SparseScalar;
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::collections::Collection;
+    use crate::context::Context;
+    use crate::operators::binary_operator::{Assignment, First, Minus, Plus};
+    use crate::operators::mask::SelectEntireVector;
+    use crate::operators::options::OperatorOptions;
+
+    #[test]
+    fn test_sparse_difference_with_accumulator() {
+        let context = Context::init_default().unwrap();
+
+        let left_argument = SparseVector::<f64>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::from_element_vector(vec![(0, 1.5).into(), (1, 2.0).into()]),
+            &First::<f64>::new(),
+        )
+        .unwrap();
+        let right_argument = SparseVector::<f64>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::from_element_vector(vec![(1, 0.5).into(), (2, 4.0).into()]),
+            &First::<f64>::new(),
+        )
+        .unwrap();
+        let zero = SparseScalar::<f64>::from_value(context.clone(), 0.0).unwrap();
+        let ten = SparseScalar::<f64>::from_value(context.clone(), 10.0).unwrap();
+        let mut product = SparseVector::<f64>::new(context.clone(), 3).unwrap();
+
+        ElementWiseVectorUnionOperator::new()
+            .apply(
+                &ElementWiseUnionArgument::new(&left_argument, &ten),
+                &Minus::<f64>::new(),
+                &ElementWiseUnionArgument::new(&right_argument, &zero),
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireVector::new(context.clone()),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product.element_value(&0).unwrap(), Some(1.5));
+        assert_eq!(product.element_value(&1).unwrap(), Some(1.5));
+        assert_eq!(product.element_value(&2).unwrap(), Some(6.0));
+
+        ElementWiseVectorUnionOperator::new()
+            .apply(
+                &ElementWiseUnionArgument::new(&left_argument, &zero),
+                &Minus::<f64>::new(),
+                &ElementWiseUnionArgument::new(&right_argument, &zero),
+                &Plus::<f64>::new(),
+                &mut product,
+                &SelectEntireVector::new(context),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.element_value(&0).unwrap(), Some(3.0));
+        assert_eq!(product.element_value(&2).unwrap(), Some(2.0));
+    }
+}
@@ -0,0 +1,7 @@
+mod element_wise_matrix_union;
+mod element_wise_union_argument;
+mod element_wise_vector_union;
+
+pub use element_wise_matrix_union::{ApplyElementWiseMatrixUnion, ElementWiseMatrixUnionOperator};
+pub use element_wise_union_argument::ElementWiseUnionArgument;
+pub use element_wise_vector_union::{ApplyElementWiseVectorUnion, ElementWiseVectorUnionOperator};
@@ -0,0 +1,673 @@
+use std::borrow::Cow;
+use std::ptr;
+use std::sync::Arc;
+
+use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
+use crate::collections::sparse_matrix::{
+    GetGraphblasSparseMatrix, GetMatrixDimensions, Size, SparseMatrix,
+};
+use crate::context::{CallGraphBlasContext, Context, GetContext};
+use crate::error::{GraphblasError, GraphblasErrorType, SparseLinearAlgebraError};
+use crate::graphblas_bindings::{
+    GrB_BinaryOp, GrB_Matrix, GrB_Matrix_apply, GrB_Matrix_eWiseAdd_BinaryOp,
+    GrB_Matrix_eWiseMult_BinaryOp, GrB_mxm, GrB_transpose,
+};
+use crate::value_type::ValueType;
+
+use super::binary_operator::{AccumulatorBinaryOperator, BinaryOperator};
+use super::mask::MatrixMask;
+use super::options::{
+    graphblas_descriptor_for_call_with_extended_options, ExtendedDescriptorOptions,
+    ExtendedGraphblasDescriptors, GraphblasDescriptorForCall,
+};
+use super::semiring::Semiring;
+use super::unary_operator::UnaryOperator;
+
+/// A lazily evaluated composition of matrix operations.
+///
+/// Evaluation lowers the root operation onto a single GraphBLAS call,
+/// which also applies the mask, accumulator and replace option of the MatrixOutput.
+/// Transposes are folded into the descriptor of the operation that uses them,
+/// so only operands that are themselves operations are evaluated into temporary matrices.
+#[derive(Clone)]
+pub enum MatrixExpression<'a, T: ValueType> {
+    Matrix(&'a SparseMatrix<T>),
+    Transpose(Box<MatrixExpression<'a, T>>),
+    Multiply(
+        Box<MatrixExpression<'a, T>>,
+        Box<MatrixExpression<'a, T>>,
+        &'a dyn Semiring<T>,
+    ),
+    /// Applies the operator to the union of the stored elements
+    ElementWiseAdd(
+        Box<MatrixExpression<'a, T>>,
+        Box<MatrixExpression<'a, T>>,
+        &'a dyn BinaryOperator<T>,
+    ),
+    /// Applies the operator to the intersection of the stored elements
+    ElementWiseMultiply(
+        Box<MatrixExpression<'a, T>>,
+        Box<MatrixExpression<'a, T>>,
+        &'a dyn BinaryOperator<T>,
+    ),
+    Apply(Box<MatrixExpression<'a, T>>, &'a dyn UnaryOperator<T>),
+}
+
+impl<'a, T: ValueType> From<&'a SparseMatrix<T>> for MatrixExpression<'a, T> {
+    fn from(matrix: &'a SparseMatrix<T>) -> Self {
+        MatrixExpression::Matrix(matrix)
+    }
+}
+
+impl<'a, T: ValueType> MatrixExpression<'a, T> {
+    pub fn transpose(self) -> Self {
+        MatrixExpression::Transpose(Box::new(self))
+    }
+
+    pub fn mxm(
+        self,
+        multiplicant: impl Into<MatrixExpression<'a, T>>,
+        semiring: &'a impl Semiring<T>,
+    ) -> Self {
+        MatrixExpression::Multiply(Box::new(self), Box::new(multiplicant.into()), semiring)
+    }
+
+    pub fn ewise_add(
+        self,
+        other: impl Into<MatrixExpression<'a, T>>,
+        operator: &'a impl BinaryOperator<T>,
+    ) -> Self {
+        MatrixExpression::ElementWiseAdd(Box::new(self), Box::new(other.into()), operator)
+    }
+
+    pub fn ewise_mult(
+        self,
+        other: impl Into<MatrixExpression<'a, T>>,
+        operator: &'a impl BinaryOperator<T>,
+    ) -> Self {
+        MatrixExpression::ElementWiseMultiply(Box::new(self), Box::new(other.into()), operator)
+    }
+
+    pub fn apply(self, operator: &'a impl UnaryOperator<T>) -> Self {
+        MatrixExpression::Apply(Box::new(self), operator)
+    }
+
+    /// Validates the dimensions of all operations, without evaluating them
+    pub fn size(&self) -> Result<Size, SparseLinearAlgebraError> {
+        Ok(self.expression_size()?.size)
+    }
+
+    fn expression_size(&self) -> Result<ExpressionSize, SparseLinearAlgebraError> {
+        match self {
+            MatrixExpression::Matrix(matrix) => Ok(ExpressionSize::new(matrix.size()?, vec![])),
+            MatrixExpression::Transpose(argument) => {
+                let argument_size = argument.expression_size()?;
+                Ok(ExpressionSize::new(
+                    Size::new(
+                        argument_size.size.column_width(),
+                        argument_size.size.row_height(),
+                    ),
+                    vec![argument_size],
+                ))
+            }
+            MatrixExpression::Multiply(multiplier, multiplicant, _) => {
+                let multiplier_size = multiplier.expression_size()?;
+                let multiplicant_size = multiplicant.expression_size()?;
+                if multiplier_size.size.column_width() != multiplicant_size.size.row_height() {
+                    return Err(dimension_mismatch(format!(
+                        "Cannot multiply a matrix of size {:?} by a matrix of size {:?}",
+                        multiplier_size.size, multiplicant_size.size
+                    )));
+                }
+                Ok(ExpressionSize::new(
+                    Size::new(
+                        multiplier_size.size.row_height(),
+                        multiplicant_size.size.column_width(),
+                    ),
+                    vec![multiplier_size, multiplicant_size],
+                ))
+            }
+            MatrixExpression::ElementWiseAdd(first, second, _)
+            | MatrixExpression::ElementWiseMultiply(first, second, _) => {
+                let first_size = first.expression_size()?;
+                let second_size = second.expression_size()?;
+                if first_size.size != second_size.size {
+                    return Err(dimension_mismatch(format!(
+                        "Element-wise operations require matrices of equal size, found {:?} and {:?}",
+                        first_size.size, second_size.size
+                    )));
+                }
+                Ok(ExpressionSize::new(
+                    first_size.size,
+                    vec![first_size, second_size],
+                ))
+            }
+            MatrixExpression::Apply(argument, _) => {
+                let argument_size = argument.expression_size()?;
+                Ok(ExpressionSize::new(argument_size.size, vec![argument_size]))
+            }
+        }
+    }
+
+    /// Evaluates into a new matrix
+    pub fn evaluate(&self) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        self.evaluate_with_size(&self.expression_size()?, &None)
+    }
+
+    /// Evaluates product<mask> accumulator= self, as configured by output
+    pub fn evaluate_into(
+        &self,
+        product: &mut SparseMatrix<T>,
+        output: &MatrixOutput<'_, T>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let size = self.expression_size()?;
+        let product_size = product.size()?;
+        if size.size != product_size {
+            return Err(dimension_mismatch(format!(
+                "Cannot evaluate an expression of size {:?} into a matrix of size {:?}",
+                size.size, product_size
+            )));
+        }
+
+        let extended_graphblas_descriptors = match output.extended_descriptor_options {
+            Some(extended_descriptor_options) => Some(ExtendedGraphblasDescriptors::cached(
+                product.context_ref(),
+                output.clear_output_before_use,
+                &extended_descriptor_options,
+            )?),
+            None => None,
+        };
+        self.evaluate_into_with_size(&size, product, output, &extended_graphblas_descriptors)
+    }
+
+    fn evaluate_with_size(
+        &self,
+        size: &ExpressionSize,
+        extended_graphblas_descriptors: &Option<Arc<ExtendedGraphblasDescriptors>>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<T>::new(self.context()?, size.size)?;
+        self.evaluate_into_with_size(
+            size,
+            &mut product,
+            &MatrixOutput::new(),
+            extended_graphblas_descriptors,
+        )?;
+        Ok(product)
+    }
+
+    // The sizes of all nodes are validated before the first GraphBLAS call.
+    // Temporary matrices share the extended descriptors of the output,
+    // clearing a new matrix without a mask has no effect.
+    fn evaluate_into_with_size(
+        &self,
+        size: &ExpressionSize,
+        product: &mut SparseMatrix<T>,
+        output: &MatrixOutput<'_, T>,
+        extended_graphblas_descriptors: &Option<Arc<ExtendedGraphblasDescriptors>>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let mask = output.graphblas_mask();
+        let accumulator = output.graphblas_accumulator();
+
+        match self {
+            MatrixExpression::Matrix(_) | MatrixExpression::Transpose(_) => {
+                let argument = self.to_operand(size, extended_graphblas_descriptors)?;
+                // GrB_transpose with a transposed input copies the input
+                let descriptor = output.graphblas_descriptor_for_call(
+                    extended_graphblas_descriptors,
+                    !argument.transpose,
+                    false,
+                )?;
+                product.context_ref().call(
+                    || unsafe {
+                        GrB_transpose(
+                            GetGraphblasSparseMatrix::graphblas_matrix(product),
+                            mask,
+                            accumulator,
+                            GetGraphblasSparseMatrix::graphblas_matrix(argument.matrix.as_ref()),
+                            descriptor.graphblas_descriptor(),
+                        )
+                    },
+                    unsafe { product.graphblas_matrix_ref() },
+                )?;
+            }
+            MatrixExpression::Multiply(multiplier, multiplicant, semiring) => {
+                let multiplier = multiplier
+                    .to_operand(&size.argument_sizes[0], extended_graphblas_descriptors)?;
+                let multiplicant = multiplicant
+                    .to_operand(&size.argument_sizes[1], extended_graphblas_descriptors)?;
+                let descriptor = output.graphblas_descriptor_for_call(
+                    extended_graphblas_descriptors,
+                    multiplier.transpose,
+                    multiplicant.transpose,
+                )?;
+                product.context_ref().call(
+                    || unsafe {
+                        GrB_mxm(
+                            GetGraphblasSparseMatrix::graphblas_matrix(product),
+                            mask,
+                            accumulator,
+                            semiring.graphblas_type(),
+                            GetGraphblasSparseMatrix::graphblas_matrix(multiplier.matrix.as_ref()),
+                            GetGraphblasSparseMatrix::graphblas_matrix(
+                                multiplicant.matrix.as_ref(),
+                            ),
+                            descriptor.graphblas_descriptor(),
+                        )
+                    },
+                    unsafe { product.graphblas_matrix_ref() },
+                )?;
+            }
+            MatrixExpression::ElementWiseAdd(first, second, operator) => {
+                let first =
+                    first.to_operand(&size.argument_sizes[0], extended_graphblas_descriptors)?;
+                let second =
+                    second.to_operand(&size.argument_sizes[1], extended_graphblas_descriptors)?;
+                let descriptor = output.graphblas_descriptor_for_call(
+                    extended_graphblas_descriptors,
+                    first.transpose,
+                    second.transpose,
+                )?;
+                product.context_ref().call(
+                    || unsafe {
+                        GrB_Matrix_eWiseAdd_BinaryOp(
+                            GetGraphblasSparseMatrix::graphblas_matrix(product),
+                            mask,
+                            accumulator,
+                            operator.graphblas_type(),
+                            GetGraphblasSparseMatrix::graphblas_matrix(first.matrix.as_ref()),
+                            GetGraphblasSparseMatrix::graphblas_matrix(second.matrix.as_ref()),
+                            descriptor.graphblas_descriptor(),
+                        )
+                    },
+                    unsafe { product.graphblas_matrix_ref() },
+                )?;
+            }
+            MatrixExpression::ElementWiseMultiply(first, second, operator) => {
+                let first =
+                    first.to_operand(&size.argument_sizes[0], extended_graphblas_descriptors)?;
+                let second =
+                    second.to_operand(&size.argument_sizes[1], extended_graphblas_descriptors)?;
+                let descriptor = output.graphblas_descriptor_for_call(
+                    extended_graphblas_descriptors,
+                    first.transpose,
+                    second.transpose,
+                )?;
+                product.context_ref().call(
+                    || unsafe {
+                        GrB_Matrix_eWiseMult_BinaryOp(
+                            GetGraphblasSparseMatrix::graphblas_matrix(product),
+                            mask,
+                            accumulator,
+                            operator.graphblas_type(),
+                            GetGraphblasSparseMatrix::graphblas_matrix(first.matrix.as_ref()),
+                            GetGraphblasSparseMatrix::graphblas_matrix(second.matrix.as_ref()),
+                            descriptor.graphblas_descriptor(),
+                        )
+                    },
+                    unsafe { product.graphblas_matrix_ref() },
+                )?;
+            }
+            MatrixExpression::Apply(argument, operator) => {
+                let argument =
+                    argument.to_operand(&size.argument_sizes[0], extended_graphblas_descriptors)?;
+                let descriptor = output.graphblas_descriptor_for_call(
+                    extended_graphblas_descriptors,
+                    argument.transpose,
+                    false,
+                )?;
+                product.context_ref().call(
+                    || unsafe {
+                        GrB_Matrix_apply(
+                            GetGraphblasSparseMatrix::graphblas_matrix(product),
+                            mask,
+                            accumulator,
+                            operator.graphblas_type(),
+                            GetGraphblasSparseMatrix::graphblas_matrix(argument.matrix.as_ref()),
+                            descriptor.graphblas_descriptor(),
+                        )
+                    },
+                    unsafe { product.graphblas_matrix_ref() },
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Matrices and transposes of operands are used in place, other operations are evaluated into a temporary matrix
+    fn to_operand(
+        &self,
+        size: &ExpressionSize,
+        extended_graphblas_descriptors: &Option<Arc<ExtendedGraphblasDescriptors>>,
+    ) -> Result<Operand<'a, T>, SparseLinearAlgebraError> {
+        match self {
+            MatrixExpression::Matrix(matrix) => Ok(Operand {
+                matrix: Cow::Borrowed(*matrix),
+                transpose: false,
+            }),
+            MatrixExpression::Transpose(argument) => {
+                let operand =
+                    argument.to_operand(&size.argument_sizes[0], extended_graphblas_descriptors)?;
+                Ok(Operand {
+                    matrix: operand.matrix,
+                    transpose: !operand.transpose,
+                })
+            }
+            _ => Ok(Operand {
+                matrix: Cow::Owned(self.evaluate_with_size(size, extended_graphblas_descriptors)?),
+                transpose: false,
+            }),
+        }
+    }
+
+    fn context(&self) -> Result<Arc<Context>, SparseLinearAlgebraError> {
+        match self {
+            MatrixExpression::Matrix(matrix) => Ok(matrix.context()),
+            MatrixExpression::Transpose(argument) | MatrixExpression::Apply(argument, _) => {
+                argument.context()
+            }
+            MatrixExpression::Multiply(first, _, _)
+            | MatrixExpression::ElementWiseAdd(first, _, _)
+            | MatrixExpression::ElementWiseMultiply(first, _, _) => first.context(),
+        }
+    }
+}
+
+struct Operand<'a, T: ValueType> {
+    matrix: Cow<'a, SparseMatrix<T>>,
+    transpose: bool,
+}
+
+// The size of each node of an expression, computed once before evaluation.
+// Arguments are in the order of the MatrixExpression variant.
+struct ExpressionSize {
+    size: Size,
+    argument_sizes: Vec<ExpressionSize>,
+}
+
+impl ExpressionSize {
+    fn new(size: Size, argument_sizes: Vec<ExpressionSize>) -> Self {
+        Self {
+            size,
+            argument_sizes,
+        }
+    }
+}
+
+/// Configures how an evaluated MatrixExpression is written into the product.
+///
+/// The GraphBLAS descriptor is derived from the mask, these settings and the transposes in the expression.
+pub struct MatrixOutput<'a, T: ValueType> {
+    mask: Option<&'a dyn MatrixMask>,
+    accumulator: Option<&'a dyn AccumulatorBinaryOperator<T>>,
+    clear_output_before_use: bool,
+    extended_descriptor_options: Option<ExtendedDescriptorOptions>,
+}
+
+// Derive would require T: Clone
+impl<'a, T: ValueType> Clone for MatrixOutput<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            mask: self.mask,
+            accumulator: self.accumulator,
+            clear_output_before_use: self.clear_output_before_use,
+            extended_descriptor_options: self.extended_descriptor_options,
+        }
+    }
+}
+
+impl<'a, T: ValueType> Default for MatrixOutput<'a, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T: ValueType> MatrixOutput<'a, T> {
+    /// Writes all elements without a mask, and without an accumulator
+    pub fn new() -> Self {
+        Self {
+            mask: None,
+            accumulator: None,
+            clear_output_before_use: false,
+            extended_descriptor_options: None,
+        }
+    }
+
+    /// Use Mask to select by structure, or by the complement of the mask
+    pub fn with_mask(&self, mask: &'a impl MatrixMask) -> Self {
+        Self {
+            mask: Some(mask),
+            ..self.clone()
+        }
+    }
+
+    pub fn with_accumulator(&self, accumulator: &'a impl AccumulatorBinaryOperator<T>) -> Self {
+        Self {
+            accumulator: Some(accumulator),
+            ..self.clone()
+        }
+    }
+
+    /// Removes the elements of the product outside the mask
+    pub fn with_replace(&self) -> Self {
+        Self {
+            clear_output_before_use: true,
+            ..self.clone()
+        }
+    }
+
+    /// Evaluates every operation of the expression with the extended descriptor options
+    pub fn with_extended_descriptor_options(
+        &self,
+        extended_descriptor_options: &ExtendedDescriptorOptions,
+    ) -> Self {
+        Self {
+            extended_descriptor_options: Some(*extended_descriptor_options),
+            ..self.clone()
+        }
+    }
+
+    fn graphblas_mask(&self) -> GrB_Matrix {
+        match self.mask {
+            Some(mask) => unsafe { mask.graphblas_matrix() },
+            None => ptr::null_mut(),
+        }
+    }
+
+    fn graphblas_accumulator(&self) -> GrB_BinaryOp {
+        match self.accumulator {
+            Some(accumulator) => accumulator.accumulator_graphblas_type(),
+            None => ptr::null_mut(),
+        }
+    }
+
+    fn graphblas_descriptor_for_call(
+        &self,
+        extended_graphblas_descriptors: &Option<Arc<ExtendedGraphblasDescriptors>>,
+        transpose_first_argument: bool,
+        transpose_second_argument: bool,
+    ) -> Result<GraphblasDescriptorForCall, SparseLinearAlgebraError> {
+        let (use_mask_structure_of_stored_values_as_mask, use_mask_complement) = match self.mask {
+            Some(mask) => (
+                mask.use_mask_structure_of_stored_values_as_mask(),
+                mask.use_mask_complement(),
+            ),
+            None => (false, false),
+        };
+        graphblas_descriptor_for_call_with_extended_options(
+            extended_graphblas_descriptors,
+            self.clear_output_before_use,
+            use_mask_structure_of_stored_values_as_mask,
+            use_mask_complement,
+            transpose_first_argument,
+            transpose_second_argument,
+        )
+    }
+}
+
+fn dimension_mismatch(explanation: String) -> SparseLinearAlgebraError {
+    GraphblasError::new(GraphblasErrorType::DimensionMismatch, explanation).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::MatrixElementList;
+    use crate::collections::Collection;
+    use crate::context::Context;
+    use crate::operators::binary_operator::{First, Plus, Times};
+    use crate::operators::mask::Mask;
+    use crate::operators::semiring::PlusTimes;
+    use crate::operators::unary_operator::AdditiveInverse;
+
+    fn matrix(size: Size, elements: Vec<(usize, usize, i32)>) -> SparseMatrix<i32> {
+        SparseMatrix::<i32>::from_element_list(
+            Context::init_default().unwrap(),
+            size,
+            MatrixElementList::from_element_vector(
+                elements.into_iter().map(|element| element.into()).collect(),
+            ),
+            &First::<i32>::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn masked_accumulation_of_transposed_multiplication() {
+        let a = matrix(Size::new(3, 2), vec![(0, 0, 1), (1, 0, 2), (2, 1, 3)]);
+        let b = matrix(Size::new(3, 2), vec![(0, 1, 4), (1, 1, 5), (2, 0, 6)]);
+        let mask = matrix(Size::new(2, 2), vec![(0, 1, 1), (1, 0, 1)]);
+        let mut c = matrix(Size::new(2, 2), vec![(0, 1, 100), (1, 1, 7)]);
+
+        // c<mask> += a' * b
+        let plus_times = PlusTimes::<i32>::new();
+        let plus = Plus::<i32>::new();
+        MatrixExpression::from(&a)
+            .transpose()
+            .mxm(&b, &plus_times)
+            .evaluate_into(
+                &mut c,
+                &MatrixOutput::new().with_mask(&mask).with_accumulator(&plus),
+            )
+            .unwrap();
+
+        assert_eq!(c.element_value(&0, &1).unwrap(), Some(114));
+        assert_eq!(c.element_value(&1, &0).unwrap(), Some(18));
+        assert_eq!(c.element_value(&1, &1).unwrap(), Some(7));
+        assert_eq!(c.element_value(&0, &0).unwrap(), None);
+    }
+
+    #[test]
+    fn replace_with_complemented_mask() {
+        let a = matrix(Size::new(2, 2), vec![(0, 0, 1), (0, 1, 2), (1, 0, 3)]);
+        let mask = matrix(Size::new(2, 2), vec![(0, 1, 0)]);
+        let mut c = matrix(Size::new(2, 2), vec![(0, 1, 9), (1, 1, 9)]);
+
+        let additive_inverse = AdditiveInverse::<i32>::new();
+        MatrixExpression::from(&a)
+            .apply(&additive_inverse)
+            .evaluate_into(
+                &mut c,
+                &MatrixOutput::new()
+                    .with_mask(&Mask::complement(&Mask::structure(&mask)))
+                    .with_replace(),
+            )
+            .unwrap();
+
+        assert_eq!(c.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(c.element_value(&0, &0).unwrap(), Some(-1));
+        assert_eq!(c.element_value(&1, &0).unwrap(), Some(-3));
+        assert_eq!(c.element_value(&0, &1).unwrap(), None);
+    }
+
+    #[test]
+    fn evaluate_nested_expression() {
+        let a = matrix(Size::new(2, 3), vec![(0, 2, 1), (1, 0, 2)]);
+        let b = matrix(Size::new(3, 2), vec![(2, 0, 3), (0, 1, 4)]);
+        let plus = Plus::<i32>::new();
+        let times = Times::<i32>::new();
+
+        // (a + b')' .* b
+        let product = MatrixExpression::from(&a)
+            .ewise_add(MatrixExpression::from(&b).transpose(), &plus)
+            .transpose()
+            .ewise_mult(&b, &times)
+            .evaluate()
+            .unwrap();
+
+        assert_eq!(product.size().unwrap(), Size::new(3, 2));
+        assert_eq!(product.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(product.element_value(&2, &0).unwrap(), Some(12));
+        assert_eq!(product.element_value(&0, &1).unwrap(), Some(24));
+    }
+
+    #[test]
+    fn evaluate_nested_expression_with_extended_descriptor_options() {
+        let a = matrix(Size::new(2, 2), vec![(0, 0, 1), (0, 1, 2), (1, 1, 3)]);
+        let mask = matrix(Size::new(2, 2), vec![(0, 1, 1), (1, 1, 1)]);
+        let mut c = matrix(Size::new(2, 2), vec![(0, 0, 5)]);
+        let plus_times = PlusTimes::<i32>::new();
+        let plus = Plus::<i32>::new();
+
+        // c<mask, replace> = (a * a) + a'
+        MatrixExpression::from(&a)
+            .mxm(&a, &plus_times)
+            .ewise_add(MatrixExpression::from(&a).transpose(), &plus)
+            .evaluate_into(
+                &mut c,
+                &MatrixOutput::new()
+                    .with_mask(&mask)
+                    .with_replace()
+                    .with_extended_descriptor_options(
+                        &ExtendedDescriptorOptions::new_default()
+                            .with_number_of_threads(2)
+                            .with_sorted_output(true),
+                    ),
+            )
+            .unwrap();
+
+        assert_eq!(c.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(c.element_value(&0, &1).unwrap(), Some(8));
+        assert_eq!(c.element_value(&1, &1).unwrap(), Some(12));
+        assert_eq!(c.element_value(&0, &0).unwrap(), None);
+    }
+
+    #[test]
+    fn transposed_matrix_is_copied() {
+        let a = matrix(Size::new(1, 2), vec![(0, 1, 5)]);
+
+        let copy = MatrixExpression::from(&a).evaluate().unwrap();
+        assert_eq!(copy.element_value(&0, &1).unwrap(), Some(5));
+
+        let transpose = MatrixExpression::from(&a)
+            .transpose()
+            .transpose()
+            .transpose()
+            .evaluate()
+            .unwrap();
+        assert_eq!(transpose.size().unwrap(), Size::new(2, 1));
+        assert_eq!(transpose.element_value(&1, &0).unwrap(), Some(5));
+    }
+
+    #[test]
+    fn reject_incompatible_sizes() {
+        let a = matrix(Size::new(2, 3), vec![]);
+        let plus_times = PlusTimes::<i32>::new();
+
+        assert!(MatrixExpression::from(&a)
+            .mxm(&a, &plus_times)
+            .size()
+            .is_err());
+
+        let mut c = matrix(Size::new(2, 2), vec![]);
+        assert!(MatrixExpression::from(&a)
+            .transpose()
+            .mxm(&a, &plus_times)
+            .evaluate_into(&mut c, &MatrixOutput::new())
+            .is_err());
+    }
+}
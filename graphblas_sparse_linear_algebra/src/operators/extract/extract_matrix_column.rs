@@ -3,9 +3,7 @@ use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
 use crate::context::CallGraphBlasContext;
 use crate::error::SparseLinearAlgebraError;
-use crate::index::{
-    ElementIndex, ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion,
-};
+use crate::index::{ElementIndex, ElementIndexSelector, IndexConversion};
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::GetOptionsForOperatorWithMatrixArgument;
@@ -54,55 +52,27 @@ impl<Column: ValueType> ExtractMatrixColumn<Column> for MatrixColumnExtractor {
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = matrix_to_extract_from.context_ref();
 
-        let number_of_indices_to_extract: ElementIndex;
-        match indices_to_extract {
-            ElementIndexSelector::Index(indices) => number_of_indices_to_extract = indices.len(),
-            ElementIndexSelector::All => {
-                number_of_indices_to_extract = matrix_to_extract_from.row_height()?
-            }
-        }
-        let number_of_indices_to_extract = number_of_indices_to_extract.to_graphblas_index()?;
-
-        let indices_to_extract = indices_to_extract.to_graphblas_type()?;
+        let indices_to_extract =
+            indices_to_extract.to_graphblas_type(matrix_to_extract_from.row_height()?)?;
 
         let column_index_to_extract = column_index_to_extract.to_graphblas_index()?;
 
-        match indices_to_extract {
-            ElementIndexSelectorGraphblasType::Index(index) => {
-                context.call(
-                    || unsafe {
-                        GrB_Col_extract(
-                            GetGraphblasSparseVector::graphblas_vector(column_vector),
-                            mask.graphblas_vector(),
-                            accumulator.accumulator_graphblas_type(),
-                            matrix_to_extract_from.graphblas_matrix(),
-                            index.as_ptr(),
-                            number_of_indices_to_extract,
-                            column_index_to_extract,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { column_vector.graphblas_vector_ref() },
-                )?;
-            }
-            ElementIndexSelectorGraphblasType::All(index) => {
-                context.call(
-                    || unsafe {
-                        GrB_Col_extract(
-                            GetGraphblasSparseVector::graphblas_vector(column_vector),
-                            mask.graphblas_vector(),
-                            accumulator.accumulator_graphblas_type(),
-                            matrix_to_extract_from.graphblas_matrix(),
-                            index,
-                            number_of_indices_to_extract,
-                            column_index_to_extract,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { column_vector.graphblas_vector_ref() },
-                )?;
-            }
-        }
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
+        context.call(
+            || unsafe {
+                GrB_Col_extract(
+                    GetGraphblasSparseVector::graphblas_vector(column_vector),
+                    mask.graphblas_vector(),
+                    accumulator.accumulator_graphblas_type(),
+                    matrix_to_extract_from.graphblas_matrix(),
+                    indices_to_extract.as_ptr(),
+                    indices_to_extract.number_of_indices(),
+                    column_index_to_extract,
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { column_vector.graphblas_vector_ref() },
+        )?;
 
         Ok(())
     }
@@ -2,9 +2,7 @@ use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
 use crate::context::CallGraphBlasContext;
 use crate::error::SparseLinearAlgebraError;
-use crate::index::{
-    ElementIndex, ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion,
-};
+use crate::index::ElementIndexSelector;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::MatrixMask;
 use crate::operators::options::GetOptionsForOperatorWithMatrixArgument;
@@ -53,113 +51,28 @@ impl<SubMatrix: ValueType> ExtractSubMatrix<SubMatrix> for SubMatrixExtractor {
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = matrix_to_extract_from.context_ref();
 
-        let number_of_rows_to_extract: ElementIndex;
-        match rows_to_extract {
-            ElementIndexSelector::Index(indices) => number_of_rows_to_extract = indices.len(),
-            ElementIndexSelector::All => {
-                number_of_rows_to_extract = matrix_to_extract_from.row_height()?
-            }
-        }
-        let number_of_rows_to_extract = number_of_rows_to_extract.to_graphblas_index()?;
-
-        let number_of_columns_to_extract: ElementIndex;
-        match columns_to_extract {
-            ElementIndexSelector::Index(indices) => number_of_columns_to_extract = indices.len(),
-            ElementIndexSelector::All => {
-                number_of_columns_to_extract = matrix_to_extract_from.column_width()?
-            }
-        }
-        let number_of_columns_to_extract = number_of_columns_to_extract.to_graphblas_index()?;
-
-        let rows_to_extract = rows_to_extract.to_graphblas_type()?;
-        let columns_to_extract = columns_to_extract.to_graphblas_type()?;
-
-        match (rows_to_extract, columns_to_extract) {
-            (
-                ElementIndexSelectorGraphblasType::Index(row),
-                ElementIndexSelectorGraphblasType::Index(column),
-            ) => {
-                context.call(
-                    || unsafe {
-                        GrB_Matrix_extract(
-                            GetGraphblasSparseMatrix::graphblas_matrix(sub_matrix),
-                            mask.graphblas_matrix(),
-                            accumulator.accumulator_graphblas_type(),
-                            matrix_to_extract_from.graphblas_matrix(),
-                            row.as_ptr(),
-                            number_of_rows_to_extract,
-                            column.as_ptr(),
-                            number_of_columns_to_extract,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { sub_matrix.graphblas_matrix_ref() },
-                )?;
-            }
-            (
-                ElementIndexSelectorGraphblasType::All(row),
-                ElementIndexSelectorGraphblasType::Index(column),
-            ) => {
-                context.call(
-                    || unsafe {
-                        GrB_Matrix_extract(
-                            GetGraphblasSparseMatrix::graphblas_matrix(sub_matrix),
-                            mask.graphblas_matrix(),
-                            accumulator.accumulator_graphblas_type(),
-                            matrix_to_extract_from.graphblas_matrix(),
-                            row,
-                            number_of_rows_to_extract,
-                            column.as_ptr(),
-                            number_of_columns_to_extract,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { sub_matrix.graphblas_matrix_ref() },
-                )?;
-            }
-            (
-                ElementIndexSelectorGraphblasType::Index(row),
-                ElementIndexSelectorGraphblasType::All(column),
-            ) => {
-                context.call(
-                    || unsafe {
-                        GrB_Matrix_extract(
-                            GetGraphblasSparseMatrix::graphblas_matrix(sub_matrix),
-                            mask.graphblas_matrix(),
-                            accumulator.accumulator_graphblas_type(),
-                            matrix_to_extract_from.graphblas_matrix(),
-                            row.as_ptr(),
-                            number_of_rows_to_extract,
-                            column,
-                            number_of_columns_to_extract,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { sub_matrix.graphblas_matrix_ref() },
-                )?;
-            }
-            (
-                ElementIndexSelectorGraphblasType::All(row),
-                ElementIndexSelectorGraphblasType::All(column),
-            ) => {
-                context.call(
-                    || unsafe {
-                        GrB_Matrix_extract(
-                            GetGraphblasSparseMatrix::graphblas_matrix(sub_matrix),
-                            mask.graphblas_matrix(),
-                            accumulator.accumulator_graphblas_type(),
-                            matrix_to_extract_from.graphblas_matrix(),
-                            row,
-                            number_of_rows_to_extract,
-                            column,
-                            number_of_columns_to_extract,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { sub_matrix.graphblas_matrix_ref() },
-                )?;
-            }
-        }
+        let rows_to_extract =
+            rows_to_extract.to_graphblas_type(matrix_to_extract_from.row_height()?)?;
+        let columns_to_extract =
+            columns_to_extract.to_graphblas_type(matrix_to_extract_from.column_width()?)?;
+
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
+        context.call(
+            || unsafe {
+                GrB_Matrix_extract(
+                    GetGraphblasSparseMatrix::graphblas_matrix(sub_matrix),
+                    mask.graphblas_matrix(),
+                    accumulator.accumulator_graphblas_type(),
+                    matrix_to_extract_from.graphblas_matrix(),
+                    rows_to_extract.as_ptr(),
+                    rows_to_extract.number_of_indices(),
+                    columns_to_extract.as_ptr(),
+                    columns_to_extract.number_of_indices(),
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { sub_matrix.graphblas_matrix_ref() },
+        )?;
 
         Ok(())
     }
@@ -175,6 +88,7 @@ mod tests {
     use crate::collections::sparse_matrix::{MatrixElementList, SparseMatrix};
     use crate::collections::Collection;
     use crate::context::Context;
+    use crate::index::ElementIndex;
     use crate::operators::binary_operator::{Assignment, First};
     use crate::operators::mask::SelectEntireMatrix;
     use crate::operators::options::OptionsForOperatorWithMatrixArgument;
@@ -2,9 +2,7 @@ use crate::collections::sparse_vector::operations::GetSparseVectorLength;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
 use crate::context::CallGraphBlasContext;
 use crate::error::SparseLinearAlgebraError;
-use crate::index::{
-    ElementIndex, ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion,
-};
+use crate::index::ElementIndexSelector;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::GetOperatorOptions;
@@ -53,51 +51,24 @@ impl<SubVector: ValueType> ExtractSubVector<SubVector> for SubVectorExtractor {
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = vector_to_extract_from.context_ref();
 
-        let number_of_indices_to_extract: ElementIndex;
-        match indices_to_extract {
-            ElementIndexSelector::Index(indices) => number_of_indices_to_extract = indices.len(),
-            ElementIndexSelector::All => {
-                number_of_indices_to_extract = vector_to_extract_from.length()?
-            }
-        }
-        let number_of_indices_to_extract = number_of_indices_to_extract.to_graphblas_index()?;
-
-        let indices_to_extract = indices_to_extract.to_graphblas_type()?;
-
-        match indices_to_extract {
-            ElementIndexSelectorGraphblasType::Index(index) => {
-                context.call(
-                    || unsafe {
-                        GrB_Vector_extract(
-                            GetGraphblasSparseVector::graphblas_vector(sub_vector),
-                            mask.graphblas_vector(),
-                            accumulator.accumulator_graphblas_type(),
-                            vector_to_extract_from.graphblas_vector(),
-                            index.as_ptr(),
-                            number_of_indices_to_extract,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { sub_vector.graphblas_vector_ref() },
-                )?;
-            }
-            ElementIndexSelectorGraphblasType::All(index) => {
-                context.call(
-                    || unsafe {
-                        GrB_Vector_extract(
-                            GetGraphblasSparseVector::graphblas_vector(sub_vector),
-                            mask.graphblas_vector(),
-                            accumulator.accumulator_graphblas_type(),
-                            vector_to_extract_from.graphblas_vector(),
-                            index,
-                            number_of_indices_to_extract,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { sub_vector.graphblas_vector_ref() },
-                )?;
-            }
-        }
+        let indices_to_extract =
+            indices_to_extract.to_graphblas_type(vector_to_extract_from.length()?)?;
+
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
+        context.call(
+            || unsafe {
+                GrB_Vector_extract(
+                    GetGraphblasSparseVector::graphblas_vector(sub_vector),
+                    mask.graphblas_vector(),
+                    accumulator.accumulator_graphblas_type(),
+                    vector_to_extract_from.graphblas_vector(),
+                    indices_to_extract.as_ptr(),
+                    indices_to_extract.number_of_indices(),
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { sub_vector.graphblas_vector_ref() },
+        )?;
 
         Ok(())
     }
@@ -113,6 +84,7 @@ mod tests {
     use crate::collections::sparse_vector::{SparseVector, VectorElementList};
     use crate::collections::Collection;
     use crate::context::Context;
+    use crate::index::ElementIndex;
     use crate::operators::binary_operator::{Assignment, First};
     use crate::operators::mask::SelectEntireVector;
     use crate::operators::options::OperatorOptions;
@@ -220,4 +192,47 @@ mod tests {
         assert_eq!(sub_vector.element_value_or_default(&2).unwrap(), 2);
         assert_eq!(sub_vector.element_value_or_default(&3).unwrap(), 3);
     }
+
+    #[test]
+    fn extract_range_stride_and_backwards() {
+        let context = Context::init_default().unwrap();
+
+        let vector = SparseVector::<u8>::from_element_list(
+            context.clone(),
+            10,
+            VectorElementList::<u8>::from_element_vector(
+                (0..10).map(|index| (index, index as u8).into()).collect(),
+            ),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+
+        let extractor = SubVectorExtractor::new();
+        let mut sub_vector = SparseVector::<u8>::new(context.clone(), 3).unwrap();
+
+        for (indices_to_extract, expected_values) in [
+            (ElementIndexSelector::Range(2..5), [2, 3, 4]),
+            (ElementIndexSelector::Stride(1, 8, 3), [1, 4, 7]),
+            (ElementIndexSelector::Backwards(9, 0, 4), [9, 5, 1]),
+            (ElementIndexSelector::Index(&[6, 0, 6]), [6, 0, 6]),
+        ] {
+            extractor
+                .apply(
+                    &vector,
+                    &indices_to_extract,
+                    &Assignment::<u8>::new(),
+                    &mut sub_vector,
+                    &SelectEntireVector::new(context.clone()),
+                    &OperatorOptions::new_default(),
+                )
+                .unwrap();
+
+            for (index, expected_value) in expected_values.into_iter().enumerate() {
+                assert_eq!(
+                    sub_vector.element_value(&index).unwrap(),
+                    Some(expected_value)
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,207 @@
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+use std::sync::Arc;
+
+use crate::context::{Context, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::{
+    GrB_Index, GrB_IndexUnaryOp, GrB_IndexUnaryOp_free, GrB_IndexUnaryOp_new,
+};
+use crate::index::ElementIndex;
+use crate::value_type::ValueType;
+
+use super::IndexUnaryOperator;
+
+// The indices GraphBLAS passes to the function convert to ElementIndex without loss.
+const _: () = assert!(mem::size_of::<ElementIndex>() == mem::size_of::<GrB_Index>());
+
+/// Computes the product from the value and coordinate of an element, and from an additional argument.
+///
+/// The column index is 0 for vector elements.
+/// GraphBLAS typecasts the value, additional argument and product as required.
+///
+/// Implement it for a unit struct, and pass that struct to CustomIndexUnaryOperator::new().
+/// GraphBLAS calls the function from C, so a panic aborts the process.
+pub trait IndexUnaryOperatorFunction<Argument, Product> {
+    fn product(
+        element_value: &Argument,
+        row_index: ElementIndex,
+        column_index: ElementIndex,
+        argument: &Argument,
+    ) -> Product;
+}
+
+// GraphBLAS may pass the same pointer for the product and the element value,
+// so the element value and argument are read before the product is written.
+unsafe extern "C" fn call_index_unary_operator_function<
+    Argument,
+    Product,
+    Function: IndexUnaryOperatorFunction<Argument, Product>,
+>(
+    product: *mut c_void,
+    element_value: *const c_void,
+    row_index: GrB_Index,
+    column_index: GrB_Index,
+    argument: *const c_void,
+) {
+    let element_value = ptr::read_unaligned(element_value as *const Argument);
+    let argument = ptr::read_unaligned(argument as *const Argument);
+    ptr::write_unaligned(
+        product as *mut Product,
+        Function::product(
+            &element_value,
+            row_index as ElementIndex,
+            column_index as ElementIndex,
+            &argument,
+        ),
+    );
+}
+
+/// An index-unary operator defined by a Rust function.
+///
+/// To select elements, the product must be a bool.
+/// The GraphBLAS operator is freed when the CustomIndexUnaryOperator is dropped.
+#[derive(Debug)]
+pub struct CustomIndexUnaryOperator<Argument: ValueType, Product: ValueType = bool> {
+    context: Arc<Context>,
+    graphblas_operator: GrB_IndexUnaryOp,
+    _argument_type: PhantomData<Argument>,
+    _product_type: PhantomData<Product>,
+}
+
+// Implemented methods do not provide mutable access to GraphBLAS operators or options.
+// Code review must consider that no mtable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl<Argument: ValueType, Product: ValueType> Send
+    for CustomIndexUnaryOperator<Argument, Product>
+{
+}
+unsafe impl<Argument: ValueType, Product: ValueType> Sync
+    for CustomIndexUnaryOperator<Argument, Product>
+{
+}
+
+impl<Argument: ValueType, Product: ValueType> CustomIndexUnaryOperator<Argument, Product> {
+    pub fn new<Function: IndexUnaryOperatorFunction<Argument, Product>>(
+        context: Arc<Context>,
+        _function: Function,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let mut graphblas_operator: MaybeUninit<GrB_IndexUnaryOp> = MaybeUninit::uninit();
+
+        let product_type = Product::graphblas_type_in_context(&context)?;
+        let argument_type = Argument::graphblas_type_in_context(&context)?;
+
+        context.call_without_detailed_error_information(|| unsafe {
+            GrB_IndexUnaryOp_new(
+                graphblas_operator.as_mut_ptr(),
+                Some(call_index_unary_operator_function::<Argument, Product, Function>),
+                product_type,
+                argument_type,
+                argument_type,
+            )
+        })?;
+
+        Ok(Self {
+            context,
+            graphblas_operator: unsafe { graphblas_operator.assume_init() },
+            _argument_type: PhantomData,
+            _product_type: PhantomData,
+        })
+    }
+}
+
+impl<Argument: ValueType, Product: ValueType> IndexUnaryOperator<Argument>
+    for CustomIndexUnaryOperator<Argument, Product>
+{
+    fn graphblas_type(&self) -> GrB_IndexUnaryOp {
+        self.graphblas_operator
+    }
+}
+
+impl<Argument: ValueType, Product: ValueType> GetContext
+    for CustomIndexUnaryOperator<Argument, Product>
+{
+    fn context(&self) -> Arc<Context> {
+        self.context.clone()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        &self.context
+    }
+}
+
+impl<Argument: ValueType, Product: ValueType> Drop for CustomIndexUnaryOperator<Argument, Product> {
+    fn drop(&mut self) {
+        let _ = self
+            .context
+            .call_without_detailed_error_information(|| unsafe {
+                GrB_IndexUnaryOp_free(&mut self.graphblas_operator)
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::FromMatrixElementList;
+    use crate::collections::sparse_matrix::operations::GetSparseMatrixElementList;
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::mask::SelectEntireMatrix;
+    use crate::operators::options::OptionsForOperatorWithMatrixArgument;
+    use crate::operators::select::{MatrixSelector, SelectFromMatrix};
+
+    struct IsWithinBand;
+
+    impl IndexUnaryOperatorFunction<i64, bool> for IsWithinBand {
+        fn product(
+            _element_value: &i64,
+            row_index: ElementIndex,
+            column_index: ElementIndex,
+            band_width: &i64,
+        ) -> bool {
+            (row_index as i64 - column_index as i64).abs() <= *band_width
+        }
+    }
+
+    #[test]
+    fn select_with_custom_index_unary_operator() {
+        let context = Context::init_default().unwrap();
+
+        let matrix = SparseMatrix::<i64>::from_element_list(
+            context.clone(),
+            Size::new(4, 4),
+            MatrixElementList::from_element_vector(vec![
+                (0, 0, 1).into(),
+                (0, 3, 2).into(),
+                (1, 2, 3).into(),
+                (3, 1, 4).into(),
+                (3, 3, 5).into(),
+            ]),
+            &First::<i64>::new(),
+        )
+        .unwrap();
+        let mut product = SparseMatrix::<i64>::new(context.clone(), Size::new(4, 4)).unwrap();
+
+        let band = CustomIndexUnaryOperator::<i64>::new(context.clone(), IsWithinBand).unwrap();
+        MatrixSelector::new()
+            .apply(
+                &band,
+                1,
+                &matrix,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context),
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )
+            .unwrap();
+
+        let element_list = product.element_list().unwrap();
+        assert_eq!(element_list.row_indices_ref(), &[0, 1, 3]);
+        assert_eq!(element_list.column_indices_ref(), &[0, 2, 3]);
+        assert_eq!(element_list.values_ref(), &[1, 3, 5]);
+    }
+}
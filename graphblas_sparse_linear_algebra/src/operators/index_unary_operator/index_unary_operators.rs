@@ -1,12 +1,18 @@
 use std::marker::PhantomData;
+use std::sync::Arc;
 
+use crate::context::Context;
+use crate::error::SparseLinearAlgebraError;
 use crate::graphblas_bindings::*;
+use crate::index::ElementIndex;
 use crate::value_type::utilities_to_implement_traits_for_all_value_types::{
     implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types,
     implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types,
 };
 use crate::value_type::ValueType;
 
+use super::{CustomIndexUnaryOperator, IndexUnaryOperatorFunction};
+
 // TODO: review EvaluationDomain. Typecasting may not work as expected, e.g. for less-than ot greater-than operators.
 pub trait IndexUnaryOperator<EvaluationDomain>
 where
@@ -92,6 +98,15 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas
     GrB_ROWINDEX
 );
 
+// z = j + y
+define_index_unary_operator!(PlusColumnIndex);
+implement_index_unary_operator!(PlusColumnIndex, PlusColumnIndexTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_index_unary_operator,
+    PlusColumnIndexTyped,
+    GrB_COLINDEX
+);
+
 // z = j - i + y
 define_index_unary_operator!(PlusDiagonalIndex);
 implement_index_unary_operator!(PlusDiagonalIndex, PlusDiagonalIndexTyped);
@@ -116,6 +131,11 @@ implement_generic_index_unary_operator!(IsOnOrAboveDiagonal, GrB_TRIU);
 define_index_unary_operator!(IsOnDiagonal);
 implement_generic_index_unary_operator!(IsOnDiagonal, GrB_DIAG);
 
+// z=(j!=(i+y))
+// true for entries not on the yth diagonal
+define_index_unary_operator!(IsOffDiagonal);
+implement_generic_index_unary_operator!(IsOffDiagonal, GrB_OFFDIAG);
+
 // z=(j<=y)
 // true for entries in columns 0 to y
 define_index_unary_operator!(IsUpToAndIncludingColumn);
@@ -136,6 +156,10 @@ implement_generic_index_unary_operator!(IsUpToAndIncludingRow, GrB_ROWLE);
 define_index_unary_operator!(IsAfterRow);
 implement_generic_index_unary_operator!(IsAfterRow, GrB_ROWGT);
 
+// The value comparisons below take the value to compare with as the argument.
+// The value filters further below compare with zero, and ignore the argument.
+
+// z=(aij!=y)
 define_index_unary_operator!(IsValueNotEqualTo);
 implement_index_unary_operator!(IsValueNotEqualTo, IsValueNotEqualToTyped);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
@@ -144,6 +168,7 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_VALUENE
 );
 
+// z=(aij==y)
 define_index_unary_operator!(IsValueEqualTo);
 implement_index_unary_operator!(IsValueEqualTo, IsValueEqualToTyped);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
@@ -152,6 +177,7 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_VALUEEQ
 );
 
+// z=(aij>y)
 define_index_unary_operator!(IsValueGreaterThan);
 implement_index_unary_operator!(IsValueGreaterThan, IsValueGreaterThanTyped);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
@@ -160,6 +186,7 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_VALUEGT
 );
 
+// z=(aij<y)
 define_index_unary_operator!(IsValueLessThan);
 implement_index_unary_operator!(IsValueLessThan, IsValueLessThanTyped);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
@@ -168,6 +195,7 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_VALUELT
 );
 
+// z=(aij<=y)
 define_index_unary_operator!(IsValueLessThanOrEqualTo);
 implement_index_unary_operator!(IsValueLessThanOrEqualTo, IsValueLessThanOrEqualToTyped);
 implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
@@ -176,5 +204,336 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_VALUELE
 );
 
+// z=(aij>=y)
+define_index_unary_operator!(IsValueGreaterThanOrEqualTo);
+implement_index_unary_operator!(
+    IsValueGreaterThanOrEqualTo,
+    IsValueGreaterThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_index_unary_operator,
+    IsValueGreaterThanOrEqualToTyped,
+    GrB_VALUEGE
+);
+
+// SuiteSparse:GraphBLAS 9 provides neither GxB_FLIPDIAGINDEX, nor the value filters GxB_NONZERO, GxB_EQ_ZERO etc.
+// as index-unary operators, the value filters are deprecated GxB_SelectOps.
+// The operators below are user-defined index-unary operators with the same definitions, so they require a context.
+// GxB_NONZOMBIE has no counterpart, because it selects entries pending deletion, which GraphBLAS never exposes.
+
+// z = i - (j + y)
+#[derive(Debug)]
+pub struct PlusFlippedDiagonalIndex<EvaluationDomain: ValueType> {
+    operator: CustomIndexUnaryOperator<EvaluationDomain, EvaluationDomain>,
+}
+
+struct FlippedDiagonalIndex;
+
+macro_rules! implement_plus_flipped_diagonal_index {
+    ($value_type:ty) => {
+        impl IndexUnaryOperatorFunction<$value_type, $value_type> for FlippedDiagonalIndex {
+            fn product(
+                _element_value: &$value_type,
+                row_index: ElementIndex,
+                column_index: ElementIndex,
+                argument: &$value_type,
+            ) -> $value_type {
+                (row_index as $value_type)
+                    .wrapping_sub((column_index as $value_type).wrapping_add(*argument))
+            }
+        }
+
+        impl PlusFlippedDiagonalIndex<$value_type> {
+            pub fn new(context: Arc<Context>) -> Result<Self, SparseLinearAlgebraError> {
+                Ok(Self {
+                    operator: CustomIndexUnaryOperator::new(context, FlippedDiagonalIndex)?,
+                })
+            }
+        }
+    };
+}
+
+implement_plus_flipped_diagonal_index!(i32);
+implement_plus_flipped_diagonal_index!(i64);
+
+impl<EvaluationDomain: ValueType> IndexUnaryOperator<EvaluationDomain>
+    for PlusFlippedDiagonalIndex<EvaluationDomain>
+{
+    fn graphblas_type(&self) -> GrB_IndexUnaryOp {
+        self.operator.graphblas_type()
+    }
+}
+
+macro_rules! define_value_filter {
+    ($operator_name:ident, $function_name:ident, $comparison:tt) => {
+        #[derive(Debug)]
+        pub struct $operator_name<EvaluationDomain: ValueType> {
+            operator: CustomIndexUnaryOperator<EvaluationDomain, bool>,
+        }
+
+        struct $function_name;
+
+        impl<T: ValueType + Default + PartialOrd> IndexUnaryOperatorFunction<T, bool>
+            for $function_name
+        {
+            fn product(
+                element_value: &T,
+                _row_index: ElementIndex,
+                _column_index: ElementIndex,
+                _argument: &T,
+            ) -> bool {
+                *element_value $comparison T::default()
+            }
+        }
+
+        impl<EvaluationDomain: ValueType + Default + PartialOrd> $operator_name<EvaluationDomain> {
+            pub fn new(context: Arc<Context>) -> Result<Self, SparseLinearAlgebraError> {
+                Ok(Self {
+                    operator: CustomIndexUnaryOperator::new(context, $function_name)?,
+                })
+            }
+        }
+
+        impl<EvaluationDomain: ValueType> IndexUnaryOperator<EvaluationDomain>
+            for $operator_name<EvaluationDomain>
+        {
+            fn graphblas_type(&self) -> GrB_IndexUnaryOp {
+                self.operator.graphblas_type()
+            }
+        }
+    };
+}
+
+// z=(aij!=0), like GxB_NONZERO. NaN is nonzero.
+define_value_filter!(IsNonzero, NonzeroValue, !=);
+
+// z=(aij==0), like GxB_EQ_ZERO
+define_value_filter!(IsZero, ZeroValue, ==);
+
+// z=(aij>0), like GxB_GT_ZERO
+define_value_filter!(IsPositive, PositiveValue, >);
+
+// z=(aij>=0), like GxB_GE_ZERO
+define_value_filter!(IsNonnegative, NonnegativeValue, >=);
+
+// z=(aij<0), like GxB_LT_ZERO
+define_value_filter!(IsNegative, NegativeValue, <);
+
+// z=(aij<=0), like GxB_LE_ZERO
+define_value_filter!(IsNonpositive, NonpositiveValue, <=);
+
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::collections::Collection;
+    use crate::context::Context;
+    use crate::operators::apply::{ApplyIndexUnaryOperator, IndexUnaryOperatorApplier};
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::mask::{SelectEntireMatrix, SelectEntireVector};
+    use crate::operators::options::{OperatorOptions, OptionsForOperatorWithMatrixArgument};
+    use crate::operators::select::{
+        MatrixSelector, SelectFromMatrix, SelectFromVector, VectorSelector,
+    };
+
+    fn test_matrix(context: &std::sync::Arc<Context>) -> SparseMatrix<i32> {
+        SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            Size::new(2, 3),
+            MatrixElementList::from_element_vector(vec![
+                (0, 0, 1).into(),
+                (0, 2, 0).into(),
+                (1, 1, 5).into(),
+                (1, 2, 3).into(),
+            ]),
+            &First::<i32>::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_select_off_diagonal() {
+        let context = Context::init_default().unwrap();
+        let matrix = test_matrix(&context);
+        let mut product = SparseMatrix::<i32>::new(context.clone(), Size::new(2, 3)).unwrap();
+
+        MatrixSelector::new()
+            .apply(
+                &IsOffDiagonal::new(),
+                0,
+                &matrix,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context),
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(product.element_value(&0, &0).unwrap(), None);
+        assert_eq!(product.element_value(&0, &2).unwrap(), Some(0));
+        assert_eq!(product.element_value(&1, &2).unwrap(), Some(3));
+    }
+
+    #[test]
+    fn test_select_nonzero_values() {
+        let context = Context::init_default().unwrap();
+        let matrix = test_matrix(&context);
+        let mut product = SparseMatrix::<i32>::new(context.clone(), Size::new(2, 3)).unwrap();
+
+        MatrixSelector::new()
+            .apply(
+                &IsValueNotEqualTo::<i32>::new(),
+                0,
+                &matrix,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context),
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product.element_value(&0, &2).unwrap(), None);
+    }
+
+    #[test]
+    fn test_select_values_greater_than_or_equal_to() {
+        let context = Context::init_default().unwrap();
+        let vector = SparseVector::<f32>::from_element_list(
+            context.clone(),
+            4,
+            VectorElementList::from_element_vector(vec![
+                (0, 1.5).into(),
+                (1, 2.0).into(),
+                (3, 2.5).into(),
+            ]),
+            &First::<f32>::new(),
+        )
+        .unwrap();
+        let mut product = SparseVector::<f32>::new(context.clone(), 4).unwrap();
+
+        VectorSelector::new()
+            .apply(
+                &IsValueGreaterThanOrEqualTo::<f32>::new(),
+                2.0,
+                &vector,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireVector::new(context),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(product.element_value(&0).unwrap(), None);
+        assert_eq!(product.element_value(&1).unwrap(), Some(2.0));
+        assert_eq!(product.element_value(&3).unwrap(), Some(2.5));
+    }
+
+    #[test]
+    fn test_select_with_value_filters() {
+        let context = Context::init_default().unwrap();
+        let matrix = test_matrix(&context);
+        let mut product = SparseMatrix::<i32>::new(context.clone(), Size::new(2, 3)).unwrap();
+
+        MatrixSelector::new()
+            .apply(
+                &IsNonzero::<i32>::new(context.clone()).unwrap(),
+                0,
+                &matrix,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product.element_value(&0, &2).unwrap(), None);
+
+        let vector = SparseVector::<f64>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::from_element_vector(vec![
+                (0, -1.5).into(),
+                (1, 0.0).into(),
+                (2, 2.0).into(),
+            ]),
+            &First::<f64>::new(),
+        )
+        .unwrap();
+        let mut product = SparseVector::<f64>::new(context.clone(), 3).unwrap();
+
+        VectorSelector::new()
+            .apply(
+                &IsNonpositive::<f64>::new(context.clone()).unwrap(),
+                0.0,
+                &vector,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireVector::new(context),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(product.element_value(&0).unwrap(), Some(-1.5));
+        assert_eq!(product.element_value(&1).unwrap(), Some(0.0));
+    }
+
+    #[test]
+    fn test_apply_flipped_diagonal_index() {
+        let context = Context::init_default().unwrap();
+        let matrix = test_matrix(&context);
+        let mut product = SparseMatrix::<i64>::new(context.clone(), Size::new(2, 3)).unwrap();
+
+        IndexUnaryOperatorApplier::new()
+            .apply_to_matrix(
+                &matrix,
+                &PlusFlippedDiagonalIndex::<i64>::new(context.clone()).unwrap(),
+                &1,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context),
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.element_value(&0, &0).unwrap(), Some(-1));
+        assert_eq!(product.element_value(&0, &2).unwrap(), Some(-3));
+        assert_eq!(product.element_value(&1, &1).unwrap(), Some(-1));
+        assert_eq!(product.element_value(&1, &2).unwrap(), Some(-2));
+    }
+
+    #[test]
+    fn test_apply_column_index() {
+        let context = Context::init_default().unwrap();
+        let matrix = test_matrix(&context);
+        let mut product = SparseMatrix::<i64>::new(context.clone(), Size::new(2, 3)).unwrap();
+
+        IndexUnaryOperatorApplier::new()
+            .apply_to_matrix(
+                &matrix,
+                &PlusColumnIndex::<i64>::new(),
+                &10,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context),
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.element_value(&0, &0).unwrap(), Some(10));
+        assert_eq!(product.element_value(&1, &2).unwrap(), Some(12));
+        assert_eq!(product.element_value(&1, &0).unwrap(), None);
+    }
+}
@@ -1,3 +1,5 @@
+mod custom_index_unary_operator;
 mod index_unary_operators;
 
+pub use custom_index_unary_operator::*;
 pub use index_unary_operators::*;
@@ -3,7 +3,7 @@ use crate::collections::sparse_matrix::operations::sparse_matrix_row_height;
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
 use crate::context::CallGraphBlasContext;
 use crate::error::SparseLinearAlgebraError;
-use crate::index::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
+use crate::index::ElementIndexSelector;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 
 use crate::operators::mask::MatrixMask;
@@ -75,105 +75,29 @@ macro_rules! implement_insert_scalar_into_matrix_trait {
                 let context = matrix_to_insert_into.context_ref();
                 let scalar_to_insert = scalar_to_insert.to_type()?;
 
-                let number_of_rows_to_insert_into = rows_to_insert_into
-                    .number_of_selected_elements(sparse_matrix_row_height(matrix_to_insert_into)?)?
-                    .to_graphblas_index()?;
-
-                let number_of_columns_to_insert_into = columns_to_insert_into
-                    .number_of_selected_elements(sparse_matrix_column_width(
-                        matrix_to_insert_into,
-                    )?)?
-                    .to_graphblas_index()?;
-
-                let rows_to_insert_into = rows_to_insert_into.to_graphblas_type()?;
-                let columns_to_insert_into = columns_to_insert_into.to_graphblas_type()?;
-
-                match (rows_to_insert_into, columns_to_insert_into) {
-                    (
-                        ElementIndexSelectorGraphblasType::Index(row),
-                        ElementIndexSelectorGraphblasType::Index(column),
-                    ) => {
-                        context.call(
-                            || unsafe {
-                                $graphblas_insert_function(
-                                    matrix_to_insert_into.graphblas_matrix(),
-                                    mask_for_matrix_to_insert_into.graphblas_matrix(),
-                                    accumulator.accumulator_graphblas_type(),
-                                    scalar_to_insert,
-                                    row.as_ptr(),
-                                    number_of_rows_to_insert_into,
-                                    column.as_ptr(),
-                                    number_of_columns_to_insert_into,
-                                    options.graphblas_descriptor(),
-                                )
-                            },
-                            unsafe { matrix_to_insert_into.graphblas_matrix_ref() },
-                        )?;
-                    }
-                    (
-                        ElementIndexSelectorGraphblasType::All(row),
-                        ElementIndexSelectorGraphblasType::Index(column),
-                    ) => {
-                        context.call(
-                            || unsafe {
-                                $graphblas_insert_function(
-                                    matrix_to_insert_into.graphblas_matrix(),
-                                    mask_for_matrix_to_insert_into.graphblas_matrix(),
-                                    accumulator.accumulator_graphblas_type(),
-                                    scalar_to_insert,
-                                    row,
-                                    number_of_rows_to_insert_into,
-                                    column.as_ptr(),
-                                    number_of_columns_to_insert_into,
-                                    options.graphblas_descriptor(),
-                                )
-                            },
-                            unsafe { matrix_to_insert_into.graphblas_matrix_ref() },
-                        )?;
-                    }
-                    (
-                        ElementIndexSelectorGraphblasType::Index(row),
-                        ElementIndexSelectorGraphblasType::All(column),
-                    ) => {
-                        context.call(
-                            || unsafe {
-                                $graphblas_insert_function(
-                                    matrix_to_insert_into.graphblas_matrix(),
-                                    mask_for_matrix_to_insert_into.graphblas_matrix(),
-                                    accumulator.accumulator_graphblas_type(),
-                                    scalar_to_insert,
-                                    row.as_ptr(),
-                                    number_of_rows_to_insert_into,
-                                    column,
-                                    number_of_columns_to_insert_into,
-                                    options.graphblas_descriptor(),
-                                )
-                            },
-                            unsafe { matrix_to_insert_into.graphblas_matrix_ref() },
-                        )?;
-                    }
-                    (
-                        ElementIndexSelectorGraphblasType::All(row),
-                        ElementIndexSelectorGraphblasType::All(column),
-                    ) => {
-                        context.call(
-                            || unsafe {
-                                $graphblas_insert_function(
-                                    matrix_to_insert_into.graphblas_matrix(),
-                                    mask_for_matrix_to_insert_into.graphblas_matrix(),
-                                    accumulator.accumulator_graphblas_type(),
-                                    scalar_to_insert,
-                                    row,
-                                    number_of_rows_to_insert_into,
-                                    column,
-                                    number_of_columns_to_insert_into,
-                                    options.graphblas_descriptor(),
-                                )
-                            },
-                            unsafe { matrix_to_insert_into.graphblas_matrix_ref() },
-                        )?;
-                    }
-                }
+                let rows_to_insert_into = rows_to_insert_into
+                    .to_graphblas_type(sparse_matrix_row_height(matrix_to_insert_into)?)?;
+                let columns_to_insert_into = columns_to_insert_into
+                    .to_graphblas_type(sparse_matrix_column_width(matrix_to_insert_into)?)?;
+
+                let graphblas_descriptor =
+                    options.graphblas_descriptor_for_mask(mask_for_matrix_to_insert_into)?;
+                context.call(
+                    || unsafe {
+                        $graphblas_insert_function(
+                            matrix_to_insert_into.graphblas_matrix(),
+                            mask_for_matrix_to_insert_into.graphblas_matrix(),
+                            accumulator.accumulator_graphblas_type(),
+                            scalar_to_insert,
+                            rows_to_insert_into.as_ptr(),
+                            rows_to_insert_into.number_of_indices(),
+                            columns_to_insert_into.as_ptr(),
+                            columns_to_insert_into.number_of_indices(),
+                            graphblas_descriptor.graphblas_descriptor(),
+                        )
+                    },
+                    unsafe { matrix_to_insert_into.graphblas_matrix_ref() },
+                )?;
 
                 Ok(())
             }
@@ -2,7 +2,7 @@ use crate::collections::sparse_vector::operations::sparse_vector_length;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
 use crate::context::CallGraphBlasContext;
 use crate::error::SparseLinearAlgebraError;
-use crate::index::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
+use crate::index::ElementIndexSelector;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::GetOperatorOptions;
@@ -72,47 +72,25 @@ macro_rules! implement_insert_scalar_into_vector_trait {
                 let context = vector_to_insert_into.context_ref();
                 let scalar_to_insert = scalar_to_insert.clone().to_type()?;
 
-                let number_of_indices_to_insert_into = indices_to_insert_into
-                    .number_of_selected_elements(sparse_vector_length(vector_to_insert_into)?)?
-                    .to_graphblas_index()?;
-
-                let indices_to_insert_into = indices_to_insert_into.to_graphblas_type()?;
-
-                match indices_to_insert_into {
-                    ElementIndexSelectorGraphblasType::Index(index) => {
-                        context.call(
-                            || unsafe {
-                                $graphblas_insert_function(
-                                    vector_to_insert_into.graphblas_vector(),
-                                    mask_for_vector_to_insert_into.graphblas_vector(),
-                                    accumulator.accumulator_graphblas_type(),
-                                    scalar_to_insert,
-                                    index.as_ptr(),
-                                    number_of_indices_to_insert_into,
-                                    options.graphblas_descriptor(),
-                                )
-                            },
-                            unsafe { vector_to_insert_into.graphblas_vector_ref() },
-                        )?;
-                    }
-
-                    ElementIndexSelectorGraphblasType::All(index) => {
-                        context.call(
-                            || unsafe {
-                                $graphblas_insert_function(
-                                    vector_to_insert_into.graphblas_vector(),
-                                    mask_for_vector_to_insert_into.graphblas_vector(),
-                                    accumulator.accumulator_graphblas_type(),
-                                    scalar_to_insert,
-                                    index,
-                                    number_of_indices_to_insert_into,
-                                    options.graphblas_descriptor(),
-                                )
-                            },
-                            unsafe { vector_to_insert_into.graphblas_vector_ref() },
-                        )?;
-                    }
-                }
+                let indices_to_insert_into = indices_to_insert_into
+                    .to_graphblas_type(sparse_vector_length(vector_to_insert_into)?)?;
+
+                let graphblas_descriptor =
+                    options.graphblas_descriptor_for_mask(mask_for_vector_to_insert_into)?;
+                context.call(
+                    || unsafe {
+                        $graphblas_insert_function(
+                            vector_to_insert_into.graphblas_vector(),
+                            mask_for_vector_to_insert_into.graphblas_vector(),
+                            accumulator.accumulator_graphblas_type(),
+                            scalar_to_insert,
+                            indices_to_insert_into.as_ptr(),
+                            indices_to_insert_into.number_of_indices(),
+                            graphblas_descriptor.graphblas_descriptor(),
+                        )
+                    },
+                    unsafe { vector_to_insert_into.graphblas_vector_ref() },
+                )?;
 
                 Ok(())
             }
@@ -226,4 +204,38 @@ mod tests {
         assert_eq!(vector.element_value_or_default(&5).unwrap(), 11);
         assert_eq!(vector.element_value_or_default(&1).unwrap(), 1);
     }
+
+    #[test]
+    fn insert_scalar_into_range() {
+        let context = Context::init_default().unwrap();
+        let mut vector = SparseVector::<i32>::new(context.clone(), 2000).unwrap();
+
+        let insert_operator = InsertScalarIntoVectorOperator::new();
+        insert_operator
+            .apply(
+                &mut vector,
+                &ElementIndexSelector::Range(1000..2000),
+                7,
+                &Assignment::<i32>::new(),
+                &SelectEntireVector::new(context.clone()),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(vector.number_of_stored_elements().unwrap(), 1000);
+        assert_eq!(vector.element_value(&999).unwrap(), None);
+        assert_eq!(vector.element_value(&1000).unwrap(), Some(7));
+        assert_eq!(vector.element_value(&1999).unwrap(), Some(7));
+
+        assert!(insert_operator
+            .apply(
+                &mut vector,
+                &ElementIndexSelector::Stride(0, 10, 0),
+                1,
+                &Assignment::<i32>::new(),
+                &SelectEntireVector::new(context),
+                &OperatorOptions::new_default(),
+            )
+            .is_err());
+    }
 }
@@ -3,9 +3,7 @@ use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
 use crate::context::CallGraphBlasContext;
 use crate::error::SparseLinearAlgebraError;
-use crate::index::{
-    ElementIndex, ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion,
-};
+use crate::index::{ElementIndex, ElementIndexSelector, IndexConversion};
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::GetOptionsForOperatorWithMatrixArgument;
@@ -64,50 +62,27 @@ impl<AccumulatorEvaluationDomain: ValueType> InsertVectorIntoRow<AccumulatorEval
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = matrix_to_insert_into.context_ref();
 
-        let number_of_indices_to_insert_into = row_indices_to_insert_into
-            .number_of_selected_elements(sparse_matrix_row_height(matrix_to_insert_into)?)?
-            .to_graphblas_index()?;
-
-        let indices_to_insert_into = row_indices_to_insert_into.to_graphblas_type()?;
+        let indices_to_insert_into = row_indices_to_insert_into
+            .to_graphblas_type(sparse_matrix_row_height(matrix_to_insert_into)?)?;
         let row_to_insert_into = row_to_insert_into.to_graphblas_index()?;
 
-        match indices_to_insert_into {
-            ElementIndexSelectorGraphblasType::Index(index) => {
-                context.call(
-                    || unsafe {
-                        GrB_Row_assign(
-                            matrix_to_insert_into.graphblas_matrix(),
-                            mask_for_row_to_insert_into.graphblas_vector(),
-                            accumulator.accumulator_graphblas_type(),
-                            vector_to_insert.graphblas_vector(),
-                            row_to_insert_into,
-                            index.as_ptr(),
-                            number_of_indices_to_insert_into,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { matrix_to_insert_into.graphblas_matrix_ref() },
-                )?;
-            }
-
-            ElementIndexSelectorGraphblasType::All(index) => {
-                context.call(
-                    || unsafe {
-                        GrB_Row_assign(
-                            matrix_to_insert_into.graphblas_matrix(),
-                            mask_for_row_to_insert_into.graphblas_vector(),
-                            accumulator.accumulator_graphblas_type(),
-                            vector_to_insert.graphblas_vector(),
-                            row_to_insert_into,
-                            index,
-                            number_of_indices_to_insert_into,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { matrix_to_insert_into.graphblas_matrix_ref() },
-                )?;
-            }
-        }
+        let graphblas_descriptor =
+            options.graphblas_descriptor_for_mask(mask_for_row_to_insert_into)?;
+        context.call(
+            || unsafe {
+                GrB_Row_assign(
+                    matrix_to_insert_into.graphblas_matrix(),
+                    mask_for_row_to_insert_into.graphblas_vector(),
+                    accumulator.accumulator_graphblas_type(),
+                    vector_to_insert.graphblas_vector(),
+                    row_to_insert_into,
+                    indices_to_insert_into.as_ptr(),
+                    indices_to_insert_into.number_of_indices(),
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { matrix_to_insert_into.graphblas_matrix_ref() },
+        )?;
 
         Ok(())
     }
@@ -6,7 +6,7 @@ use crate::operators::options::GetOperatorOptions;
 
 use crate::collections::sparse_vector::operations::sparse_vector_length;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
-use crate::index::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
+use crate::index::ElementIndexSelector;
 use crate::value_type::ValueType;
 
 use crate::graphblas_bindings::GrB_Vector_assign;
@@ -59,47 +59,25 @@ impl<AccumulatorEvaluationDomain: ValueType> InsertVectorIntoVector<AccumulatorE
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = vector_to_insert_into.context_ref();
 
-        let number_of_indices_to_insert_into = indices_to_insert_into
-            .number_of_selected_elements(sparse_vector_length(vector_to_insert_into)?)?
-            .to_graphblas_index()?;
-
-        let indices_to_insert_into = indices_to_insert_into.to_graphblas_type()?;
-
-        match indices_to_insert_into {
-            ElementIndexSelectorGraphblasType::Index(index) => {
-                context.call(
-                    || unsafe {
-                        GrB_Vector_assign(
-                            vector_to_insert_into.graphblas_vector(),
-                            mask_for_vector_to_insert_into.graphblas_vector(),
-                            accumulator.accumulator_graphblas_type(),
-                            vector_to_insert.graphblas_vector(),
-                            index.as_ptr(),
-                            number_of_indices_to_insert_into,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { vector_to_insert_into.graphblas_vector_ref() },
-                )?;
-            }
-
-            ElementIndexSelectorGraphblasType::All(index) => {
-                context.call(
-                    || unsafe {
-                        GrB_Vector_assign(
-                            vector_to_insert_into.graphblas_vector(),
-                            mask_for_vector_to_insert_into.graphblas_vector(),
-                            accumulator.accumulator_graphblas_type(),
-                            vector_to_insert.graphblas_vector(),
-                            index,
-                            number_of_indices_to_insert_into,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { vector_to_insert_into.graphblas_vector_ref() },
-                )?;
-            }
-        }
+        let indices_to_insert_into = indices_to_insert_into
+            .to_graphblas_type(sparse_vector_length(vector_to_insert_into)?)?;
+
+        let graphblas_descriptor =
+            options.graphblas_descriptor_for_mask(mask_for_vector_to_insert_into)?;
+        context.call(
+            || unsafe {
+                GrB_Vector_assign(
+                    vector_to_insert_into.graphblas_vector(),
+                    mask_for_vector_to_insert_into.graphblas_vector(),
+                    accumulator.accumulator_graphblas_type(),
+                    vector_to_insert.graphblas_vector(),
+                    indices_to_insert_into.as_ptr(),
+                    indices_to_insert_into.number_of_indices(),
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { vector_to_insert_into.graphblas_vector_ref() },
+        )?;
 
         Ok(())
     }
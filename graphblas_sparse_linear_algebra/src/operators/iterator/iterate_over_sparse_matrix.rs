@@ -0,0 +1,80 @@
+use crate::collections::sparse_matrix::SparseMatrix;
+use crate::error::SparseLinearAlgebraError;
+use crate::value_type::ValueType;
+
+use super::{
+    GetElementValueAtIteratorPosition, MatrixColumnIterator, MatrixElementIterator,
+    MatrixRowIterator,
+};
+
+pub trait IterateOverSparseMatrix<T: ValueType + Copy + GetElementValueAtIteratorPosition<T>> {
+    /// Requires the matrix to be stored by row
+    fn iter_rows(&self) -> Result<MatrixRowIterator<'_, T>, SparseLinearAlgebraError>;
+    /// Requires the matrix to be stored by column
+    fn iter_columns(&self) -> Result<MatrixColumnIterator<'_, T>, SparseLinearAlgebraError>;
+    fn iter_elements(&self) -> Result<MatrixElementIterator<'_, T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType + Copy + GetElementValueAtIteratorPosition<T>> IterateOverSparseMatrix<T>
+    for SparseMatrix<T>
+{
+    fn iter_rows(&self) -> Result<MatrixRowIterator<'_, T>, SparseLinearAlgebraError> {
+        MatrixRowIterator::new(self)
+    }
+
+    fn iter_columns(&self) -> Result<MatrixColumnIterator<'_, T>, SparseLinearAlgebraError> {
+        MatrixColumnIterator::new(self)
+    }
+
+    fn iter_elements(&self) -> Result<MatrixElementIterator<'_, T>, SparseLinearAlgebraError> {
+        MatrixElementIterator::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::FromMatrixElementList;
+    use crate::collections::sparse_matrix::{GetMatrixElementValue, MatrixElementList};
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    #[test]
+    fn stream_adjacency_lists() {
+        let context = Context::init_default().unwrap();
+
+        let element_list = MatrixElementList::<u8>::from_element_vector(vec![
+            (0, 1, 1).into(),
+            (0, 2, 1).into(),
+            (1, 2, 1).into(),
+            (3, 0, 1).into(),
+        ]);
+
+        let matrix = SparseMatrix::<u8>::from_element_list(
+            context.clone(),
+            (4, 4).into(),
+            element_list,
+            &First::<u8>::new(),
+        )
+        .unwrap();
+
+        let adjacency_lists: Vec<(usize, Vec<usize>)> = matrix
+            .iter_rows()
+            .unwrap()
+            .map(|row| (row.row_index(), row.elements_ref().indices_ref().to_vec()))
+            .collect();
+
+        assert_eq!(
+            adjacency_lists,
+            vec![(0, vec![1, 2]), (1, vec![2]), (3, vec![0])]
+        );
+
+        let sum_of_values: u8 = matrix
+            .iter_elements()
+            .unwrap()
+            .map(|element| element.value())
+            .sum();
+        assert_eq!(sum_of_values, 4);
+    }
+}
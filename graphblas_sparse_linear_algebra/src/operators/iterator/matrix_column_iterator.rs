@@ -0,0 +1,235 @@
+use once_cell::sync::Lazy;
+use suitesparse_graphblas_sys::{
+    GxB_Iterator, GxB_Iterator_free, GxB_colIterator_attach, GxB_colIterator_getColIndex,
+    GxB_colIterator_getRowIndex, GxB_colIterator_nextCol, GxB_colIterator_nextRow,
+    GxB_colIterator_seekCol,
+};
+
+use crate::collections::sparse_matrix::{ColumnIndex, GetGraphblasSparseMatrix, SparseMatrix};
+use crate::collections::sparse_vector::{VectorElement, VectorElementList};
+use crate::context::{CallGraphBlasContext, GetContext, Status};
+use crate::error::SparseLinearAlgebraError;
+use crate::index::{ElementIndex, IndexConversion};
+use crate::operators::options::{GetGraphblasDescriptor, OperatorOptions};
+use crate::value_type::ValueType;
+
+use super::{new_graphblas_iterator, GetElementValueAtIteratorPosition};
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OperatorOptions> =
+    Lazy::new(OperatorOptions::new_default);
+
+/// The stored elements of a single matrix column, indexed by row index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatrixColumn<T: ValueType> {
+    column_index: ColumnIndex,
+    elements: VectorElementList<T>,
+}
+
+impl<T: ValueType> MatrixColumn<T> {
+    pub fn column_index(&self) -> ColumnIndex {
+        self.column_index
+    }
+
+    pub fn elements_ref(&self) -> &VectorElementList<T> {
+        &self.elements
+    }
+
+    pub fn into_elements(self) -> VectorElementList<T> {
+        self.elements
+    }
+}
+
+/// Iterates over the non-empty columns of a matrix stored by column.
+///
+/// Attaching fails with NotImplemented if the matrix is stored by row.
+/// Columns without stored elements are skipped.
+pub struct MatrixColumnIterator<'a, T: ValueType + Copy + GetElementValueAtIteratorPosition<T>> {
+    matrix: &'a SparseMatrix<T>,
+    graphblas_iterator: GxB_Iterator,
+    position_status: Status,
+}
+
+impl<'a, T: ValueType + Copy + GetElementValueAtIteratorPosition<T>> MatrixColumnIterator<'a, T> {
+    pub fn new(matrix: &'a SparseMatrix<T>) -> Result<Self, SparseLinearAlgebraError> {
+        let graphblas_iterator = unsafe { new_graphblas_iterator(matrix.context_ref()) }?;
+
+        let mut iterator = Self {
+            matrix,
+            graphblas_iterator,
+            position_status: Status::IteratorExhausted,
+        };
+
+        iterator.matrix.context_ref().call(
+            || unsafe {
+                GxB_colIterator_attach(
+                    iterator.graphblas_iterator,
+                    iterator.matrix.graphblas_matrix(),
+                    DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+                )
+            },
+            unsafe { &iterator.matrix.graphblas_matrix() },
+        )?;
+
+        iterator.seek_to_column(0)?;
+        Ok(iterator)
+    }
+
+    /// Positions the iterator such that the next column returned is the first
+    /// non-empty column at or after column_index.
+    pub fn seek_to_column(
+        &mut self,
+        column_index: ColumnIndex,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let column_index = column_index.to_graphblas_index()?;
+        self.position_status =
+            Status::from(unsafe { GxB_colIterator_seekCol(self.graphblas_iterator, column_index) });
+        Ok(())
+    }
+
+    fn advance_to_next_column(&mut self) {
+        self.position_status =
+            Status::from(unsafe { GxB_colIterator_nextCol(self.graphblas_iterator) });
+    }
+
+    fn column_at_iterator_position(&self) -> MatrixColumn<T> {
+        let column_index = ColumnIndex::from_graphblas_index(unsafe {
+            GxB_colIterator_getColIndex(self.graphblas_iterator)
+        })
+        .unwrap();
+
+        let mut elements = VectorElementList::new();
+        loop {
+            let row_index = ElementIndex::from_graphblas_index(unsafe {
+                GxB_colIterator_getRowIndex(self.graphblas_iterator)
+            })
+            .unwrap();
+            let value = T::element_value_at_iterator_position(self.graphblas_iterator).unwrap();
+            elements.push_element(VectorElement::new(row_index, value));
+
+            match Status::from(unsafe { GxB_colIterator_nextRow(self.graphblas_iterator) }) {
+                Status::Success => {}
+                _ => break,
+            }
+        }
+
+        MatrixColumn {
+            column_index,
+            elements,
+        }
+    }
+}
+
+impl<'a, T: ValueType + Copy + GetElementValueAtIteratorPosition<T>> Drop
+    for MatrixColumnIterator<'a, T>
+{
+    fn drop(&mut self) {
+        let context = self.matrix.context_ref();
+        let _ = context.call_without_detailed_error_information(|| unsafe {
+            GxB_Iterator_free(&mut self.graphblas_iterator)
+        });
+    }
+}
+
+impl<'a, T: ValueType + Copy + GetElementValueAtIteratorPosition<T>> Iterator
+    for MatrixColumnIterator<'a, T>
+{
+    type Item = MatrixColumn<T>;
+
+    fn next(&mut self) -> Option<MatrixColumn<T>> {
+        loop {
+            match self.position_status {
+                Status::Success => {
+                    let column = self.column_at_iterator_position();
+                    self.advance_to_next_column();
+                    return Some(column);
+                }
+                // The column at the iterator position is empty
+                Status::NoValue => self.advance_to_next_column(),
+                Status::IteratorExhausted => return None,
+                _ => {
+                    debug_assert!(
+                        false,
+                        "An unexpected status occured while iterating: {:?}",
+                        self.position_status
+                    );
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, SetSparseMatrixStorageOptions,
+    };
+    use crate::collections::sparse_matrix::MatrixElementList;
+    use crate::context::{Context, MatrixStorageFormat};
+    use crate::operators::binary_operator::First;
+
+    fn test_matrix(context: &std::sync::Arc<Context>) -> SparseMatrix<u8> {
+        let element_list = MatrixElementList::<u8>::from_element_vector(vec![
+            (1, 0, 1).into(),
+            (3, 0, 2).into(),
+            (2, 2, 3).into(),
+            (0, 5, 4).into(),
+            (4, 5, 5).into(),
+        ]);
+
+        let mut matrix = SparseMatrix::<u8>::from_element_list(
+            context.clone(),
+            (5, 7).into(),
+            element_list,
+            &First::<u8>::new(),
+        )
+        .unwrap();
+
+        matrix
+            .set_storage_format(MatrixStorageFormat::ByColumn)
+            .unwrap();
+
+        matrix
+    }
+
+    #[test]
+    fn test_matrix_column_iterator() {
+        let context = Context::init_default().unwrap();
+        let matrix = test_matrix(&context);
+
+        let columns: Vec<MatrixColumn<u8>> = MatrixColumnIterator::new(&matrix).unwrap().collect();
+
+        assert_eq!(columns.len(), 3);
+        assert_eq!(columns[0].column_index(), 0);
+        assert_eq!(columns[0].elements_ref().indices_ref(), &[1, 3]);
+        assert_eq!(columns[0].elements_ref().values_ref(), &[1, 2]);
+        assert_eq!(columns[1].column_index(), 2);
+        assert_eq!(columns[1].elements_ref().indices_ref(), &[2]);
+        assert_eq!(columns[2].column_index(), 5);
+        assert_eq!(columns[2].elements_ref().indices_ref(), &[0, 4]);
+        assert_eq!(columns[2].elements_ref().values_ref(), &[4, 5]);
+    }
+
+    #[test]
+    fn test_seek_to_column() {
+        let context = Context::init_default().unwrap();
+        let matrix = test_matrix(&context);
+
+        let mut iterator = MatrixColumnIterator::new(&matrix).unwrap();
+        iterator.seek_to_column(1).unwrap();
+
+        let column_indices: Vec<ColumnIndex> =
+            iterator.map(|column| column.column_index()).collect();
+        assert_eq!(column_indices, vec![2, 5]);
+    }
+
+    #[test]
+    fn test_attach_to_matrix_stored_by_row() {
+        let context = Context::init_default().unwrap();
+        let matrix = SparseMatrix::<u8>::new(context, (5, 7).into()).unwrap();
+
+        assert!(MatrixColumnIterator::new(&matrix).is_err());
+    }
+}
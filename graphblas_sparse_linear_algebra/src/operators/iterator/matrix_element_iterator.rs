@@ -0,0 +1,163 @@
+use std::mem::MaybeUninit;
+
+use once_cell::sync::Lazy;
+use suitesparse_graphblas_sys::{
+    GrB_Index, GxB_Iterator, GxB_Iterator_free, GxB_Matrix_Iterator_attach,
+    GxB_Matrix_Iterator_getIndex, GxB_Matrix_Iterator_next, GxB_Matrix_Iterator_seek,
+};
+
+use crate::collections::sparse_matrix::{
+    ColumnIndex, GetGraphblasSparseMatrix, MatrixElement, RowIndex, SparseMatrix,
+};
+use crate::context::{CallGraphBlasContext, GetContext, Status};
+use crate::error::SparseLinearAlgebraError;
+use crate::index::IndexConversion;
+use crate::operators::options::{GetGraphblasDescriptor, OperatorOptions};
+use crate::value_type::ValueType;
+
+use super::{new_graphblas_iterator, GetElementValueAtIteratorPosition};
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OperatorOptions> =
+    Lazy::new(OperatorOptions::new_default);
+
+/// Iterates over all stored elements of a matrix, in the order in which they are stored.
+///
+/// Works for any storage format and orientation.
+pub struct MatrixElementIterator<'a, T: ValueType + GetElementValueAtIteratorPosition<T>> {
+    matrix: &'a SparseMatrix<T>,
+    graphblas_iterator: GxB_Iterator,
+    position_status: Status,
+}
+
+impl<'a, T: ValueType + GetElementValueAtIteratorPosition<T>> MatrixElementIterator<'a, T> {
+    pub fn new(matrix: &'a SparseMatrix<T>) -> Result<Self, SparseLinearAlgebraError> {
+        let graphblas_iterator = unsafe { new_graphblas_iterator(matrix.context_ref()) }?;
+
+        let mut iterator = Self {
+            matrix,
+            graphblas_iterator,
+            position_status: Status::IteratorExhausted,
+        };
+
+        iterator.matrix.context_ref().call(
+            || unsafe {
+                GxB_Matrix_Iterator_attach(
+                    iterator.graphblas_iterator,
+                    iterator.matrix.graphblas_matrix(),
+                    DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+                )
+            },
+            unsafe { &iterator.matrix.graphblas_matrix() },
+        )?;
+
+        iterator.position_status =
+            Status::from(unsafe { GxB_Matrix_Iterator_seek(iterator.graphblas_iterator, 0) });
+        Ok(iterator)
+    }
+
+    fn element_at_iterator_position(&self) -> MatrixElement<T> {
+        let mut row_index: MaybeUninit<GrB_Index> = MaybeUninit::uninit();
+        let mut column_index: MaybeUninit<GrB_Index> = MaybeUninit::uninit();
+        unsafe {
+            GxB_Matrix_Iterator_getIndex(
+                self.graphblas_iterator,
+                row_index.as_mut_ptr(),
+                column_index.as_mut_ptr(),
+            )
+        };
+
+        let row_index = RowIndex::from_graphblas_index(unsafe { row_index.assume_init() }).unwrap();
+        let column_index =
+            ColumnIndex::from_graphblas_index(unsafe { column_index.assume_init() }).unwrap();
+        let value = T::element_value_at_iterator_position(self.graphblas_iterator).unwrap();
+
+        MatrixElement::new((row_index, column_index).into(), value)
+    }
+}
+
+impl<'a, T: ValueType + GetElementValueAtIteratorPosition<T>> Drop
+    for MatrixElementIterator<'a, T>
+{
+    fn drop(&mut self) {
+        let context = self.matrix.context_ref();
+        let _ = context.call_without_detailed_error_information(|| unsafe {
+            GxB_Iterator_free(&mut self.graphblas_iterator)
+        });
+    }
+}
+
+impl<'a, T: ValueType + GetElementValueAtIteratorPosition<T>> Iterator
+    for MatrixElementIterator<'a, T>
+{
+    type Item = MatrixElement<T>;
+
+    fn next(&mut self) -> Option<MatrixElement<T>> {
+        match self.position_status {
+            Status::Success => {
+                let element = self.element_at_iterator_position();
+                self.position_status =
+                    Status::from(unsafe { GxB_Matrix_Iterator_next(self.graphblas_iterator) });
+                Some(element)
+            }
+            Status::IteratorExhausted => None,
+            _ => {
+                debug_assert!(
+                    false,
+                    "An unexpected status occured while iterating: {:?}",
+                    self.position_status
+                );
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementList,
+    };
+    use crate::collections::sparse_matrix::MatrixElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    #[test]
+    fn test_matrix_element_iterator() {
+        let context = Context::init_default().unwrap();
+
+        let element_list = MatrixElementList::<u8>::from_element_vector(vec![
+            (0, 1, 1).into(),
+            (0, 3, 2).into(),
+            (2, 2, 3).into(),
+            (5, 0, 4).into(),
+            (5, 4, 5).into(),
+        ]);
+
+        let matrix = SparseMatrix::<u8>::from_element_list(
+            context.clone(),
+            (7, 5).into(),
+            element_list,
+            &First::<u8>::new(),
+        )
+        .unwrap();
+
+        let elements: Vec<MatrixElement<u8>> =
+            MatrixElementIterator::new(&matrix).unwrap().collect();
+
+        let expected_element_list = matrix.element_list().unwrap();
+        assert_eq!(
+            MatrixElementList::from_element_vector(elements),
+            expected_element_list
+        );
+    }
+
+    #[test]
+    fn test_iterate_over_empty_matrix() {
+        let context = Context::init_default().unwrap();
+        let matrix = SparseMatrix::<u8>::new(context, (7, 5).into()).unwrap();
+
+        assert_eq!(MatrixElementIterator::new(&matrix).unwrap().count(), 0);
+    }
+}
@@ -0,0 +1,234 @@
+use once_cell::sync::Lazy;
+use suitesparse_graphblas_sys::{
+    GxB_Iterator, GxB_Iterator_free, GxB_rowIterator_attach, GxB_rowIterator_getColIndex,
+    GxB_rowIterator_getRowIndex, GxB_rowIterator_nextCol, GxB_rowIterator_nextRow,
+    GxB_rowIterator_seekRow,
+};
+
+use crate::collections::sparse_matrix::{GetGraphblasSparseMatrix, RowIndex, SparseMatrix};
+use crate::collections::sparse_vector::{VectorElement, VectorElementList};
+use crate::context::{CallGraphBlasContext, GetContext, Status};
+use crate::error::SparseLinearAlgebraError;
+use crate::index::{ElementIndex, IndexConversion};
+use crate::operators::options::{GetGraphblasDescriptor, OperatorOptions};
+use crate::value_type::ValueType;
+
+use super::{new_graphblas_iterator, GetElementValueAtIteratorPosition};
+
+static DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS: Lazy<OperatorOptions> =
+    Lazy::new(OperatorOptions::new_default);
+
+/// The stored elements of a single matrix row, indexed by column index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatrixRow<T: ValueType> {
+    row_index: RowIndex,
+    elements: VectorElementList<T>,
+}
+
+impl<T: ValueType> MatrixRow<T> {
+    pub fn row_index(&self) -> RowIndex {
+        self.row_index
+    }
+
+    pub fn elements_ref(&self) -> &VectorElementList<T> {
+        &self.elements
+    }
+
+    pub fn into_elements(self) -> VectorElementList<T> {
+        self.elements
+    }
+}
+
+/// Iterates over the non-empty rows of a matrix stored by row.
+///
+/// Attaching fails with NotImplemented if the matrix is stored by column.
+/// Rows without stored elements are skipped.
+pub struct MatrixRowIterator<'a, T: ValueType + Copy + GetElementValueAtIteratorPosition<T>> {
+    matrix: &'a SparseMatrix<T>,
+    graphblas_iterator: GxB_Iterator,
+    position_status: Status,
+}
+
+impl<'a, T: ValueType + Copy + GetElementValueAtIteratorPosition<T>> MatrixRowIterator<'a, T> {
+    pub fn new(matrix: &'a SparseMatrix<T>) -> Result<Self, SparseLinearAlgebraError> {
+        let graphblas_iterator = unsafe { new_graphblas_iterator(matrix.context_ref()) }?;
+
+        let mut iterator = Self {
+            matrix,
+            graphblas_iterator,
+            position_status: Status::IteratorExhausted,
+        };
+
+        iterator.matrix.context_ref().call(
+            || unsafe {
+                GxB_rowIterator_attach(
+                    iterator.graphblas_iterator,
+                    iterator.matrix.graphblas_matrix(),
+                    DEFAULT_GRAPHBLAS_OPERATOR_OPTIONS.graphblas_descriptor(),
+                )
+            },
+            unsafe { &iterator.matrix.graphblas_matrix() },
+        )?;
+
+        iterator.seek_to_row(0)?;
+        Ok(iterator)
+    }
+
+    /// Positions the iterator such that the next row returned is the first
+    /// non-empty row at or after row_index.
+    pub fn seek_to_row(&mut self, row_index: RowIndex) -> Result<(), SparseLinearAlgebraError> {
+        let row_index = row_index.to_graphblas_index()?;
+        self.position_status =
+            Status::from(unsafe { GxB_rowIterator_seekRow(self.graphblas_iterator, row_index) });
+        Ok(())
+    }
+
+    fn advance_to_next_row(&mut self) {
+        self.position_status =
+            Status::from(unsafe { GxB_rowIterator_nextRow(self.graphblas_iterator) });
+    }
+
+    fn row_at_iterator_position(&self) -> MatrixRow<T> {
+        let row_index = RowIndex::from_graphblas_index(unsafe {
+            GxB_rowIterator_getRowIndex(self.graphblas_iterator)
+        })
+        .unwrap();
+
+        let mut elements = VectorElementList::new();
+        loop {
+            let column_index = ElementIndex::from_graphblas_index(unsafe {
+                GxB_rowIterator_getColIndex(self.graphblas_iterator)
+            })
+            .unwrap();
+            let value = T::element_value_at_iterator_position(self.graphblas_iterator).unwrap();
+            elements.push_element(VectorElement::new(column_index, value));
+
+            match Status::from(unsafe { GxB_rowIterator_nextCol(self.graphblas_iterator) }) {
+                Status::Success => {}
+                _ => break,
+            }
+        }
+
+        MatrixRow {
+            row_index,
+            elements,
+        }
+    }
+}
+
+impl<'a, T: ValueType + Copy + GetElementValueAtIteratorPosition<T>> Drop
+    for MatrixRowIterator<'a, T>
+{
+    fn drop(&mut self) {
+        let context = self.matrix.context_ref();
+        let _ = context.call_without_detailed_error_information(|| unsafe {
+            GxB_Iterator_free(&mut self.graphblas_iterator)
+        });
+    }
+}
+
+impl<'a, T: ValueType + Copy + GetElementValueAtIteratorPosition<T>> Iterator
+    for MatrixRowIterator<'a, T>
+{
+    type Item = MatrixRow<T>;
+
+    fn next(&mut self) -> Option<MatrixRow<T>> {
+        loop {
+            match self.position_status {
+                Status::Success => {
+                    let row = self.row_at_iterator_position();
+                    self.advance_to_next_row();
+                    return Some(row);
+                }
+                // The row at the iterator position is empty
+                Status::NoValue => self.advance_to_next_row(),
+                Status::IteratorExhausted => return None,
+                _ => {
+                    debug_assert!(
+                        false,
+                        "An unexpected status occured while iterating: {:?}",
+                        self.position_status
+                    );
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::FromMatrixElementList;
+    use crate::collections::sparse_matrix::MatrixElementList;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    fn test_matrix(context: &std::sync::Arc<Context>) -> SparseMatrix<u8> {
+        let element_list = MatrixElementList::<u8>::from_element_vector(vec![
+            (0, 1, 1).into(),
+            (0, 3, 2).into(),
+            (2, 2, 3).into(),
+            (5, 0, 4).into(),
+            (5, 4, 5).into(),
+        ]);
+
+        SparseMatrix::<u8>::from_element_list(
+            context.clone(),
+            (7, 5).into(),
+            element_list,
+            &First::<u8>::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_matrix_row_iterator() {
+        let context = Context::init_default().unwrap();
+        let matrix = test_matrix(&context);
+
+        let rows: Vec<MatrixRow<u8>> = MatrixRowIterator::new(&matrix).unwrap().collect();
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].row_index(), 0);
+        assert_eq!(rows[0].elements_ref().indices_ref(), &[1, 3]);
+        assert_eq!(rows[0].elements_ref().values_ref(), &[1, 2]);
+        assert_eq!(rows[1].row_index(), 2);
+        assert_eq!(rows[1].elements_ref().indices_ref(), &[2]);
+        assert_eq!(rows[2].row_index(), 5);
+        assert_eq!(rows[2].elements_ref().indices_ref(), &[0, 4]);
+        assert_eq!(rows[2].elements_ref().values_ref(), &[4, 5]);
+    }
+
+    #[test]
+    fn test_seek_to_row() {
+        let context = Context::init_default().unwrap();
+        let matrix = test_matrix(&context);
+
+        let mut iterator = MatrixRowIterator::new(&matrix).unwrap();
+        iterator.seek_to_row(1).unwrap();
+
+        let row_indices: Vec<RowIndex> = iterator.map(|row| row.row_index()).collect();
+        assert_eq!(row_indices, vec![2, 5]);
+    }
+
+    #[test]
+    fn test_seek_beyond_last_row() {
+        let context = Context::init_default().unwrap();
+        let matrix = test_matrix(&context);
+
+        let mut iterator = MatrixRowIterator::new(&matrix).unwrap();
+        iterator.seek_to_row(10).unwrap();
+
+        assert!(iterator.next().is_none());
+    }
+
+    #[test]
+    fn test_iterate_over_rows_of_empty_matrix() {
+        let context = Context::init_default().unwrap();
+        let matrix = SparseMatrix::<u8>::new(context, (7, 5).into()).unwrap();
+
+        assert_eq!(MatrixRowIterator::new(&matrix).unwrap().count(), 0);
+    }
+}
@@ -1,9 +1,17 @@
 mod graphblas_iterator;
+mod iterate_over_sparse_matrix;
+mod matrix_column_iterator;
+mod matrix_element_iterator;
+mod matrix_row_iterator;
 mod vector_element_index_iterator;
 mod vector_element_iterator;
 mod vector_element_value_iterator;
 
 pub(crate) use graphblas_iterator::*;
+pub use iterate_over_sparse_matrix::*;
+pub use matrix_column_iterator::*;
+pub use matrix_element_iterator::*;
+pub use matrix_row_iterator::*;
 pub use vector_element_index_iterator::*;
 pub use vector_element_iterator::*;
 pub use vector_element_value_iterator::*;
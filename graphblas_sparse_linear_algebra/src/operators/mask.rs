@@ -4,11 +4,24 @@ use suitesparse_graphblas_sys::{GrB_Matrix, GrB_Vector};
 
 use crate::context::{Context, GetContext};
 
-pub trait MatrixMask: GetContext {
+/// How an operator interprets a mask.
+///
+/// By default, a mask selects the elements where it stores a value that is true after typecasting to bool.
+pub trait GetMaskOptions {
+    fn use_mask_structure_of_stored_values_as_mask(&self) -> bool {
+        false
+    }
+
+    fn use_mask_complement(&self) -> bool {
+        false
+    }
+}
+
+pub trait MatrixMask: GetContext + GetMaskOptions {
     unsafe fn graphblas_matrix(&self) -> GrB_Matrix;
 }
 
-pub trait VectorMask: GetContext {
+pub trait VectorMask: GetContext + GetMaskOptions {
     unsafe fn graphblas_vector(&self) -> GrB_Vector;
 }
 
@@ -23,6 +36,8 @@ impl SelectEntireMatrix {
     }
 }
 
+impl GetMaskOptions for SelectEntireMatrix {}
+
 impl MatrixMask for SelectEntireMatrix {
     unsafe fn graphblas_matrix(&self) -> GrB_Matrix {
         ptr::null_mut()
@@ -50,6 +65,8 @@ impl SelectEntireVector {
     }
 }
 
+impl GetMaskOptions for SelectEntireVector {}
+
 impl VectorMask for SelectEntireVector {
     unsafe fn graphblas_vector(&self) -> GrB_Vector {
         ptr::null_mut()
@@ -65,3 +82,188 @@ impl GetContext for SelectEntireVector {
         &self.context
     }
 }
+
+/// Constructs mask adaptors, which operators translate into the matching descriptor.
+///
+/// For example, Mask::complement(&Mask::structure(&matrix)) selects the elements that matrix does not store.
+#[derive(Debug, Clone)]
+pub struct Mask {}
+
+impl Mask {
+    /// Selects the stored elements of the mask, regardless of their values
+    pub fn structure<M: GetContext + GetMaskOptions>(mask: &M) -> StructureMask<'_, M> {
+        StructureMask { mask }
+    }
+
+    /// Selects the stored elements of the mask that are true after typecasting to bool
+    pub fn values<M: GetContext + GetMaskOptions>(mask: &M) -> ValueMask<'_, M> {
+        ValueMask { mask }
+    }
+
+    /// Selects the elements that the mask does not select
+    pub fn complement<M: GetContext + GetMaskOptions>(mask: &M) -> ComplementMask<'_, M> {
+        ComplementMask { mask }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StructureMask<'a, M: GetContext + GetMaskOptions> {
+    mask: &'a M,
+}
+
+#[derive(Debug, Clone)]
+pub struct ValueMask<'a, M: GetContext + GetMaskOptions> {
+    mask: &'a M,
+}
+
+#[derive(Debug, Clone)]
+pub struct ComplementMask<'a, M: GetContext + GetMaskOptions> {
+    mask: &'a M,
+}
+
+impl<'a, M: GetContext + GetMaskOptions> GetMaskOptions for StructureMask<'a, M> {
+    fn use_mask_structure_of_stored_values_as_mask(&self) -> bool {
+        true
+    }
+
+    fn use_mask_complement(&self) -> bool {
+        self.mask.use_mask_complement()
+    }
+}
+
+impl<'a, M: GetContext + GetMaskOptions> GetMaskOptions for ValueMask<'a, M> {
+    fn use_mask_structure_of_stored_values_as_mask(&self) -> bool {
+        false
+    }
+
+    fn use_mask_complement(&self) -> bool {
+        self.mask.use_mask_complement()
+    }
+}
+
+impl<'a, M: GetContext + GetMaskOptions> GetMaskOptions for ComplementMask<'a, M> {
+    fn use_mask_structure_of_stored_values_as_mask(&self) -> bool {
+        self.mask.use_mask_structure_of_stored_values_as_mask()
+    }
+
+    fn use_mask_complement(&self) -> bool {
+        !self.mask.use_mask_complement()
+    }
+}
+
+macro_rules! implement_mask_adaptor {
+    ($adaptor:ident) => {
+        impl<'a, M: GetContext + GetMaskOptions> GetContext for $adaptor<'a, M> {
+            fn context(&self) -> Arc<Context> {
+                self.mask.context()
+            }
+
+            fn context_ref(&self) -> &Arc<Context> {
+                self.mask.context_ref()
+            }
+        }
+
+        impl<'a, M: MatrixMask> MatrixMask for $adaptor<'a, M> {
+            unsafe fn graphblas_matrix(&self) -> GrB_Matrix {
+                self.mask.graphblas_matrix()
+            }
+        }
+
+        impl<'a, M: VectorMask> VectorMask for $adaptor<'a, M> {
+            unsafe fn graphblas_vector(&self) -> GrB_Vector {
+                self.mask.graphblas_vector()
+            }
+        }
+    };
+}
+
+implement_mask_adaptor!(StructureMask);
+implement_mask_adaptor!(ValueMask);
+implement_mask_adaptor!(ComplementMask);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue, GetSparseMatrixSize,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::collections::Collection;
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::options::OptionsForOperatorWithMatrixArgument;
+    use crate::operators::transpose::{MatrixTranspose, TransposeMatrix};
+
+    #[test]
+    fn mask_adaptor_options() {
+        let context = Context::init_default().unwrap();
+        let matrix = SparseMatrix::<bool>::new(context, Size::new(1, 1)).unwrap();
+
+        assert!(!matrix.use_mask_structure_of_stored_values_as_mask());
+        assert!(Mask::structure(&matrix).use_mask_structure_of_stored_values_as_mask());
+        assert!(
+            !Mask::values(&Mask::structure(&matrix)).use_mask_structure_of_stored_values_as_mask()
+        );
+
+        let structure = Mask::structure(&matrix);
+        let complement = Mask::complement(&structure);
+        assert!(complement.use_mask_complement());
+        assert!(complement.use_mask_structure_of_stored_values_as_mask());
+        assert!(!Mask::complement(&complement).use_mask_complement());
+    }
+
+    fn copy_with_mask(matrix: &SparseMatrix<u8>, mask: &impl MatrixMask) -> SparseMatrix<u8> {
+        let mut product =
+            SparseMatrix::<u8>::new(matrix.context(), matrix.size().unwrap()).unwrap();
+
+        // Transposing a transposed argument copies it
+        MatrixTranspose::new()
+            .apply(
+                matrix,
+                &Assignment::<u8>::new(),
+                &mut product,
+                mask,
+                &OptionsForOperatorWithMatrixArgument::new(false, true),
+            )
+            .unwrap();
+        product
+    }
+
+    #[test]
+    fn copy_through_structure_and_value_masks() {
+        let context = Context::init_default().unwrap();
+        let size = Size::new(2, 2);
+
+        // The mask stores a false value at (0, 0)
+        let mask = SparseMatrix::<u8>::from_element_list(
+            context.clone(),
+            size,
+            MatrixElementList::from_element_vector(vec![(0, 0, 0).into(), (1, 1, 1).into()]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+        let matrix = SparseMatrix::<u8>::from_element_list(
+            context.clone(),
+            size,
+            MatrixElementList::from_element_vector(vec![
+                (0, 0, 5).into(),
+                (0, 1, 6).into(),
+                (1, 1, 7).into(),
+            ]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+
+        let product = copy_with_mask(&matrix, &Mask::values(&mask));
+        assert_eq!(product.number_of_stored_elements().unwrap(), 1);
+        assert_eq!(product.element_value(&1, &1).unwrap(), Some(7));
+
+        let product = copy_with_mask(&matrix, &Mask::structure(&mask));
+        assert_eq!(product.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(product.element_value(&0, &0).unwrap(), Some(5));
+
+        let product = copy_with_mask(&matrix, &Mask::complement(&Mask::structure(&mask)));
+        assert_eq!(product.number_of_stored_elements().unwrap(), 1);
+        assert_eq!(product.element_value(&0, &1).unwrap(), Some(6));
+    }
+}
@@ -2,6 +2,8 @@ pub mod apply;
 pub mod binary_operator;
 pub mod element_wise_addition;
 pub mod element_wise_multiplication;
+pub mod element_wise_union;
+pub mod expression;
 pub mod extract;
 pub mod index_unary_operator;
 pub mod insert;
@@ -16,4 +18,5 @@ pub mod select;
 pub mod semiring;
 pub mod subinsert;
 pub mod transpose;
+pub mod typed;
 pub mod unary_operator;
@@ -0,0 +1,297 @@
+use std::ffi::c_void;
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::sync::Arc;
+
+use crate::context::{Context, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::{
+    GrB_BinaryOp, GrB_Monoid, GrB_Monoid_free, GrB_Monoid_new_BOOL, GrB_Monoid_new_FP32,
+    GrB_Monoid_new_FP64, GrB_Monoid_new_INT16, GrB_Monoid_new_INT32, GrB_Monoid_new_INT64,
+    GrB_Monoid_new_INT8, GrB_Monoid_new_UDT, GrB_Monoid_new_UINT16, GrB_Monoid_new_UINT32,
+    GrB_Monoid_new_UINT64, GrB_Monoid_new_UINT8, GxB_Monoid_terminal_new_BOOL,
+    GxB_Monoid_terminal_new_FP32, GxB_Monoid_terminal_new_FP64, GxB_Monoid_terminal_new_INT16,
+    GxB_Monoid_terminal_new_INT32, GxB_Monoid_terminal_new_INT64, GxB_Monoid_terminal_new_INT8,
+    GxB_Monoid_terminal_new_UDT, GxB_Monoid_terminal_new_UINT16, GxB_Monoid_terminal_new_UINT32,
+    GxB_Monoid_terminal_new_UINT64, GxB_Monoid_terminal_new_UINT8,
+};
+use crate::operators::binary_operator::BinaryOperator;
+use crate::value_type::utilities_to_implement_traits_for_all_value_types::implement_1_type_macro_for_all_value_types_and_2_typed_graphblas_functions_with_implementation_type;
+use crate::value_type::{ConvertScalar, CustomValueType, ValueType};
+
+use super::Monoid;
+
+/// A monoid composed from any associative and commutative binary operator, with an identity value.
+///
+/// A terminal value lets GraphBLAS stop reducing early once it is reached, e.g. false for a logical and.
+/// The monoid owns its binary operator, because the GraphBLAS monoid refers to it.
+/// The GraphBLAS monoid is freed when the CustomMonoid is dropped.
+///
+/// Monoid is a trait, implemented by a unit struct for each built-in GraphBLAS monoid, e.g. Min,
+/// which refers to a static GraphBLAS monoid without a context.
+/// A composed monoid is created in a context at runtime and must be freed, so it is a separate type,
+/// like CustomBinaryOperator is for binary operators.
+pub struct CustomMonoid<T: ValueType> {
+    context: Arc<Context>,
+    graphblas_monoid: GrB_Monoid,
+    _binary_operator: Box<dyn BinaryOperator<T> + Send + Sync>,
+}
+
+// Implemented methods do not provide mutable access to GraphBLAS operators or options.
+// Code review must consider that no mtable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl<T: ValueType> Send for CustomMonoid<T> {}
+unsafe impl<T: ValueType> Sync for CustomMonoid<T> {}
+
+impl<T: ValueType + NewGraphblasMonoidTyped<T>> CustomMonoid<T> {
+    pub fn new(
+        context: Arc<Context>,
+        binary_operator: impl BinaryOperator<T> + Send + Sync + 'static,
+        identity: T,
+        terminal: Option<T>,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        // The monoid owns the binary operator, so the binary operator outlives the GraphBLAS monoid
+        let graphblas_monoid = unsafe {
+            match terminal {
+                Some(terminal) => T::new_graphblas_monoid_with_terminal(
+                    &context,
+                    binary_operator.graphblas_type(),
+                    identity,
+                    terminal,
+                )?,
+                None => {
+                    T::new_graphblas_monoid(&context, binary_operator.graphblas_type(), identity)?
+                }
+            }
+        };
+
+        Ok(Self {
+            context,
+            graphblas_monoid,
+            _binary_operator: Box::new(binary_operator),
+        })
+    }
+}
+
+impl<T: ValueType> Monoid<T> for CustomMonoid<T> {
+    fn graphblas_type(&self) -> GrB_Monoid {
+        self.graphblas_monoid
+    }
+}
+
+impl<T: ValueType> GetContext for CustomMonoid<T> {
+    fn context(&self) -> Arc<Context> {
+        self.context.clone()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        &self.context
+    }
+}
+
+impl<T: ValueType> fmt::Debug for CustomMonoid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomMonoid")
+            .field("graphblas_monoid", &self.graphblas_monoid)
+            .finish()
+    }
+}
+
+impl<T: ValueType> Drop for CustomMonoid<T> {
+    fn drop(&mut self) {
+        let _ = self
+            .context
+            .call_without_detailed_error_information(|| unsafe {
+                GrB_Monoid_free(&mut self.graphblas_monoid)
+            });
+    }
+}
+
+/// Creates a GraphBLAS monoid over the value type.
+///
+/// # Safety
+/// The binary operator must be a valid GraphBLAS binary operator with T as its domains,
+/// and must outlive the returned monoid, which refers to it.
+pub trait NewGraphblasMonoidTyped<T: ValueType> {
+    /// # Safety
+    /// See the safety requirements of NewGraphblasMonoidTyped.
+    unsafe fn new_graphblas_monoid(
+        context: &Arc<Context>,
+        binary_operator: GrB_BinaryOp,
+        identity: T,
+    ) -> Result<GrB_Monoid, SparseLinearAlgebraError>;
+
+    /// # Safety
+    /// See the safety requirements of NewGraphblasMonoidTyped.
+    unsafe fn new_graphblas_monoid_with_terminal(
+        context: &Arc<Context>,
+        binary_operator: GrB_BinaryOp,
+        identity: T,
+        terminal: T,
+    ) -> Result<GrB_Monoid, SparseLinearAlgebraError>;
+}
+
+macro_rules! implement_new_graphblas_monoid {
+    ($value_type:ty, $_implementation_type:ty, $new_monoid_function:ident, $new_monoid_with_terminal_function:ident) => {
+        impl NewGraphblasMonoidTyped<$value_type> for $value_type {
+            unsafe fn new_graphblas_monoid(
+                context: &Arc<Context>,
+                binary_operator: GrB_BinaryOp,
+                identity: $value_type,
+            ) -> Result<GrB_Monoid, SparseLinearAlgebraError> {
+                let identity = identity.to_type()?;
+                let mut graphblas_monoid: MaybeUninit<GrB_Monoid> = MaybeUninit::uninit();
+
+                context.call_without_detailed_error_information(|| unsafe {
+                    $new_monoid_function(graphblas_monoid.as_mut_ptr(), binary_operator, identity)
+                })?;
+
+                Ok(unsafe { graphblas_monoid.assume_init() })
+            }
+
+            unsafe fn new_graphblas_monoid_with_terminal(
+                context: &Arc<Context>,
+                binary_operator: GrB_BinaryOp,
+                identity: $value_type,
+                terminal: $value_type,
+            ) -> Result<GrB_Monoid, SparseLinearAlgebraError> {
+                let identity = identity.to_type()?;
+                let terminal = terminal.to_type()?;
+                let mut graphblas_monoid: MaybeUninit<GrB_Monoid> = MaybeUninit::uninit();
+
+                context.call_without_detailed_error_information(|| unsafe {
+                    $new_monoid_with_terminal_function(
+                        graphblas_monoid.as_mut_ptr(),
+                        binary_operator,
+                        identity,
+                        terminal,
+                    )
+                })?;
+
+                Ok(unsafe { graphblas_monoid.assume_init() })
+            }
+        }
+    };
+}
+
+implement_1_type_macro_for_all_value_types_and_2_typed_graphblas_functions_with_implementation_type!(
+    implement_new_graphblas_monoid,
+    GrB_Monoid_new,
+    GxB_Monoid_terminal_new
+);
+
+impl<T: CustomValueType> NewGraphblasMonoidTyped<T> for T {
+    unsafe fn new_graphblas_monoid(
+        context: &Arc<Context>,
+        binary_operator: GrB_BinaryOp,
+        mut identity: T,
+    ) -> Result<GrB_Monoid, SparseLinearAlgebraError> {
+        let mut graphblas_monoid: MaybeUninit<GrB_Monoid> = MaybeUninit::uninit();
+
+        // GraphBLAS copies the identity value
+        context.call_without_detailed_error_information(|| unsafe {
+            GrB_Monoid_new_UDT(
+                graphblas_monoid.as_mut_ptr(),
+                binary_operator,
+                &mut identity as *mut T as *mut c_void,
+            )
+        })?;
+
+        Ok(unsafe { graphblas_monoid.assume_init() })
+    }
+
+    unsafe fn new_graphblas_monoid_with_terminal(
+        context: &Arc<Context>,
+        binary_operator: GrB_BinaryOp,
+        mut identity: T,
+        mut terminal: T,
+    ) -> Result<GrB_Monoid, SparseLinearAlgebraError> {
+        let mut graphblas_monoid: MaybeUninit<GrB_Monoid> = MaybeUninit::uninit();
+
+        // GraphBLAS copies the identity and terminal values
+        context.call_without_detailed_error_information(|| unsafe {
+            GxB_Monoid_terminal_new_UDT(
+                graphblas_monoid.as_mut_ptr(),
+                binary_operator,
+                &mut identity as *mut T as *mut c_void,
+                &mut terminal as *mut T as *mut c_void,
+            )
+        })?;
+
+        Ok(unsafe { graphblas_monoid.assume_init() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::FromMatrixElementList;
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::collections::sparse_vector::operations::GetSparseVectorElementValue;
+    use crate::collections::sparse_vector::SparseVector;
+    use crate::operators::binary_operator::{
+        Assignment, BinaryOperatorFunction, CustomBinaryOperator, First, Min,
+    };
+    use crate::operators::mask::SelectEntireVector;
+    use crate::operators::options::OptionsForOperatorWithMatrixArgument;
+    use crate::operators::reduce::{MonoidReducer, MonoidVectorReducer};
+
+    struct GreatestCommonDivisor;
+
+    impl BinaryOperatorFunction<u32, u32> for GreatestCommonDivisor {
+        fn product(first_argument: &u32, second_argument: &u32) -> u32 {
+            let (mut a, mut b) = (*first_argument, *second_argument);
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        }
+    }
+
+    #[test]
+    fn reduce_with_monoid_of_custom_binary_operator() {
+        let context = Context::init_default().unwrap();
+
+        let matrix = SparseMatrix::<u32>::from_element_list(
+            context.clone(),
+            Size::new(2, 3),
+            MatrixElementList::from_element_vector(vec![
+                (0, 0, 12).into(),
+                (0, 1, 18).into(),
+                (0, 2, 30).into(),
+                (1, 1, 7).into(),
+            ]),
+            &First::<u32>::new(),
+        )
+        .unwrap();
+        let mut product = SparseVector::<u32>::new(context.clone(), 2).unwrap();
+
+        let greatest_common_divisor =
+            CustomBinaryOperator::<u32>::new(context.clone(), GreatestCommonDivisor).unwrap();
+        let monoid =
+            CustomMonoid::new(context.clone(), greatest_common_divisor, 0, Some(1)).unwrap();
+
+        MonoidReducer::new()
+            .to_column_vector(
+                &monoid,
+                &matrix,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireVector::new(context),
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.element_value(&0).unwrap(), Some(6));
+        assert_eq!(product.element_value(&1).unwrap(), Some(7));
+    }
+
+    #[test]
+    fn monoid_from_built_in_binary_operator() {
+        let context = Context::init_default().unwrap();
+
+        let monoid = CustomMonoid::new(context, Min::<u8>::new(), u8::MAX, Some(0));
+        assert!(monoid.is_ok());
+    }
+}
@@ -1,3 +1,5 @@
+mod custom_monoid;
 mod monoid;
 
+pub use custom_monoid::*;
 pub use monoid::*;
@@ -8,6 +8,8 @@ use crate::value_type::utilities_to_implement_traits_for_all_value_types::{
 };
 use crate::value_type::ValueType;
 
+/// Implemented by the built-in GraphBLAS monoids, e.g. Min::new().
+/// Use CustomMonoid::new() to compose a monoid from any other binary operator.
 pub trait Monoid<T>
 where
     T: ValueType,
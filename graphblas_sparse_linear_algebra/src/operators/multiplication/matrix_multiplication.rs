@@ -26,7 +26,6 @@ impl MatrixMultiplicationOperator {
 }
 
 pub trait MultiplyMatrices<EvaluationDomain: ValueType> {
-    // TODO: consider a version where the resulting product matrix is generated in the function body
     fn apply(
         &self,
         multiplier: &impl GetGraphblasSparseMatrix,
@@ -42,7 +41,6 @@ pub trait MultiplyMatrices<EvaluationDomain: ValueType> {
 impl<EvaluationDomain: ValueType> MultiplyMatrices<EvaluationDomain>
     for MatrixMultiplicationOperator
 {
-    // TODO: consider a version where the resulting product matrix is generated in the function body
     fn apply(
         &self,
         multiplier: &impl GetGraphblasSparseMatrix,
@@ -55,6 +53,7 @@ impl<EvaluationDomain: ValueType> MultiplyMatrices<EvaluationDomain>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_mxm(
@@ -64,7 +63,7 @@ impl<EvaluationDomain: ValueType> MultiplyMatrices<EvaluationDomain>
                     operator.graphblas_type(),
                     multiplier.graphblas_matrix(),
                     multiplicant.graphblas_matrix(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { product.graphblas_matrix_ref() },
@@ -87,7 +86,10 @@ mod tests {
     use crate::operators::binary_operator::Plus;
     use crate::operators::binary_operator::{Assignment, First};
     use crate::operators::mask::SelectEntireMatrix;
-    use crate::operators::options::OptionsForOperatorWithMatrixArguments;
+    use crate::operators::options::{
+        ExtendedDescriptorOptions, MatrixMultiplicationMethod,
+        OptionsForOperatorWithMatrixArguments, WithExtendedDescriptorOptions,
+    };
     use crate::operators::semiring::PlusTimes;
 
     #[test]
@@ -237,4 +239,64 @@ mod tests {
         assert_eq!(product.element_value(&0, &1).unwrap(), None);
         assert_eq!(product.element_value_or_default(&1, &1).unwrap(), 46.);
     }
+
+    #[test]
+    fn multiply_with_each_multiplication_method() {
+        let context = Context::init_default().unwrap();
+        let size = Size::new(2, 2);
+
+        let multiplier = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            size,
+            MatrixElementList::from_element_vector(vec![
+                (0, 0, 1).into(),
+                (0, 1, 2).into(),
+                (1, 1, 3).into(),
+            ]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let mask = SparseMatrix::<bool>::from_element_list(
+            context.clone(),
+            size,
+            MatrixElementList::from_element_vector(vec![(0, 1, true).into(), (1, 0, true).into()]),
+            &First::<bool>::new(),
+        )
+        .unwrap();
+
+        for method in [
+            MatrixMultiplicationMethod::Automatic,
+            MatrixMultiplicationMethod::Gustavson,
+            MatrixMultiplicationMethod::Dot,
+            MatrixMultiplicationMethod::Hash,
+            MatrixMultiplicationMethod::Saxpy,
+        ] {
+            let options = OptionsForOperatorWithMatrixArguments::new(false, false, true)
+                .with_extended_descriptor_options(
+                    &context,
+                    &ExtendedDescriptorOptions::new_default()
+                        .with_matrix_multiplication_method(method)
+                        .with_sorted_output(true),
+                )
+                .unwrap();
+            let mut product = SparseMatrix::<i32>::new(context.clone(), size).unwrap();
+
+            // Computes multiplier * multiplier' where the mask is true
+            MatrixMultiplicationOperator::new()
+                .apply(
+                    &multiplier,
+                    &PlusTimes::<i32>::new(),
+                    &multiplier,
+                    &Assignment::<i32>::new(),
+                    &mut product,
+                    &mask,
+                    &options,
+                )
+                .unwrap();
+
+            assert_eq!(product.number_of_stored_elements().unwrap(), 2);
+            assert_eq!(product.element_value(&0, &1).unwrap(), Some(6));
+            assert_eq!(product.element_value(&1, &0).unwrap(), Some(6));
+        }
+    }
 }
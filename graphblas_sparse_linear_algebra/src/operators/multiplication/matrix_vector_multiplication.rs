@@ -27,7 +27,6 @@ impl MatrixVectorMultiplicationOperator {
 }
 
 pub trait MultiplyMatrixByVector<EvaluationDomain: ValueType> {
-    // TODO: consider a version where the resulting product matrix is generated in the function body
     fn apply(
         &self,
         multiplier: &impl GetGraphblasSparseMatrix,
@@ -43,7 +42,6 @@ pub trait MultiplyMatrixByVector<EvaluationDomain: ValueType> {
 impl<EvaluationDomain: ValueType> MultiplyMatrixByVector<EvaluationDomain>
     for MatrixVectorMultiplicationOperator
 {
-    // TODO: consider a version where the resulting product matrix is generated in the function body
     fn apply(
         &self,
         multiplier: &impl GetGraphblasSparseMatrix,
@@ -56,6 +54,7 @@ impl<EvaluationDomain: ValueType> MultiplyMatrixByVector<EvaluationDomain>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_mxv(
@@ -65,7 +64,7 @@ impl<EvaluationDomain: ValueType> MultiplyMatrixByVector<EvaluationDomain>
                     operator.graphblas_type(),
                     multiplier.graphblas_matrix(),
                     multiplicant.graphblas_vector(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { product.graphblas_vector_ref() },
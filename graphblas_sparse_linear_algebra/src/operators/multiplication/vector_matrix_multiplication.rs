@@ -27,7 +27,6 @@ impl VectorMatrixMultiplicationOperator {
 }
 
 pub trait MultiplyVectorByMatrix<EvaluationDomain: ValueType> {
-    // TODO: consider a version where the resulting product matrix is generated in the function body
     fn apply(
         &self,
         multiplier: &impl GetGraphblasSparseVector,
@@ -43,7 +42,6 @@ pub trait MultiplyVectorByMatrix<EvaluationDomain: ValueType> {
 impl<EvaluationDomain: ValueType> MultiplyVectorByMatrix<EvaluationDomain>
     for VectorMatrixMultiplicationOperator
 {
-    // TODO: consider a version where the resulting product matrix is generated in the function body
     fn apply(
         &self,
         multiplier: &impl GetGraphblasSparseVector,
@@ -56,6 +54,7 @@ impl<EvaluationDomain: ValueType> MultiplyVectorByMatrix<EvaluationDomain>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_vxm(
@@ -65,7 +64,7 @@ impl<EvaluationDomain: ValueType> MultiplyVectorByMatrix<EvaluationDomain>
                     operator.graphblas_type(),
                     multiplier.graphblas_vector(),
                     multiplicant.graphblas_matrix(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { product.graphblas_vector_ref() },
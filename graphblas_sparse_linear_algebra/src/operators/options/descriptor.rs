@@ -9,8 +9,30 @@ use suitesparse_graphblas_sys::{
     GrB_Descriptor,
 };
 
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::mask::GetMaskOptions;
+
+use super::GraphblasDescriptorForCall;
+
 pub trait GetGraphblasDescriptor {
+    /// The descriptor for operators without a mask
+    ///
+    /// Does not engage the number of threads and chunk size of the extended descriptor options,
+    /// use graphblas_descriptor_for_call() for a GraphBLAS call.
     fn graphblas_descriptor(&self) -> GrB_Descriptor;
+    /// The descriptor with the structure and complement options of the mask.
+    ///
+    /// Fails if GraphBLAS cannot create a descriptor with extended descriptor options that is used for the first time.
+    fn graphblas_descriptor_for_mask(
+        &self,
+        mask: &impl GetMaskOptions,
+    ) -> Result<GraphblasDescriptorForCall, SparseLinearAlgebraError>;
+    /// The descriptor for operators without a mask, with the number of threads and chunk size engaged for the call.
+    ///
+    /// Fails if GraphBLAS cannot engage the number of threads and chunk size of the extended descriptor options.
+    fn graphblas_descriptor_for_call(
+        &self,
+    ) -> Result<GraphblasDescriptorForCall, SparseLinearAlgebraError>;
 }
 
 pub(crate) fn graphblas_descriptor(
@@ -0,0 +1,691 @@
+use std::collections::HashMap;
+use std::ffi::c_int;
+use std::mem::MaybeUninit;
+use std::sync::{Arc, Mutex, PoisonError, Weak};
+
+use once_cell::sync::{Lazy, OnceCell};
+use suitesparse_graphblas_sys::{
+    GrB_Desc_Field, GrB_Desc_Field_GrB_INP0, GrB_Desc_Field_GrB_INP1, GrB_Desc_Field_GrB_MASK,
+    GrB_Desc_Field_GrB_OUTP, GrB_Desc_Field_GxB_AxB_METHOD, GrB_Desc_Field_GxB_IMPORT,
+    GrB_Desc_Field_GxB_SORT, GrB_Desc_Value, GrB_Desc_Value_GrB_COMP,
+    GrB_Desc_Value_GrB_COMP_STRUCTURE, GrB_Desc_Value_GrB_REPLACE, GrB_Desc_Value_GrB_STRUCTURE,
+    GrB_Desc_Value_GrB_TRAN, GrB_Desc_Value_GxB_AxB_DOT, GrB_Desc_Value_GxB_AxB_GUSTAVSON,
+    GrB_Desc_Value_GxB_AxB_HASH, GrB_Desc_Value_GxB_AxB_SAXPY, GrB_Desc_Value_GxB_SECURE_IMPORT,
+    GrB_Descriptor, GrB_Descriptor_free, GrB_Descriptor_new, GrB_Descriptor_set, GxB_Context,
+    GxB_Context_Field_GxB_CONTEXT_CHUNK, GxB_Context_Field_GxB_CONTEXT_NTHREADS,
+    GxB_Context_disengage, GxB_Context_engage, GxB_Context_free, GxB_Context_new,
+    GxB_Context_set_FP64, GxB_Context_set_INT32,
+};
+
+use crate::context::{CallGraphBlasContext, Context};
+use crate::error::{GraphblasError, GraphblasErrorType, SparseLinearAlgebraError};
+
+use super::graphblas_descriptor;
+
+/// The algorithm SuiteSparse:GraphBLAS uses to multiply matrices and vectors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatrixMultiplicationMethod {
+    /// Selects a method with heuristics
+    Automatic,
+    /// Saxpy with a gather/scatter workspace in every task
+    Gustavson,
+    /// Dot products, suited for a very sparse mask that is not complemented, or a small or dense product
+    Dot,
+    /// Saxpy with a hash table in every task, suited for hypersparse matrices and many threads
+    Hash,
+    /// Saxpy, where every task chooses between Gustavson and Hash
+    Saxpy,
+}
+
+/// SuiteSparse:GraphBLAS descriptor settings in addition to the mask, transpose and clear-output options.
+///
+/// SuiteSparse:GraphBLAS 8 and later read the number of threads and the chunk size from a GxB_Context instead of a descriptor.
+/// Operators engage a GxB_Context with these settings on the calling thread for the duration of each GraphBLAS call.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExtendedDescriptorOptions {
+    matrix_multiplication_method: MatrixMultiplicationMethod,
+    sort_output: bool,
+    number_of_threads: Option<usize>,
+    chunk_size: Option<f64>,
+    trust_input: bool,
+}
+
+impl ExtendedDescriptorOptions {
+    pub fn new_default() -> Self {
+        Self {
+            matrix_multiplication_method: MatrixMultiplicationMethod::Automatic,
+            sort_output: false,
+            number_of_threads: None,
+            chunk_size: None,
+            trust_input: false,
+        }
+    }
+
+    pub fn with_matrix_multiplication_method(
+        mut self,
+        matrix_multiplication_method: MatrixMultiplicationMethod,
+    ) -> Self {
+        self.matrix_multiplication_method = matrix_multiplication_method;
+        self
+    }
+
+    /// Sort the product while computing it, instead of leaving the sort pending. This is a hint.
+    pub fn with_sorted_output(mut self, sort_output: bool) -> Self {
+        self.sort_output = sort_output;
+        self
+    }
+
+    /// The maximum number of threads, GraphBLAS selects the number of threads if not set
+    pub fn with_number_of_threads(mut self, number_of_threads: usize) -> Self {
+        self.number_of_threads = Some(number_of_threads);
+        self
+    }
+
+    /// The amount of work per thread, which determines how many threads GraphBLAS uses for small problems
+    pub fn with_chunk_size(mut self, chunk_size: f64) -> Self {
+        self.chunk_size = Some(chunk_size);
+        self
+    }
+
+    /// Pack methods given these options check that their input is valid, i.e. in bounds, sorted and without duplicates,
+    /// unless trust_input is true. Pack methods given options without extended descriptor options trust their input.
+    pub fn with_trusted_input(mut self, trust_input: bool) -> Self {
+        self.trust_input = trust_input;
+        self
+    }
+
+    pub fn matrix_multiplication_method(&self) -> MatrixMultiplicationMethod {
+        self.matrix_multiplication_method
+    }
+
+    pub fn sort_output(&self) -> bool {
+        self.sort_output
+    }
+
+    pub fn number_of_threads(&self) -> Option<usize> {
+        self.number_of_threads
+    }
+
+    pub fn chunk_size(&self) -> Option<f64> {
+        self.chunk_size
+    }
+
+    pub fn trust_input(&self) -> bool {
+        self.trust_input
+    }
+}
+
+// Descriptors belong to the context they were created in.
+// The address of a context cannot be reused while cached descriptors keep the context alive.
+// The chunk size is compared by its bits, such that the key is hashable.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    context: usize,
+    clear_output_before_use: bool,
+    matrix_multiplication_method: MatrixMultiplicationMethod,
+    sort_output: bool,
+    number_of_threads: Option<usize>,
+    chunk_size: Option<u64>,
+    trust_input: bool,
+}
+
+impl CacheKey {
+    fn new(
+        context: &Arc<Context>,
+        clear_output_before_use: bool,
+        options: &ExtendedDescriptorOptions,
+    ) -> Self {
+        Self {
+            context: Arc::as_ptr(context) as usize,
+            clear_output_before_use,
+            matrix_multiplication_method: options.matrix_multiplication_method,
+            sort_output: options.sort_output,
+            number_of_threads: options.number_of_threads,
+            chunk_size: options.chunk_size.map(f64::to_bits),
+            trust_input: options.trust_input,
+        }
+    }
+}
+
+// Holds weak references, such that the descriptors are freed when the last options using them are dropped
+static CACHED_DESCRIPTORS: Lazy<Mutex<HashMap<CacheKey, Weak<ExtendedGraphblasDescriptors>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug)]
+struct OwnedGraphblasDescriptor {
+    context: Arc<Context>,
+    graphblas_descriptor: GrB_Descriptor,
+}
+
+impl OwnedGraphblasDescriptor {
+    fn new(context: &Arc<Context>) -> Result<Self, SparseLinearAlgebraError> {
+        let mut graphblas_descriptor: MaybeUninit<GrB_Descriptor> = MaybeUninit::uninit();
+
+        context.call_without_detailed_error_information(|| unsafe {
+            GrB_Descriptor_new(graphblas_descriptor.as_mut_ptr())
+        })?;
+
+        Ok(Self {
+            context: context.clone(),
+            graphblas_descriptor: unsafe { graphblas_descriptor.assume_init() },
+        })
+    }
+
+    fn set(
+        &self,
+        field: GrB_Desc_Field,
+        value: GrB_Desc_Value,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        self.context.call(
+            || unsafe { GrB_Descriptor_set(self.graphblas_descriptor, field, value) },
+            &self.graphblas_descriptor,
+        )?;
+        Ok(())
+    }
+}
+
+impl Drop for OwnedGraphblasDescriptor {
+    fn drop(&mut self) {
+        let _ = self
+            .context
+            .call_without_detailed_error_information(|| unsafe {
+                GrB_Descriptor_free(&mut self.graphblas_descriptor)
+            });
+    }
+}
+
+// Holds the number of threads and the chunk size
+#[derive(Debug)]
+struct OwnedGraphblasContext {
+    context: Arc<Context>,
+    graphblas_context: GxB_Context,
+}
+
+impl OwnedGraphblasContext {
+    fn new(
+        context: &Arc<Context>,
+        options: &ExtendedDescriptorOptions,
+    ) -> Result<Option<Self>, SparseLinearAlgebraError> {
+        if options.number_of_threads.is_none() && options.chunk_size.is_none() {
+            return Ok(None);
+        }
+
+        let mut graphblas_context: MaybeUninit<GxB_Context> = MaybeUninit::uninit();
+        context.call_without_detailed_error_information(|| unsafe {
+            GxB_Context_new(graphblas_context.as_mut_ptr())
+        })?;
+        let owned_graphblas_context = Self {
+            context: context.clone(),
+            graphblas_context: unsafe { graphblas_context.assume_init() },
+        };
+
+        if let Some(number_of_threads) = options.number_of_threads {
+            let number_of_threads = c_int::try_from(number_of_threads).map_err(|_| {
+                GraphblasError::new(
+                    GraphblasErrorType::InvalidValue,
+                    format!("The number of threads is too large: {}", number_of_threads),
+                )
+            })?;
+            context.call_without_detailed_error_information(|| unsafe {
+                GxB_Context_set_INT32(
+                    owned_graphblas_context.graphblas_context,
+                    GxB_Context_Field_GxB_CONTEXT_NTHREADS,
+                    number_of_threads,
+                )
+            })?;
+        }
+        if let Some(chunk_size) = options.chunk_size {
+            context.call_without_detailed_error_information(|| unsafe {
+                GxB_Context_set_FP64(
+                    owned_graphblas_context.graphblas_context,
+                    GxB_Context_Field_GxB_CONTEXT_CHUNK,
+                    chunk_size,
+                )
+            })?;
+        }
+
+        Ok(Some(owned_graphblas_context))
+    }
+}
+
+impl Drop for OwnedGraphblasContext {
+    fn drop(&mut self) {
+        let _ = self
+            .context
+            .call_without_detailed_error_information(|| unsafe {
+                GxB_Context_free(&mut self.graphblas_context)
+            });
+    }
+}
+
+/// The descriptor for a single GraphBLAS call.
+///
+/// Engages the number of threads and the chunk size of the extended descriptor options on the calling thread,
+/// until it is dropped. It is not Send, because GraphBLAS engages a GxB_Context per thread.
+#[derive(Debug)]
+pub struct GraphblasDescriptorForCall {
+    graphblas_descriptor: GrB_Descriptor,
+    // Keeps the engaged GxB_Context alive
+    engaged_descriptors: Option<Arc<ExtendedGraphblasDescriptors>>,
+}
+
+impl GraphblasDescriptorForCall {
+    pub(crate) fn new(graphblas_descriptor: GrB_Descriptor) -> Self {
+        Self {
+            graphblas_descriptor,
+            engaged_descriptors: None,
+        }
+    }
+
+    fn engage(
+        graphblas_descriptor: GrB_Descriptor,
+        descriptors: &Arc<ExtendedGraphblasDescriptors>,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let graphblas_context = match &descriptors.graphblas_context {
+            Some(graphblas_context) => graphblas_context,
+            None => return Ok(Self::new(graphblas_descriptor)),
+        };
+        graphblas_context
+            .context
+            .call_without_detailed_error_information(|| unsafe {
+                GxB_Context_engage(graphblas_context.graphblas_context)
+            })?;
+        Ok(Self {
+            graphblas_descriptor,
+            engaged_descriptors: Some(descriptors.clone()),
+        })
+    }
+
+    pub fn graphblas_descriptor(&self) -> GrB_Descriptor {
+        self.graphblas_descriptor
+    }
+}
+
+impl Drop for GraphblasDescriptorForCall {
+    fn drop(&mut self) {
+        if let Some(graphblas_context) = self
+            .engaged_descriptors
+            .as_ref()
+            .and_then(|descriptors| descriptors.graphblas_context.as_ref())
+        {
+            let _ = graphblas_context
+                .context
+                .call_without_detailed_error_information(|| unsafe {
+                    GxB_Context_disengage(graphblas_context.graphblas_context)
+                });
+        }
+    }
+}
+
+/// Descriptors for every combination of mask and transpose options, with the same extended options.
+///
+/// The descriptors without a mask are created up front, such that invalid options fail when the options are constructed.
+/// A descriptor with a mask is created when it is first used.
+#[derive(Debug)]
+pub(crate) struct ExtendedGraphblasDescriptors {
+    context: Arc<Context>,
+    clear_output_before_use: bool,
+    options: ExtendedDescriptorOptions,
+    graphblas_context: Option<OwnedGraphblasContext>,
+    // Indexed by transpose_index()
+    unmasked_descriptors: [OwnedGraphblasDescriptor; 4],
+    // Indexed by mask_index() * 4 + transpose_index()
+    masked_descriptors: [OnceCell<OwnedGraphblasDescriptor>; 12],
+}
+
+// Implemented methods do not provide mutable access to GraphBLAS operators or options.
+// Code review must consider that no mtable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl Send for ExtendedGraphblasDescriptors {}
+unsafe impl Sync for ExtendedGraphblasDescriptors {}
+
+impl ExtendedGraphblasDescriptors {
+    /// Reuses the descriptors of live options with the same settings
+    pub(crate) fn cached(
+        context: &Arc<Context>,
+        clear_output_before_use: bool,
+        options: &ExtendedDescriptorOptions,
+    ) -> Result<Arc<Self>, SparseLinearAlgebraError> {
+        let key = CacheKey::new(context, clear_output_before_use, options);
+        let mut cached_descriptors = CACHED_DESCRIPTORS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(descriptors) = cached_descriptors.get(&key).and_then(Weak::upgrade) {
+            return Ok(descriptors);
+        }
+
+        cached_descriptors.retain(|_, descriptors| descriptors.strong_count() > 0);
+        let descriptors = Arc::new(Self::new(context, clear_output_before_use, options)?);
+        cached_descriptors.insert(key, Arc::downgrade(&descriptors));
+        Ok(descriptors)
+    }
+
+    fn new(
+        context: &Arc<Context>,
+        clear_output_before_use: bool,
+        options: &ExtendedDescriptorOptions,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let new_unmasked_descriptor = |transpose_input0, transpose_input1| {
+            new_graphblas_descriptor(
+                context,
+                clear_output_before_use,
+                false,
+                false,
+                transpose_input0,
+                transpose_input1,
+                options,
+            )
+        };
+        Ok(Self {
+            context: context.clone(),
+            clear_output_before_use,
+            options: *options,
+            graphblas_context: OwnedGraphblasContext::new(context, options)?,
+            unmasked_descriptors: [
+                new_unmasked_descriptor(false, false)?,
+                new_unmasked_descriptor(false, true)?,
+                new_unmasked_descriptor(true, false)?,
+                new_unmasked_descriptor(true, true)?,
+            ],
+            masked_descriptors: Default::default(),
+        })
+    }
+
+    pub(crate) fn options(&self) -> &ExtendedDescriptorOptions {
+        &self.options
+    }
+
+    pub(crate) fn unmasked_graphblas_descriptor(
+        &self,
+        transpose_input0: bool,
+        transpose_input1: bool,
+    ) -> GrB_Descriptor {
+        self.unmasked_descriptors[transpose_index(transpose_input0, transpose_input1)]
+            .graphblas_descriptor
+    }
+
+    /// Fails if GraphBLAS cannot create a descriptor with a mask that is used for the first time
+    pub(crate) fn graphblas_descriptor(
+        &self,
+        use_mask_structure_of_stored_values_as_mask: bool,
+        use_mask_complement: bool,
+        transpose_input0: bool,
+        transpose_input1: bool,
+    ) -> Result<GrB_Descriptor, SparseLinearAlgebraError> {
+        let mask_index = match mask_index(
+            use_mask_structure_of_stored_values_as_mask,
+            use_mask_complement,
+        ) {
+            Some(mask_index) => mask_index,
+            None => {
+                return Ok(self.unmasked_graphblas_descriptor(transpose_input0, transpose_input1))
+            }
+        };
+        let descriptor = self.masked_descriptors
+            [mask_index * 4 + transpose_index(transpose_input0, transpose_input1)]
+        .get_or_try_init(|| {
+            new_graphblas_descriptor(
+                &self.context,
+                self.clear_output_before_use,
+                use_mask_structure_of_stored_values_as_mask,
+                use_mask_complement,
+                transpose_input0,
+                transpose_input1,
+                &self.options,
+            )
+        })?;
+        Ok(descriptor.graphblas_descriptor)
+    }
+}
+
+/// Falls back to the predefined descriptors without extended options
+pub(crate) fn unmasked_graphblas_descriptor_with_extended_options(
+    extended_graphblas_descriptors: &Option<Arc<ExtendedGraphblasDescriptors>>,
+    clear_output_before_use: bool,
+    transpose_input0: bool,
+    transpose_input1: bool,
+) -> GrB_Descriptor {
+    match extended_graphblas_descriptors {
+        Some(descriptors) => {
+            descriptors.unmasked_graphblas_descriptor(transpose_input0, transpose_input1)
+        }
+        None => graphblas_descriptor(
+            clear_output_before_use,
+            false,
+            false,
+            transpose_input0,
+            transpose_input1,
+        ),
+    }
+}
+
+/// Falls back to the predefined descriptors without extended options
+pub(crate) fn graphblas_descriptor_for_call_with_extended_options(
+    extended_graphblas_descriptors: &Option<Arc<ExtendedGraphblasDescriptors>>,
+    clear_output_before_use: bool,
+    use_mask_structure_of_stored_values_as_mask: bool,
+    use_mask_complement: bool,
+    transpose_input0: bool,
+    transpose_input1: bool,
+) -> Result<GraphblasDescriptorForCall, SparseLinearAlgebraError> {
+    match extended_graphblas_descriptors {
+        Some(descriptors) => GraphblasDescriptorForCall::engage(
+            descriptors.graphblas_descriptor(
+                use_mask_structure_of_stored_values_as_mask,
+                use_mask_complement,
+                transpose_input0,
+                transpose_input1,
+            )?,
+            descriptors,
+        ),
+        None => Ok(GraphblasDescriptorForCall::new(graphblas_descriptor(
+            clear_output_before_use,
+            use_mask_structure_of_stored_values_as_mask,
+            use_mask_complement,
+            transpose_input0,
+            transpose_input1,
+        ))),
+    }
+}
+
+fn transpose_index(transpose_input0: bool, transpose_input1: bool) -> usize {
+    (transpose_input0 as usize) << 1 | transpose_input1 as usize
+}
+
+// None without a mask option
+fn mask_index(
+    use_mask_structure_of_stored_values_as_mask: bool,
+    use_mask_complement: bool,
+) -> Option<usize> {
+    match (
+        use_mask_structure_of_stored_values_as_mask,
+        use_mask_complement,
+    ) {
+        (false, false) => None,
+        (false, true) => Some(0),
+        (true, false) => Some(1),
+        (true, true) => Some(2),
+    }
+}
+
+fn new_graphblas_descriptor(
+    context: &Arc<Context>,
+    clear_output_before_use: bool,
+    use_mask_structure_of_stored_values_as_mask: bool,
+    use_mask_complement: bool,
+    transpose_input0: bool,
+    transpose_input1: bool,
+    options: &ExtendedDescriptorOptions,
+) -> Result<OwnedGraphblasDescriptor, SparseLinearAlgebraError> {
+    let descriptor = OwnedGraphblasDescriptor::new(context)?;
+
+    if clear_output_before_use {
+        descriptor.set(GrB_Desc_Field_GrB_OUTP, GrB_Desc_Value_GrB_REPLACE)?;
+    }
+    match (
+        use_mask_structure_of_stored_values_as_mask,
+        use_mask_complement,
+    ) {
+        (false, false) => {}
+        (false, true) => descriptor.set(GrB_Desc_Field_GrB_MASK, GrB_Desc_Value_GrB_COMP)?,
+        (true, false) => descriptor.set(GrB_Desc_Field_GrB_MASK, GrB_Desc_Value_GrB_STRUCTURE)?,
+        (true, true) => {
+            descriptor.set(GrB_Desc_Field_GrB_MASK, GrB_Desc_Value_GrB_COMP_STRUCTURE)?
+        }
+    }
+    if transpose_input0 {
+        descriptor.set(GrB_Desc_Field_GrB_INP0, GrB_Desc_Value_GrB_TRAN)?;
+    }
+    if transpose_input1 {
+        descriptor.set(GrB_Desc_Field_GrB_INP1, GrB_Desc_Value_GrB_TRAN)?;
+    }
+
+    match options.matrix_multiplication_method {
+        MatrixMultiplicationMethod::Automatic => {}
+        MatrixMultiplicationMethod::Gustavson => descriptor.set(
+            GrB_Desc_Field_GxB_AxB_METHOD,
+            GrB_Desc_Value_GxB_AxB_GUSTAVSON,
+        )?,
+        MatrixMultiplicationMethod::Dot => {
+            descriptor.set(GrB_Desc_Field_GxB_AxB_METHOD, GrB_Desc_Value_GxB_AxB_DOT)?
+        }
+        MatrixMultiplicationMethod::Hash => {
+            descriptor.set(GrB_Desc_Field_GxB_AxB_METHOD, GrB_Desc_Value_GxB_AxB_HASH)?
+        }
+        MatrixMultiplicationMethod::Saxpy => {
+            descriptor.set(GrB_Desc_Field_GxB_AxB_METHOD, GrB_Desc_Value_GxB_AxB_SAXPY)?
+        }
+    }
+    if options.sort_output {
+        descriptor.set(GrB_Desc_Field_GxB_SORT, 1)?;
+    }
+    if !options.trust_input {
+        descriptor.set(GrB_Desc_Field_GxB_IMPORT, GrB_Desc_Value_GxB_SECURE_IMPORT)?;
+    }
+
+    Ok(descriptor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_identical_descriptors() {
+        let context = Context::init_default().unwrap();
+        let options = ExtendedDescriptorOptions::new_default()
+            .with_matrix_multiplication_method(MatrixMultiplicationMethod::Hash)
+            .with_sorted_output(true);
+
+        let descriptors = ExtendedGraphblasDescriptors::cached(&context, false, &options).unwrap();
+        let same_descriptors =
+            ExtendedGraphblasDescriptors::cached(&context, false, &options).unwrap();
+        let cleared_descriptors =
+            ExtendedGraphblasDescriptors::cached(&context, true, &options).unwrap();
+
+        assert!(Arc::ptr_eq(&descriptors, &same_descriptors));
+        assert!(!Arc::ptr_eq(&descriptors, &cleared_descriptors));
+        assert_eq!(descriptors.options(), &options);
+        assert_ne!(
+            descriptors.unmasked_graphblas_descriptor(false, false),
+            descriptors
+                .graphblas_descriptor(true, false, false, true)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn cache_descriptors_per_context() {
+        let context = Context::init_default().unwrap();
+        let other_context = Context::init_default().unwrap();
+        let options = ExtendedDescriptorOptions::new_default().with_sorted_output(true);
+
+        let descriptors = ExtendedGraphblasDescriptors::cached(&context, false, &options).unwrap();
+        let other_descriptors =
+            ExtendedGraphblasDescriptors::cached(&other_context, false, &options).unwrap();
+
+        assert!(!Arc::ptr_eq(&descriptors, &other_descriptors));
+        assert!(Arc::ptr_eq(&other_descriptors.context, &other_context));
+    }
+
+    #[test]
+    fn create_descriptors_when_first_used() {
+        let context = Context::init_default().unwrap();
+        let options = ExtendedDescriptorOptions::new_default()
+            .with_matrix_multiplication_method(MatrixMultiplicationMethod::Dot);
+
+        let descriptors = ExtendedGraphblasDescriptors::cached(&context, true, &options).unwrap();
+        assert!(descriptors
+            .masked_descriptors
+            .iter()
+            .all(|descriptor| descriptor.get().is_none()));
+
+        descriptors
+            .graphblas_descriptor(false, true, true, false)
+            .unwrap();
+        assert!(descriptors.masked_descriptors
+            [mask_index(false, true).unwrap() * 4 + transpose_index(true, false)]
+        .get()
+        .is_some());
+    }
+
+    #[test]
+    fn engage_number_of_threads_and_chunk_size() {
+        let context = Context::init_default().unwrap();
+        let options = ExtendedDescriptorOptions::new_default()
+            .with_number_of_threads(2)
+            .with_chunk_size(4096.0);
+
+        let descriptors = ExtendedGraphblasDescriptors::cached(&context, false, &options).unwrap();
+        assert!(descriptors.graphblas_context.is_some());
+        assert_eq!(descriptors.options().number_of_threads(), Some(2));
+
+        let descriptor_for_call = graphblas_descriptor_for_call_with_extended_options(
+            &Some(descriptors.clone()),
+            false,
+            true,
+            false,
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            descriptor_for_call.graphblas_descriptor(),
+            descriptors
+                .graphblas_descriptor(true, false, false, false)
+                .unwrap()
+        );
+        drop(descriptor_for_call);
+
+        let other_descriptors =
+            ExtendedGraphblasDescriptors::cached(&context, false, &options.with_chunk_size(1024.0))
+                .unwrap();
+        assert!(!Arc::ptr_eq(&descriptors, &other_descriptors));
+    }
+
+    #[test]
+    fn reject_too_many_threads() {
+        let context = Context::init_default().unwrap();
+        let options = ExtendedDescriptorOptions::new_default().with_number_of_threads(usize::MAX);
+
+        assert!(ExtendedGraphblasDescriptors::cached(&context, false, &options).is_err());
+    }
+
+    #[test]
+    fn check_input_by_default() {
+        assert!(!ExtendedDescriptorOptions::new_default().trust_input());
+    }
+
+    #[test]
+    fn free_descriptors_that_are_no_longer_used() {
+        let context = Context::init_default().unwrap();
+        let options = ExtendedDescriptorOptions::new_default();
+
+        let descriptors = ExtendedGraphblasDescriptors::cached(&context, true, &options).unwrap();
+        let weak_descriptors = Arc::downgrade(&descriptors);
+        drop(descriptors);
+
+        assert!(weak_descriptors.upgrade().is_none());
+    }
+}
@@ -1,4 +1,5 @@
 mod descriptor;
+mod extended_descriptor;
 mod operator_options;
 mod options;
 mod options_for_operator_with_matrix_argument;
@@ -7,6 +8,7 @@ mod options_for_operator_with_matrix_as_first_argument;
 mod options_for_operator_with_matrix_as_second_argument;
 
 pub use descriptor::*;
+pub use extended_descriptor::*;
 pub use operator_options::*;
 pub use options::*;
 pub use options_for_operator_with_matrix_argument::*;
@@ -1,7 +1,16 @@
+use std::sync::Arc;
+
 use suitesparse_graphblas_sys::GrB_Descriptor;
 
+use crate::context::Context;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::mask::GetMaskOptions;
+
 use super::{
-    graphblas_descriptor, GetClearOutputBeforeUse, GetGraphblasDescriptor, GetOperatorMaskOptions,
+    graphblas_descriptor_for_call_with_extended_options,
+    unmasked_graphblas_descriptor_with_extended_options, ExtendedDescriptorOptions,
+    ExtendedGraphblasDescriptors, GetClearOutputBeforeUse, GetExtendedDescriptorOptions,
+    GetGraphblasDescriptor, GraphblasDescriptorForCall, WithExtendedDescriptorOptions,
 };
 
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
@@ -13,15 +22,11 @@ unsafe impl Sync for OperatorOptions {}
 #[derive(Debug, Clone)]
 pub struct OperatorOptions {
     clear_output_before_use: bool,
-    use_mask_structure_of_stored_values_as_mask: bool,
-    use_mask_complement: bool,
     graphblas_descriptor: GrB_Descriptor,
+    extended_graphblas_descriptors: Option<Arc<ExtendedGraphblasDescriptors>>,
 }
 
-pub trait GetOperatorOptions:
-    GetClearOutputBeforeUse + GetOperatorMaskOptions + GetGraphblasDescriptor
-{
-}
+pub trait GetOperatorOptions: GetClearOutputBeforeUse + GetGraphblasDescriptor {}
 
 impl GetOperatorOptions for OperatorOptions {}
 
@@ -31,57 +36,87 @@ impl GetClearOutputBeforeUse for OperatorOptions {
     }
 }
 
-impl GetOperatorMaskOptions for OperatorOptions {
-    fn use_mask_structure_of_stored_values_as_mask(&self) -> bool {
-        self.use_mask_structure_of_stored_values_as_mask
+impl GetGraphblasDescriptor for OperatorOptions {
+    fn graphblas_descriptor(&self) -> GrB_Descriptor {
+        self.graphblas_descriptor
+    }
+
+    fn graphblas_descriptor_for_mask(
+        &self,
+        mask: &impl GetMaskOptions,
+    ) -> Result<GraphblasDescriptorForCall, SparseLinearAlgebraError> {
+        graphblas_descriptor_for_call_with_extended_options(
+            &self.extended_graphblas_descriptors,
+            self.clear_output_before_use,
+            mask.use_mask_structure_of_stored_values_as_mask(),
+            mask.use_mask_complement(),
+            false,
+            false,
+        )
     }
 
-    fn use_mask_complement(&self) -> bool {
-        self.use_mask_complement
+    fn graphblas_descriptor_for_call(
+        &self,
+    ) -> Result<GraphblasDescriptorForCall, SparseLinearAlgebraError> {
+        graphblas_descriptor_for_call_with_extended_options(
+            &self.extended_graphblas_descriptors,
+            self.clear_output_before_use,
+            false,
+            false,
+            false,
+            false,
+        )
     }
 }
 
-impl GetGraphblasDescriptor for OperatorOptions {
-    fn graphblas_descriptor(&self) -> GrB_Descriptor {
-        self.graphblas_descriptor
+impl WithExtendedDescriptorOptions for OperatorOptions {
+    fn with_extended_descriptor_options(
+        &self,
+        context: &Arc<Context>,
+        extended_descriptor_options: &ExtendedDescriptorOptions,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        Ok(Self::new_with_extended_graphblas_descriptors(
+            Some(ExtendedGraphblasDescriptors::cached(
+                context,
+                self.clear_output_before_use,
+                extended_descriptor_options,
+            )?),
+            self.clear_output_before_use,
+        ))
+    }
+}
+
+impl GetExtendedDescriptorOptions for OperatorOptions {
+    fn extended_descriptor_options(&self) -> Option<&ExtendedDescriptorOptions> {
+        self.extended_graphblas_descriptors
+            .as_ref()
+            .map(|descriptors| descriptors.options())
     }
 }
 
 impl OperatorOptions {
-    pub fn new(
-        clear_output_before_use: bool,
-        use_mask_structure_of_stored_values_as_mask: bool,
-        use_mask_complement: bool,
-    ) -> Self {
-        Self {
-            clear_output_before_use,
-            use_mask_structure_of_stored_values_as_mask,
-            use_mask_complement,
-            graphblas_descriptor: graphblas_descriptor(
-                clear_output_before_use,
-                use_mask_structure_of_stored_values_as_mask,
-                use_mask_complement,
-                false,
-                false,
-            ),
-        }
+    pub fn new(clear_output_before_use: bool) -> Self {
+        Self::new_with_extended_graphblas_descriptors(None, clear_output_before_use)
     }
 
     pub fn new_default() -> Self {
-        let clear_output_before_use = false;
-        let use_mask_structure_of_stored_values_as_mask = false;
-        let use_mask_complement = false;
+        Self::new(false)
+    }
+
+    fn new_with_extended_graphblas_descriptors(
+        extended_graphblas_descriptors: Option<Arc<ExtendedGraphblasDescriptors>>,
+        clear_output_before_use: bool,
+    ) -> Self {
         Self {
             clear_output_before_use,
-            use_mask_structure_of_stored_values_as_mask,
-            use_mask_complement,
-            graphblas_descriptor: graphblas_descriptor(
+
+            graphblas_descriptor: unmasked_graphblas_descriptor_with_extended_options(
+                &extended_graphblas_descriptors,
                 clear_output_before_use,
-                use_mask_structure_of_stored_values_as_mask,
-                use_mask_complement,
                 false,
                 false,
             ),
+            extended_graphblas_descriptors,
         }
     }
 }
@@ -98,4 +133,21 @@ mod tests {
         let expected_value: GrB_Descriptor = ptr::null_mut();
         assert_eq!(default_options.graphblas_descriptor(), expected_value)
     }
+
+    #[test]
+    fn engage_extended_options_without_mask() {
+        let context = Context::init_default().unwrap();
+        let options = OperatorOptions::new_default()
+            .with_extended_descriptor_options(
+                &context,
+                &ExtendedDescriptorOptions::new_default().with_number_of_threads(2),
+            )
+            .unwrap();
+
+        let descriptor_for_call = options.graphblas_descriptor_for_call().unwrap();
+        assert_eq!(
+            descriptor_for_call.graphblas_descriptor(),
+            options.graphblas_descriptor()
+        );
+    }
 }
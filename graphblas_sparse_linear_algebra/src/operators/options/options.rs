@@ -1,3 +1,10 @@
+use std::sync::Arc;
+
+use crate::context::Context;
+use crate::error::SparseLinearAlgebraError;
+
+use super::ExtendedDescriptorOptions;
+
 pub trait GetClearOutputBeforeUse {
     fn clear_output_before_use(&self) -> bool;
 }
@@ -19,11 +26,6 @@ pub trait GetTransposeSecondMatrixArgument {
     fn transpose_second_matrix_argument(&self) -> bool;
 }
 
-pub trait GetOperatorMaskOptions {
-    fn use_mask_structure_of_stored_values_as_mask(&self) -> bool;
-    fn use_mask_complement(&self) -> bool;
-}
-
 pub trait WithTransposeMatrixArgument {
     fn with_negated_transpose_matrix_argument(&self) -> Self;
     fn with_transpose_matrix_argument(&self, transpose_matrix_argument: bool) -> Self;
@@ -42,3 +44,18 @@ pub trait WithTransposeArguments {
         transpose_second_matrix_argument: bool,
     ) -> Self;
 }
+
+pub trait WithExtendedDescriptorOptions {
+    /// Uses SuiteSparse:GraphBLAS descriptors with the extended options, which are shared by options with the same settings
+    fn with_extended_descriptor_options(
+        &self,
+        context: &Arc<Context>,
+        extended_descriptor_options: &ExtendedDescriptorOptions,
+    ) -> Result<Self, SparseLinearAlgebraError>
+    where
+        Self: Sized;
+}
+
+pub trait GetExtendedDescriptorOptions {
+    fn extended_descriptor_options(&self) -> Option<&ExtendedDescriptorOptions>;
+}
@@ -1,8 +1,17 @@
+use std::sync::Arc;
+
 use suitesparse_graphblas_sys::GrB_Descriptor;
 
+use crate::context::Context;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::mask::GetMaskOptions;
+
 use super::{
-    graphblas_descriptor, GetClearOutputBeforeUse, GetGraphblasDescriptor, GetOperatorMaskOptions,
-    GetOperatorOptions, GetTransposeArguments, WithTransposeArguments,
+    graphblas_descriptor_for_call_with_extended_options,
+    unmasked_graphblas_descriptor_with_extended_options, ExtendedDescriptorOptions,
+    ExtendedGraphblasDescriptors, GetClearOutputBeforeUse, GetExtendedDescriptorOptions,
+    GetGraphblasDescriptor, GetOperatorOptions, GetTransposeArguments, GraphblasDescriptorForCall,
+    WithExtendedDescriptorOptions, WithTransposeArguments,
 };
 
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
@@ -14,12 +23,11 @@ unsafe impl Sync for OptionsForOperatorWithMatrixArguments {}
 #[derive(Debug, Clone)]
 pub struct OptionsForOperatorWithMatrixArguments {
     clear_output_before_use: bool,
-    use_mask_structure_of_stored_values_as_mask: bool,
-    use_mask_complement: bool,
     transpose_first_argument: bool,
     transpose_second_argument: bool,
 
     graphblas_descriptor: GrB_Descriptor,
+    extended_graphblas_descriptors: Option<Arc<ExtendedGraphblasDescriptors>>,
 }
 
 pub trait GetOptionsForOperatorWithMatrixArguments:
@@ -36,16 +44,6 @@ impl GetClearOutputBeforeUse for OptionsForOperatorWithMatrixArguments {
     }
 }
 
-impl GetOperatorMaskOptions for OptionsForOperatorWithMatrixArguments {
-    fn use_mask_structure_of_stored_values_as_mask(&self) -> bool {
-        self.use_mask_structure_of_stored_values_as_mask
-    }
-
-    fn use_mask_complement(&self) -> bool {
-        self.use_mask_complement
-    }
-}
-
 impl GetTransposeArguments for OptionsForOperatorWithMatrixArguments {
     fn transpose_first_argument(&self) -> bool {
         self.transpose_first_argument
@@ -60,24 +58,49 @@ impl GetGraphblasDescriptor for OptionsForOperatorWithMatrixArguments {
     fn graphblas_descriptor(&self) -> GrB_Descriptor {
         self.graphblas_descriptor
     }
+
+    fn graphblas_descriptor_for_mask(
+        &self,
+        mask: &impl GetMaskOptions,
+    ) -> Result<GraphblasDescriptorForCall, SparseLinearAlgebraError> {
+        graphblas_descriptor_for_call_with_extended_options(
+            &self.extended_graphblas_descriptors,
+            self.clear_output_before_use,
+            mask.use_mask_structure_of_stored_values_as_mask(),
+            mask.use_mask_complement(),
+            self.transpose_first_argument,
+            self.transpose_second_argument,
+        )
+    }
+
+    fn graphblas_descriptor_for_call(
+        &self,
+    ) -> Result<GraphblasDescriptorForCall, SparseLinearAlgebraError> {
+        graphblas_descriptor_for_call_with_extended_options(
+            &self.extended_graphblas_descriptors,
+            self.clear_output_before_use,
+            false,
+            false,
+            self.transpose_first_argument,
+            self.transpose_second_argument,
+        )
+    }
 }
 
 impl WithTransposeArguments for OptionsForOperatorWithMatrixArguments {
     fn with_negated_transpose_first_argument(&self) -> Self {
-        OptionsForOperatorWithMatrixArguments::new(
+        OptionsForOperatorWithMatrixArguments::new_with_extended_graphblas_descriptors(
+            self.extended_graphblas_descriptors.clone(),
             self.clear_output_before_use,
-            self.use_mask_structure_of_stored_values_as_mask,
-            self.use_mask_complement,
             !self.transpose_first_argument,
             self.transpose_second_argument,
         )
     }
 
     fn with_negated_transpose_second_argument(&self) -> Self {
-        OptionsForOperatorWithMatrixArguments::new(
+        OptionsForOperatorWithMatrixArguments::new_with_extended_graphblas_descriptors(
+            self.extended_graphblas_descriptors.clone(),
             self.clear_output_before_use,
-            self.use_mask_structure_of_stored_values_as_mask,
-            self.use_mask_complement,
             self.transpose_first_argument,
             !self.transpose_second_argument,
         )
@@ -87,10 +110,9 @@ impl WithTransposeArguments for OptionsForOperatorWithMatrixArguments {
         if transpose_first_argument == self.transpose_first_argument {
             self.clone()
         } else {
-            OptionsForOperatorWithMatrixArguments::new(
+            OptionsForOperatorWithMatrixArguments::new_with_extended_graphblas_descriptors(
+                self.extended_graphblas_descriptors.clone(),
                 self.clear_output_before_use,
-                self.use_mask_structure_of_stored_values_as_mask,
-                self.use_mask_complement,
                 transpose_first_argument,
                 self.transpose_second_argument,
             )
@@ -101,10 +123,9 @@ impl WithTransposeArguments for OptionsForOperatorWithMatrixArguments {
         if transpose_second_argument == self.transpose_second_argument {
             self.clone()
         } else {
-            OptionsForOperatorWithMatrixArguments::new(
+            OptionsForOperatorWithMatrixArguments::new_with_extended_graphblas_descriptors(
+                self.extended_graphblas_descriptors.clone(),
                 self.clear_output_before_use,
-                self.use_mask_structure_of_stored_values_as_mask,
-                self.use_mask_complement,
                 self.transpose_first_argument,
                 transpose_second_argument,
             )
@@ -121,10 +142,9 @@ impl WithTransposeArguments for OptionsForOperatorWithMatrixArguments {
         {
             self.clone()
         } else {
-            OptionsForOperatorWithMatrixArguments::new(
+            OptionsForOperatorWithMatrixArguments::new_with_extended_graphblas_descriptors(
+                self.extended_graphblas_descriptors.clone(),
                 self.clear_output_before_use,
-                self.use_mask_structure_of_stored_values_as_mask,
-                self.use_mask_complement,
                 transpose_first_argument,
                 transpose_second_argument,
             )
@@ -132,52 +152,69 @@ impl WithTransposeArguments for OptionsForOperatorWithMatrixArguments {
     }
 }
 
+impl WithExtendedDescriptorOptions for OptionsForOperatorWithMatrixArguments {
+    fn with_extended_descriptor_options(
+        &self,
+        context: &Arc<Context>,
+        extended_descriptor_options: &ExtendedDescriptorOptions,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        Ok(Self::new_with_extended_graphblas_descriptors(
+            Some(ExtendedGraphblasDescriptors::cached(
+                context,
+                self.clear_output_before_use,
+                extended_descriptor_options,
+            )?),
+            self.clear_output_before_use,
+            self.transpose_first_argument,
+            self.transpose_second_argument,
+        ))
+    }
+}
+
+impl GetExtendedDescriptorOptions for OptionsForOperatorWithMatrixArguments {
+    fn extended_descriptor_options(&self) -> Option<&ExtendedDescriptorOptions> {
+        self.extended_graphblas_descriptors
+            .as_ref()
+            .map(|descriptors| descriptors.options())
+    }
+}
+
 impl OptionsForOperatorWithMatrixArguments {
     pub fn new(
         clear_output_before_use: bool,
-        use_mask_structure_of_stored_values_as_mask: bool,
-        use_mask_complement: bool,
         transpose_first_argument: bool,
         transpose_second_argument: bool,
     ) -> Self {
-        Self {
+        Self::new_with_extended_graphblas_descriptors(
+            None,
             clear_output_before_use,
-            use_mask_structure_of_stored_values_as_mask,
-            use_mask_complement,
             transpose_first_argument,
             transpose_second_argument,
-
-            graphblas_descriptor: graphblas_descriptor(
-                clear_output_before_use,
-                false,
-                false,
-                transpose_first_argument,
-                transpose_second_argument,
-            ),
-        }
+        )
     }
 
     pub fn new_default() -> Self {
-        let use_mask_structure_of_stored_values_as_mask = false;
-        let use_mask_complement = false;
-        let transpose_first_argument = false;
-        let transpose_second_argument = false;
-        let clear_output_before_use = false;
+        Self::new(false, false, false)
+    }
 
+    fn new_with_extended_graphblas_descriptors(
+        extended_graphblas_descriptors: Option<Arc<ExtendedGraphblasDescriptors>>,
+        clear_output_before_use: bool,
+        transpose_first_argument: bool,
+        transpose_second_argument: bool,
+    ) -> Self {
         Self {
             clear_output_before_use,
-            use_mask_structure_of_stored_values_as_mask,
-            use_mask_complement,
             transpose_first_argument,
             transpose_second_argument,
 
-            graphblas_descriptor: graphblas_descriptor(
+            graphblas_descriptor: unmasked_graphblas_descriptor_with_extended_options(
+                &extended_graphblas_descriptors,
                 clear_output_before_use,
-                false,
-                false,
                 transpose_first_argument,
                 transpose_second_argument,
             ),
+            extended_graphblas_descriptors,
         }
     }
 }
@@ -188,10 +225,71 @@ mod tests {
 
     use super::*;
 
+    use crate::collections::sparse_matrix::{Size, SparseMatrix};
+    use crate::context::Context;
+    use crate::operators::mask::Mask;
+    use crate::operators::options::MatrixMultiplicationMethod;
+
     #[test]
     fn test_options() {
         let default_options = OptionsForOperatorWithMatrixArguments::new_default();
         let expected_value: GrB_Descriptor = ptr::null_mut();
         assert_eq!(default_options.graphblas_descriptor(), expected_value)
     }
+
+    #[test]
+    fn mask_options_select_descriptor() {
+        let context = Context::init_default().unwrap();
+        let mask = SparseMatrix::<bool>::new(context, Size::new(1, 1)).unwrap();
+        let options = OptionsForOperatorWithMatrixArguments::new(false, true, false);
+
+        assert_eq!(
+            options
+                .graphblas_descriptor_for_mask(&mask)
+                .unwrap()
+                .graphblas_descriptor(),
+            unsafe { suitesparse_graphblas_sys::GrB_DESC_T0 }
+        );
+        assert_eq!(
+            options
+                .graphblas_descriptor_for_mask(&Mask::complement(&Mask::structure(&mask)))
+                .unwrap()
+                .graphblas_descriptor(),
+            unsafe { suitesparse_graphblas_sys::GrB_DESC_SCT0 }
+        );
+    }
+
+    #[test]
+    fn keep_extended_descriptor_options() {
+        let context = Context::init_default().unwrap();
+        let mask = SparseMatrix::<bool>::new(context.clone(), Size::new(1, 1)).unwrap();
+        let extended_descriptor_options = ExtendedDescriptorOptions::new_default()
+            .with_matrix_multiplication_method(MatrixMultiplicationMethod::Dot);
+
+        let options = OptionsForOperatorWithMatrixArguments::new(true, false, false)
+            .with_extended_descriptor_options(&context, &extended_descriptor_options)
+            .unwrap();
+        let transposed_options = options.with_transpose_first_argument(true);
+
+        assert_eq!(
+            transposed_options.extended_descriptor_options(),
+            Some(&extended_descriptor_options)
+        );
+        assert!(!options.graphblas_descriptor().is_null());
+        assert_ne!(
+            options.graphblas_descriptor(),
+            transposed_options.graphblas_descriptor()
+        );
+        assert_ne!(
+            transposed_options
+                .graphblas_descriptor_for_mask(&Mask::structure(&mask))
+                .unwrap()
+                .graphblas_descriptor(),
+            unsafe { suitesparse_graphblas_sys::GrB_DESC_RST0 }
+        );
+        assert_eq!(
+            OptionsForOperatorWithMatrixArguments::new_default().extended_descriptor_options(),
+            None
+        );
+    }
 }
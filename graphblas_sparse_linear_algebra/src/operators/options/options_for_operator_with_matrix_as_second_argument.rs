@@ -1,8 +1,17 @@
+use std::sync::Arc;
+
 use suitesparse_graphblas_sys::GrB_Descriptor;
 
+use crate::context::Context;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::mask::GetMaskOptions;
+
 use super::{
-    graphblas_descriptor, GetClearOutputBeforeUse, GetGraphblasDescriptor, GetOperatorMaskOptions,
-    GetOperatorOptions, GetTransposeSecondMatrixArgument, WithTransposeMatrixArgument,
+    graphblas_descriptor_for_call_with_extended_options,
+    unmasked_graphblas_descriptor_with_extended_options, ExtendedDescriptorOptions,
+    ExtendedGraphblasDescriptors, GetClearOutputBeforeUse, GetExtendedDescriptorOptions,
+    GetGraphblasDescriptor, GetOperatorOptions, GetTransposeSecondMatrixArgument,
+    GraphblasDescriptorForCall, WithExtendedDescriptorOptions, WithTransposeMatrixArgument,
 };
 
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
@@ -14,11 +23,10 @@ unsafe impl Sync for OptionsForOperatorWithMatrixAsSecondArgument {}
 #[derive(Debug, Clone)]
 pub struct OptionsForOperatorWithMatrixAsSecondArgument {
     clear_output_before_use: bool,
-    use_mask_structure_of_stored_values_as_mask: bool,
-    use_mask_complement: bool,
     transpose_matrix_argument: bool,
 
     graphblas_descriptor: GrB_Descriptor,
+    extended_graphblas_descriptors: Option<Arc<ExtendedGraphblasDescriptors>>,
 }
 
 pub trait GetOptionsForOperatorWithMatrixAsSecondArgument:
@@ -38,16 +46,6 @@ impl GetClearOutputBeforeUse for OptionsForOperatorWithMatrixAsSecondArgument {
     }
 }
 
-impl GetOperatorMaskOptions for OptionsForOperatorWithMatrixAsSecondArgument {
-    fn use_mask_structure_of_stored_values_as_mask(&self) -> bool {
-        self.use_mask_structure_of_stored_values_as_mask
-    }
-
-    fn use_mask_complement(&self) -> bool {
-        self.use_mask_complement
-    }
-}
-
 impl GetTransposeSecondMatrixArgument for OptionsForOperatorWithMatrixAsSecondArgument {
     fn transpose_second_matrix_argument(&self) -> bool {
         self.transpose_matrix_argument
@@ -58,14 +56,40 @@ impl GetGraphblasDescriptor for OptionsForOperatorWithMatrixAsSecondArgument {
     fn graphblas_descriptor(&self) -> GrB_Descriptor {
         self.graphblas_descriptor
     }
+
+    fn graphblas_descriptor_for_mask(
+        &self,
+        mask: &impl GetMaskOptions,
+    ) -> Result<GraphblasDescriptorForCall, SparseLinearAlgebraError> {
+        graphblas_descriptor_for_call_with_extended_options(
+            &self.extended_graphblas_descriptors,
+            self.clear_output_before_use,
+            mask.use_mask_structure_of_stored_values_as_mask(),
+            mask.use_mask_complement(),
+            false,
+            self.transpose_matrix_argument,
+        )
+    }
+
+    fn graphblas_descriptor_for_call(
+        &self,
+    ) -> Result<GraphblasDescriptorForCall, SparseLinearAlgebraError> {
+        graphblas_descriptor_for_call_with_extended_options(
+            &self.extended_graphblas_descriptors,
+            self.clear_output_before_use,
+            false,
+            false,
+            false,
+            self.transpose_matrix_argument,
+        )
+    }
 }
 
 impl WithTransposeMatrixArgument for OptionsForOperatorWithMatrixAsSecondArgument {
     fn with_negated_transpose_matrix_argument(&self) -> Self {
-        OptionsForOperatorWithMatrixAsSecondArgument::new(
+        OptionsForOperatorWithMatrixAsSecondArgument::new_with_extended_graphblas_descriptors(
+            self.extended_graphblas_descriptors.clone(),
             self.clear_output_before_use,
-            self.use_mask_structure_of_stored_values_as_mask,
-            self.use_mask_complement,
             !self.transpose_matrix_argument,
         )
     }
@@ -74,58 +98,70 @@ impl WithTransposeMatrixArgument for OptionsForOperatorWithMatrixAsSecondArgumen
         if transpose_matrix == self.transpose_matrix_argument {
             self.clone()
         } else {
-            OptionsForOperatorWithMatrixAsSecondArgument::new(
+            OptionsForOperatorWithMatrixAsSecondArgument::new_with_extended_graphblas_descriptors(
+                self.extended_graphblas_descriptors.clone(),
                 self.clear_output_before_use,
-                self.use_mask_structure_of_stored_values_as_mask,
-                self.use_mask_complement,
                 transpose_matrix,
             )
         }
     }
 }
 
+impl WithExtendedDescriptorOptions for OptionsForOperatorWithMatrixAsSecondArgument {
+    fn with_extended_descriptor_options(
+        &self,
+        context: &Arc<Context>,
+        extended_descriptor_options: &ExtendedDescriptorOptions,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        Ok(Self::new_with_extended_graphblas_descriptors(
+            Some(ExtendedGraphblasDescriptors::cached(
+                context,
+                self.clear_output_before_use,
+                extended_descriptor_options,
+            )?),
+            self.clear_output_before_use,
+            self.transpose_matrix_argument,
+        ))
+    }
+}
+
+impl GetExtendedDescriptorOptions for OptionsForOperatorWithMatrixAsSecondArgument {
+    fn extended_descriptor_options(&self) -> Option<&ExtendedDescriptorOptions> {
+        self.extended_graphblas_descriptors
+            .as_ref()
+            .map(|descriptors| descriptors.options())
+    }
+}
+
 impl OptionsForOperatorWithMatrixAsSecondArgument {
-    pub fn new(
-        clear_output_before_use: bool,
-        use_mask_structure_of_stored_values_as_mask: bool,
-        use_mask_complement: bool,
-        transpose_matrix_argument: bool,
-    ) -> Self {
-        Self {
+    pub fn new(clear_output_before_use: bool, transpose_matrix_argument: bool) -> Self {
+        Self::new_with_extended_graphblas_descriptors(
+            None,
             clear_output_before_use,
-            use_mask_structure_of_stored_values_as_mask,
-            use_mask_complement,
             transpose_matrix_argument,
-
-            graphblas_descriptor: graphblas_descriptor(
-                clear_output_before_use,
-                false,
-                false,
-                false,
-                transpose_matrix_argument,
-            ),
-        }
+        )
     }
 
     pub fn new_default() -> Self {
-        let clear_output_before_use = false;
-        let use_mask_structure_of_stored_values_as_mask = false;
-        let use_mask_complement = false;
-        let transpose_matrix_argument = false;
+        Self::new(false, false)
+    }
 
+    fn new_with_extended_graphblas_descriptors(
+        extended_graphblas_descriptors: Option<Arc<ExtendedGraphblasDescriptors>>,
+        clear_output_before_use: bool,
+        transpose_matrix_argument: bool,
+    ) -> Self {
         Self {
             clear_output_before_use,
-            use_mask_structure_of_stored_values_as_mask,
-            use_mask_complement,
             transpose_matrix_argument,
 
-            graphblas_descriptor: graphblas_descriptor(
+            graphblas_descriptor: unmasked_graphblas_descriptor_with_extended_options(
+                &extended_graphblas_descriptors,
                 clear_output_before_use,
                 false,
-                false,
-                false,
                 transpose_matrix_argument,
             ),
+            extended_graphblas_descriptors,
         }
     }
 }
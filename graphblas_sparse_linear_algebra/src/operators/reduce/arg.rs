@@ -0,0 +1,439 @@
+use std::ptr;
+use std::sync::Arc;
+
+use crate::collections::sparse_matrix::operations::{FromDiagonalVector, GetSparseMatrixSize};
+use crate::collections::sparse_matrix::{GetMatrixDimensions, Size, SparseMatrix};
+use crate::collections::sparse_scalar::{GetScalarValue, GraphblasSparseScalarTrait, SparseScalar};
+use crate::collections::sparse_vector::operations::GetSparseVectorLength;
+use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
+use crate::context::{CallGraphBlasContext, Context, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::GrB_Vector_assign_Scalar;
+use crate::index::{ElementCount, ElementIndex, ElementIndexSelector};
+use crate::operators::apply::{ApplyIndexUnaryOperator, IndexUnaryOperatorApplier};
+use crate::operators::binary_operator::{Assignment, BinaryOperator, Max, MaxTyped, Min, MinTyped};
+use crate::operators::index_unary_operator::{IsValueEqualTo, IsValueEqualToTyped, PlusRowIndex};
+use crate::operators::mask::{SelectEntireMatrix, SelectEntireVector};
+use crate::operators::multiplication::{
+    MatrixMultiplicationOperator, MatrixVectorMultiplicationOperator, MultiplyMatrices,
+    MultiplyMatrixByVector,
+};
+use crate::operators::options::{
+    GetGraphblasDescriptor, OperatorOptions, OptionsForOperatorWithMatrixArgument,
+    OptionsForOperatorWithMatrixArguments, OptionsForOperatorWithMatrixAsFirstArgument,
+};
+use crate::operators::select::{
+    MatrixSelector, SelectFromMatrix, SelectFromVector, VectorSelector,
+};
+use crate::operators::semiring::{AnyEqualTo, AnyEqualToTyped, MinColumnIndexFirstArgument};
+use crate::value_type::ValueType;
+
+use super::{BinaryOperatorReducer, BinaryOperatorScalarReducer, ReduceWithBinaryOperator};
+
+// Implemented methods do not provide mutable access to GraphBLAS operators or options.
+// Code review must consider that no mtable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl Send for ArgumentReducer {}
+unsafe impl Sync for ArgumentReducer {}
+
+/// Finds the indices of the smallest or largest stored elements.
+///
+/// Ties resolve to the smallest index.
+/// Rows or columns without stored elements have no index and no extreme value.
+///
+/// Like the MIN and MAX operators of SuiteSparse:GraphBLAS, NaN values are ignored.
+/// A row, column or vector that stores only NaN values has NaN as its extreme value, which equals none of its elements,
+/// so it has no index.
+#[derive(Debug, Clone)]
+pub struct ArgumentReducer {}
+
+impl ArgumentReducer {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for ArgumentReducer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The index of the extreme element of each row or column, with the extreme values.
+#[derive(Debug)]
+pub struct ArgumentExtrema<T: ValueType> {
+    indices: SparseVector<ElementIndex>,
+    values: SparseVector<T>,
+}
+
+impl<T: ValueType> ArgumentExtrema<T> {
+    pub fn indices_ref(&self) -> &SparseVector<ElementIndex> {
+        &self.indices
+    }
+
+    pub fn values_ref(&self) -> &SparseVector<T> {
+        &self.values
+    }
+
+    pub fn into_parts(self) -> (SparseVector<ElementIndex>, SparseVector<T>) {
+        (self.indices, self.values)
+    }
+}
+
+pub trait ReduceToArgumentExtrema<T: ValueType> {
+    /// Returns the column index of the smallest element of each row
+    fn argmin_per_row(
+        &self,
+        argument: &SparseMatrix<T>,
+    ) -> Result<ArgumentExtrema<T>, SparseLinearAlgebraError>;
+    /// Returns the column index of the largest element of each row
+    fn argmax_per_row(
+        &self,
+        argument: &SparseMatrix<T>,
+    ) -> Result<ArgumentExtrema<T>, SparseLinearAlgebraError>;
+    /// Returns the row index of the smallest element of each column
+    fn argmin_per_column(
+        &self,
+        argument: &SparseMatrix<T>,
+    ) -> Result<ArgumentExtrema<T>, SparseLinearAlgebraError>;
+    /// Returns the row index of the largest element of each column
+    fn argmax_per_column(
+        &self,
+        argument: &SparseMatrix<T>,
+    ) -> Result<ArgumentExtrema<T>, SparseLinearAlgebraError>;
+
+    /// Returns None if the vector has no stored elements, or stores only NaN values
+    fn argmin_of_vector(
+        &self,
+        argument: &SparseVector<T>,
+    ) -> Result<Option<(ElementIndex, T)>, SparseLinearAlgebraError>;
+    /// Returns None if the vector has no stored elements, or stores only NaN values
+    fn argmax_of_vector(
+        &self,
+        argument: &SparseVector<T>,
+    ) -> Result<Option<(ElementIndex, T)>, SparseLinearAlgebraError>;
+}
+
+impl<T> ReduceToArgumentExtrema<T> for ArgumentReducer
+where
+    T: ValueType
+        + Copy
+        + Default
+        + MinTyped<T>
+        + MaxTyped<T>
+        + AnyEqualToTyped<T>
+        + IsValueEqualToTyped<T>,
+    SparseScalar<T>: GetScalarValue<T>,
+    VectorSelector: SelectFromVector<T>,
+{
+    fn argmin_per_row(
+        &self,
+        argument: &SparseMatrix<T>,
+    ) -> Result<ArgumentExtrema<T>, SparseLinearAlgebraError> {
+        arg_extrema_per_row(argument, &Min::<T>::new(), false)
+    }
+
+    fn argmax_per_row(
+        &self,
+        argument: &SparseMatrix<T>,
+    ) -> Result<ArgumentExtrema<T>, SparseLinearAlgebraError> {
+        arg_extrema_per_row(argument, &Max::<T>::new(), false)
+    }
+
+    fn argmin_per_column(
+        &self,
+        argument: &SparseMatrix<T>,
+    ) -> Result<ArgumentExtrema<T>, SparseLinearAlgebraError> {
+        arg_extrema_per_row(argument, &Min::<T>::new(), true)
+    }
+
+    fn argmax_per_column(
+        &self,
+        argument: &SparseMatrix<T>,
+    ) -> Result<ArgumentExtrema<T>, SparseLinearAlgebraError> {
+        arg_extrema_per_row(argument, &Max::<T>::new(), true)
+    }
+
+    fn argmin_of_vector(
+        &self,
+        argument: &SparseVector<T>,
+    ) -> Result<Option<(ElementIndex, T)>, SparseLinearAlgebraError> {
+        arg_extremum_of_vector(argument, &Min::<T>::new())
+    }
+
+    fn argmax_of_vector(
+        &self,
+        argument: &SparseVector<T>,
+    ) -> Result<Option<(ElementIndex, T)>, SparseLinearAlgebraError> {
+        arg_extremum_of_vector(argument, &Max::<T>::new())
+    }
+}
+
+/// Reduces the columns instead of the rows if transpose_argument is true
+fn arg_extrema_per_row<T: ValueType + AnyEqualToTyped<T>>(
+    argument: &SparseMatrix<T>,
+    extremum_operator: &impl BinaryOperator<T>,
+    transpose_argument: bool,
+) -> Result<ArgumentExtrema<T>, SparseLinearAlgebraError> {
+    let context = argument.context();
+    let size = argument.size()?;
+    let (row_height, column_width) = match transpose_argument {
+        false => (size.row_height(), size.column_width()),
+        true => (size.column_width(), size.row_height()),
+    };
+
+    let mut values = SparseVector::<T>::new(context.clone(), row_height)?;
+    BinaryOperatorReducer::new().to_colunm_vector(
+        extremum_operator,
+        argument,
+        &Assignment::new(),
+        &mut values,
+        &SelectEntireVector::new(context.clone()),
+        &OptionsForOperatorWithMatrixArgument::new(false, transpose_argument),
+    )?;
+
+    // diag(values) any.== argument marks each element that equals the extreme value of its row
+    let extreme_values_on_diagonal = SparseMatrix::<T>::from_diagonal_vector(&values, &0)?;
+    let mut is_extremum =
+        SparseMatrix::<bool>::new(context.clone(), Size::new(row_height, column_width))?;
+    MatrixMultiplicationOperator::new().apply(
+        &extreme_values_on_diagonal,
+        &AnyEqualTo::<T>::new(),
+        argument,
+        &Assignment::new(),
+        &mut is_extremum,
+        &SelectEntireMatrix::new(context.clone()),
+        &OptionsForOperatorWithMatrixArguments::new(false, false, transpose_argument),
+    )?;
+
+    let mut extrema =
+        SparseMatrix::<bool>::new(context.clone(), Size::new(row_height, column_width))?;
+    MatrixSelector::new().apply(
+        &IsValueEqualTo::<bool>::new(),
+        true,
+        &is_extremum,
+        &Assignment::new(),
+        &mut extrema,
+        &SelectEntireMatrix::new(context.clone()),
+        &OptionsForOperatorWithMatrixArgument::new_default(),
+    )?;
+
+    // The min.firstj semiring ignores the values, and returns the smallest column index of each row
+    let ones = iso_vector_of_ones(&context, column_width)?;
+
+    let mut indices = SparseVector::<ElementIndex>::new(context.clone(), row_height)?;
+    MatrixVectorMultiplicationOperator::new().apply(
+        &extrema,
+        &MinColumnIndexFirstArgument::<i64>::new(),
+        &ones,
+        &Assignment::new(),
+        &mut indices,
+        &SelectEntireVector::new(context),
+        &OptionsForOperatorWithMatrixAsFirstArgument::new_default(),
+    )?;
+
+    Ok(ArgumentExtrema { indices, values })
+}
+
+// Assigning a GrB_Scalar makes an iso vector, which stores its single value once
+fn iso_vector_of_ones(
+    context: &Arc<Context>,
+    length: ElementCount,
+) -> Result<SparseVector<bool>, SparseLinearAlgebraError> {
+    let ones = SparseVector::<bool>::new(context.clone(), length)?;
+    let one = SparseScalar::<bool>::from_value(context.clone(), true)?;
+    let indices = ElementIndexSelector::All.to_graphblas_type(length)?;
+
+    let graphblas_descriptor = OperatorOptions::new_default().graphblas_descriptor_for_call()?;
+    context.call(
+        || unsafe {
+            GrB_Vector_assign_Scalar(
+                ones.graphblas_vector(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                one.graphblas_scalar(),
+                indices.as_ptr(),
+                indices.number_of_indices(),
+                graphblas_descriptor.graphblas_descriptor(),
+            )
+        },
+        unsafe { ones.graphblas_vector_ref() },
+    )?;
+    Ok(ones)
+}
+
+fn arg_extremum_of_vector<T>(
+    argument: &SparseVector<T>,
+    extremum_operator: &impl BinaryOperator<T>,
+) -> Result<Option<(ElementIndex, T)>, SparseLinearAlgebraError>
+where
+    T: ValueType + Copy + Default + IsValueEqualToTyped<T>,
+    SparseScalar<T>: GetScalarValue<T>,
+    VectorSelector: SelectFromVector<T>,
+{
+    let context = argument.context();
+    let length = argument.length()?;
+
+    let mut extreme_value = SparseScalar::<T>::new(context.clone())?;
+    BinaryOperatorReducer::new().vector_to_scalar(
+        extremum_operator,
+        argument,
+        &Assignment::new(),
+        &mut extreme_value,
+        &OperatorOptions::new_default(),
+    )?;
+    let extreme_value = match extreme_value.get_value()? {
+        Some(extreme_value) => extreme_value,
+        None => return Ok(None),
+    };
+
+    let mut extrema = SparseVector::<T>::new(context.clone(), length)?;
+    VectorSelector::new().apply(
+        &IsValueEqualTo::<T>::new(),
+        extreme_value,
+        argument,
+        &Assignment::new(),
+        &mut extrema,
+        &SelectEntireVector::new(context.clone()),
+        &OperatorOptions::new_default(),
+    )?;
+
+    // Replaces each value by its index
+    let mut indices = SparseVector::<i64>::new(context.clone(), length)?;
+    IndexUnaryOperatorApplier::new().apply_to_vector(
+        &extrema,
+        &PlusRowIndex::<i64>::new(),
+        &0,
+        &Assignment::new(),
+        &mut indices,
+        &SelectEntireVector::new(context.clone()),
+        &OperatorOptions::new_default(),
+    )?;
+
+    let mut index = SparseScalar::<ElementIndex>::new(context)?;
+    BinaryOperatorReducer::new().vector_to_scalar(
+        &Min::<i64>::new(),
+        &indices,
+        &Assignment::new(),
+        &mut index,
+        &OperatorOptions::new_default(),
+    )?;
+
+    Ok(index.get_value()?.map(|index| (index, extreme_value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::FromMatrixElementList;
+    use crate::collections::sparse_matrix::MatrixElementList;
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::VectorElementList;
+    use crate::collections::Collection;
+    use crate::context::Context;
+    use crate::operators::binary_operator::First;
+
+    fn scores(context: &std::sync::Arc<Context>) -> SparseMatrix<f32> {
+        SparseMatrix::<f32>::from_element_list(
+            context.clone(),
+            Size::new(4, 3),
+            MatrixElementList::from_element_vector(vec![
+                (0, 0, 0.5).into(),
+                (0, 1, 0.9).into(),
+                (0, 2, 0.1).into(),
+                (1, 0, 0.7).into(),
+                (1, 2, 0.7).into(),
+                (3, 2, -1.0).into(),
+            ]),
+            &First::<f32>::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn argmax_per_row() {
+        let context = Context::init_default().unwrap();
+        let matrix = scores(&context);
+
+        let argmax = ArgumentReducer::new().argmax_per_row(&matrix).unwrap();
+
+        let indices = argmax.indices_ref();
+        assert_eq!(indices.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(indices.element_value(&0).unwrap(), Some(1));
+        assert_eq!(indices.element_value(&1).unwrap(), Some(0));
+        assert_eq!(indices.element_value(&2).unwrap(), None);
+        assert_eq!(indices.element_value(&3).unwrap(), Some(2));
+
+        let values = argmax.values_ref();
+        assert_eq!(values.element_value(&0).unwrap(), Some(0.9));
+        assert_eq!(values.element_value(&1).unwrap(), Some(0.7));
+        assert_eq!(values.element_value(&2).unwrap(), None);
+    }
+
+    #[test]
+    fn argmin_per_column() {
+        let context = Context::init_default().unwrap();
+        let matrix = scores(&context);
+
+        let (indices, values) = ArgumentReducer::new()
+            .argmin_per_column(&matrix)
+            .unwrap()
+            .into_parts();
+
+        assert_eq!(indices.length().unwrap(), 3);
+        assert_eq!(indices.element_value(&0).unwrap(), Some(0));
+        assert_eq!(indices.element_value(&1).unwrap(), Some(0));
+        assert_eq!(indices.element_value(&2).unwrap(), Some(3));
+        assert_eq!(values.element_value(&2).unwrap(), Some(-1.0));
+    }
+
+    #[test]
+    fn argmax_and_argmin_of_vector() {
+        let context = Context::init_default().unwrap();
+        let vector = SparseVector::<i32>::from_element_list(
+            context.clone(),
+            10,
+            VectorElementList::from_element_vector(vec![
+                (2, 4).into(),
+                (5, 8).into(),
+                (7, -3).into(),
+                (9, 8).into(),
+            ]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+
+        let reducer = ArgumentReducer::new();
+        assert_eq!(reducer.argmax_of_vector(&vector).unwrap(), Some((5, 8)));
+        assert_eq!(reducer.argmin_of_vector(&vector).unwrap(), Some((7, -3)));
+
+        let empty_vector = SparseVector::<i32>::new(context, 10).unwrap();
+        assert_eq!(reducer.argmax_of_vector(&empty_vector).unwrap(), None);
+    }
+
+    #[test]
+    fn argmax_ignores_nan() {
+        let context = Context::init_default().unwrap();
+        let vector = SparseVector::<f64>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::from_element_vector(vec![(0, 1.0).into(), (1, f64::NAN).into()]),
+            &First::<f64>::new(),
+        )
+        .unwrap();
+        let only_nan = SparseVector::<f64>::from_element_list(
+            context,
+            3,
+            VectorElementList::from_element_vector(vec![(2, f64::NAN).into()]),
+            &First::<f64>::new(),
+        )
+        .unwrap();
+
+        let reducer = ArgumentReducer::new();
+        assert_eq!(reducer.argmax_of_vector(&vector).unwrap(), Some((0, 1.0)));
+        assert_eq!(reducer.argmax_of_vector(&only_nan).unwrap(), None);
+    }
+}
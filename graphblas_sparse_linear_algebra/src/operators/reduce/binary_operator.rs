@@ -1,4 +1,5 @@
 use crate::collections::sparse_matrix::GetGraphblasSparseMatrix;
+use crate::collections::sparse_scalar::GraphblasSparseScalarTrait;
 use crate::collections::sparse_vector::GetGraphblasSparseVector;
 use crate::context::CallGraphBlasContext;
 use crate::error::SparseLinearAlgebraError;
@@ -6,11 +7,14 @@ use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::binary_operator::BinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::{
-    GetOptionsForOperatorWithMatrixArgument, WithTransposeMatrixArgument,
+    GetOperatorOptions, GetOptionsForOperatorWithMatrixArgument, WithTransposeMatrixArgument,
 };
 use crate::value_type::ValueType;
 
-use crate::graphblas_bindings::GrB_Matrix_reduce_BinaryOp;
+use crate::graphblas_bindings::{
+    GrB_Matrix_reduce_BinaryOp, GrB_Matrix_reduce_BinaryOp_Scalar,
+    GrB_Vector_reduce_BinaryOp_Scalar,
+};
 
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
 // Code review must consider that no mtable access is provided.
@@ -63,6 +67,7 @@ impl<EvaluationDomain: ValueType> ReduceWithBinaryOperator<EvaluationDomain>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Matrix_reduce_BinaryOp(
@@ -71,7 +76,7 @@ impl<EvaluationDomain: ValueType> ReduceWithBinaryOperator<EvaluationDomain>
                     accumulator.accumulator_graphblas_type(),
                     operator.graphblas_type(),
                     argument.graphblas_matrix(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { product.graphblas_vector_ref() },
@@ -100,24 +105,108 @@ impl<EvaluationDomain: ValueType> ReduceWithBinaryOperator<EvaluationDomain>
     }
 }
 
+/// Reduces all stored elements to a sparse scalar.
+///
+/// The product has no value if the argument has no stored elements and the accumulator is Assignment.
+/// GraphBLAS requires the binary operator to correspond to a built-in monoid, e.g. Plus, Times, Min, Max, Any, LogicalOr or LogicalAnd.
+/// Use a MonoidReducer to reduce with any other operator.
+pub trait BinaryOperatorScalarReducer<EvaluationDomain: ValueType> {
+    fn matrix_to_scalar(
+        &self,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        argument: &impl GetGraphblasSparseMatrix,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl GraphblasSparseScalarTrait,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+
+    fn vector_to_scalar(
+        &self,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        argument: &impl GetGraphblasSparseVector,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl GraphblasSparseScalarTrait,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> BinaryOperatorScalarReducer<EvaluationDomain>
+    for BinaryOperatorReducer
+{
+    fn matrix_to_scalar(
+        &self,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        argument: &impl GetGraphblasSparseMatrix,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl GraphblasSparseScalarTrait,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let context = product.context_ref();
+
+        let graphblas_descriptor = options.graphblas_descriptor_for_call()?;
+        context.call(
+            || unsafe {
+                GrB_Matrix_reduce_BinaryOp_Scalar(
+                    product.graphblas_scalar(),
+                    accumulator.accumulator_graphblas_type(),
+                    operator.graphblas_type(),
+                    argument.graphblas_matrix(),
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { product.graphblas_scalar_ref() },
+        )?;
+
+        Ok(())
+    }
+
+    fn vector_to_scalar(
+        &self,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        argument: &impl GetGraphblasSparseVector,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl GraphblasSparseScalarTrait,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let context = product.context_ref();
+
+        let graphblas_descriptor = options.graphblas_descriptor_for_call()?;
+        context.call(
+            || unsafe {
+                GrB_Vector_reduce_BinaryOp_Scalar(
+                    product.graphblas_scalar(),
+                    accumulator.accumulator_graphblas_type(),
+                    operator.graphblas_type(),
+                    argument.graphblas_vector(),
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { product.graphblas_scalar_ref() },
+        )?;
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use crate::collections::sparse_matrix::operations::FromMatrixElementList;
+    use crate::collections::sparse_scalar::{GetScalarValue, SparseScalar};
     use crate::collections::sparse_vector::operations::{
         FromVectorElementList, GetSparseVectorElementValue,
     };
     use crate::collections::Collection;
     use crate::context::Context;
-    use crate::operators::binary_operator::{Assignment, First, Plus};
+    use crate::operators::binary_operator::{Assignment, First, Max, Plus};
 
     use crate::collections::sparse_matrix::{
         GetMatrixDimensions, MatrixElementList, Size, SparseMatrix,
     };
     use crate::collections::sparse_vector::{SparseVector, VectorElementList};
     use crate::operators::mask::SelectEntireVector;
-    use crate::operators::options::OptionsForOperatorWithMatrixArgument;
+    use crate::operators::options::{OperatorOptions, OptionsForOperatorWithMatrixArgument};
 
     #[test]
     fn test_binary_operator_reducer() {
@@ -201,4 +290,64 @@ mod tests {
         assert_eq!(product_vector.element_value(&5).unwrap(), None);
         assert_eq!(product_vector.element_value(&9).unwrap(), None);
     }
+
+    #[test]
+    fn reduce_to_scalar_with_binary_operator() {
+        let context = Context::init_default().unwrap();
+
+        let matrix = SparseMatrix::<u8>::from_element_list(
+            context.clone(),
+            Size::new(3, 4),
+            MatrixElementList::from_element_vector(vec![
+                (0, 1, 2).into(),
+                (1, 3, 7).into(),
+                (2, 0, 4).into(),
+            ]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+        let mut product = SparseScalar::<u8>::new(context.clone()).unwrap();
+
+        let reducer = BinaryOperatorReducer::new();
+        reducer
+            .matrix_to_scalar(
+                &Plus::<u8>::new(),
+                &matrix,
+                &Assignment::new(),
+                &mut product,
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )
+            .unwrap();
+        assert_eq!(product.get_value().unwrap(), Some(13));
+
+        let vector = SparseVector::<u8>::from_element_list(
+            context.clone(),
+            5,
+            VectorElementList::from_element_vector(vec![(0, 3).into(), (4, 9).into()]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+        reducer
+            .vector_to_scalar(
+                &Max::<u8>::new(),
+                &vector,
+                &Plus::<u8>::new(),
+                &mut product,
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+        assert_eq!(product.get_value().unwrap(), Some(22));
+
+        let empty_vector = SparseVector::<u8>::new(context, 5).unwrap();
+        reducer
+            .vector_to_scalar(
+                &Max::<u8>::new(),
+                &empty_vector,
+                &Assignment::new(),
+                &mut product,
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+        assert_eq!(product.get_value().unwrap(), None);
+    }
 }
@@ -1,5 +1,9 @@
+mod arg;
 mod binary_operator;
 mod monoid;
 
-pub use binary_operator::{BinaryOperatorReducer, ReduceWithBinaryOperator};
+pub use arg::*;
+pub use binary_operator::{
+    BinaryOperatorReducer, BinaryOperatorScalarReducer, ReduceWithBinaryOperator,
+};
 pub use monoid::{MonoidReducer, MonoidScalarReducer, MonoidVectorReducer};
@@ -75,6 +75,7 @@ impl<EvaluationDomain: ValueType> MonoidVectorReducer<EvaluationDomain> for Mono
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = product.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_Matrix_reduce_Monoid(
@@ -83,7 +84,7 @@ impl<EvaluationDomain: ValueType> MonoidVectorReducer<EvaluationDomain> for Mono
                     accumulator.accumulator_graphblas_type(),
                     operator.graphblas_type(),
                     argument.graphblas_matrix(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { product.graphblas_vector_ref() },
@@ -146,6 +147,7 @@ macro_rules! implement_monoid_reducer {
                 let context = argument.context_ref();
                 let mut tmp_product = product.clone().to_type()?;
 
+                let graphblas_descriptor = options.graphblas_descriptor_for_call()?;
                 // TODO: support detailed error information
                 context.call_without_detailed_error_information(|| unsafe {
                     $matrix_reducer_operator(
@@ -153,7 +155,7 @@ macro_rules! implement_monoid_reducer {
                         accumulator.accumulator_graphblas_type(),
                         operator.graphblas_type(),
                         argument.graphblas_matrix(),
-                        options.graphblas_descriptor(),
+                        graphblas_descriptor.graphblas_descriptor(),
                     )
                 })?;
 
@@ -174,13 +176,14 @@ macro_rules! implement_monoid_reducer {
                 let context = argument.context_ref();
                 let mut tmp_product = product.clone().to_type()?;
 
+                let graphblas_descriptor = options.graphblas_descriptor_for_call()?;
                 context.call_without_detailed_error_information(|| unsafe {
                     $vector_reducer_operator(
                         &mut tmp_product,
                         accumulator.accumulator_graphblas_type(),
                         operator.graphblas_type(),
                         argument.graphblas_vector(),
-                        options.graphblas_descriptor(),
+                        graphblas_descriptor.graphblas_descriptor(),
                     )
                 })?;
 
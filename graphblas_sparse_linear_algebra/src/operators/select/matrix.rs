@@ -58,6 +58,7 @@ macro_rules! implement_select_from_matrix {
                 options: &impl GetOptionsForOperatorWithMatrixArgument,
             ) -> Result<(), SparseLinearAlgebraError> {
                 let selector_argument = selector_argument.clone().to_type()?;
+                let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
                 argument.context_ref().call(
                     || unsafe {
                         $graphblas_operator(
@@ -67,7 +68,7 @@ macro_rules! implement_select_from_matrix {
                             selector.graphblas_type(),
                             argument.graphblas_matrix(),
                             selector_argument,
-                            options.graphblas_descriptor(),
+                            graphblas_descriptor.graphblas_descriptor(),
                         )
                     },
                     unsafe { product.graphblas_matrix_ref() },
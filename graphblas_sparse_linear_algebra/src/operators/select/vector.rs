@@ -58,6 +58,7 @@ macro_rules! implement_select_from_vector {
                 options: &impl GetOperatorOptions,
             ) -> Result<(), SparseLinearAlgebraError> {
                 let selector_argument = selector_argument.to_owned().to_type()?;
+                let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
                 argument.context_ref().call(
                     || unsafe {
                         $graphblas_operator(
@@ -67,7 +68,7 @@ macro_rules! implement_select_from_vector {
                             selector.graphblas_type(),
                             argument.graphblas_vector(),
                             selector_argument,
-                            options.graphblas_descriptor(),
+                            graphblas_descriptor.graphblas_descriptor(),
                         )
                     },
                     unsafe { product.graphblas_vector_ref() },
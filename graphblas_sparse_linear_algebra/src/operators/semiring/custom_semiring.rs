@@ -0,0 +1,238 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::sync::Arc;
+
+use crate::context::{Context, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::{GrB_Semiring, GrB_Semiring_free, GrB_Semiring_new};
+use crate::operators::binary_operator::BinaryOperator;
+use crate::operators::monoid::Monoid;
+use crate::value_type::ValueType;
+
+use super::{Semiring, TypedSemiring};
+
+/// A semiring composed from any additive monoid and multiplicative binary operator.
+///
+/// The semiring owns its monoid and binary operator, because the GraphBLAS semiring refers to them.
+/// The GraphBLAS semiring is freed when the CustomSemiring is dropped.
+pub struct CustomSemiring<EvaluationDomain: ValueType> {
+    context: Arc<Context>,
+    graphblas_semiring: GrB_Semiring,
+    _additive_monoid: Box<dyn Monoid<EvaluationDomain> + Send + Sync>,
+    _multiplicative_binary_operator: Box<dyn BinaryOperator<EvaluationDomain> + Send + Sync>,
+}
+
+// Implemented methods do not provide mutable access to GraphBLAS operators or options.
+// Code review must consider that no mtable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl<EvaluationDomain: ValueType> Send for CustomSemiring<EvaluationDomain> {}
+unsafe impl<EvaluationDomain: ValueType> Sync for CustomSemiring<EvaluationDomain> {}
+
+impl<EvaluationDomain: ValueType> CustomSemiring<EvaluationDomain> {
+    pub fn new(
+        context: Arc<Context>,
+        additive_monoid: impl Monoid<EvaluationDomain> + Send + Sync + 'static,
+        multiplicative_binary_operator: impl BinaryOperator<EvaluationDomain> + Send + Sync + 'static,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let mut graphblas_semiring: MaybeUninit<GrB_Semiring> = MaybeUninit::uninit();
+
+        context.call_without_detailed_error_information(|| unsafe {
+            GrB_Semiring_new(
+                graphblas_semiring.as_mut_ptr(),
+                additive_monoid.graphblas_type(),
+                multiplicative_binary_operator.graphblas_type(),
+            )
+        })?;
+
+        Ok(Self {
+            context,
+            graphblas_semiring: unsafe { graphblas_semiring.assume_init() },
+            _additive_monoid: Box::new(additive_monoid),
+            _multiplicative_binary_operator: Box::new(multiplicative_binary_operator),
+        })
+    }
+}
+
+impl<EvaluationDomain: ValueType> Semiring<EvaluationDomain> for CustomSemiring<EvaluationDomain> {
+    fn graphblas_type(&self) -> GrB_Semiring {
+        self.graphblas_semiring
+    }
+}
+
+// The additive monoid is in the evaluation domain
+impl<EvaluationDomain: ValueType> TypedSemiring<EvaluationDomain>
+    for CustomSemiring<EvaluationDomain>
+{
+    type OutputDomain = EvaluationDomain;
+}
+
+impl<EvaluationDomain: ValueType> GetContext for CustomSemiring<EvaluationDomain> {
+    fn context(&self) -> Arc<Context> {
+        self.context.clone()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        &self.context
+    }
+}
+
+impl<EvaluationDomain: ValueType> fmt::Debug for CustomSemiring<EvaluationDomain> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomSemiring")
+            .field("graphblas_semiring", &self.graphblas_semiring)
+            .finish()
+    }
+}
+
+impl<EvaluationDomain: ValueType> Drop for CustomSemiring<EvaluationDomain> {
+    fn drop(&mut self) {
+        let _ = self
+            .context
+            .call_without_detailed_error_information(|| unsafe {
+                GrB_Semiring_free(&mut self.graphblas_semiring)
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue, SetSparseMatrixElement,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::operators::binary_operator::{
+        Assignment, BinaryOperatorFunction, CustomBinaryOperator, First, Max, Min,
+    };
+    use crate::operators::kronecker_product::{
+        SemiringKroneckerProduct, SemiringKroneckerProductOperator,
+    };
+    use crate::operators::mask::SelectEntireMatrix;
+    use crate::operators::monoid::CustomMonoid;
+    use crate::operators::multiplication::{MatrixMultiplicationOperator, MultiplyMatrices};
+    use crate::operators::options::OptionsForOperatorWithMatrixArguments;
+    use crate::value_type::GraphblasValueType;
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, GraphblasValueType)]
+    #[graphblas_value_type(crate = "crate")]
+    struct Capacity {
+        value: u32,
+    }
+
+    struct LargestCapacity;
+
+    impl BinaryOperatorFunction<Capacity, Capacity> for LargestCapacity {
+        fn product(first_argument: &Capacity, second_argument: &Capacity) -> Capacity {
+            Capacity {
+                value: first_argument.value.max(second_argument.value),
+            }
+        }
+    }
+
+    struct SmallestCapacity;
+
+    impl BinaryOperatorFunction<Capacity, Capacity> for SmallestCapacity {
+        fn product(first_argument: &Capacity, second_argument: &Capacity) -> Capacity {
+            Capacity {
+                value: first_argument.value.min(second_argument.value),
+            }
+        }
+    }
+
+    #[test]
+    fn multiply_matrices_with_max_min_semiring_over_custom_value_type() {
+        let context = Context::init_default().unwrap();
+
+        let largest_capacity =
+            CustomBinaryOperator::<Capacity>::new(context.clone(), LargestCapacity).unwrap();
+        let smallest_capacity =
+            CustomBinaryOperator::<Capacity>::new(context.clone(), SmallestCapacity).unwrap();
+        let additive_monoid = CustomMonoid::new(
+            context.clone(),
+            largest_capacity,
+            Capacity { value: 0 },
+            Some(Capacity { value: u32::MAX }),
+        )
+        .unwrap();
+        let semiring =
+            CustomSemiring::new(context.clone(), additive_monoid, smallest_capacity).unwrap();
+
+        // Each product element is the largest bottleneck capacity of the paths with two edges
+        let mut adjacency =
+            SparseMatrix::<Capacity>::new(context.clone(), Size::new(3, 3)).unwrap();
+        adjacency.set_value(0, 1, Capacity { value: 3 }).unwrap();
+        adjacency.set_value(0, 2, Capacity { value: 1 }).unwrap();
+        adjacency.set_value(1, 2, Capacity { value: 8 }).unwrap();
+        adjacency.set_value(1, 1, Capacity { value: 5 }).unwrap();
+        let mut product = SparseMatrix::<Capacity>::new(context.clone(), Size::new(3, 3)).unwrap();
+
+        MatrixMultiplicationOperator::new()
+            .apply(
+                &adjacency,
+                &semiring,
+                &adjacency,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            product.element_value(&0, &1).unwrap(),
+            Some(Capacity { value: 3 })
+        );
+        assert_eq!(
+            product.element_value(&0, &2).unwrap(),
+            Some(Capacity { value: 3 })
+        );
+        assert_eq!(
+            product.element_value(&1, &2).unwrap(),
+            Some(Capacity { value: 5 })
+        );
+        assert_eq!(product.element_value(&2, &2).unwrap(), None);
+    }
+
+    #[test]
+    fn kronecker_product_with_semiring_of_built_in_operators() {
+        let context = Context::init_default().unwrap();
+
+        let additive_monoid =
+            CustomMonoid::new(context.clone(), Max::<u8>::new(), u8::MIN, Some(u8::MAX)).unwrap();
+        let semiring =
+            CustomSemiring::new(context.clone(), additive_monoid, Min::<u8>::new()).unwrap();
+
+        let multiplier = SparseMatrix::<u8>::from_element_list(
+            context.clone(),
+            Size::new(1, 2),
+            MatrixElementList::from_element_vector(vec![(0, 0, 4).into(), (0, 1, 9).into()]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseMatrix::<u8>::from_element_list(
+            context.clone(),
+            Size::new(1, 1),
+            MatrixElementList::from_element_vector(vec![(0, 0, 6).into()]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+        let mut product = SparseMatrix::<u8>::new(context.clone(), Size::new(1, 2)).unwrap();
+
+        SemiringKroneckerProductOperator::new()
+            .apply(
+                &multiplier,
+                &semiring,
+                &multiplicant,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.element_value(&0, &0).unwrap(), Some(4));
+        assert_eq!(product.element_value(&0, &1).unwrap(), Some(6));
+    }
+}
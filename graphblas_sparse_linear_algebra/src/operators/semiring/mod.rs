@@ -1,3 +1,5 @@
+mod custom_semiring;
 mod semiring;
 
+pub use custom_semiring::*;
 pub use semiring::*;
@@ -1,6 +1,11 @@
 use std::marker::PhantomData;
 
-use crate::value_type::utilities_to_implement_traits_for_all_value_types::implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean;
+use crate::value_type::utilities_to_implement_traits_for_all_value_types::{
+    implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types,
+    implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types,
+    implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean,
+    implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers,
+};
 use crate::value_type::ValueType;
 
 use crate::graphblas_bindings::*;
@@ -12,6 +17,14 @@ where
     fn graphblas_type(&self) -> GrB_Semiring;
 }
 
+/// A semiring with the domain of its product, such that typed operators can require a product collection of that domain.
+pub trait TypedSemiring<EvaluationDomain>: Semiring<EvaluationDomain>
+where
+    EvaluationDomain: ValueType,
+{
+    type OutputDomain: ValueType;
+}
+
 macro_rules! define_semiring {
     ($semiring:ident) => {
         #[derive(Debug, Clone)]
@@ -24,26 +37,12 @@ macro_rules! define_semiring {
     };
 }
 
-// macro_rules! implement_semiring {
-//     ($semiring:ident, $graphblas_operator:ident, $evaluation_domain:ty) => {
-//         impl $semiring<$evaluation_domain> {
-//             pub fn new() -> Self {
-//                 Self {
-//                     _evaluation_domain: PhantomData,
-//                 }
-//             }
-//         }
-
-//         impl Semiring<$evaluation_domain> for $semiring<$evaluation_domain> {
-//             fn graphblas_type(&self) -> GrB_Semiring {
-//                 unsafe { $graphblas_operator }
-//             }
-//         }
-//     };
-// }
-
+// The product is in the evaluation domain, unless an output domain is given
 macro_rules! implement_semiring {
     ($operator_name:ident, $graphblas_operator_trait_name:ident) => {
+        implement_semiring!($operator_name, $graphblas_operator_trait_name, T);
+    };
+    ($operator_name:ident, $graphblas_operator_trait_name:ident, $output_domain:ty) => {
         pub trait $graphblas_operator_trait_name<T: ValueType> {
             fn graphblas_type() -> GrB_Semiring;
         }
@@ -54,6 +53,12 @@ macro_rules! implement_semiring {
             }
         }
 
+        impl<T: ValueType + $graphblas_operator_trait_name<T>> TypedSemiring<T>
+            for $operator_name<T>
+        {
+            type OutputDomain = $output_domain;
+        }
+
         impl<T: ValueType> $operator_name<T> {
             pub fn new() -> Self {
                 Self {
@@ -77,6 +82,10 @@ macro_rules! implement_typed_semiring {
     };
 }
 
+// Multiplicative operators named Is... return one or zero in the evaluation domain,
+// the other comparisons return a boolean.
+// The One multiplicative operator makes a PAIR semiring, which only depends on the structure of the arguments.
+
 // MAX
 
 define_semiring!(MaxFirst);
@@ -95,12 +104,13 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_MAX_SECOND_SEMIRING
 );
 
-// define_semiring!(MaxOne);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxOne,
-//     GrB_MAX_ONEB_SEMIRING
-// );
+define_semiring!(MaxOne);
+implement_semiring!(MaxOne, MaxOneTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxOneTyped,
+    GxB_MAX_PAIR
+);
 
 define_semiring!(MaxMin);
 implement_semiring!(MaxMin, MaxMinTyped);
@@ -110,12 +120,13 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_MAX_MIN_SEMIRING
 );
 
-// define_semiring!(MaxMax);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxMax,
-//     GrB_MAX_MAX_SEMIRING
-// );
+define_semiring!(MaxMax);
+implement_semiring!(MaxMax, MaxMaxTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxMaxTyped,
+    GxB_MAX_MAX
+);
 
 define_semiring!(MaxPlus);
 implement_semiring!(MaxPlus, MaxPlusTyped);
@@ -125,19 +136,21 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_MAX_PLUS_SEMIRING
 );
 
-// define_semiring!(MaxMaxus);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxMaxus,
-//     GrB_MAX_MINUS_SEMIRING
-// );
+define_semiring!(MaxMinus);
+implement_semiring!(MaxMinus, MaxMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxMinusTyped,
+    GxB_MAX_MINUS
+);
 
-// define_semiring!(MaxRightMaxus);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxRightMaxus,
-//     GrB_MAX_RMINUS_SEMIRING
-// );
+define_semiring!(MaxRightMinus);
+implement_semiring!(MaxRightMinus, MaxRightMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxRightMinusTyped,
+    GxB_MAX_RMINUS
+);
 
 define_semiring!(MaxTimes);
 implement_semiring!(MaxTimes, MaxTimesTyped);
@@ -147,82 +160,93 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_MAX_TIMES_SEMIRING
 );
 
-// define_semiring!(MaxDivide);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxDivide,
-//     GrB_MAX_DIVIDE_SEMIRING
-// );
-
-// define_semiring!(MaxRightDivide);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxRightDivide,
-//     GrB_MAX_RDIVIDE_SEMIRING
-// );
-
-// define_semiring!(MaxIsEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxIsEqualTo,
-//     GrB_MAX_ISEQ_SEMIRING
-// );
-
-// define_semiring!(MaxIsNotEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxIsNotEqualTo,
-//     GrB_MAX_ISNE_SEMIRING
-// );
-
-// define_semiring!(MaxIsLessThan);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxIsLessThan,
-//     GrB_MAX_ISLT_SEMIRING
-// );
-
-// define_semiring!(MaxIsGreaterThan);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxIsGreaterThan,
-//     GrB_MAX_ISGT_SEMIRING
-// );
-
-// define_semiring!(MaxIsLessThanOrEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxIsLessThanOrEqualTo,
-//     GrB_MAX_ISLE_SEMIRING
-// );
-
-// define_semiring!(MaxIsGreaterThanOrEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxIsGreaterThanOrEqualTo,
-//     GrB_MAX_ISGE_SEMIRING
-// );
-
-// define_semiring!(MaxLogicalOr);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxLogicalOr,
-//     GrB_MAX_LOR_SEMIRING
-// );
-
-// define_semiring!(MaxLogicalAnd);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxLogicalAnd,
-//     GrB_MAX_LAND_SEMIRING
-// );
-
-// define_semiring!(MaxLogicalExclusiveOr);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MaxLogicalExclusiveOr,
-//     GrB_MAX_LXOR_SEMIRING
-// );
+define_semiring!(MaxDivide);
+implement_semiring!(MaxDivide, MaxDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxDivideTyped,
+    GxB_MAX_DIV
+);
+
+define_semiring!(MaxRightDivide);
+implement_semiring!(MaxRightDivide, MaxRightDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxRightDivideTyped,
+    GxB_MAX_RDIV
+);
+
+define_semiring!(MaxIsEqualTo);
+implement_semiring!(MaxIsEqualTo, MaxIsEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxIsEqualToTyped,
+    GxB_MAX_ISEQ
+);
+
+define_semiring!(MaxIsNotEqualTo);
+implement_semiring!(MaxIsNotEqualTo, MaxIsNotEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxIsNotEqualToTyped,
+    GxB_MAX_ISNE
+);
+
+define_semiring!(MaxIsLessThan);
+implement_semiring!(MaxIsLessThan, MaxIsLessThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxIsLessThanTyped,
+    GxB_MAX_ISLT
+);
+
+define_semiring!(MaxIsGreaterThan);
+implement_semiring!(MaxIsGreaterThan, MaxIsGreaterThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxIsGreaterThanTyped,
+    GxB_MAX_ISGT
+);
+
+define_semiring!(MaxIsLessThanOrEqualTo);
+implement_semiring!(MaxIsLessThanOrEqualTo, MaxIsLessThanOrEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxIsLessThanOrEqualToTyped,
+    GxB_MAX_ISLE
+);
+
+define_semiring!(MaxIsGreaterThanOrEqualTo);
+implement_semiring!(MaxIsGreaterThanOrEqualTo, MaxIsGreaterThanOrEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxIsGreaterThanOrEqualToTyped,
+    GxB_MAX_ISGE
+);
+
+define_semiring!(MaxLogicalOr);
+implement_semiring!(MaxLogicalOr, MaxLogicalOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxLogicalOrTyped,
+    GxB_MAX_LOR
+);
+
+define_semiring!(MaxLogicalAnd);
+implement_semiring!(MaxLogicalAnd, MaxLogicalAndTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxLogicalAndTyped,
+    GxB_MAX_LAND
+);
+
+define_semiring!(MaxLogicalExclusiveOr);
+implement_semiring!(MaxLogicalExclusiveOr, MaxLogicalExclusiveOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MaxLogicalExclusiveOrTyped,
+    GxB_MAX_LXOR
+);
 
 // MIN
 
@@ -242,19 +266,21 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_MIN_SECOND_SEMIRING
 );
 
-// define_semiring!(MinOne);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinOne,
-//     GrB_MIN_ONEB_SEMIRING
-// );
+define_semiring!(MinOne);
+implement_semiring!(MinOne, MinOneTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinOneTyped,
+    GxB_MIN_PAIR
+);
 
-// define_semiring!(MinMIN);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinSecond,
-//     GrB_MIN_MIN_SEMIRING
-// );
+define_semiring!(MinMin);
+implement_semiring!(MinMin, MinMinTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinMinTyped,
+    GxB_MIN_MIN
+);
 
 define_semiring!(MinMax);
 implement_semiring!(MinMax, MinMaxTyped);
@@ -272,19 +298,21 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_MIN_PLUS_SEMIRING
 );
 
-// define_semiring!(MinMinus);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinMinus,
-//     GrB_MIN_MINUS_SEMIRING
-// );
+define_semiring!(MinMinus);
+implement_semiring!(MinMinus, MinMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinMinusTyped,
+    GxB_MIN_MINUS
+);
 
-// define_semiring!(MinRightMinus);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinRightMinus,
-//     GrB_MIN_RMINUS_SEMIRING
-// );
+define_semiring!(MinRightMinus);
+implement_semiring!(MinRightMinus, MinRightMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinRightMinusTyped,
+    GxB_MIN_RMINUS
+);
 
 define_semiring!(MinTimes);
 implement_semiring!(MinTimes, MinTimesTyped);
@@ -294,140 +322,159 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_MIN_TIMES_SEMIRING
 );
 
-// define_semiring!(MinDivide);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinDivide,
-//     GrB_MIN_DIVIDE_SEMIRING
-// );
-
-// define_semiring!(MinRightDivide);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinRightDivide,
-//     GrB_MIN_RDIVIDE_SEMIRING
-// );
-
-// define_semiring!(MinIsEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinIsEqualTo,
-//     GrB_MIN_ISEQ_SEMIRING
-// );
-
-// define_semiring!(MinIsNotEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinIsNotEqualTo,
-//     GrB_MIN_ISNE_SEMIRING
-// );
-
-// define_semiring!(MinIsLessThan);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinIsLessThan,
-//     GrB_MIN_ISLT_SEMIRING
-// );
-
-// define_semiring!(MinIsGreaterThan);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinIsGreaterThan,
-//     GrB_MIN_ISGT_SEMIRING
-// );
-
-// define_semiring!(MinIsLessThanOrEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinIsLessThanOrEqualTo,
-//     GrB_MIN_ISLE_SEMIRING
-// );
-
-// define_semiring!(MinIsGreaterThanOrEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinIsGreaterThanOrEqualTo,
-//     GrB_MIN_ISGE_SEMIRING
-// );
-
-// define_semiring!(MinLogicalOr);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinLogicalOr,
-//     GrB_MIN_LOR_SEMIRING
-// );
-
-// define_semiring!(MinLogicalAnd);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinLogicalAnd,
-//     GrB_MIN_LAND_SEMIRING
-// );
-
-// define_semiring!(MinLogicalExclusiveOr);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     MinLogicalExclusiveOr,
-//     GrB_MIN_LXOR_SEMIRING
-// );
+define_semiring!(MinDivide);
+implement_semiring!(MinDivide, MinDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinDivideTyped,
+    GxB_MIN_DIV
+);
+
+define_semiring!(MinRightDivide);
+implement_semiring!(MinRightDivide, MinRightDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinRightDivideTyped,
+    GxB_MIN_RDIV
+);
+
+define_semiring!(MinIsEqualTo);
+implement_semiring!(MinIsEqualTo, MinIsEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinIsEqualToTyped,
+    GxB_MIN_ISEQ
+);
+
+define_semiring!(MinIsNotEqualTo);
+implement_semiring!(MinIsNotEqualTo, MinIsNotEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinIsNotEqualToTyped,
+    GxB_MIN_ISNE
+);
+
+define_semiring!(MinIsLessThan);
+implement_semiring!(MinIsLessThan, MinIsLessThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinIsLessThanTyped,
+    GxB_MIN_ISLT
+);
+
+define_semiring!(MinIsGreaterThan);
+implement_semiring!(MinIsGreaterThan, MinIsGreaterThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinIsGreaterThanTyped,
+    GxB_MIN_ISGT
+);
+
+define_semiring!(MinIsLessThanOrEqualTo);
+implement_semiring!(MinIsLessThanOrEqualTo, MinIsLessThanOrEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinIsLessThanOrEqualToTyped,
+    GxB_MIN_ISLE
+);
+
+define_semiring!(MinIsGreaterThanOrEqualTo);
+implement_semiring!(MinIsGreaterThanOrEqualTo, MinIsGreaterThanOrEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinIsGreaterThanOrEqualToTyped,
+    GxB_MIN_ISGE
+);
+
+define_semiring!(MinLogicalOr);
+implement_semiring!(MinLogicalOr, MinLogicalOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinLogicalOrTyped,
+    GxB_MIN_LOR
+);
+
+define_semiring!(MinLogicalAnd);
+implement_semiring!(MinLogicalAnd, MinLogicalAndTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinLogicalAndTyped,
+    GxB_MIN_LAND
+);
+
+define_semiring!(MinLogicalExclusiveOr);
+implement_semiring!(MinLogicalExclusiveOr, MinLogicalExclusiveOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    MinLogicalExclusiveOrTyped,
+    GxB_MIN_LXOR
+);
 
 // PLUS
 
-// define_semiring!(PlusFirst);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusFirst,
-//     GrB_PLUS_FIRST_SEMIRING
-// );
-
-// define_semiring!(PlusSecond);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusSecond,
-//     GrB_PLUS_SECOND_SEMIRING
-// );
-
-// define_semiring!(PlusOne);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusOne,
-//     GrB_PLUS_ONEB_SEMIRING
-// );
-
-// define_semiring!(PlusPLUS);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusSecond,
-//     GrB_PLUS_PLUS_SEMIRING
-// );
-
-// define_semiring!(PlusMax);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusMax,
-//     GrB_PLUS_MAX_SEMIRING
-// );
-
-// define_semiring!(PlusPlus);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusPlus,
-//     GrB_PLUS_PLUS_SEMIRING
-// );
-
-// define_semiring!(PlusPlus);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusPlus,
-//     GrB_PLUS_PLUS_SEMIRING
-// );
-
-// define_semiring!(PlusRightPlusus);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusRightPlusus,
-//     GrB_PLUS_RPLUSUS_SEMIRING
-// );
+define_semiring!(PlusFirst);
+implement_semiring!(PlusFirst, PlusFirstTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusFirstTyped,
+    GxB_PLUS_FIRST
+);
+
+define_semiring!(PlusSecond);
+implement_semiring!(PlusSecond, PlusSecondTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusSecondTyped,
+    GxB_PLUS_SECOND
+);
+
+define_semiring!(PlusOne);
+implement_semiring!(PlusOne, PlusOneTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusOneTyped,
+    GxB_PLUS_PAIR
+);
+
+define_semiring!(PlusMin);
+implement_semiring!(PlusMin, PlusMinTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusMinTyped,
+    GrB_PLUS_MIN_SEMIRING
+);
+
+define_semiring!(PlusMax);
+implement_semiring!(PlusMax, PlusMaxTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusMaxTyped,
+    GxB_PLUS_MAX
+);
+
+define_semiring!(PlusPlus);
+implement_semiring!(PlusPlus, PlusPlusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusPlusTyped,
+    GxB_PLUS_PLUS
+);
+
+define_semiring!(PlusMinus);
+implement_semiring!(PlusMinus, PlusMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusMinusTyped,
+    GxB_PLUS_MINUS
+);
+
+define_semiring!(PlusRightMinus);
+implement_semiring!(PlusRightMinus, PlusRightMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusRightMinusTyped,
+    GxB_PLUS_RMINUS
+);
 
 define_semiring!(PlusTimes);
 implement_semiring!(PlusTimes, PlusTimesTyped);
@@ -437,236 +484,1577 @@ implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_typ
     GrB_PLUS_TIMES_SEMIRING
 );
 
-// define_semiring!(PlusDivide);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusDivide,
-//     GrB_PLUS_DIVIDE_SEMIRING
-// );
-
-// define_semiring!(PlusRightDivide);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusRightDivide,
-//     GrB_PLUS_RDIVIDE_SEMIRING
-// );
-
-// define_semiring!(PlusIsEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusIsEqualTo,
-//     GrB_PLUS_ISEQ_SEMIRING
-// );
-
-// define_semiring!(PlusIsNotEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusIsNotEqualTo,
-//     GrB_PLUS_ISNE_SEMIRING
-// );
-
-// define_semiring!(PlusIsLessThan);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusIsLessThan,
-//     GrB_PLUS_ISLT_SEMIRING
-// );
-
-// define_semiring!(PlusIsGreaterThan);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusIsGreaterThan,
-//     GrB_PLUS_ISGT_SEMIRING
-// );
-
-// define_semiring!(PlusIsLessThanOrEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusIsLessThanOrEqualTo,
-//     GrB_PLUS_ISLE_SEMIRING
-// );
-
-// define_semiring!(PlusIsGreaterThanOrEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusIsGreaterThanOrEqualTo,
-//     GrB_PLUS_ISGE_SEMIRING
-// );
-
-// define_semiring!(PlusLogicalOr);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusLogicalOr,
-//     GrB_PLUS_LOR_SEMIRING
-// );
-
-// define_semiring!(PlusLogicalAnd);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusLogicalAnd,
-//     GrB_PLUS_LAND_SEMIRING
-// );
-
-// define_semiring!(PlusLogicalExclusiveOr);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     PlusLogicalExclusiveOr,
-//     GrB_MIN_LXOR_SEMIRING
-// );
+define_semiring!(PlusDivide);
+implement_semiring!(PlusDivide, PlusDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusDivideTyped,
+    GxB_PLUS_DIV
+);
+
+define_semiring!(PlusRightDivide);
+implement_semiring!(PlusRightDivide, PlusRightDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusRightDivideTyped,
+    GxB_PLUS_RDIV
+);
+
+define_semiring!(PlusIsEqualTo);
+implement_semiring!(PlusIsEqualTo, PlusIsEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusIsEqualToTyped,
+    GxB_PLUS_ISEQ
+);
+
+define_semiring!(PlusIsNotEqualTo);
+implement_semiring!(PlusIsNotEqualTo, PlusIsNotEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusIsNotEqualToTyped,
+    GxB_PLUS_ISNE
+);
+
+define_semiring!(PlusIsLessThan);
+implement_semiring!(PlusIsLessThan, PlusIsLessThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusIsLessThanTyped,
+    GxB_PLUS_ISLT
+);
+
+define_semiring!(PlusIsGreaterThan);
+implement_semiring!(PlusIsGreaterThan, PlusIsGreaterThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusIsGreaterThanTyped,
+    GxB_PLUS_ISGT
+);
+
+define_semiring!(PlusIsLessThanOrEqualTo);
+implement_semiring!(PlusIsLessThanOrEqualTo, PlusIsLessThanOrEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusIsLessThanOrEqualToTyped,
+    GxB_PLUS_ISLE
+);
+
+define_semiring!(PlusIsGreaterThanOrEqualTo);
+implement_semiring!(PlusIsGreaterThanOrEqualTo, PlusIsGreaterThanOrEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusIsGreaterThanOrEqualToTyped,
+    GxB_PLUS_ISGE
+);
+
+define_semiring!(PlusLogicalOr);
+implement_semiring!(PlusLogicalOr, PlusLogicalOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusLogicalOrTyped,
+    GxB_PLUS_LOR
+);
+
+define_semiring!(PlusLogicalAnd);
+implement_semiring!(PlusLogicalAnd, PlusLogicalAndTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusLogicalAndTyped,
+    GxB_PLUS_LAND
+);
+
+define_semiring!(PlusLogicalExclusiveOr);
+implement_semiring!(PlusLogicalExclusiveOr, PlusLogicalExclusiveOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    PlusLogicalExclusiveOrTyped,
+    GxB_PLUS_LXOR
+);
 
 // TIMES
 
-// define_semiring!(TimesFirst);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesFirst,
-//     GrB_TIMES_FIRST_SEMIRING
-// );
-
-// define_semiring!(TimesSecond);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesSecond,
-//     GrB_TIMES_SECOND_SEMIRING
-// );
-
-// define_semiring!(TimesOne);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesOne,
-//     GrB_TIMES_ONEB_SEMIRING
-// );
-
-// define_semiring!(TimesTIMES);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesSecond,
-//     GrB_TIMES_TIMES_SEMIRING
-// );
-
-// define_semiring!(TimesMax);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesMax,
-//     GrB_TIMES_MAX_SEMIRING
-// );
-
-// define_semiring!(TimesTimes);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesTimes,
-//     GrB_TIMES_TIMES_SEMIRING
-// );
-
-// define_semiring!(TimesTimes);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesTimes,
-//     GrB_TIMES_TIMES_SEMIRING
-// );
-
-// define_semiring!(TimesRightTimesus);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesRightTimesus,
-//     GrB_TIMES_RTIMESUS_SEMIRING
-// );
-
-// define_semiring!(TimesTimes);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesTimes,
-//     GrB_TIMES_TIMES_SEMIRING
-// );
-
-// define_semiring!(TimesDivide);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesDivide,
-//     GrB_TIMES_DIVIDE_SEMIRING
-// );
-
-// define_semiring!(TimesRightDivide);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesRightDivide,
-//     GrB_TIMES_RDIVIDE_SEMIRING
-// );
-
-// define_semiring!(TimesIsEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesIsEqualTo,
-//     GrB_TIMES_ISEQ_SEMIRING
-// );
-
-// define_semiring!(TimesIsNotEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesIsNotEqualTo,
-//     GrB_TIMES_ISNE_SEMIRING
-// );
-
-// define_semiring!(TimesIsLessThan);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesIsLessThan,
-//     GrB_TIMES_ISLT_SEMIRING
-// );
-
-// define_semiring!(TimesIsGreaterThan);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesIsGreaterThan,
-//     GrB_TIMES_ISGT_SEMIRING
-// );
-
-// define_semiring!(TimesIsLessThanOrEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesIsLessThanOrEqualTo,
-//     GrB_TIMES_ISLE_SEMIRING
-// );
-
-// define_semiring!(TimesIsGreaterThanOrEqualTo);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesIsGreaterThanOrEqualTo,
-//     GrB_TIMES_ISGE_SEMIRING
-// );
-
-// define_semiring!(TimesLogicalOr);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesLogicalOr,
-//     GrB_TIMES_LOR_SEMIRING
-// );
-
-// define_semiring!(TimesLogicalAnd);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesLogicalAnd,
-//     GrB_PLUS_LAND_SEMIRING
-// );
-
-// define_semiring!(TimesLogicalExclusiveOr);
-// implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
-//     implement_semiring,
-//     TimesLogicalExclusiveOr,
-//     GrB_PLUS_LXOR_SEMIRING
-// );
+define_semiring!(TimesFirst);
+implement_semiring!(TimesFirst, TimesFirstTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesFirstTyped,
+    GxB_TIMES_FIRST
+);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+define_semiring!(TimesSecond);
+implement_semiring!(TimesSecond, TimesSecondTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesSecondTyped,
+    GxB_TIMES_SECOND
+);
 
-    #[test]
-    fn test_new_semiring() {
-        let semiring = PlusTimes::<i8>::new();
+define_semiring!(TimesOne);
+implement_semiring!(TimesOne, TimesOneTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesOneTyped,
+    GxB_TIMES_PAIR
+);
 
-        unsafe {
-            assert_eq!(semiring.graphblas_type(), GrB_PLUS_TIMES_SEMIRING_INT8);
-            assert_ne!(semiring.graphblas_type(), GrB_PLUS_TIMES_SEMIRING_INT16);
-        }
+define_semiring!(TimesMin);
+implement_semiring!(TimesMin, TimesMinTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesMinTyped,
+    GxB_TIMES_MIN
+);
+
+define_semiring!(TimesMax);
+implement_semiring!(TimesMax, TimesMaxTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesMaxTyped,
+    GxB_TIMES_MAX
+);
+
+define_semiring!(TimesPlus);
+implement_semiring!(TimesPlus, TimesPlusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesPlusTyped,
+    GxB_TIMES_PLUS
+);
+
+define_semiring!(TimesMinus);
+implement_semiring!(TimesMinus, TimesMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesMinusTyped,
+    GxB_TIMES_MINUS
+);
+
+define_semiring!(TimesRightMinus);
+implement_semiring!(TimesRightMinus, TimesRightMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesRightMinusTyped,
+    GxB_TIMES_RMINUS
+);
+
+define_semiring!(TimesTimes);
+implement_semiring!(TimesTimes, TimesTimesTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesTimesTyped,
+    GxB_TIMES_TIMES
+);
+
+define_semiring!(TimesDivide);
+implement_semiring!(TimesDivide, TimesDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesDivideTyped,
+    GxB_TIMES_DIV
+);
+
+define_semiring!(TimesRightDivide);
+implement_semiring!(TimesRightDivide, TimesRightDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesRightDivideTyped,
+    GxB_TIMES_RDIV
+);
+
+define_semiring!(TimesIsEqualTo);
+implement_semiring!(TimesIsEqualTo, TimesIsEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesIsEqualToTyped,
+    GxB_TIMES_ISEQ
+);
+
+define_semiring!(TimesIsNotEqualTo);
+implement_semiring!(TimesIsNotEqualTo, TimesIsNotEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesIsNotEqualToTyped,
+    GxB_TIMES_ISNE
+);
+
+define_semiring!(TimesIsLessThan);
+implement_semiring!(TimesIsLessThan, TimesIsLessThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesIsLessThanTyped,
+    GxB_TIMES_ISLT
+);
+
+define_semiring!(TimesIsGreaterThan);
+implement_semiring!(TimesIsGreaterThan, TimesIsGreaterThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesIsGreaterThanTyped,
+    GxB_TIMES_ISGT
+);
+
+define_semiring!(TimesIsLessThanOrEqualTo);
+implement_semiring!(TimesIsLessThanOrEqualTo, TimesIsLessThanOrEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesIsLessThanOrEqualToTyped,
+    GxB_TIMES_ISLE
+);
+
+define_semiring!(TimesIsGreaterThanOrEqualTo);
+implement_semiring!(
+    TimesIsGreaterThanOrEqualTo,
+    TimesIsGreaterThanOrEqualToTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesIsGreaterThanOrEqualToTyped,
+    GxB_TIMES_ISGE
+);
+
+define_semiring!(TimesLogicalOr);
+implement_semiring!(TimesLogicalOr, TimesLogicalOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesLogicalOrTyped,
+    GxB_TIMES_LOR
+);
+
+define_semiring!(TimesLogicalAnd);
+implement_semiring!(TimesLogicalAnd, TimesLogicalAndTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesLogicalAndTyped,
+    GxB_TIMES_LAND
+);
+
+define_semiring!(TimesLogicalExclusiveOr);
+implement_semiring!(TimesLogicalExclusiveOr, TimesLogicalExclusiveOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    TimesLogicalExclusiveOrTyped,
+    GxB_TIMES_LXOR
+);
+
+// ANY
+// The any monoid returns any of the products, which lets GraphBLAS stop as soon as one product is found.
+
+define_semiring!(AnyFirst);
+implement_semiring!(AnyFirst, AnyFirstTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyFirstTyped,
+    GxB_ANY_FIRST
+);
+
+define_semiring!(AnySecond);
+implement_semiring!(AnySecond, AnySecondTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnySecondTyped,
+    GxB_ANY_SECOND
+);
+
+define_semiring!(AnyOne);
+implement_semiring!(AnyOne, AnyOneTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyOneTyped,
+    GxB_ANY_PAIR
+);
+
+define_semiring!(AnyMin);
+implement_semiring!(AnyMin, AnyMinTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyMinTyped,
+    GxB_ANY_MIN
+);
+
+define_semiring!(AnyMax);
+implement_semiring!(AnyMax, AnyMaxTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyMaxTyped,
+    GxB_ANY_MAX
+);
+
+define_semiring!(AnyPlus);
+implement_semiring!(AnyPlus, AnyPlusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyPlusTyped,
+    GxB_ANY_PLUS
+);
+
+define_semiring!(AnyMinus);
+implement_semiring!(AnyMinus, AnyMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyMinusTyped,
+    GxB_ANY_MINUS
+);
+
+define_semiring!(AnyRightMinus);
+implement_semiring!(AnyRightMinus, AnyRightMinusTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyRightMinusTyped,
+    GxB_ANY_RMINUS
+);
+
+define_semiring!(AnyTimes);
+implement_semiring!(AnyTimes, AnyTimesTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyTimesTyped,
+    GxB_ANY_TIMES
+);
+
+define_semiring!(AnyDivide);
+implement_semiring!(AnyDivide, AnyDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyDivideTyped,
+    GxB_ANY_DIV
+);
+
+define_semiring!(AnyRightDivide);
+implement_semiring!(AnyRightDivide, AnyRightDivideTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyRightDivideTyped,
+    GxB_ANY_RDIV
+);
+
+define_semiring!(AnyIsEqualTo);
+implement_semiring!(AnyIsEqualTo, AnyIsEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyIsEqualToTyped,
+    GxB_ANY_ISEQ
+);
+
+define_semiring!(AnyIsNotEqualTo);
+implement_semiring!(AnyIsNotEqualTo, AnyIsNotEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyIsNotEqualToTyped,
+    GxB_ANY_ISNE
+);
+
+define_semiring!(AnyIsLessThan);
+implement_semiring!(AnyIsLessThan, AnyIsLessThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyIsLessThanTyped,
+    GxB_ANY_ISLT
+);
+
+define_semiring!(AnyIsGreaterThan);
+implement_semiring!(AnyIsGreaterThan, AnyIsGreaterThanTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyIsGreaterThanTyped,
+    GxB_ANY_ISGT
+);
+
+define_semiring!(AnyIsLessThanOrEqualTo);
+implement_semiring!(AnyIsLessThanOrEqualTo, AnyIsLessThanOrEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyIsLessThanOrEqualToTyped,
+    GxB_ANY_ISLE
+);
+
+define_semiring!(AnyIsGreaterThanOrEqualTo);
+implement_semiring!(AnyIsGreaterThanOrEqualTo, AnyIsGreaterThanOrEqualToTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyIsGreaterThanOrEqualToTyped,
+    GxB_ANY_ISGE
+);
+
+define_semiring!(AnyLogicalOr);
+implement_semiring!(AnyLogicalOr, AnyLogicalOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyLogicalOrTyped,
+    GxB_ANY_LOR
+);
+
+define_semiring!(AnyLogicalAnd);
+implement_semiring!(AnyLogicalAnd, AnyLogicalAndTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyLogicalAndTyped,
+    GxB_ANY_LAND
+);
+
+define_semiring!(AnyLogicalExclusiveOr);
+implement_semiring!(AnyLogicalExclusiveOr, AnyLogicalExclusiveOrTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyLogicalExclusiveOrTyped,
+    GxB_ANY_LXOR
+);
+
+define_semiring!(AnyEqualTo);
+implement_semiring!(AnyEqualTo, AnyEqualToTyped, bool);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyEqualToTyped,
+    GxB_ANY_EQ
+);
+
+define_semiring!(AnyNotEqualTo);
+implement_semiring!(AnyNotEqualTo, AnyNotEqualToTyped, bool);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    AnyNotEqualToTyped,
+    GxB_ANY_NE
+);
+
+define_semiring!(AnyLessThan);
+implement_semiring!(AnyLessThan, AnyLessThanTyped, bool);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyLessThanTyped,
+    GxB_ANY_LT
+);
+
+define_semiring!(AnyGreaterThan);
+implement_semiring!(AnyGreaterThan, AnyGreaterThanTyped, bool);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyGreaterThanTyped,
+    GxB_ANY_GT
+);
+
+define_semiring!(AnyLessThanOrEqualTo);
+implement_semiring!(AnyLessThanOrEqualTo, AnyLessThanOrEqualToTyped, bool);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyLessThanOrEqualToTyped,
+    GxB_ANY_LE
+);
+
+define_semiring!(AnyGreaterThanOrEqualTo);
+implement_semiring!(AnyGreaterThanOrEqualTo, AnyGreaterThanOrEqualToTyped, bool);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    AnyGreaterThanOrEqualToTyped,
+    GxB_ANY_GE
+);
+
+// LOGICAL OR
+
+define_semiring!(LogicalOrFirst);
+implement_semiring!(LogicalOrFirst, LogicalOrFirstTyped);
+implement_typed_semiring!(LogicalOrFirstTyped, GxB_LOR_FIRST_BOOL, bool);
+
+define_semiring!(LogicalOrSecond);
+implement_semiring!(LogicalOrSecond, LogicalOrSecondTyped);
+implement_typed_semiring!(LogicalOrSecondTyped, GxB_LOR_SECOND_BOOL, bool);
+
+define_semiring!(LogicalOrOne);
+implement_semiring!(LogicalOrOne, LogicalOrOneTyped);
+implement_typed_semiring!(LogicalOrOneTyped, GxB_LOR_PAIR_BOOL, bool);
+
+define_semiring!(LogicalOrLogicalOr);
+implement_semiring!(LogicalOrLogicalOr, LogicalOrLogicalOrTyped);
+implement_typed_semiring!(LogicalOrLogicalOrTyped, GxB_LOR_LOR_BOOL, bool);
+
+define_semiring!(LogicalOrLogicalAnd);
+implement_semiring!(LogicalOrLogicalAnd, LogicalOrLogicalAndTyped);
+implement_typed_semiring!(LogicalOrLogicalAndTyped, GrB_LOR_LAND_SEMIRING_BOOL, bool);
+
+define_semiring!(LogicalOrLogicalExclusiveOr);
+implement_semiring!(
+    LogicalOrLogicalExclusiveOr,
+    LogicalOrLogicalExclusiveOrTyped
+);
+implement_typed_semiring!(LogicalOrLogicalExclusiveOrTyped, GxB_LOR_LXOR_BOOL, bool);
+
+define_semiring!(LogicalOrEqualTo);
+implement_semiring!(LogicalOrEqualTo, LogicalOrEqualToTyped, bool);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalOrEqualToTyped,
+    GxB_LOR_EQ
+);
+
+define_semiring!(LogicalOrNotEqualTo);
+implement_semiring!(LogicalOrNotEqualTo, LogicalOrNotEqualToTyped, bool);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    LogicalOrNotEqualToTyped,
+    GxB_LOR_NE
+);
+
+define_semiring!(LogicalOrLessThan);
+implement_semiring!(LogicalOrLessThan, LogicalOrLessThanTyped, bool);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalOrLessThanTyped,
+    GxB_LOR_LT
+);
+
+define_semiring!(LogicalOrGreaterThan);
+implement_semiring!(LogicalOrGreaterThan, LogicalOrGreaterThanTyped, bool);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalOrGreaterThanTyped,
+    GxB_LOR_GT
+);
+
+define_semiring!(LogicalOrLessThanOrEqualTo);
+implement_semiring!(
+    LogicalOrLessThanOrEqualTo,
+    LogicalOrLessThanOrEqualToTyped,
+    bool
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalOrLessThanOrEqualToTyped,
+    GxB_LOR_LE
+);
+
+define_semiring!(LogicalOrGreaterThanOrEqualTo);
+implement_semiring!(
+    LogicalOrGreaterThanOrEqualTo,
+    LogicalOrGreaterThanOrEqualToTyped,
+    bool
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalOrGreaterThanOrEqualToTyped,
+    GxB_LOR_GE
+);
+
+// LOGICAL AND
+
+define_semiring!(LogicalAndFirst);
+implement_semiring!(LogicalAndFirst, LogicalAndFirstTyped);
+implement_typed_semiring!(LogicalAndFirstTyped, GxB_LAND_FIRST_BOOL, bool);
+
+define_semiring!(LogicalAndSecond);
+implement_semiring!(LogicalAndSecond, LogicalAndSecondTyped);
+implement_typed_semiring!(LogicalAndSecondTyped, GxB_LAND_SECOND_BOOL, bool);
+
+define_semiring!(LogicalAndOne);
+implement_semiring!(LogicalAndOne, LogicalAndOneTyped);
+implement_typed_semiring!(LogicalAndOneTyped, GxB_LAND_PAIR_BOOL, bool);
+
+define_semiring!(LogicalAndLogicalOr);
+implement_semiring!(LogicalAndLogicalOr, LogicalAndLogicalOrTyped);
+implement_typed_semiring!(LogicalAndLogicalOrTyped, GrB_LAND_LOR_SEMIRING_BOOL, bool);
+
+define_semiring!(LogicalAndLogicalAnd);
+implement_semiring!(LogicalAndLogicalAnd, LogicalAndLogicalAndTyped);
+implement_typed_semiring!(LogicalAndLogicalAndTyped, GxB_LAND_LAND_BOOL, bool);
+
+define_semiring!(LogicalAndLogicalExclusiveOr);
+implement_semiring!(
+    LogicalAndLogicalExclusiveOr,
+    LogicalAndLogicalExclusiveOrTyped
+);
+implement_typed_semiring!(LogicalAndLogicalExclusiveOrTyped, GxB_LAND_LXOR_BOOL, bool);
+
+define_semiring!(LogicalAndEqualTo);
+implement_semiring!(LogicalAndEqualTo, LogicalAndEqualToTyped, bool);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalAndEqualToTyped,
+    GxB_LAND_EQ
+);
+
+define_semiring!(LogicalAndNotEqualTo);
+implement_semiring!(LogicalAndNotEqualTo, LogicalAndNotEqualToTyped, bool);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    LogicalAndNotEqualToTyped,
+    GxB_LAND_NE
+);
+
+define_semiring!(LogicalAndLessThan);
+implement_semiring!(LogicalAndLessThan, LogicalAndLessThanTyped, bool);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalAndLessThanTyped,
+    GxB_LAND_LT
+);
+
+define_semiring!(LogicalAndGreaterThan);
+implement_semiring!(LogicalAndGreaterThan, LogicalAndGreaterThanTyped, bool);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalAndGreaterThanTyped,
+    GxB_LAND_GT
+);
+
+define_semiring!(LogicalAndLessThanOrEqualTo);
+implement_semiring!(
+    LogicalAndLessThanOrEqualTo,
+    LogicalAndLessThanOrEqualToTyped,
+    bool
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalAndLessThanOrEqualToTyped,
+    GxB_LAND_LE
+);
+
+define_semiring!(LogicalAndGreaterThanOrEqualTo);
+implement_semiring!(
+    LogicalAndGreaterThanOrEqualTo,
+    LogicalAndGreaterThanOrEqualToTyped,
+    bool
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalAndGreaterThanOrEqualToTyped,
+    GxB_LAND_GE
+);
+
+// LOGICAL EXCLUSIVE OR
+
+define_semiring!(LogicalExclusiveOrFirst);
+implement_semiring!(LogicalExclusiveOrFirst, LogicalExclusiveOrFirstTyped);
+implement_typed_semiring!(LogicalExclusiveOrFirstTyped, GxB_LXOR_FIRST_BOOL, bool);
+
+define_semiring!(LogicalExclusiveOrSecond);
+implement_semiring!(LogicalExclusiveOrSecond, LogicalExclusiveOrSecondTyped);
+implement_typed_semiring!(LogicalExclusiveOrSecondTyped, GxB_LXOR_SECOND_BOOL, bool);
+
+define_semiring!(LogicalExclusiveOrOne);
+implement_semiring!(LogicalExclusiveOrOne, LogicalExclusiveOrOneTyped);
+implement_typed_semiring!(LogicalExclusiveOrOneTyped, GxB_LXOR_PAIR_BOOL, bool);
+
+define_semiring!(LogicalExclusiveOrLogicalOr);
+implement_semiring!(
+    LogicalExclusiveOrLogicalOr,
+    LogicalExclusiveOrLogicalOrTyped
+);
+implement_typed_semiring!(LogicalExclusiveOrLogicalOrTyped, GxB_LXOR_LOR_BOOL, bool);
+
+define_semiring!(LogicalExclusiveOrLogicalAnd);
+implement_semiring!(
+    LogicalExclusiveOrLogicalAnd,
+    LogicalExclusiveOrLogicalAndTyped
+);
+implement_typed_semiring!(
+    LogicalExclusiveOrLogicalAndTyped,
+    GrB_LXOR_LAND_SEMIRING_BOOL,
+    bool
+);
+
+define_semiring!(LogicalExclusiveOrLogicalExclusiveOr);
+implement_semiring!(
+    LogicalExclusiveOrLogicalExclusiveOr,
+    LogicalExclusiveOrLogicalExclusiveOrTyped
+);
+implement_typed_semiring!(
+    LogicalExclusiveOrLogicalExclusiveOrTyped,
+    GxB_LXOR_LXOR_BOOL,
+    bool
+);
+
+define_semiring!(LogicalExclusiveOrEqualTo);
+implement_semiring!(
+    LogicalExclusiveOrEqualTo,
+    LogicalExclusiveOrEqualToTyped,
+    bool
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalExclusiveOrEqualToTyped,
+    GxB_LXOR_EQ
+);
+
+define_semiring!(LogicalExclusiveOrNotEqualTo);
+implement_semiring!(
+    LogicalExclusiveOrNotEqualTo,
+    LogicalExclusiveOrNotEqualToTyped,
+    bool
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    LogicalExclusiveOrNotEqualToTyped,
+    GxB_LXOR_NE
+);
+
+define_semiring!(LogicalExclusiveOrLessThan);
+implement_semiring!(
+    LogicalExclusiveOrLessThan,
+    LogicalExclusiveOrLessThanTyped,
+    bool
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalExclusiveOrLessThanTyped,
+    GxB_LXOR_LT
+);
+
+define_semiring!(LogicalExclusiveOrGreaterThan);
+implement_semiring!(
+    LogicalExclusiveOrGreaterThan,
+    LogicalExclusiveOrGreaterThanTyped,
+    bool
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalExclusiveOrGreaterThanTyped,
+    GxB_LXOR_GT
+);
+
+define_semiring!(LogicalExclusiveOrLessThanOrEqualTo);
+implement_semiring!(
+    LogicalExclusiveOrLessThanOrEqualTo,
+    LogicalExclusiveOrLessThanOrEqualToTyped,
+    bool
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalExclusiveOrLessThanOrEqualToTyped,
+    GxB_LXOR_LE
+);
+
+define_semiring!(LogicalExclusiveOrGreaterThanOrEqualTo);
+implement_semiring!(
+    LogicalExclusiveOrGreaterThanOrEqualTo,
+    LogicalExclusiveOrGreaterThanOrEqualToTyped,
+    bool
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalExclusiveOrGreaterThanOrEqualToTyped,
+    GxB_LXOR_GE
+);
+
+// LOGICAL EXCLUSIVE NOT OR, also known as EQ
+
+define_semiring!(LogicalExclusiveNotOrFirst);
+implement_semiring!(LogicalExclusiveNotOrFirst, LogicalExclusiveNotOrFirstTyped);
+implement_typed_semiring!(LogicalExclusiveNotOrFirstTyped, GxB_EQ_FIRST_BOOL, bool);
+
+define_semiring!(LogicalExclusiveNotOrSecond);
+implement_semiring!(
+    LogicalExclusiveNotOrSecond,
+    LogicalExclusiveNotOrSecondTyped
+);
+implement_typed_semiring!(LogicalExclusiveNotOrSecondTyped, GxB_EQ_SECOND_BOOL, bool);
+
+define_semiring!(LogicalExclusiveNotOrOne);
+implement_semiring!(LogicalExclusiveNotOrOne, LogicalExclusiveNotOrOneTyped);
+implement_typed_semiring!(LogicalExclusiveNotOrOneTyped, GxB_EQ_PAIR_BOOL, bool);
+
+define_semiring!(LogicalExclusiveNotOrLogicalOr);
+implement_semiring!(
+    LogicalExclusiveNotOrLogicalOr,
+    LogicalExclusiveNotOrLogicalOrTyped
+);
+implement_typed_semiring!(
+    LogicalExclusiveNotOrLogicalOrTyped,
+    GrB_LXNOR_LOR_SEMIRING_BOOL,
+    bool
+);
+
+define_semiring!(LogicalExclusiveNotOrLogicalAnd);
+implement_semiring!(
+    LogicalExclusiveNotOrLogicalAnd,
+    LogicalExclusiveNotOrLogicalAndTyped
+);
+implement_typed_semiring!(LogicalExclusiveNotOrLogicalAndTyped, GxB_EQ_LAND_BOOL, bool);
+
+define_semiring!(LogicalExclusiveNotOrLogicalExclusiveOr);
+implement_semiring!(
+    LogicalExclusiveNotOrLogicalExclusiveOr,
+    LogicalExclusiveNotOrLogicalExclusiveOrTyped
+);
+implement_typed_semiring!(
+    LogicalExclusiveNotOrLogicalExclusiveOrTyped,
+    GxB_EQ_LXOR_BOOL,
+    bool
+);
+
+define_semiring!(LogicalExclusiveNotOrEqualTo);
+implement_semiring!(
+    LogicalExclusiveNotOrEqualTo,
+    LogicalExclusiveNotOrEqualToTyped,
+    bool
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalExclusiveNotOrEqualToTyped,
+    GxB_EQ_EQ
+);
+
+define_semiring!(LogicalExclusiveNotOrNotEqualTo);
+implement_semiring!(
+    LogicalExclusiveNotOrNotEqualTo,
+    LogicalExclusiveNotOrNotEqualToTyped,
+    bool
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types_without_boolean!(
+    implement_typed_semiring,
+    LogicalExclusiveNotOrNotEqualToTyped,
+    GxB_EQ_NE
+);
+
+define_semiring!(LogicalExclusiveNotOrLessThan);
+implement_semiring!(
+    LogicalExclusiveNotOrLessThan,
+    LogicalExclusiveNotOrLessThanTyped,
+    bool
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalExclusiveNotOrLessThanTyped,
+    GxB_EQ_LT
+);
+
+define_semiring!(LogicalExclusiveNotOrGreaterThan);
+implement_semiring!(
+    LogicalExclusiveNotOrGreaterThan,
+    LogicalExclusiveNotOrGreaterThanTyped,
+    bool
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalExclusiveNotOrGreaterThanTyped,
+    GxB_EQ_GT
+);
+
+define_semiring!(LogicalExclusiveNotOrLessThanOrEqualTo);
+implement_semiring!(
+    LogicalExclusiveNotOrLessThanOrEqualTo,
+    LogicalExclusiveNotOrLessThanOrEqualToTyped,
+    bool
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalExclusiveNotOrLessThanOrEqualToTyped,
+    GxB_EQ_LE
+);
+
+define_semiring!(LogicalExclusiveNotOrGreaterThanOrEqualTo);
+implement_semiring!(
+    LogicalExclusiveNotOrGreaterThanOrEqualTo,
+    LogicalExclusiveNotOrGreaterThanOrEqualToTyped,
+    bool
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_value_types!(
+    implement_typed_semiring,
+    LogicalExclusiveNotOrGreaterThanOrEqualToTyped,
+    GxB_EQ_GE
+);
+
+// BITWISE
+
+define_semiring!(BitWiseLogicalOrBitWiseLogicalOr);
+implement_semiring!(
+    BitWiseLogicalOrBitWiseLogicalOr,
+    BitWiseLogicalOrBitWiseLogicalOrTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitWiseLogicalOrBitWiseLogicalOrTyped,
+    GxB_BOR_BOR
+);
+
+define_semiring!(BitWiseLogicalOrBitWiseLogicalAnd);
+implement_semiring!(
+    BitWiseLogicalOrBitWiseLogicalAnd,
+    BitWiseLogicalOrBitWiseLogicalAndTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitWiseLogicalOrBitWiseLogicalAndTyped,
+    GxB_BOR_BAND
+);
+
+define_semiring!(BitWiseLogicalOrBitWiseLogicalExclusiveOr);
+implement_semiring!(
+    BitWiseLogicalOrBitWiseLogicalExclusiveOr,
+    BitWiseLogicalOrBitWiseLogicalExclusiveOrTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitWiseLogicalOrBitWiseLogicalExclusiveOrTyped,
+    GxB_BOR_BXOR
+);
+
+define_semiring!(BitWiseLogicalOrBitWiseLogicalExclusiveNotOr);
+implement_semiring!(
+    BitWiseLogicalOrBitWiseLogicalExclusiveNotOr,
+    BitWiseLogicalOrBitWiseLogicalExclusiveNotOrTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitWiseLogicalOrBitWiseLogicalExclusiveNotOrTyped,
+    GxB_BOR_BXNOR
+);
+
+define_semiring!(BitWiseLogicalAndBitWiseLogicalOr);
+implement_semiring!(
+    BitWiseLogicalAndBitWiseLogicalOr,
+    BitWiseLogicalAndBitWiseLogicalOrTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitWiseLogicalAndBitWiseLogicalOrTyped,
+    GxB_BAND_BOR
+);
+
+define_semiring!(BitWiseLogicalAndBitWiseLogicalAnd);
+implement_semiring!(
+    BitWiseLogicalAndBitWiseLogicalAnd,
+    BitWiseLogicalAndBitWiseLogicalAndTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitWiseLogicalAndBitWiseLogicalAndTyped,
+    GxB_BAND_BAND
+);
+
+define_semiring!(BitWiseLogicalAndBitWiseLogicalExclusiveOr);
+implement_semiring!(
+    BitWiseLogicalAndBitWiseLogicalExclusiveOr,
+    BitWiseLogicalAndBitWiseLogicalExclusiveOrTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitWiseLogicalAndBitWiseLogicalExclusiveOrTyped,
+    GxB_BAND_BXOR
+);
+
+define_semiring!(BitWiseLogicalAndBitWiseLogicalExclusiveNotOr);
+implement_semiring!(
+    BitWiseLogicalAndBitWiseLogicalExclusiveNotOr,
+    BitWiseLogicalAndBitWiseLogicalExclusiveNotOrTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitWiseLogicalAndBitWiseLogicalExclusiveNotOrTyped,
+    GxB_BAND_BXNOR
+);
+
+define_semiring!(BitWiseLogicalExclusiveOrBitWiseLogicalOr);
+implement_semiring!(
+    BitWiseLogicalExclusiveOrBitWiseLogicalOr,
+    BitWiseLogicalExclusiveOrBitWiseLogicalOrTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitWiseLogicalExclusiveOrBitWiseLogicalOrTyped,
+    GxB_BXOR_BOR
+);
+
+define_semiring!(BitWiseLogicalExclusiveOrBitWiseLogicalAnd);
+implement_semiring!(
+    BitWiseLogicalExclusiveOrBitWiseLogicalAnd,
+    BitWiseLogicalExclusiveOrBitWiseLogicalAndTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitWiseLogicalExclusiveOrBitWiseLogicalAndTyped,
+    GxB_BXOR_BAND
+);
+
+define_semiring!(BitWiseLogicalExclusiveOrBitWiseLogicalExclusiveOr);
+implement_semiring!(
+    BitWiseLogicalExclusiveOrBitWiseLogicalExclusiveOr,
+    BitWiseLogicalExclusiveOrBitWiseLogicalExclusiveOrTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitWiseLogicalExclusiveOrBitWiseLogicalExclusiveOrTyped,
+    GxB_BXOR_BXOR
+);
+
+define_semiring!(BitWiseLogicalExclusiveOrBitWiseLogicalExclusiveNotOr);
+implement_semiring!(
+    BitWiseLogicalExclusiveOrBitWiseLogicalExclusiveNotOr,
+    BitWiseLogicalExclusiveOrBitWiseLogicalExclusiveNotOrTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitWiseLogicalExclusiveOrBitWiseLogicalExclusiveNotOrTyped,
+    GxB_BXOR_BXNOR
+);
+
+define_semiring!(BitWiseLogicalExclusiveNotOrBitWiseLogicalOr);
+implement_semiring!(
+    BitWiseLogicalExclusiveNotOrBitWiseLogicalOr,
+    BitWiseLogicalExclusiveNotOrBitWiseLogicalOrTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitWiseLogicalExclusiveNotOrBitWiseLogicalOrTyped,
+    GxB_BXNOR_BOR
+);
+
+define_semiring!(BitWiseLogicalExclusiveNotOrBitWiseLogicalAnd);
+implement_semiring!(
+    BitWiseLogicalExclusiveNotOrBitWiseLogicalAnd,
+    BitWiseLogicalExclusiveNotOrBitWiseLogicalAndTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitWiseLogicalExclusiveNotOrBitWiseLogicalAndTyped,
+    GxB_BXNOR_BAND
+);
+
+define_semiring!(BitWiseLogicalExclusiveNotOrBitWiseLogicalExclusiveOr);
+implement_semiring!(
+    BitWiseLogicalExclusiveNotOrBitWiseLogicalExclusiveOr,
+    BitWiseLogicalExclusiveNotOrBitWiseLogicalExclusiveOrTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitWiseLogicalExclusiveNotOrBitWiseLogicalExclusiveOrTyped,
+    GxB_BXNOR_BXOR
+);
+
+define_semiring!(BitWiseLogicalExclusiveNotOrBitWiseLogicalExclusiveNotOr);
+implement_semiring!(
+    BitWiseLogicalExclusiveNotOrBitWiseLogicalExclusiveNotOr,
+    BitWiseLogicalExclusiveNotOrBitWiseLogicalExclusiveNotOrTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers!(
+    implement_typed_semiring,
+    BitWiseLogicalExclusiveNotOrBitWiseLogicalExclusiveNotOrTyped,
+    GxB_BXNOR_BXNOR
+);
+
+// POSITIONAL
+// The product is an index of the multiplied elements, their values are ignored.
+// Indices are zero-based, OneBased variants add one.
+
+define_semiring!(MinRowIndexFirstArgument);
+implement_semiring!(MinRowIndexFirstArgument, MinRowIndexFirstArgumentTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MinRowIndexFirstArgumentTyped,
+    GxB_MIN_FIRSTI
+);
+
+define_semiring!(MinOneBasedRowIndexFirstArgument);
+implement_semiring!(
+    MinOneBasedRowIndexFirstArgument,
+    MinOneBasedRowIndexFirstArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MinOneBasedRowIndexFirstArgumentTyped,
+    GxB_MIN_FIRSTI1
+);
+
+define_semiring!(MinColumnIndexFirstArgument);
+implement_semiring!(
+    MinColumnIndexFirstArgument,
+    MinColumnIndexFirstArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MinColumnIndexFirstArgumentTyped,
+    GxB_MIN_FIRSTJ
+);
+
+define_semiring!(MinOneBasedColumnIndexFirstArgument);
+implement_semiring!(
+    MinOneBasedColumnIndexFirstArgument,
+    MinOneBasedColumnIndexFirstArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MinOneBasedColumnIndexFirstArgumentTyped,
+    GxB_MIN_FIRSTJ1
+);
+
+define_semiring!(MinRowIndexSecondArgument);
+implement_semiring!(MinRowIndexSecondArgument, MinRowIndexSecondArgumentTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MinRowIndexSecondArgumentTyped,
+    GxB_MIN_SECONDI
+);
+
+define_semiring!(MinOneBasedRowIndexSecondArgument);
+implement_semiring!(
+    MinOneBasedRowIndexSecondArgument,
+    MinOneBasedRowIndexSecondArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MinOneBasedRowIndexSecondArgumentTyped,
+    GxB_MIN_SECONDI1
+);
+
+define_semiring!(MinColumnIndexSecondArgument);
+implement_semiring!(
+    MinColumnIndexSecondArgument,
+    MinColumnIndexSecondArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MinColumnIndexSecondArgumentTyped,
+    GxB_MIN_SECONDJ
+);
+
+define_semiring!(MinOneBasedColumnIndexSecondArgument);
+implement_semiring!(
+    MinOneBasedColumnIndexSecondArgument,
+    MinOneBasedColumnIndexSecondArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MinOneBasedColumnIndexSecondArgumentTyped,
+    GxB_MIN_SECONDJ1
+);
+
+define_semiring!(MaxRowIndexFirstArgument);
+implement_semiring!(MaxRowIndexFirstArgument, MaxRowIndexFirstArgumentTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MaxRowIndexFirstArgumentTyped,
+    GxB_MAX_FIRSTI
+);
+
+define_semiring!(MaxOneBasedRowIndexFirstArgument);
+implement_semiring!(
+    MaxOneBasedRowIndexFirstArgument,
+    MaxOneBasedRowIndexFirstArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MaxOneBasedRowIndexFirstArgumentTyped,
+    GxB_MAX_FIRSTI1
+);
+
+define_semiring!(MaxColumnIndexFirstArgument);
+implement_semiring!(
+    MaxColumnIndexFirstArgument,
+    MaxColumnIndexFirstArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MaxColumnIndexFirstArgumentTyped,
+    GxB_MAX_FIRSTJ
+);
+
+define_semiring!(MaxOneBasedColumnIndexFirstArgument);
+implement_semiring!(
+    MaxOneBasedColumnIndexFirstArgument,
+    MaxOneBasedColumnIndexFirstArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MaxOneBasedColumnIndexFirstArgumentTyped,
+    GxB_MAX_FIRSTJ1
+);
+
+define_semiring!(MaxRowIndexSecondArgument);
+implement_semiring!(MaxRowIndexSecondArgument, MaxRowIndexSecondArgumentTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MaxRowIndexSecondArgumentTyped,
+    GxB_MAX_SECONDI
+);
+
+define_semiring!(MaxOneBasedRowIndexSecondArgument);
+implement_semiring!(
+    MaxOneBasedRowIndexSecondArgument,
+    MaxOneBasedRowIndexSecondArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MaxOneBasedRowIndexSecondArgumentTyped,
+    GxB_MAX_SECONDI1
+);
+
+define_semiring!(MaxColumnIndexSecondArgument);
+implement_semiring!(
+    MaxColumnIndexSecondArgument,
+    MaxColumnIndexSecondArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MaxColumnIndexSecondArgumentTyped,
+    GxB_MAX_SECONDJ
+);
+
+define_semiring!(MaxOneBasedColumnIndexSecondArgument);
+implement_semiring!(
+    MaxOneBasedColumnIndexSecondArgument,
+    MaxOneBasedColumnIndexSecondArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    MaxOneBasedColumnIndexSecondArgumentTyped,
+    GxB_MAX_SECONDJ1
+);
+
+define_semiring!(PlusRowIndexFirstArgument);
+implement_semiring!(PlusRowIndexFirstArgument, PlusRowIndexFirstArgumentTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    PlusRowIndexFirstArgumentTyped,
+    GxB_PLUS_FIRSTI
+);
+
+define_semiring!(PlusOneBasedRowIndexFirstArgument);
+implement_semiring!(
+    PlusOneBasedRowIndexFirstArgument,
+    PlusOneBasedRowIndexFirstArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    PlusOneBasedRowIndexFirstArgumentTyped,
+    GxB_PLUS_FIRSTI1
+);
+
+define_semiring!(PlusColumnIndexFirstArgument);
+implement_semiring!(
+    PlusColumnIndexFirstArgument,
+    PlusColumnIndexFirstArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    PlusColumnIndexFirstArgumentTyped,
+    GxB_PLUS_FIRSTJ
+);
+
+define_semiring!(PlusOneBasedColumnIndexFirstArgument);
+implement_semiring!(
+    PlusOneBasedColumnIndexFirstArgument,
+    PlusOneBasedColumnIndexFirstArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    PlusOneBasedColumnIndexFirstArgumentTyped,
+    GxB_PLUS_FIRSTJ1
+);
+
+define_semiring!(PlusRowIndexSecondArgument);
+implement_semiring!(PlusRowIndexSecondArgument, PlusRowIndexSecondArgumentTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    PlusRowIndexSecondArgumentTyped,
+    GxB_PLUS_SECONDI
+);
+
+define_semiring!(PlusOneBasedRowIndexSecondArgument);
+implement_semiring!(
+    PlusOneBasedRowIndexSecondArgument,
+    PlusOneBasedRowIndexSecondArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    PlusOneBasedRowIndexSecondArgumentTyped,
+    GxB_PLUS_SECONDI1
+);
+
+define_semiring!(PlusColumnIndexSecondArgument);
+implement_semiring!(
+    PlusColumnIndexSecondArgument,
+    PlusColumnIndexSecondArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    PlusColumnIndexSecondArgumentTyped,
+    GxB_PLUS_SECONDJ
+);
+
+define_semiring!(PlusOneBasedColumnIndexSecondArgument);
+implement_semiring!(
+    PlusOneBasedColumnIndexSecondArgument,
+    PlusOneBasedColumnIndexSecondArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    PlusOneBasedColumnIndexSecondArgumentTyped,
+    GxB_PLUS_SECONDJ1
+);
+
+define_semiring!(TimesRowIndexFirstArgument);
+implement_semiring!(TimesRowIndexFirstArgument, TimesRowIndexFirstArgumentTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    TimesRowIndexFirstArgumentTyped,
+    GxB_TIMES_FIRSTI
+);
+
+define_semiring!(TimesOneBasedRowIndexFirstArgument);
+implement_semiring!(
+    TimesOneBasedRowIndexFirstArgument,
+    TimesOneBasedRowIndexFirstArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    TimesOneBasedRowIndexFirstArgumentTyped,
+    GxB_TIMES_FIRSTI1
+);
+
+define_semiring!(TimesColumnIndexFirstArgument);
+implement_semiring!(
+    TimesColumnIndexFirstArgument,
+    TimesColumnIndexFirstArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    TimesColumnIndexFirstArgumentTyped,
+    GxB_TIMES_FIRSTJ
+);
+
+define_semiring!(TimesOneBasedColumnIndexFirstArgument);
+implement_semiring!(
+    TimesOneBasedColumnIndexFirstArgument,
+    TimesOneBasedColumnIndexFirstArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    TimesOneBasedColumnIndexFirstArgumentTyped,
+    GxB_TIMES_FIRSTJ1
+);
+
+define_semiring!(TimesRowIndexSecondArgument);
+implement_semiring!(
+    TimesRowIndexSecondArgument,
+    TimesRowIndexSecondArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    TimesRowIndexSecondArgumentTyped,
+    GxB_TIMES_SECONDI
+);
+
+define_semiring!(TimesOneBasedRowIndexSecondArgument);
+implement_semiring!(
+    TimesOneBasedRowIndexSecondArgument,
+    TimesOneBasedRowIndexSecondArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    TimesOneBasedRowIndexSecondArgumentTyped,
+    GxB_TIMES_SECONDI1
+);
+
+define_semiring!(TimesColumnIndexSecondArgument);
+implement_semiring!(
+    TimesColumnIndexSecondArgument,
+    TimesColumnIndexSecondArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    TimesColumnIndexSecondArgumentTyped,
+    GxB_TIMES_SECONDJ
+);
+
+define_semiring!(TimesOneBasedColumnIndexSecondArgument);
+implement_semiring!(
+    TimesOneBasedColumnIndexSecondArgument,
+    TimesOneBasedColumnIndexSecondArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    TimesOneBasedColumnIndexSecondArgumentTyped,
+    GxB_TIMES_SECONDJ1
+);
+
+define_semiring!(AnyRowIndexFirstArgument);
+implement_semiring!(AnyRowIndexFirstArgument, AnyRowIndexFirstArgumentTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    AnyRowIndexFirstArgumentTyped,
+    GxB_ANY_FIRSTI
+);
+
+define_semiring!(AnyOneBasedRowIndexFirstArgument);
+implement_semiring!(
+    AnyOneBasedRowIndexFirstArgument,
+    AnyOneBasedRowIndexFirstArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    AnyOneBasedRowIndexFirstArgumentTyped,
+    GxB_ANY_FIRSTI1
+);
+
+define_semiring!(AnyColumnIndexFirstArgument);
+implement_semiring!(
+    AnyColumnIndexFirstArgument,
+    AnyColumnIndexFirstArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    AnyColumnIndexFirstArgumentTyped,
+    GxB_ANY_FIRSTJ
+);
+
+define_semiring!(AnyOneBasedColumnIndexFirstArgument);
+implement_semiring!(
+    AnyOneBasedColumnIndexFirstArgument,
+    AnyOneBasedColumnIndexFirstArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    AnyOneBasedColumnIndexFirstArgumentTyped,
+    GxB_ANY_FIRSTJ1
+);
+
+define_semiring!(AnyRowIndexSecondArgument);
+implement_semiring!(AnyRowIndexSecondArgument, AnyRowIndexSecondArgumentTyped);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    AnyRowIndexSecondArgumentTyped,
+    GxB_ANY_SECONDI
+);
+
+define_semiring!(AnyOneBasedRowIndexSecondArgument);
+implement_semiring!(
+    AnyOneBasedRowIndexSecondArgument,
+    AnyOneBasedRowIndexSecondArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    AnyOneBasedRowIndexSecondArgumentTyped,
+    GxB_ANY_SECONDI1
+);
+
+define_semiring!(AnyColumnIndexSecondArgument);
+implement_semiring!(
+    AnyColumnIndexSecondArgument,
+    AnyColumnIndexSecondArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    AnyColumnIndexSecondArgumentTyped,
+    GxB_ANY_SECONDJ
+);
+
+define_semiring!(AnyOneBasedColumnIndexSecondArgument);
+implement_semiring!(
+    AnyOneBasedColumnIndexSecondArgument,
+    AnyOneBasedColumnIndexSecondArgumentTyped
+);
+implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types!(
+    implement_typed_semiring,
+    AnyOneBasedColumnIndexSecondArgumentTyped,
+    GxB_ANY_SECONDJ1
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::context::Context;
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::mask::SelectEntireMatrix;
+    use crate::operators::multiplication::{MatrixMultiplicationOperator, MultiplyMatrices};
+    use crate::operators::options::OptionsForOperatorWithMatrixArguments;
+
+    #[test]
+    fn test_new_semiring() {
+        let semiring = PlusTimes::<i8>::new();
+
+        unsafe {
+            assert_eq!(semiring.graphblas_type(), GrB_PLUS_TIMES_SEMIRING_INT8);
+            assert_ne!(semiring.graphblas_type(), GrB_PLUS_TIMES_SEMIRING_INT16);
+        }
+    }
+
+    #[test]
+    fn test_boolean_and_suitesparse_semirings() {
+        // The unsafe blocks copy the GraphBLAS globals, such that assert_eq! does not reference a static mut
+        assert_eq!(
+            LogicalOrLogicalAnd::<bool>::new().graphblas_type(),
+            unsafe { GrB_LOR_LAND_SEMIRING_BOOL }
+        );
+        assert_eq!(
+            LogicalExclusiveNotOrLogicalOr::<bool>::new().graphblas_type(),
+            unsafe { GrB_LXNOR_LOR_SEMIRING_BOOL }
+        );
+        assert_eq!(AnyOne::<bool>::new().graphblas_type(), unsafe {
+            GxB_ANY_PAIR_BOOL
+        });
+        assert_eq!(PlusOne::<u32>::new().graphblas_type(), unsafe {
+            GxB_PLUS_PAIR_UINT32
+        });
+        assert_eq!(
+            AnyRowIndexSecondArgument::<i64>::new().graphblas_type(),
+            unsafe { GxB_ANY_SECONDI_INT64 }
+        );
+        assert_eq!(
+            LogicalOrGreaterThan::<f32>::new().graphblas_type(),
+            unsafe { GxB_LOR_GT_FP32 }
+        );
+    }
+
+    #[test]
+    fn test_structural_and_positional_semirings() {
+        let context = Context::init_default().unwrap();
+        let size = Size::new(3, 3);
+
+        // Edges 0->1, 0->2, 1->2 and 2->2, values are ignored by both semirings
+        let adjacency = SparseMatrix::<u8>::from_element_list(
+            context.clone(),
+            size,
+            MatrixElementList::from_element_vector(vec![
+                (0, 1, 7).into(),
+                (0, 2, 7).into(),
+                (1, 2, 7).into(),
+                (2, 2, 7).into(),
+            ]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+
+        let mut number_of_paths = SparseMatrix::<u8>::new(context.clone(), size).unwrap();
+        MatrixMultiplicationOperator::new()
+            .apply(
+                &adjacency,
+                &PlusOne::<u8>::new(),
+                &adjacency,
+                &Assignment::new(),
+                &mut number_of_paths,
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+            .unwrap();
+
+        // 0->1->2 and 0->2->2
+        assert_eq!(number_of_paths.element_value(&0, &2).unwrap(), Some(2));
+        assert_eq!(number_of_paths.element_value(&1, &2).unwrap(), Some(1));
+        assert_eq!(number_of_paths.element_value(&0, &1).unwrap(), None);
+
+        let mut first_intermediate_vertex =
+            SparseMatrix::<i64>::new(context.clone(), size).unwrap();
+        MatrixMultiplicationOperator::new()
+            .apply(
+                &adjacency,
+                &MinColumnIndexFirstArgument::<i64>::new(),
+                &adjacency,
+                &Assignment::new(),
+                &mut first_intermediate_vertex,
+                &SelectEntireMatrix::new(context),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            first_intermediate_vertex.element_value(&0, &2).unwrap(),
+            Some(1)
+        );
+        assert_eq!(
+            first_intermediate_vertex.element_value(&2, &2).unwrap(),
+            Some(2)
+        );
     }
 }
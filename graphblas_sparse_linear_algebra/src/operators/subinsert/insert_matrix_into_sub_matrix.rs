@@ -3,7 +3,7 @@ use crate::collections::sparse_matrix::{GetGraphblasSparseMatrix, SparseMatrix};
 use crate::context::{CallGraphBlasContext, GetContext};
 use crate::error::SparseLinearAlgebraError;
 use crate::graphblas_bindings::GxB_Matrix_subassign;
-use crate::index::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
+use crate::index::ElementIndexSelector;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::MatrixMask;
 use crate::operators::options::GetOptionsForOperatorWithMatrixArguments;
@@ -59,103 +59,29 @@ impl<MatrixToInsertInto: ValueType> InsertMatrixIntoSubMatrix<MatrixToInsertInto
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = matrix_to_insert_into.context_ref();
 
-        let number_of_rows_to_insert_into = rows_to_insert_into
-            .number_of_selected_elements(matrix_to_insert_into.row_height()?)?
-            .to_graphblas_index()?;
-
-        let number_of_columns_to_insert_into = columns_to_insert_into
-            .number_of_selected_elements(matrix_to_insert_into.column_width()?)?
-            .to_graphblas_index()?;
-
-        let rows_to_insert_into = rows_to_insert_into.to_graphblas_type()?;
-        let columns_to_insert_into = columns_to_insert_into.to_graphblas_type()?;
-
-        match (rows_to_insert_into, columns_to_insert_into) {
-            (
-                ElementIndexSelectorGraphblasType::Index(row),
-                ElementIndexSelectorGraphblasType::Index(column),
-            ) => {
-                context.call(
-                    || unsafe {
-                        GxB_Matrix_subassign(
-                            GetGraphblasSparseMatrix::graphblas_matrix(matrix_to_insert_into),
-                            mask_for_matrix_to_insert_into.graphblas_matrix(),
-                            accumulator.accumulator_graphblas_type(),
-                            matrix_to_insert.graphblas_matrix(),
-                            row.as_ptr(),
-                            number_of_rows_to_insert_into,
-                            column.as_ptr(),
-                            number_of_columns_to_insert_into,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { matrix_to_insert_into.graphblas_matrix_ref() },
-                )?;
-            }
-            (
-                ElementIndexSelectorGraphblasType::All(row),
-                ElementIndexSelectorGraphblasType::Index(column),
-            ) => {
-                context.call(
-                    || unsafe {
-                        GxB_Matrix_subassign(
-                            GetGraphblasSparseMatrix::graphblas_matrix(matrix_to_insert_into),
-                            mask_for_matrix_to_insert_into.graphblas_matrix(),
-                            accumulator.accumulator_graphblas_type(),
-                            matrix_to_insert.graphblas_matrix(),
-                            row,
-                            number_of_rows_to_insert_into,
-                            column.as_ptr(),
-                            number_of_columns_to_insert_into,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { matrix_to_insert_into.graphblas_matrix_ref() },
-                )?;
-            }
-            (
-                ElementIndexSelectorGraphblasType::Index(row),
-                ElementIndexSelectorGraphblasType::All(column),
-            ) => {
-                context.call(
-                    || unsafe {
-                        GxB_Matrix_subassign(
-                            GetGraphblasSparseMatrix::graphblas_matrix(matrix_to_insert_into),
-                            mask_for_matrix_to_insert_into.graphblas_matrix(),
-                            accumulator.accumulator_graphblas_type(),
-                            matrix_to_insert.graphblas_matrix(),
-                            row.as_ptr(),
-                            number_of_rows_to_insert_into,
-                            column,
-                            number_of_columns_to_insert_into,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { matrix_to_insert_into.graphblas_matrix_ref() },
-                )?;
-            }
-            (
-                ElementIndexSelectorGraphblasType::All(row),
-                ElementIndexSelectorGraphblasType::All(column),
-            ) => {
-                context.call(
-                    || unsafe {
-                        GxB_Matrix_subassign(
-                            GetGraphblasSparseMatrix::graphblas_matrix(matrix_to_insert_into),
-                            mask_for_matrix_to_insert_into.graphblas_matrix(),
-                            accumulator.accumulator_graphblas_type(),
-                            matrix_to_insert.graphblas_matrix(),
-                            row,
-                            number_of_rows_to_insert_into,
-                            column,
-                            number_of_columns_to_insert_into,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { matrix_to_insert_into.graphblas_matrix_ref() },
-                )?;
-            }
-        }
+        let rows_to_insert_into =
+            rows_to_insert_into.to_graphblas_type(matrix_to_insert_into.row_height()?)?;
+        let columns_to_insert_into =
+            columns_to_insert_into.to_graphblas_type(matrix_to_insert_into.column_width()?)?;
+
+        let graphblas_descriptor =
+            options.graphblas_descriptor_for_mask(mask_for_matrix_to_insert_into)?;
+        context.call(
+            || unsafe {
+                GxB_Matrix_subassign(
+                    GetGraphblasSparseMatrix::graphblas_matrix(matrix_to_insert_into),
+                    mask_for_matrix_to_insert_into.graphblas_matrix(),
+                    accumulator.accumulator_graphblas_type(),
+                    matrix_to_insert.graphblas_matrix(),
+                    rows_to_insert_into.as_ptr(),
+                    rows_to_insert_into.number_of_indices(),
+                    columns_to_insert_into.as_ptr(),
+                    columns_to_insert_into.number_of_indices(),
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { matrix_to_insert_into.graphblas_matrix_ref() },
+        )?;
 
         Ok(())
     }
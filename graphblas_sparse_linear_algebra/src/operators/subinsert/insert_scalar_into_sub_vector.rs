@@ -8,7 +8,7 @@ use crate::graphblas_bindings::{
     GxB_Vector_subassign_INT8, GxB_Vector_subassign_UINT16, GxB_Vector_subassign_UINT32,
     GxB_Vector_subassign_UINT64, GxB_Vector_subassign_UINT8,
 };
-use crate::index::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
+use crate::index::ElementIndexSelector;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::GetOperatorOptions;
@@ -71,51 +71,25 @@ macro_rules! implement_insert_scalar_into_sub_vector_trait {
                 let context = vector_to_insert_into.context_ref();
                 let scalar_to_insert = scalar_to_insert.to_owned().to_type()?;
 
-                let number_of_indices_to_insert_into = indices_to_insert_into
-                    .number_of_selected_elements(vector_to_insert_into.length()?)?
-                    .to_graphblas_index()?;
-
-                let indices_to_insert_into = indices_to_insert_into.to_graphblas_type()?;
-
-                match indices_to_insert_into {
-                    ElementIndexSelectorGraphblasType::Index(index) => {
-                        context.call(
-                            || unsafe {
-                                $graphblas_insert_function(
-                                    GetGraphblasSparseVector::graphblas_vector(
-                                        vector_to_insert_into,
-                                    ),
-                                    mask_for_vector_to_insert_into.graphblas_vector(),
-                                    accumulator.accumulator_graphblas_type(),
-                                    scalar_to_insert,
-                                    index.as_ptr(),
-                                    number_of_indices_to_insert_into,
-                                    options.graphblas_descriptor(),
-                                )
-                            },
-                            unsafe { vector_to_insert_into.graphblas_vector_ref() },
-                        )?;
-                    }
-
-                    ElementIndexSelectorGraphblasType::All(index) => {
-                        context.call(
-                            || unsafe {
-                                $graphblas_insert_function(
-                                    GetGraphblasSparseVector::graphblas_vector(
-                                        vector_to_insert_into,
-                                    ),
-                                    mask_for_vector_to_insert_into.graphblas_vector(),
-                                    accumulator.accumulator_graphblas_type(),
-                                    scalar_to_insert,
-                                    index,
-                                    number_of_indices_to_insert_into,
-                                    options.graphblas_descriptor(),
-                                )
-                            },
-                            unsafe { vector_to_insert_into.graphblas_vector_ref() },
-                        )?;
-                    }
-                }
+                let indices_to_insert_into =
+                    indices_to_insert_into.to_graphblas_type(vector_to_insert_into.length()?)?;
+
+                let graphblas_descriptor =
+                    options.graphblas_descriptor_for_mask(mask_for_vector_to_insert_into)?;
+                context.call(
+                    || unsafe {
+                        $graphblas_insert_function(
+                            GetGraphblasSparseVector::graphblas_vector(vector_to_insert_into),
+                            mask_for_vector_to_insert_into.graphblas_vector(),
+                            accumulator.accumulator_graphblas_type(),
+                            scalar_to_insert,
+                            indices_to_insert_into.as_ptr(),
+                            indices_to_insert_into.number_of_indices(),
+                            graphblas_descriptor.graphblas_descriptor(),
+                        )
+                    },
+                    unsafe { vector_to_insert_into.graphblas_vector_ref() },
+                )?;
 
                 Ok(())
             }
@@ -4,9 +4,7 @@ use crate::collections::sparse_vector::GetGraphblasSparseVector;
 use crate::context::{CallGraphBlasContext, GetContext};
 use crate::error::SparseLinearAlgebraError;
 use crate::graphblas_bindings::GxB_Row_subassign;
-use crate::index::{
-    ElementIndex, ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion,
-};
+use crate::index::{ElementIndex, ElementIndexSelector, IndexConversion};
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::GetOptionsForOperatorWithMatrixArgument;
@@ -63,50 +61,27 @@ impl<MatrixToInsertInto: ValueType> InsertVectorIntoSubRow<MatrixToInsertInto>
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = matrix_to_insert_into.context_ref();
 
-        let number_of_indices_to_insert_into = row_indices_to_insert_into
-            .number_of_selected_elements(matrix_to_insert_into.row_height()?)?
-            .to_graphblas_index()?;
-
-        let indices_to_insert_into = row_indices_to_insert_into.to_graphblas_type()?;
+        let indices_to_insert_into =
+            row_indices_to_insert_into.to_graphblas_type(matrix_to_insert_into.row_height()?)?;
         let row_to_insert_into = row_to_insert_into.to_graphblas_index()?;
 
-        match indices_to_insert_into {
-            ElementIndexSelectorGraphblasType::Index(index) => {
-                context.call(
-                    || unsafe {
-                        GxB_Row_subassign(
-                            matrix_to_insert_into.graphblas_matrix(),
-                            mask_for_row_to_insert_into.graphblas_vector(),
-                            accumulator.accumulator_graphblas_type(),
-                            vector_to_insert.graphblas_vector(),
-                            row_to_insert_into,
-                            index.as_ptr(),
-                            number_of_indices_to_insert_into,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { matrix_to_insert_into.graphblas_matrix_ref() },
-                )?;
-            }
-
-            ElementIndexSelectorGraphblasType::All(index) => {
-                context.call(
-                    || unsafe {
-                        GxB_Row_subassign(
-                            matrix_to_insert_into.graphblas_matrix(),
-                            mask_for_row_to_insert_into.graphblas_vector(),
-                            accumulator.accumulator_graphblas_type(),
-                            vector_to_insert.graphblas_vector(),
-                            row_to_insert_into,
-                            index,
-                            number_of_indices_to_insert_into,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { matrix_to_insert_into.graphblas_matrix_ref() },
-                )?;
-            }
-        }
+        let graphblas_descriptor =
+            options.graphblas_descriptor_for_mask(mask_for_row_to_insert_into)?;
+        context.call(
+            || unsafe {
+                GxB_Row_subassign(
+                    matrix_to_insert_into.graphblas_matrix(),
+                    mask_for_row_to_insert_into.graphblas_vector(),
+                    accumulator.accumulator_graphblas_type(),
+                    vector_to_insert.graphblas_vector(),
+                    row_to_insert_into,
+                    indices_to_insert_into.as_ptr(),
+                    indices_to_insert_into.number_of_indices(),
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { matrix_to_insert_into.graphblas_matrix_ref() },
+        )?;
 
         Ok(())
     }
@@ -3,7 +3,7 @@ use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
 use crate::context::{CallGraphBlasContext, GetContext};
 use crate::error::SparseLinearAlgebraError;
 use crate::graphblas_bindings::GxB_Vector_subassign;
-use crate::index::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
+use crate::index::ElementIndexSelector;
 use crate::operators::binary_operator::AccumulatorBinaryOperator;
 use crate::operators::mask::VectorMask;
 use crate::operators::options::GetOperatorOptions;
@@ -58,47 +58,25 @@ impl<VectorToInsertInto: ValueType> InsertVectorIntoSubVector<VectorToInsertInto
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = vector_to_insert_into.context_ref();
 
-        let number_of_indices_to_insert_into = indices_to_insert_into
-            .number_of_selected_elements(vector_to_insert_into.length()?)?
-            .to_graphblas_index()?;
-
-        let indices_to_insert_into = indices_to_insert_into.to_graphblas_type()?;
-
-        match indices_to_insert_into {
-            ElementIndexSelectorGraphblasType::Index(index) => {
-                context.call(
-                    || unsafe {
-                        GxB_Vector_subassign(
-                            GetGraphblasSparseVector::graphblas_vector(vector_to_insert_into),
-                            mask_for_vector_to_insert_into.graphblas_vector(),
-                            accumulator.accumulator_graphblas_type(),
-                            vector_to_insert.graphblas_vector(),
-                            index.as_ptr(),
-                            number_of_indices_to_insert_into,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { vector_to_insert_into.graphblas_vector_ref() },
-                )?;
-            }
-
-            ElementIndexSelectorGraphblasType::All(index) => {
-                context.call(
-                    || unsafe {
-                        GxB_Vector_subassign(
-                            GetGraphblasSparseVector::graphblas_vector(vector_to_insert_into),
-                            mask_for_vector_to_insert_into.graphblas_vector(),
-                            accumulator.accumulator_graphblas_type(),
-                            vector_to_insert.graphblas_vector(),
-                            index,
-                            number_of_indices_to_insert_into,
-                            options.graphblas_descriptor(),
-                        )
-                    },
-                    unsafe { vector_to_insert_into.graphblas_vector_ref() },
-                )?;
-            }
-        }
+        let indices_to_insert_into =
+            indices_to_insert_into.to_graphblas_type(vector_to_insert_into.length()?)?;
+
+        let graphblas_descriptor =
+            options.graphblas_descriptor_for_mask(mask_for_vector_to_insert_into)?;
+        context.call(
+            || unsafe {
+                GxB_Vector_subassign(
+                    GetGraphblasSparseVector::graphblas_vector(vector_to_insert_into),
+                    mask_for_vector_to_insert_into.graphblas_vector(),
+                    accumulator.accumulator_graphblas_type(),
+                    vector_to_insert.graphblas_vector(),
+                    indices_to_insert_into.as_ptr(),
+                    indices_to_insert_into.number_of_indices(),
+                    graphblas_descriptor.graphblas_descriptor(),
+                )
+            },
+            unsafe { vector_to_insert_into.graphblas_vector_ref() },
+        )?;
 
         Ok(())
     }
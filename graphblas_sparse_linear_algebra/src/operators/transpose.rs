@@ -46,6 +46,7 @@ impl<EvaluationDomain: ValueType> TransposeMatrix<EvaluationDomain> for MatrixTr
     ) -> Result<(), SparseLinearAlgebraError> {
         let context = transpose.context_ref();
 
+        let graphblas_descriptor = options.graphblas_descriptor_for_mask(mask)?;
         context.call(
             || unsafe {
                 GrB_transpose(
@@ -53,7 +54,7 @@ impl<EvaluationDomain: ValueType> TransposeMatrix<EvaluationDomain> for MatrixTr
                     mask.graphblas_matrix(),
                     accumulator.accumulator_graphblas_type(),
                     matrix.graphblas_matrix(),
-                    options.graphblas_descriptor(),
+                    graphblas_descriptor.graphblas_descriptor(),
                 )
             },
             unsafe { transpose.graphblas_matrix_ref() },
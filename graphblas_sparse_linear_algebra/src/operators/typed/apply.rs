@@ -0,0 +1,122 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::{ApplyUnaryOperator, UnaryOperatorApplier};
+use crate::operators::binary_operator::AccumulatorBinaryOperator;
+use crate::operators::mask::{MatrixMask, VectorMask};
+use crate::operators::options::{GetOperatorOptions, GetOptionsForOperatorWithMatrixArgument};
+use crate::operators::unary_operator::UnaryOperator;
+use crate::value_type::ValueType;
+
+use super::{
+    TypedSparseMatrixArgument, TypedSparseMatrixProduct, TypedSparseVectorArgument,
+    TypedSparseVectorProduct,
+};
+
+pub trait TypedApplyUnaryOperator<EvaluationDomain: ValueType> {
+    fn apply_to_vector_typed(
+        &self,
+        operator: &impl UnaryOperator<EvaluationDomain>,
+        argument: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+
+    fn apply_to_matrix_typed(
+        &self,
+        operator: &impl UnaryOperator<EvaluationDomain>,
+        argument: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        mask: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedApplyUnaryOperator<EvaluationDomain>
+    for UnaryOperatorApplier
+{
+    fn apply_to_vector_typed(
+        &self,
+        operator: &impl UnaryOperator<EvaluationDomain>,
+        argument: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        ApplyUnaryOperator::<EvaluationDomain>::apply_to_vector(
+            self,
+            operator,
+            argument.untyped_vector(),
+            accumulator,
+            product.untyped_vector_mut(),
+            mask,
+            options,
+        )
+    }
+
+    fn apply_to_matrix_typed(
+        &self,
+        operator: &impl UnaryOperator<EvaluationDomain>,
+        argument: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        mask: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        ApplyUnaryOperator::<EvaluationDomain>::apply_to_matrix(
+            self,
+            operator,
+            argument.untyped_matrix(),
+            accumulator,
+            product.untyped_matrix_mut(),
+            mask,
+            options,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::context::Context;
+    use crate::operators::apply::UnaryOperatorApplier;
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::mask::SelectEntireMatrix;
+    use crate::operators::options::OptionsForOperatorWithMatrixArgument;
+    use crate::operators::typed::{CastMut, TypedApplyUnaryOperator};
+    use crate::operators::unary_operator::AdditiveInverse;
+
+    #[test]
+    fn apply_to_matrix_into_wider_product() {
+        let context = Context::init_default().unwrap();
+        let size = Size::new(1, 2);
+
+        let argument = SparseMatrix::<i16>::from_element_list(
+            context.clone(),
+            size,
+            MatrixElementList::from_element_vector(vec![(0, 0, 7).into(), (0, 1, -8).into()]),
+            &First::<i16>::new(),
+        )
+        .unwrap();
+        let mut product = SparseMatrix::<f64>::new(context.clone(), size).unwrap();
+
+        UnaryOperatorApplier::new()
+            .apply_to_matrix_typed(
+                &AdditiveInverse::<i16>::new(),
+                &argument,
+                &Assignment::<i16>::new(),
+                &mut CastMut::<i16, _>::new(&mut product),
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArgument::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.element_value(&0, &0).unwrap(), Some(-7.0));
+        assert_eq!(product.element_value(&0, &1).unwrap(), Some(8.0));
+    }
+}
@@ -0,0 +1,171 @@
+use std::marker::PhantomData;
+
+use crate::collections::sparse_matrix::operations::GetSparseMatrixSize;
+use crate::collections::sparse_matrix::{GetGraphblasSparseMatrix, SparseMatrix};
+use crate::collections::sparse_scalar::{GraphblasSparseScalarTrait, SparseScalar};
+use crate::collections::sparse_vector::operations::GetSparseVectorLength;
+use crate::collections::sparse_vector::{GetGraphblasSparseVector, SparseVector};
+use crate::value_type::ValueType;
+
+/// A matrix argument with elements of type T.
+///
+/// Implemented by SparseMatrix<T>, and by Cast<T, SparseMatrix<U>> to explicitly typecast from U to T.
+pub trait TypedSparseMatrixArgument<T: ValueType> {
+    type Matrix: GetGraphblasSparseMatrix + GetSparseMatrixSize;
+
+    fn untyped_matrix(&self) -> &Self::Matrix;
+}
+
+/// A matrix product with elements of type T.
+///
+/// Implemented by SparseMatrix<T>, and by CastMut<T, SparseMatrix<U>> to explicitly typecast from T to U.
+pub trait TypedSparseMatrixProduct<T: ValueType> {
+    type Matrix: GetGraphblasSparseMatrix;
+
+    fn untyped_matrix_mut(&mut self) -> &mut Self::Matrix;
+}
+
+/// A vector argument with elements of type T.
+///
+/// Implemented by SparseVector<T>, and by Cast<T, SparseVector<U>> to explicitly typecast from U to T.
+pub trait TypedSparseVectorArgument<T: ValueType> {
+    type Vector: GetGraphblasSparseVector + GetSparseVectorLength;
+
+    fn untyped_vector(&self) -> &Self::Vector;
+}
+
+/// A vector product with elements of type T.
+///
+/// Implemented by SparseVector<T>, and by CastMut<T, SparseVector<U>> to explicitly typecast from T to U.
+pub trait TypedSparseVectorProduct<T: ValueType> {
+    type Vector: GetGraphblasSparseVector;
+
+    fn untyped_vector_mut(&mut self) -> &mut Self::Vector;
+}
+
+/// A scalar product with a value of type T.
+///
+/// Implemented by SparseScalar<T>, and by CastMut<T, SparseScalar<U>> to explicitly typecast from T to U.
+pub trait TypedSparseScalarProduct<T: ValueType> {
+    type Scalar: GraphblasSparseScalarTrait;
+
+    fn untyped_scalar_mut(&mut self) -> &mut Self::Scalar;
+}
+
+/// Presents a collection as an argument of type T, which GraphBLAS typecasts the stored elements to.
+#[derive(Debug)]
+pub struct Cast<'a, T: ValueType, C> {
+    collection: &'a C,
+    _domain: PhantomData<T>,
+}
+
+impl<'a, T: ValueType, C> Cast<'a, T, C> {
+    pub fn new(collection: &'a C) -> Self {
+        Self {
+            collection,
+            _domain: PhantomData,
+        }
+    }
+}
+
+/// Presents a collection as a product of type T, which GraphBLAS typecasts to the type of the collection.
+#[derive(Debug)]
+pub struct CastMut<'a, T: ValueType, C> {
+    collection: &'a mut C,
+    _domain: PhantomData<T>,
+}
+
+impl<'a, T: ValueType, C> CastMut<'a, T, C> {
+    pub fn new(collection: &'a mut C) -> Self {
+        Self {
+            collection,
+            _domain: PhantomData,
+        }
+    }
+}
+
+impl<T: ValueType> TypedSparseMatrixArgument<T> for SparseMatrix<T> {
+    type Matrix = SparseMatrix<T>;
+
+    fn untyped_matrix(&self) -> &Self::Matrix {
+        self
+    }
+}
+
+impl<T: ValueType> TypedSparseMatrixProduct<T> for SparseMatrix<T> {
+    type Matrix = SparseMatrix<T>;
+
+    fn untyped_matrix_mut(&mut self) -> &mut Self::Matrix {
+        self
+    }
+}
+
+impl<'a, T: ValueType, U: ValueType> TypedSparseMatrixArgument<T> for Cast<'a, T, SparseMatrix<U>> {
+    type Matrix = SparseMatrix<U>;
+
+    fn untyped_matrix(&self) -> &Self::Matrix {
+        self.collection
+    }
+}
+
+impl<'a, T: ValueType, U: ValueType> TypedSparseMatrixProduct<T>
+    for CastMut<'a, T, SparseMatrix<U>>
+{
+    type Matrix = SparseMatrix<U>;
+
+    fn untyped_matrix_mut(&mut self) -> &mut Self::Matrix {
+        self.collection
+    }
+}
+
+impl<T: ValueType> TypedSparseVectorArgument<T> for SparseVector<T> {
+    type Vector = SparseVector<T>;
+
+    fn untyped_vector(&self) -> &Self::Vector {
+        self
+    }
+}
+
+impl<T: ValueType> TypedSparseVectorProduct<T> for SparseVector<T> {
+    type Vector = SparseVector<T>;
+
+    fn untyped_vector_mut(&mut self) -> &mut Self::Vector {
+        self
+    }
+}
+
+impl<'a, T: ValueType, U: ValueType> TypedSparseVectorArgument<T> for Cast<'a, T, SparseVector<U>> {
+    type Vector = SparseVector<U>;
+
+    fn untyped_vector(&self) -> &Self::Vector {
+        self.collection
+    }
+}
+
+impl<'a, T: ValueType, U: ValueType> TypedSparseVectorProduct<T>
+    for CastMut<'a, T, SparseVector<U>>
+{
+    type Vector = SparseVector<U>;
+
+    fn untyped_vector_mut(&mut self) -> &mut Self::Vector {
+        self.collection
+    }
+}
+
+impl<T: ValueType> TypedSparseScalarProduct<T> for SparseScalar<T> {
+    type Scalar = SparseScalar<T>;
+
+    fn untyped_scalar_mut(&mut self) -> &mut Self::Scalar {
+        self
+    }
+}
+
+impl<'a, T: ValueType, U: ValueType> TypedSparseScalarProduct<T>
+    for CastMut<'a, T, SparseScalar<U>>
+{
+    type Scalar = SparseScalar<U>;
+
+    fn untyped_scalar_mut(&mut self) -> &mut Self::Scalar {
+        self.collection
+    }
+}
@@ -0,0 +1,168 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{AccumulatorBinaryOperator, BinaryOperator};
+use crate::operators::element_wise_addition::{
+    ApplyElementWiseMatrixAdditionBinaryOperator, ApplyElementWiseVectorAdditionBinaryOperator,
+    ElementWiseMatrixAdditionBinaryOperator, ElementWiseVectorAdditionBinaryOperator,
+};
+use crate::operators::element_wise_multiplication::{
+    ApplyElementWiseMatrixMultiplicationBinaryOperator,
+    ApplyElementWiseVectorMultiplicationBinaryOperator,
+    ElementWiseMatrixMultiplicationBinaryOperator, ElementWiseVectorMultiplicationBinaryOperator,
+};
+use crate::operators::mask::{MatrixMask, VectorMask};
+use crate::operators::options::{GetOperatorOptions, GetOptionsForOperatorWithMatrixArguments};
+use crate::value_type::ValueType;
+
+use super::{
+    TypedSparseMatrixArgument, TypedSparseMatrixProduct, TypedSparseVectorArgument,
+    TypedSparseVectorProduct,
+};
+
+macro_rules! implement_typed_element_wise_operator {
+    (
+        $typed_trait:ident,
+        $untyped_trait:ident,
+        $operator:ident,
+        $argument:ident,
+        $untyped_argument:ident,
+        $product:ident,
+        $untyped_product:ident,
+        $mask:ident,
+        $options:ident
+    ) => {
+        pub trait $typed_trait<EvaluationDomain: ValueType> {
+            fn apply_typed(
+                &self,
+                multiplier: &impl $argument<EvaluationDomain>,
+                operator: &impl BinaryOperator<EvaluationDomain>,
+                multiplicant: &impl $argument<EvaluationDomain>,
+                accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+                product: &mut impl $product<EvaluationDomain>,
+                mask: &impl $mask,
+                options: &impl $options,
+            ) -> Result<(), SparseLinearAlgebraError>;
+        }
+
+        impl<EvaluationDomain: ValueType> $typed_trait<EvaluationDomain> for $operator {
+            fn apply_typed(
+                &self,
+                multiplier: &impl $argument<EvaluationDomain>,
+                operator: &impl BinaryOperator<EvaluationDomain>,
+                multiplicant: &impl $argument<EvaluationDomain>,
+                accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+                product: &mut impl $product<EvaluationDomain>,
+                mask: &impl $mask,
+                options: &impl $options,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                $untyped_trait::<EvaluationDomain>::apply(
+                    self,
+                    multiplier.$untyped_argument(),
+                    operator,
+                    multiplicant.$untyped_argument(),
+                    accumulator,
+                    product.$untyped_product(),
+                    mask,
+                    options,
+                )
+            }
+        }
+    };
+}
+
+implement_typed_element_wise_operator!(
+    TypedApplyElementWiseMatrixAdditionBinaryOperator,
+    ApplyElementWiseMatrixAdditionBinaryOperator,
+    ElementWiseMatrixAdditionBinaryOperator,
+    TypedSparseMatrixArgument,
+    untyped_matrix,
+    TypedSparseMatrixProduct,
+    untyped_matrix_mut,
+    MatrixMask,
+    GetOptionsForOperatorWithMatrixArguments
+);
+
+implement_typed_element_wise_operator!(
+    TypedApplyElementWiseMatrixMultiplicationBinaryOperator,
+    ApplyElementWiseMatrixMultiplicationBinaryOperator,
+    ElementWiseMatrixMultiplicationBinaryOperator,
+    TypedSparseMatrixArgument,
+    untyped_matrix,
+    TypedSparseMatrixProduct,
+    untyped_matrix_mut,
+    MatrixMask,
+    GetOptionsForOperatorWithMatrixArguments
+);
+
+implement_typed_element_wise_operator!(
+    TypedApplyElementWiseVectorAdditionBinaryOperator,
+    ApplyElementWiseVectorAdditionBinaryOperator,
+    ElementWiseVectorAdditionBinaryOperator,
+    TypedSparseVectorArgument,
+    untyped_vector,
+    TypedSparseVectorProduct,
+    untyped_vector_mut,
+    VectorMask,
+    GetOperatorOptions
+);
+
+implement_typed_element_wise_operator!(
+    TypedApplyElementWiseVectorMultiplicationBinaryOperator,
+    ApplyElementWiseVectorMultiplicationBinaryOperator,
+    ElementWiseVectorMultiplicationBinaryOperator,
+    TypedSparseVectorArgument,
+    untyped_vector,
+    TypedSparseVectorProduct,
+    untyped_vector_mut,
+    VectorMask,
+    GetOperatorOptions
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::context::Context;
+    use crate::operators::binary_operator::{Assignment, First, Plus};
+    use crate::operators::element_wise_addition::ElementWiseVectorAdditionBinaryOperator;
+    use crate::operators::mask::SelectEntireVector;
+    use crate::operators::options::OperatorOptions;
+    use crate::operators::typed::{Cast, TypedApplyElementWiseVectorAdditionBinaryOperator};
+
+    #[test]
+    fn add_vectors_in_explicit_domain() {
+        let context = Context::init_default().unwrap();
+
+        let multiplier = SparseVector::<i8>::from_element_list(
+            context.clone(),
+            2,
+            VectorElementList::from_element_vector(vec![(0, 100).into(), (1, -3).into()]),
+            &First::<i8>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseVector::<i8>::from_element_list(
+            context.clone(),
+            2,
+            VectorElementList::from_element_vector(vec![(0, 100).into()]),
+            &First::<i8>::new(),
+        )
+        .unwrap();
+        let mut product = SparseVector::<i32>::new(context.clone(), 2).unwrap();
+
+        ElementWiseVectorAdditionBinaryOperator::new()
+            .apply_typed(
+                &Cast::<i32, _>::new(&multiplier),
+                &Plus::<i32>::new(),
+                &Cast::<i32, _>::new(&multiplicant),
+                &Assignment::<i32>::new(),
+                &mut product,
+                &SelectEntireVector::new(context.clone()),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.element_value(&0).unwrap(), Some(200));
+        assert_eq!(product.element_value(&1).unwrap(), Some(-3));
+    }
+}
@@ -0,0 +1,163 @@
+use crate::collections::sparse_scalar::SparseScalar;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{AccumulatorBinaryOperator, BinaryOperator};
+use crate::operators::element_wise_union::{
+    ApplyElementWiseMatrixUnion, ApplyElementWiseVectorUnion, ElementWiseMatrixUnionOperator,
+    ElementWiseUnionArgument, ElementWiseVectorUnionOperator,
+};
+use crate::operators::mask::{MatrixMask, VectorMask};
+use crate::operators::options::{GetOperatorOptions, GetOptionsForOperatorWithMatrixArguments};
+use crate::value_type::ValueType;
+
+use super::{
+    TypedSparseMatrixArgument, TypedSparseMatrixProduct, TypedSparseVectorArgument,
+    TypedSparseVectorProduct,
+};
+
+macro_rules! implement_typed_element_wise_union {
+    (
+        $typed_trait:ident,
+        $untyped_trait:ident,
+        $operator:ident,
+        $argument:ident,
+        $untyped_argument:ident,
+        $product:ident,
+        $untyped_product:ident,
+        $mask:ident,
+        $options:ident
+    ) => {
+        /// The fill values of the arguments are scalars of the evaluation domain, and must not be empty.
+        pub trait $typed_trait<EvaluationDomain: ValueType> {
+            fn apply_typed(
+                &self,
+                left_argument: &ElementWiseUnionArgument<
+                    impl $argument<EvaluationDomain>,
+                    SparseScalar<EvaluationDomain>,
+                >,
+                operator: &impl BinaryOperator<EvaluationDomain>,
+                right_argument: &ElementWiseUnionArgument<
+                    impl $argument<EvaluationDomain>,
+                    SparseScalar<EvaluationDomain>,
+                >,
+                accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+                product: &mut impl $product<EvaluationDomain>,
+                mask: &impl $mask,
+                options: &impl $options,
+            ) -> Result<(), SparseLinearAlgebraError>;
+        }
+
+        impl<EvaluationDomain: ValueType> $typed_trait<EvaluationDomain> for $operator {
+            fn apply_typed(
+                &self,
+                left_argument: &ElementWiseUnionArgument<
+                    impl $argument<EvaluationDomain>,
+                    SparseScalar<EvaluationDomain>,
+                >,
+                operator: &impl BinaryOperator<EvaluationDomain>,
+                right_argument: &ElementWiseUnionArgument<
+                    impl $argument<EvaluationDomain>,
+                    SparseScalar<EvaluationDomain>,
+                >,
+                accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+                product: &mut impl $product<EvaluationDomain>,
+                mask: &impl $mask,
+                options: &impl $options,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                $untyped_trait::<EvaluationDomain>::apply(
+                    self,
+                    &ElementWiseUnionArgument::new(
+                        left_argument.argument().$untyped_argument(),
+                        left_argument.fill_value(),
+                    ),
+                    operator,
+                    &ElementWiseUnionArgument::new(
+                        right_argument.argument().$untyped_argument(),
+                        right_argument.fill_value(),
+                    ),
+                    accumulator,
+                    product.$untyped_product(),
+                    mask,
+                    options,
+                )
+            }
+        }
+    };
+}
+
+implement_typed_element_wise_union!(
+    TypedApplyElementWiseMatrixUnion,
+    ApplyElementWiseMatrixUnion,
+    ElementWiseMatrixUnionOperator,
+    TypedSparseMatrixArgument,
+    untyped_matrix,
+    TypedSparseMatrixProduct,
+    untyped_matrix_mut,
+    MatrixMask,
+    GetOptionsForOperatorWithMatrixArguments
+);
+
+implement_typed_element_wise_union!(
+    TypedApplyElementWiseVectorUnion,
+    ApplyElementWiseVectorUnion,
+    ElementWiseVectorUnionOperator,
+    TypedSparseVectorArgument,
+    untyped_vector,
+    TypedSparseVectorProduct,
+    untyped_vector_mut,
+    VectorMask,
+    GetOperatorOptions
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::collections::sparse_scalar::SparseScalar;
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::context::Context;
+    use crate::operators::binary_operator::{Assignment, First, Minus};
+    use crate::operators::element_wise_union::{
+        ElementWiseUnionArgument, ElementWiseVectorUnionOperator,
+    };
+    use crate::operators::mask::SelectEntireVector;
+    use crate::operators::options::OperatorOptions;
+    use crate::operators::typed::{Cast, TypedApplyElementWiseVectorUnion};
+
+    #[test]
+    fn subtract_vectors_in_signed_domain() {
+        let context = Context::init_default().unwrap();
+
+        let left_argument = SparseVector::<u8>::from_element_list(
+            context.clone(),
+            2,
+            VectorElementList::from_element_vector(vec![(0, 3).into()]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+        let right_argument = SparseVector::<u8>::from_element_list(
+            context.clone(),
+            2,
+            VectorElementList::from_element_vector(vec![(0, 5).into(), (1, 7).into()]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+        let zero = SparseScalar::<i16>::from_value(context.clone(), 0).unwrap();
+        let mut product = SparseVector::<i16>::new(context.clone(), 2).unwrap();
+
+        ElementWiseVectorUnionOperator::new()
+            .apply_typed(
+                &ElementWiseUnionArgument::new(&Cast::<i16, _>::new(&left_argument), &zero),
+                &Minus::<i16>::new(),
+                &ElementWiseUnionArgument::new(&Cast::<i16, _>::new(&right_argument), &zero),
+                &Assignment::<i16>::new(),
+                &mut product,
+                &SelectEntireVector::new(context.clone()),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.element_value(&0).unwrap(), Some(-2));
+        assert_eq!(product.element_value(&1).unwrap(), Some(-7));
+    }
+}
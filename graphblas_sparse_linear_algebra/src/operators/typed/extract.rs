@@ -0,0 +1,215 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::index::{ElementIndex, ElementIndexSelector};
+use crate::operators::binary_operator::AccumulatorBinaryOperator;
+use crate::operators::extract::{
+    ExtractMatrixColumn, ExtractMatrixRow, ExtractSubMatrix, ExtractSubVector,
+    MatrixColumnExtractor, MatrixRowExtractor, SubMatrixExtractor, SubVectorExtractor,
+};
+use crate::operators::mask::{MatrixMask, VectorMask};
+use crate::operators::options::{
+    GetOperatorOptions, GetOptionsForOperatorWithMatrixArgument, WithTransposeMatrixArgument,
+};
+use crate::value_type::ValueType;
+
+use super::{
+    TypedSparseMatrixArgument, TypedSparseMatrixProduct, TypedSparseVectorArgument,
+    TypedSparseVectorProduct,
+};
+
+pub trait TypedExtractSubMatrix<EvaluationDomain: ValueType> {
+    fn apply_typed(
+        &self,
+        matrix_to_extract_from: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        rows_to_extract: &ElementIndexSelector, // length must equal row_height of sub_matrix
+        columns_to_extract: &ElementIndexSelector, // length must equal column_width of sub_matrix
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        sub_matrix: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        mask: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedExtractSubMatrix<EvaluationDomain> for SubMatrixExtractor {
+    fn apply_typed(
+        &self,
+        matrix_to_extract_from: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        rows_to_extract: &ElementIndexSelector,
+        columns_to_extract: &ElementIndexSelector,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        sub_matrix: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        mask: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        ExtractSubMatrix::<EvaluationDomain>::apply(
+            self,
+            matrix_to_extract_from.untyped_matrix(),
+            rows_to_extract,
+            columns_to_extract,
+            accumulator,
+            sub_matrix.untyped_matrix_mut(),
+            mask,
+            options,
+        )
+    }
+}
+
+pub trait TypedExtractSubVector<EvaluationDomain: ValueType> {
+    /// Length of the mask must equal length of sub_vector
+    fn apply_typed(
+        &self,
+        vector_to_extract_from: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        indices_to_extract: &ElementIndexSelector,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        sub_vector: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedExtractSubVector<EvaluationDomain> for SubVectorExtractor {
+    fn apply_typed(
+        &self,
+        vector_to_extract_from: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        indices_to_extract: &ElementIndexSelector,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        sub_vector: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        ExtractSubVector::<EvaluationDomain>::apply(
+            self,
+            vector_to_extract_from.untyped_vector(),
+            indices_to_extract,
+            accumulator,
+            sub_vector.untyped_vector_mut(),
+            mask,
+            options,
+        )
+    }
+}
+
+pub trait TypedExtractMatrixRow<EvaluationDomain: ValueType> {
+    fn apply_typed(
+        &self,
+        matrix_to_extract_from: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        row_index_to_extract: ElementIndex,
+        indices_to_extract: &ElementIndexSelector,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        row_vector: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &(impl GetOptionsForOperatorWithMatrixArgument + WithTransposeMatrixArgument),
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedExtractMatrixRow<EvaluationDomain> for MatrixRowExtractor {
+    fn apply_typed(
+        &self,
+        matrix_to_extract_from: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        row_index_to_extract: ElementIndex,
+        indices_to_extract: &ElementIndexSelector,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        row_vector: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &(impl GetOptionsForOperatorWithMatrixArgument + WithTransposeMatrixArgument),
+    ) -> Result<(), SparseLinearAlgebraError> {
+        ExtractMatrixRow::<EvaluationDomain>::apply(
+            self,
+            matrix_to_extract_from.untyped_matrix(),
+            row_index_to_extract,
+            indices_to_extract,
+            accumulator,
+            row_vector.untyped_vector_mut(),
+            mask,
+            options,
+        )
+    }
+}
+
+pub trait TypedExtractMatrixColumn<EvaluationDomain: ValueType> {
+    fn apply_typed(
+        &self,
+        matrix_to_extract_from: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        column_index_to_extract: ElementIndex,
+        indices_to_extract: &ElementIndexSelector,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        column_vector: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedExtractMatrixColumn<EvaluationDomain>
+    for MatrixColumnExtractor
+{
+    fn apply_typed(
+        &self,
+        matrix_to_extract_from: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        column_index_to_extract: ElementIndex,
+        indices_to_extract: &ElementIndexSelector,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        column_vector: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        ExtractMatrixColumn::<EvaluationDomain>::apply(
+            self,
+            matrix_to_extract_from.untyped_matrix(),
+            column_index_to_extract,
+            indices_to_extract,
+            accumulator,
+            column_vector.untyped_vector_mut(),
+            mask,
+            options,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::context::Context;
+    use crate::index::ElementIndexSelector;
+    use crate::operators::binary_operator::{First, Plus};
+    use crate::operators::extract::SubVectorExtractor;
+    use crate::operators::mask::SelectEntireVector;
+    use crate::operators::options::OperatorOptions;
+    use crate::operators::typed::{CastMut, TypedExtractSubVector};
+
+    #[test]
+    fn accumulate_extracted_elements_in_argument_domain() {
+        let context = Context::init_default().unwrap();
+
+        let vector_to_extract_from = SparseVector::<i8>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::from_element_vector(vec![(1, -4).into(), (2, 5).into()]),
+            &First::<i8>::new(),
+        )
+        .unwrap();
+        let mut sub_vector = SparseVector::<f32>::from_element_list(
+            context.clone(),
+            2,
+            VectorElementList::from_element_vector(vec![(0, 0.5).into()]),
+            &First::<f32>::new(),
+        )
+        .unwrap();
+
+        // The accumulator adds in the i8 domain, dropping the fraction of the existing element
+        SubVectorExtractor::new()
+            .apply_typed(
+                &vector_to_extract_from,
+                &ElementIndexSelector::Index(&[1, 2]),
+                &Plus::<i8>::new(),
+                &mut CastMut::<i8, _>::new(&mut sub_vector),
+                &SelectEntireVector::new(context.clone()),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(sub_vector.element_value(&0).unwrap(), Some(-4.0));
+        assert_eq!(sub_vector.element_value(&1).unwrap(), Some(5.0));
+    }
+}
@@ -0,0 +1,335 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::index::{ElementIndex, ElementIndexSelector};
+use crate::operators::binary_operator::AccumulatorBinaryOperator;
+use crate::operators::insert::{
+    InsertMatrixIntoMatrix, InsertMatrixIntoMatrixOperator, InsertScalarIntoMatrix,
+    InsertScalarIntoMatrixOperator, InsertScalarIntoVector, InsertScalarIntoVectorOperator,
+    InsertVectorIntoColumn, InsertVectorIntoColumnOperator, InsertVectorIntoRow,
+    InsertVectorIntoRowOperator, InsertVectorIntoVector, InsertVectorIntoVectorOperator,
+};
+use crate::operators::mask::{MatrixMask, VectorMask};
+use crate::operators::options::{
+    GetOperatorOptions, GetOptionsForOperatorWithMatrixArgument,
+    GetOptionsForOperatorWithMatrixArguments,
+};
+use crate::value_type::ValueType;
+
+use super::{
+    TypedSparseMatrixArgument, TypedSparseMatrixProduct, TypedSparseVectorArgument,
+    TypedSparseVectorProduct,
+};
+
+pub trait TypedInsertMatrixIntoMatrix<EvaluationDomain: ValueType> {
+    /// mask and replace option apply to entire matrix_to_insert_to
+    fn apply_typed(
+        &self,
+        matrix_to_insert_into: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        rows_to_insert_into: &ElementIndexSelector, // length must equal row_height of matrix_to_insert
+        columns_to_insert_into: &ElementIndexSelector, // length must equal column_width of matrix_to_insert
+        matrix_to_insert: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        mask_for_matrix_to_insert_into: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArguments,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedInsertMatrixIntoMatrix<EvaluationDomain>
+    for InsertMatrixIntoMatrixOperator
+{
+    fn apply_typed(
+        &self,
+        matrix_to_insert_into: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        rows_to_insert_into: &ElementIndexSelector,
+        columns_to_insert_into: &ElementIndexSelector,
+        matrix_to_insert: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        mask_for_matrix_to_insert_into: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArguments,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        InsertMatrixIntoMatrix::<EvaluationDomain>::apply(
+            self,
+            matrix_to_insert_into.untyped_matrix_mut(),
+            rows_to_insert_into,
+            columns_to_insert_into,
+            matrix_to_insert.untyped_matrix(),
+            accumulator,
+            mask_for_matrix_to_insert_into,
+            options,
+        )
+    }
+}
+
+pub trait TypedInsertVectorIntoVector<EvaluationDomain: ValueType> {
+    /// mask and replace option apply to entire vector_to_insert_into
+    fn apply_typed(
+        &self,
+        vector_to_insert_into: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        indices_to_insert_into: &ElementIndexSelector,
+        vector_to_insert: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        mask_for_vector_to_insert_into: &impl VectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedInsertVectorIntoVector<EvaluationDomain>
+    for InsertVectorIntoVectorOperator
+{
+    fn apply_typed(
+        &self,
+        vector_to_insert_into: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        indices_to_insert_into: &ElementIndexSelector,
+        vector_to_insert: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        mask_for_vector_to_insert_into: &impl VectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        InsertVectorIntoVector::<EvaluationDomain>::apply(
+            self,
+            vector_to_insert_into.untyped_vector_mut(),
+            indices_to_insert_into,
+            vector_to_insert.untyped_vector(),
+            accumulator,
+            mask_for_vector_to_insert_into,
+            options,
+        )
+    }
+}
+
+pub trait TypedInsertScalarIntoMatrix<EvaluationDomain: ValueType> {
+    /// mask and replace option apply to entire matrix_to_insert_to
+    fn apply_typed(
+        &self,
+        matrix_to_insert_into: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        rows_to_insert_into: &ElementIndexSelector,
+        columns_to_insert_into: &ElementIndexSelector,
+        scalar_to_insert: EvaluationDomain,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        mask_for_matrix_to_insert_into: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedInsertScalarIntoMatrix<EvaluationDomain>
+    for InsertScalarIntoMatrixOperator
+where
+    InsertScalarIntoMatrixOperator: InsertScalarIntoMatrix<EvaluationDomain, EvaluationDomain>,
+{
+    fn apply_typed(
+        &self,
+        matrix_to_insert_into: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        rows_to_insert_into: &ElementIndexSelector,
+        columns_to_insert_into: &ElementIndexSelector,
+        scalar_to_insert: EvaluationDomain,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        mask_for_matrix_to_insert_into: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        InsertScalarIntoMatrix::<EvaluationDomain, EvaluationDomain>::apply(
+            self,
+            matrix_to_insert_into.untyped_matrix_mut(),
+            rows_to_insert_into,
+            columns_to_insert_into,
+            scalar_to_insert,
+            accumulator,
+            mask_for_matrix_to_insert_into,
+            options,
+        )
+    }
+}
+
+pub trait TypedInsertScalarIntoVector<EvaluationDomain: ValueType> {
+    /// mask and replace option apply to entire vector_to_insert_to
+    fn apply_typed(
+        &self,
+        vector_to_insert_into: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        indices_to_insert_into: &ElementIndexSelector,
+        scalar_to_insert: EvaluationDomain,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        mask_for_vector_to_insert_into: &impl VectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedInsertScalarIntoVector<EvaluationDomain>
+    for InsertScalarIntoVectorOperator
+where
+    InsertScalarIntoVectorOperator: InsertScalarIntoVector<EvaluationDomain, EvaluationDomain>,
+{
+    fn apply_typed(
+        &self,
+        vector_to_insert_into: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        indices_to_insert_into: &ElementIndexSelector,
+        scalar_to_insert: EvaluationDomain,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        mask_for_vector_to_insert_into: &impl VectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        InsertScalarIntoVector::<EvaluationDomain, EvaluationDomain>::apply(
+            self,
+            vector_to_insert_into.untyped_vector_mut(),
+            indices_to_insert_into,
+            scalar_to_insert,
+            accumulator,
+            mask_for_vector_to_insert_into,
+            options,
+        )
+    }
+}
+
+pub trait TypedInsertVectorIntoRow<EvaluationDomain: ValueType> {
+    /// mask and replace option apply to entire matrix_to_insert_to
+    fn apply_typed(
+        &self,
+        matrix_to_insert_into: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        row_indices_to_insert_into: &ElementIndexSelector,
+        row_to_insert_into: &ElementIndex,
+        vector_to_insert: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        mask_for_row_to_insert_into: &impl VectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedInsertVectorIntoRow<EvaluationDomain>
+    for InsertVectorIntoRowOperator
+{
+    fn apply_typed(
+        &self,
+        matrix_to_insert_into: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        row_indices_to_insert_into: &ElementIndexSelector,
+        row_to_insert_into: &ElementIndex,
+        vector_to_insert: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        mask_for_row_to_insert_into: &impl VectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        InsertVectorIntoRow::<EvaluationDomain>::apply(
+            self,
+            matrix_to_insert_into.untyped_matrix_mut(),
+            row_indices_to_insert_into,
+            row_to_insert_into,
+            vector_to_insert.untyped_vector(),
+            accumulator,
+            mask_for_row_to_insert_into,
+            options,
+        )
+    }
+}
+
+pub trait TypedInsertVectorIntoColumn<EvaluationDomain: ValueType> {
+    /// mask and replace option apply to entire matrix_to_insert_to
+    fn apply_typed(
+        &self,
+        matrix_to_insert_into: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        column_indices_to_insert_into: &ElementIndexSelector,
+        column_to_insert_into: &ElementIndex,
+        vector_to_insert: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        mask_for_column_to_insert_into: &impl VectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedInsertVectorIntoColumn<EvaluationDomain>
+    for InsertVectorIntoColumnOperator
+{
+    fn apply_typed(
+        &self,
+        matrix_to_insert_into: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        column_indices_to_insert_into: &ElementIndexSelector,
+        column_to_insert_into: &ElementIndex,
+        vector_to_insert: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        mask_for_column_to_insert_into: &impl VectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        InsertVectorIntoColumn::<EvaluationDomain>::apply(
+            self,
+            matrix_to_insert_into.untyped_matrix_mut(),
+            column_indices_to_insert_into,
+            column_to_insert_into,
+            vector_to_insert.untyped_vector(),
+            accumulator,
+            mask_for_column_to_insert_into,
+            options,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::context::Context;
+    use crate::index::ElementIndexSelector;
+    use crate::operators::binary_operator::{Assignment, First, Plus};
+    use crate::operators::insert::{
+        InsertScalarIntoVectorOperator, InsertVectorIntoVectorOperator,
+    };
+    use crate::operators::mask::SelectEntireVector;
+    use crate::operators::options::OperatorOptions;
+    use crate::operators::typed::{
+        Cast, CastMut, TypedInsertScalarIntoVector, TypedInsertVectorIntoVector,
+    };
+
+    #[test]
+    fn insert_vector_of_other_type_with_explicit_cast() {
+        let context = Context::init_default().unwrap();
+
+        let mut vector_to_insert_into = SparseVector::<f64>::new(context.clone(), 3).unwrap();
+        let vector_to_insert = SparseVector::<u16>::from_element_list(
+            context.clone(),
+            2,
+            VectorElementList::from_element_vector(vec![(0, 1).into(), (1, 2).into()]),
+            &First::<u16>::new(),
+        )
+        .unwrap();
+
+        InsertVectorIntoVectorOperator::new()
+            .apply_typed(
+                &mut vector_to_insert_into,
+                &ElementIndexSelector::Index(&[2, 0]),
+                &Cast::<f64, _>::new(&vector_to_insert),
+                &Assignment::<f64>::new(),
+                &SelectEntireVector::new(context.clone()),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(vector_to_insert_into.element_value(&0).unwrap(), Some(2.0));
+        assert_eq!(vector_to_insert_into.element_value(&1).unwrap(), None);
+        assert_eq!(vector_to_insert_into.element_value(&2).unwrap(), Some(1.0));
+    }
+
+    #[test]
+    fn insert_scalar_in_domain_of_accumulator() {
+        let context = Context::init_default().unwrap();
+
+        let mut vector_to_insert_into = SparseVector::<f32>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::from_element_vector(vec![(0, 1.5).into()]),
+            &First::<f32>::new(),
+        )
+        .unwrap();
+
+        // The existing element is typecast to u8 before the accumulator adds the scalar
+        InsertScalarIntoVectorOperator::new()
+            .apply_typed(
+                &mut CastMut::<u8, _>::new(&mut vector_to_insert_into),
+                &ElementIndexSelector::Index(&[0, 2]),
+                2u8,
+                &Plus::<u8>::new(),
+                &SelectEntireVector::new(context.clone()),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(vector_to_insert_into.element_value(&0).unwrap(), Some(3.0));
+        assert_eq!(vector_to_insert_into.element_value(&1).unwrap(), None);
+        assert_eq!(vector_to_insert_into.element_value(&2).unwrap(), Some(2.0));
+    }
+}
@@ -0,0 +1,126 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{AccumulatorBinaryOperator, BinaryOperator};
+use crate::operators::kronecker_product::{
+    BinaryOperatorKroneckerProduct, BinaryOperatorKroneckerProductOperator, MonoidKroneckerProduct,
+    MonoidKroneckerProductOperator, SemiringKroneckerProduct, SemiringKroneckerProductOperator,
+};
+use crate::operators::mask::MatrixMask;
+use crate::operators::monoid::Monoid;
+use crate::operators::options::GetOptionsForOperatorWithMatrixArguments;
+use crate::operators::semiring::TypedSemiring;
+use crate::value_type::ValueType;
+
+use super::{TypedSparseMatrixArgument, TypedSparseMatrixProduct};
+
+macro_rules! implement_typed_kronecker_product {
+    ($typed_trait:ident, $untyped_trait:ident, $operator:ident, $multiplication_operator:path) => {
+        pub trait $typed_trait<EvaluationDomain: ValueType> {
+            fn apply_typed(
+                &self,
+                multiplier: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+                multiplication_operator: &impl $multiplication_operator,
+                multiplicant: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+                accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+                product: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+                mask: &impl MatrixMask,
+                options: &impl GetOptionsForOperatorWithMatrixArguments,
+            ) -> Result<(), SparseLinearAlgebraError>;
+        }
+
+        impl<EvaluationDomain: ValueType> $typed_trait<EvaluationDomain> for $operator {
+            fn apply_typed(
+                &self,
+                multiplier: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+                multiplication_operator: &impl $multiplication_operator,
+                multiplicant: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+                accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+                product: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+                mask: &impl MatrixMask,
+                options: &impl GetOptionsForOperatorWithMatrixArguments,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                $untyped_trait::<EvaluationDomain>::apply(
+                    self,
+                    multiplier.untyped_matrix(),
+                    multiplication_operator,
+                    multiplicant.untyped_matrix(),
+                    accumulator,
+                    product.untyped_matrix_mut(),
+                    mask,
+                    options,
+                )
+            }
+        }
+    };
+}
+
+implement_typed_kronecker_product!(
+    TypedSemiringKroneckerProduct,
+    SemiringKroneckerProduct,
+    SemiringKroneckerProductOperator,
+    TypedSemiring<EvaluationDomain, OutputDomain = EvaluationDomain>
+);
+
+implement_typed_kronecker_product!(
+    TypedMonoidKroneckerProduct,
+    MonoidKroneckerProduct,
+    MonoidKroneckerProductOperator,
+    Monoid<EvaluationDomain>
+);
+
+implement_typed_kronecker_product!(
+    TypedBinaryOperatorKroneckerProduct,
+    BinaryOperatorKroneckerProduct,
+    BinaryOperatorKroneckerProductOperator,
+    BinaryOperator<EvaluationDomain>
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::context::Context;
+    use crate::operators::binary_operator::{Assignment, First, Times};
+    use crate::operators::kronecker_product::BinaryOperatorKroneckerProductOperator;
+    use crate::operators::mask::SelectEntireMatrix;
+    use crate::operators::options::OptionsForOperatorWithMatrixArguments;
+    use crate::operators::typed::{Cast, TypedBinaryOperatorKroneckerProduct};
+
+    #[test]
+    fn kronecker_product_in_explicit_domain() {
+        let context = Context::init_default().unwrap();
+
+        let multiplier = SparseMatrix::<u8>::from_element_list(
+            context.clone(),
+            Size::new(1, 2),
+            MatrixElementList::from_element_vector(vec![(0, 0, 20).into(), (0, 1, 2).into()]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseMatrix::<u16>::from_element_list(
+            context.clone(),
+            Size::new(1, 1),
+            MatrixElementList::from_element_vector(vec![(0, 0, 30).into()]),
+            &First::<u16>::new(),
+        )
+        .unwrap();
+        let mut product = SparseMatrix::<u16>::new(context.clone(), Size::new(1, 2)).unwrap();
+
+        // The product of 20 and 30 would overflow in the u8 domain
+        BinaryOperatorKroneckerProductOperator::new()
+            .apply_typed(
+                &Cast::<u16, _>::new(&multiplier),
+                &Times::<u16>::new(),
+                &multiplicant,
+                &Assignment::<u16>::new(),
+                &mut product,
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.element_value(&0, &0).unwrap(), Some(600));
+        assert_eq!(product.element_value(&0, &1).unwrap(), Some(60));
+    }
+}
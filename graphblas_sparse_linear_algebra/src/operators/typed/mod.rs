@@ -0,0 +1,86 @@
+//! Operators that check at compile time that the arguments, the product and the operator share one domain.
+//!
+//! The operators in the parent modules accept collections of any value type, and GraphBLAS silently typecasts
+//! between them. The traits in this module are implemented by the same operators, but only accept a collection
+//! of another value type through an explicit Cast or CastMut. Their methods carry a `_typed` suffix, such that
+//! the typed and untyped traits can be imported in the same scope.
+//!
+//! ```compile_fail
+//! use graphblas_sparse_linear_algebra::collections::sparse_matrix::{Size, SparseMatrix};
+//! use graphblas_sparse_linear_algebra::context::Context;
+//! use graphblas_sparse_linear_algebra::operators::binary_operator::Assignment;
+//! use graphblas_sparse_linear_algebra::operators::mask::SelectEntireMatrix;
+//! use graphblas_sparse_linear_algebra::operators::multiplication::MatrixMultiplicationOperator;
+//! use graphblas_sparse_linear_algebra::operators::options::OptionsForOperatorWithMatrixArguments;
+//! use graphblas_sparse_linear_algebra::operators::semiring::PlusTimes;
+//! use graphblas_sparse_linear_algebra::operators::typed::TypedMultiplyMatrices;
+//!
+//! let context = Context::init_default().unwrap();
+//! let multiplier = SparseMatrix::<u8>::new(context.clone(), Size::new(2, 2)).unwrap();
+//! let multiplicant = SparseMatrix::<f32>::new(context.clone(), Size::new(2, 2)).unwrap();
+//! let mut product = SparseMatrix::<f32>::new(context.clone(), Size::new(2, 2)).unwrap();
+//!
+//! // A SparseMatrix<u8> is only an argument in the f32 domain through Cast::<f32, _>::new(&multiplier)
+//! MatrixMultiplicationOperator::new().apply_typed(
+//!     &multiplier,
+//!     &PlusTimes::<f32>::new(),
+//!     &multiplicant,
+//!     &Assignment::<f32>::new(),
+//!     &mut product,
+//!     &SelectEntireMatrix::new(context.clone()),
+//!     &OptionsForOperatorWithMatrixArguments::new_default(),
+//! );
+//! ```
+//!
+//! A typed multiplication also requires the product of the semiring to be in the evaluation domain.
+//! Comparison semirings, such as AnyEqualTo, produce a boolean:
+//!
+//! ```compile_fail
+//! use graphblas_sparse_linear_algebra::collections::sparse_matrix::{Size, SparseMatrix};
+//! use graphblas_sparse_linear_algebra::context::Context;
+//! use graphblas_sparse_linear_algebra::operators::binary_operator::Assignment;
+//! use graphblas_sparse_linear_algebra::operators::mask::SelectEntireMatrix;
+//! use graphblas_sparse_linear_algebra::operators::multiplication::MatrixMultiplicationOperator;
+//! use graphblas_sparse_linear_algebra::operators::options::OptionsForOperatorWithMatrixArguments;
+//! use graphblas_sparse_linear_algebra::operators::semiring::AnyEqualTo;
+//! use graphblas_sparse_linear_algebra::operators::typed::TypedMultiplyMatrices;
+//!
+//! let context = Context::init_default().unwrap();
+//! let multiplier = SparseMatrix::<f32>::new(context.clone(), Size::new(2, 2)).unwrap();
+//! let multiplicant = SparseMatrix::<f32>::new(context.clone(), Size::new(2, 2)).unwrap();
+//! let mut product = SparseMatrix::<f32>::new(context.clone(), Size::new(2, 2)).unwrap();
+//!
+//! MatrixMultiplicationOperator::new().apply_typed(
+//!     &multiplier,
+//!     &AnyEqualTo::<f32>::new(),
+//!     &multiplicant,
+//!     &Assignment::<f32>::new(),
+//!     &mut product,
+//!     &SelectEntireMatrix::new(context.clone()),
+//!     &OptionsForOperatorWithMatrixArguments::new_default(),
+//! );
+//! ```
+
+mod apply;
+mod argument;
+mod element_wise;
+mod element_wise_union;
+mod extract;
+mod insert;
+mod kronecker_product;
+mod multiplication;
+mod reduce;
+mod select;
+mod transpose;
+
+pub use apply::*;
+pub use argument::*;
+pub use element_wise::*;
+pub use element_wise_union::*;
+pub use extract::*;
+pub use insert::*;
+pub use kronecker_product::*;
+pub use multiplication::*;
+pub use reduce::*;
+pub use select::*;
+pub use transpose::*;
@@ -0,0 +1,238 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::AccumulatorBinaryOperator;
+use crate::operators::mask::{MatrixMask, VectorMask};
+use crate::operators::multiplication::{
+    MatrixMultiplicationOperator, MatrixVectorMultiplicationOperator, MultiplyMatrices,
+    MultiplyMatrixByVector, MultiplyVectorByMatrix, VectorMatrixMultiplicationOperator,
+};
+use crate::operators::options::{
+    GetOptionsForOperatorWithMatrixArguments, GetOptionsForOperatorWithMatrixAsFirstArgument,
+    GetOptionsForOperatorWithMatrixAsSecondArgument,
+};
+use crate::operators::semiring::TypedSemiring;
+use crate::value_type::ValueType;
+
+use super::{
+    TypedSparseMatrixArgument, TypedSparseMatrixProduct, TypedSparseVectorArgument,
+    TypedSparseVectorProduct,
+};
+
+pub trait TypedMultiplyMatrices<EvaluationDomain: ValueType> {
+    fn apply_typed(
+        &self,
+        multiplier: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        operator: &impl TypedSemiring<EvaluationDomain, OutputDomain = EvaluationDomain>,
+        multiplicant: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        mask: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArguments,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedMultiplyMatrices<EvaluationDomain>
+    for MatrixMultiplicationOperator
+{
+    fn apply_typed(
+        &self,
+        multiplier: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        operator: &impl TypedSemiring<EvaluationDomain, OutputDomain = EvaluationDomain>,
+        multiplicant: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        mask: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArguments,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MultiplyMatrices::<EvaluationDomain>::apply(
+            self,
+            multiplier.untyped_matrix(),
+            operator,
+            multiplicant.untyped_matrix(),
+            accumulator,
+            product.untyped_matrix_mut(),
+            mask,
+            options,
+        )
+    }
+}
+
+pub trait TypedMultiplyMatrixByVector<EvaluationDomain: ValueType> {
+    fn apply_typed(
+        &self,
+        multiplier: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        operator: &impl TypedSemiring<EvaluationDomain, OutputDomain = EvaluationDomain>,
+        multiplicant: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixAsFirstArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedMultiplyMatrixByVector<EvaluationDomain>
+    for MatrixVectorMultiplicationOperator
+{
+    fn apply_typed(
+        &self,
+        multiplier: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        operator: &impl TypedSemiring<EvaluationDomain, OutputDomain = EvaluationDomain>,
+        multiplicant: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixAsFirstArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MultiplyMatrixByVector::<EvaluationDomain>::apply(
+            self,
+            multiplier.untyped_matrix(),
+            operator,
+            multiplicant.untyped_vector(),
+            accumulator,
+            product.untyped_vector_mut(),
+            mask,
+            options,
+        )
+    }
+}
+
+pub trait TypedMultiplyVectorByMatrix<EvaluationDomain: ValueType> {
+    fn apply_typed(
+        &self,
+        multiplier: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        operator: &impl TypedSemiring<EvaluationDomain, OutputDomain = EvaluationDomain>,
+        multiplicant: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixAsSecondArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedMultiplyVectorByMatrix<EvaluationDomain>
+    for VectorMatrixMultiplicationOperator
+{
+    fn apply_typed(
+        &self,
+        multiplier: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        operator: &impl TypedSemiring<EvaluationDomain, OutputDomain = EvaluationDomain>,
+        multiplicant: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixAsSecondArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MultiplyVectorByMatrix::<EvaluationDomain>::apply(
+            self,
+            multiplier.untyped_vector(),
+            operator,
+            multiplicant.untyped_matrix(),
+            accumulator,
+            product.untyped_vector_mut(),
+            mask,
+            options,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collections::sparse_matrix::operations::{
+        FromMatrixElementList, GetSparseMatrixElementValue,
+    };
+    use crate::collections::sparse_matrix::{MatrixElementList, Size, SparseMatrix};
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::context::Context;
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::mask::{SelectEntireMatrix, SelectEntireVector};
+    use crate::operators::multiplication::{
+        MatrixMultiplicationOperator, MatrixVectorMultiplicationOperator,
+    };
+    use crate::operators::options::{
+        OptionsForOperatorWithMatrixArguments, OptionsForOperatorWithMatrixAsFirstArgument,
+    };
+    use crate::operators::semiring::PlusTimes;
+    use crate::operators::typed::{
+        Cast, CastMut, TypedMultiplyMatrices, TypedMultiplyMatrixByVector,
+    };
+
+    #[test]
+    fn multiply_matrices_with_explicit_casts() {
+        let context = Context::init_default().unwrap();
+        let size = Size::new(2, 2);
+
+        let multiplier = SparseMatrix::<u8>::from_element_list(
+            context.clone(),
+            size,
+            MatrixElementList::from_element_vector(vec![(0, 0, 200).into(), (1, 1, 3).into()]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseMatrix::<f32>::from_element_list(
+            context.clone(),
+            size,
+            MatrixElementList::from_element_vector(vec![(0, 0, 2.0).into(), (1, 1, 0.5).into()]),
+            &First::<f32>::new(),
+        )
+        .unwrap();
+        let mut product = SparseMatrix::<f64>::new(context.clone(), size).unwrap();
+
+        // The product of 200 and 2 would overflow in the u8 domain
+        MatrixMultiplicationOperator::new()
+            .apply_typed(
+                &Cast::<f32, _>::new(&multiplier),
+                &PlusTimes::<f32>::new(),
+                &multiplicant,
+                &Assignment::<f32>::new(),
+                &mut CastMut::<f32, _>::new(&mut product),
+                &SelectEntireMatrix::new(context.clone()),
+                &OptionsForOperatorWithMatrixArguments::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.element_value(&0, &0).unwrap(), Some(400.0));
+        assert_eq!(product.element_value(&1, &1).unwrap(), Some(1.5));
+    }
+
+    #[test]
+    fn multiply_matrix_by_vector_of_matching_type() {
+        let context = Context::init_default().unwrap();
+
+        let multiplier = SparseMatrix::<i32>::from_element_list(
+            context.clone(),
+            Size::new(2, 2),
+            MatrixElementList::from_element_vector(vec![
+                (0, 0, 1).into(),
+                (0, 1, 2).into(),
+                (1, 1, 3).into(),
+            ]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseVector::<i32>::from_element_list(
+            context.clone(),
+            2,
+            VectorElementList::from_element_vector(vec![(0, 4).into(), (1, 5).into()]),
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let mut product = SparseVector::<i32>::new(context.clone(), 2).unwrap();
+
+        MatrixVectorMultiplicationOperator::new()
+            .apply_typed(
+                &multiplier,
+                &PlusTimes::<i32>::new(),
+                &multiplicant,
+                &Assignment::<i32>::new(),
+                &mut product,
+                &SelectEntireVector::new(context.clone()),
+                &OptionsForOperatorWithMatrixAsFirstArgument::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.element_value(&0).unwrap(), Some(14));
+        assert_eq!(product.element_value(&1).unwrap(), Some(15));
+    }
+}
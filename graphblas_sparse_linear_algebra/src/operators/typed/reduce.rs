@@ -0,0 +1,337 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{AccumulatorBinaryOperator, BinaryOperator};
+use crate::operators::mask::VectorMask;
+use crate::operators::monoid::Monoid;
+use crate::operators::options::{
+    GetOperatorOptions, GetOptionsForOperatorWithMatrixArgument, WithTransposeMatrixArgument,
+};
+use crate::operators::reduce::{
+    BinaryOperatorReducer, BinaryOperatorScalarReducer, MonoidReducer, MonoidScalarReducer,
+    MonoidVectorReducer, ReduceWithBinaryOperator,
+};
+use crate::value_type::ValueType;
+
+use super::{
+    TypedSparseMatrixArgument, TypedSparseScalarProduct, TypedSparseVectorArgument,
+    TypedSparseVectorProduct,
+};
+
+pub trait TypedMonoidVectorReducer<EvaluationDomain: ValueType> {
+    fn to_column_vector_typed(
+        &self,
+        operator: &impl Monoid<EvaluationDomain>,
+        argument: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+
+    fn to_row_vector_typed(
+        &self,
+        operator: &impl Monoid<EvaluationDomain>,
+        argument: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &(impl GetOptionsForOperatorWithMatrixArgument + WithTransposeMatrixArgument),
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedMonoidVectorReducer<EvaluationDomain> for MonoidReducer {
+    fn to_column_vector_typed(
+        &self,
+        operator: &impl Monoid<EvaluationDomain>,
+        argument: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MonoidVectorReducer::<EvaluationDomain>::to_column_vector(
+            self,
+            operator,
+            argument.untyped_matrix(),
+            accumulator,
+            product.untyped_vector_mut(),
+            mask,
+            options,
+        )
+    }
+
+    fn to_row_vector_typed(
+        &self,
+        operator: &impl Monoid<EvaluationDomain>,
+        argument: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &(impl GetOptionsForOperatorWithMatrixArgument + WithTransposeMatrixArgument),
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MonoidVectorReducer::<EvaluationDomain>::to_row_vector(
+            self,
+            operator,
+            argument.untyped_matrix(),
+            accumulator,
+            product.untyped_vector_mut(),
+            mask,
+            options,
+        )
+    }
+}
+
+pub trait TypedMonoidScalarReducer<EvaluationDomain: ValueType> {
+    fn matrix_to_scalar_typed(
+        &self,
+        operator: &impl Monoid<EvaluationDomain>,
+        argument: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut EvaluationDomain,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+
+    fn vector_to_scalar_typed(
+        &self,
+        operator: &impl Monoid<EvaluationDomain>,
+        argument: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut EvaluationDomain,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedMonoidScalarReducer<EvaluationDomain> for MonoidReducer
+where
+    MonoidReducer: MonoidScalarReducer<EvaluationDomain>,
+{
+    fn matrix_to_scalar_typed(
+        &self,
+        operator: &impl Monoid<EvaluationDomain>,
+        argument: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut EvaluationDomain,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MonoidScalarReducer::<EvaluationDomain>::matrix_to_scalar(
+            self,
+            operator,
+            argument.untyped_matrix(),
+            accumulator,
+            product,
+            options,
+        )
+    }
+
+    fn vector_to_scalar_typed(
+        &self,
+        operator: &impl Monoid<EvaluationDomain>,
+        argument: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut EvaluationDomain,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MonoidScalarReducer::<EvaluationDomain>::vector_to_scalar(
+            self,
+            operator,
+            argument.untyped_vector(),
+            accumulator,
+            product,
+            options,
+        )
+    }
+}
+
+pub trait TypedReduceWithBinaryOperator<EvaluationDomain: ValueType> {
+    fn to_column_vector_typed(
+        &self,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        argument: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+
+    fn to_row_vector_typed(
+        &self,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        argument: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &(impl GetOptionsForOperatorWithMatrixArgument + WithTransposeMatrixArgument),
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedReduceWithBinaryOperator<EvaluationDomain>
+    for BinaryOperatorReducer
+{
+    fn to_column_vector_typed(
+        &self,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        argument: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        ReduceWithBinaryOperator::<EvaluationDomain>::to_colunm_vector(
+            self,
+            operator,
+            argument.untyped_matrix(),
+            accumulator,
+            product.untyped_vector_mut(),
+            mask,
+            options,
+        )
+    }
+
+    fn to_row_vector_typed(
+        &self,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        argument: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &(impl GetOptionsForOperatorWithMatrixArgument + WithTransposeMatrixArgument),
+    ) -> Result<(), SparseLinearAlgebraError> {
+        ReduceWithBinaryOperator::<EvaluationDomain>::to_row_vector(
+            self,
+            operator,
+            argument.untyped_matrix(),
+            accumulator,
+            product.untyped_vector_mut(),
+            mask,
+            options,
+        )
+    }
+}
+
+pub trait TypedBinaryOperatorScalarReducer<EvaluationDomain: ValueType> {
+    fn matrix_to_scalar_typed(
+        &self,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        argument: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseScalarProduct<EvaluationDomain>,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+
+    fn vector_to_scalar_typed(
+        &self,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        argument: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseScalarProduct<EvaluationDomain>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedBinaryOperatorScalarReducer<EvaluationDomain>
+    for BinaryOperatorReducer
+{
+    fn matrix_to_scalar_typed(
+        &self,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        argument: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseScalarProduct<EvaluationDomain>,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        BinaryOperatorScalarReducer::<EvaluationDomain>::matrix_to_scalar(
+            self,
+            operator,
+            argument.untyped_matrix(),
+            accumulator,
+            product.untyped_scalar_mut(),
+            options,
+        )
+    }
+
+    fn vector_to_scalar_typed(
+        &self,
+        operator: &impl BinaryOperator<EvaluationDomain>,
+        argument: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseScalarProduct<EvaluationDomain>,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        BinaryOperatorScalarReducer::<EvaluationDomain>::vector_to_scalar(
+            self,
+            operator,
+            argument.untyped_vector(),
+            accumulator,
+            product.untyped_scalar_mut(),
+            options,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collections::sparse_scalar::{GetScalarValue, SparseScalar};
+    use crate::collections::sparse_vector::operations::FromVectorElementList;
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::context::Context;
+    use crate::operators::binary_operator::Max;
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::monoid::Plus;
+    use crate::operators::options::OperatorOptions;
+    use crate::operators::reduce::BinaryOperatorReducer;
+    use crate::operators::reduce::MonoidReducer;
+    use crate::operators::typed::{
+        Cast, CastMut, TypedBinaryOperatorScalarReducer, TypedMonoidScalarReducer,
+    };
+
+    #[test]
+    fn sum_vector_in_wider_domain() {
+        let context = Context::init_default().unwrap();
+
+        let argument = SparseVector::<u8>::from_element_list(
+            context.clone(),
+            2,
+            VectorElementList::from_element_vector(vec![(0, 200).into(), (1, 100).into()]),
+            &First::<u8>::new(),
+        )
+        .unwrap();
+        let mut product = 0u32;
+
+        MonoidReducer::new()
+            .vector_to_scalar_typed(
+                &Plus::<u32>::new(),
+                &Cast::<u32, _>::new(&argument),
+                &Assignment::<u32>::new(),
+                &mut product,
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product, 300);
+    }
+
+    #[test]
+    fn reduce_vector_with_binary_operator_into_scalar_of_other_type() {
+        let context = Context::init_default().unwrap();
+
+        let argument = SparseVector::<i16>::from_element_list(
+            context.clone(),
+            3,
+            VectorElementList::from_element_vector(vec![(0, 300).into(), (2, -7).into()]),
+            &First::<i16>::new(),
+        )
+        .unwrap();
+        let mut product = SparseScalar::<f64>::new(context.clone()).unwrap();
+
+        BinaryOperatorReducer::new()
+            .vector_to_scalar_typed(
+                &Max::<i16>::new(),
+                &argument,
+                &Assignment::<i16>::new(),
+                &mut CastMut::<i16, _>::new(&mut product),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.get_value().unwrap(), Some(300.0));
+    }
+}
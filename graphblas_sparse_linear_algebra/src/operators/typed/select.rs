@@ -0,0 +1,94 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::AccumulatorBinaryOperator;
+use crate::operators::index_unary_operator::IndexUnaryOperator;
+use crate::operators::mask::{MatrixMask, VectorMask};
+use crate::operators::options::{GetOperatorOptions, GetOptionsForOperatorWithMatrixArgument};
+use crate::operators::select::{
+    MatrixSelector, SelectFromMatrix, SelectFromVector, VectorSelector,
+};
+use crate::value_type::ValueType;
+
+use super::{
+    TypedSparseMatrixArgument, TypedSparseMatrixProduct, TypedSparseVectorArgument,
+    TypedSparseVectorProduct,
+};
+
+pub trait TypedSelectFromMatrix<EvaluationDomain: ValueType> {
+    fn apply_typed(
+        &self,
+        selector: &impl IndexUnaryOperator<EvaluationDomain>,
+        selector_argument: EvaluationDomain,
+        argument: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        mask: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedSelectFromMatrix<EvaluationDomain> for MatrixSelector
+where
+    MatrixSelector: SelectFromMatrix<EvaluationDomain>,
+{
+    fn apply_typed(
+        &self,
+        selector: &impl IndexUnaryOperator<EvaluationDomain>,
+        selector_argument: EvaluationDomain,
+        argument: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        mask: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        SelectFromMatrix::<EvaluationDomain>::apply(
+            self,
+            selector,
+            selector_argument,
+            argument.untyped_matrix(),
+            accumulator,
+            product.untyped_matrix_mut(),
+            mask,
+            options,
+        )
+    }
+}
+
+pub trait TypedSelectFromVector<EvaluationDomain: ValueType> {
+    fn apply_typed(
+        &self,
+        selector: &impl IndexUnaryOperator<EvaluationDomain>,
+        selector_argument: EvaluationDomain,
+        argument: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedSelectFromVector<EvaluationDomain> for VectorSelector
+where
+    VectorSelector: SelectFromVector<EvaluationDomain>,
+{
+    fn apply_typed(
+        &self,
+        selector: &impl IndexUnaryOperator<EvaluationDomain>,
+        selector_argument: EvaluationDomain,
+        argument: &impl TypedSparseVectorArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        product: &mut impl TypedSparseVectorProduct<EvaluationDomain>,
+        mask: &impl VectorMask,
+        options: &impl GetOperatorOptions,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        SelectFromVector::<EvaluationDomain>::apply(
+            self,
+            selector,
+            selector_argument,
+            argument.untyped_vector(),
+            accumulator,
+            product.untyped_vector_mut(),
+            mask,
+            options,
+        )
+    }
+}
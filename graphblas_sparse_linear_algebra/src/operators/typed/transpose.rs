@@ -0,0 +1,39 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::AccumulatorBinaryOperator;
+use crate::operators::mask::MatrixMask;
+use crate::operators::options::GetOptionsForOperatorWithMatrixArgument;
+use crate::operators::transpose::{MatrixTranspose, TransposeMatrix};
+use crate::value_type::ValueType;
+
+use super::{TypedSparseMatrixArgument, TypedSparseMatrixProduct};
+
+pub trait TypedTransposeMatrix<EvaluationDomain: ValueType> {
+    fn apply_typed(
+        &self,
+        matrix: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        transpose: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        mask: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<EvaluationDomain: ValueType> TypedTransposeMatrix<EvaluationDomain> for MatrixTranspose {
+    fn apply_typed(
+        &self,
+        matrix: &impl TypedSparseMatrixArgument<EvaluationDomain>,
+        accumulator: &impl AccumulatorBinaryOperator<EvaluationDomain>,
+        transpose: &mut impl TypedSparseMatrixProduct<EvaluationDomain>,
+        mask: &impl MatrixMask,
+        options: &impl GetOptionsForOperatorWithMatrixArgument,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        TransposeMatrix::<EvaluationDomain>::apply(
+            self,
+            matrix.untyped_matrix(),
+            accumulator,
+            transpose.untyped_matrix_mut(),
+            mask,
+            options,
+        )
+    }
+}
@@ -0,0 +1,212 @@
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::sync::Arc;
+
+use crate::context::{Context, GetContext};
+use crate::error::SparseLinearAlgebraError;
+use crate::graphblas_bindings::{GrB_UnaryOp, GrB_UnaryOp_free, GrB_UnaryOp_new};
+use crate::value_type::ValueType;
+
+use super::UnaryOperator;
+
+/// Computes the product from the argument. GraphBLAS typecasts the argument and product as required.
+///
+/// Implement it for a unit struct, and pass that struct to CustomUnaryOperator::new().
+/// GraphBLAS calls the function from C, so a panic aborts the process.
+pub trait UnaryOperatorFunction<Argument, Product> {
+    fn product(argument: &Argument) -> Product;
+}
+
+// GraphBLAS may pass the same pointer for the product and the argument,
+// so the argument is read before the product is written.
+unsafe extern "C" fn call_unary_operator_function<
+    Argument,
+    Product,
+    Function: UnaryOperatorFunction<Argument, Product>,
+>(
+    product: *mut c_void,
+    argument: *const c_void,
+) {
+    let argument = ptr::read_unaligned(argument as *const Argument);
+    ptr::write_unaligned(product as *mut Product, Function::product(&argument));
+}
+
+/// A unary operator defined by a Rust function.
+///
+/// The GraphBLAS operator is freed when the CustomUnaryOperator is dropped.
+#[derive(Debug)]
+pub struct CustomUnaryOperator<Argument: ValueType, Product: ValueType = Argument> {
+    context: Arc<Context>,
+    graphblas_operator: GrB_UnaryOp,
+    _argument_type: PhantomData<Argument>,
+    _product_type: PhantomData<Product>,
+}
+
+// Implemented methods do not provide mutable access to GraphBLAS operators or options.
+// Code review must consider that no mtable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl<Argument: ValueType, Product: ValueType> Send
+    for CustomUnaryOperator<Argument, Product>
+{
+}
+unsafe impl<Argument: ValueType, Product: ValueType> Sync
+    for CustomUnaryOperator<Argument, Product>
+{
+}
+
+impl<Argument: ValueType, Product: ValueType> CustomUnaryOperator<Argument, Product> {
+    pub fn new<Function: UnaryOperatorFunction<Argument, Product>>(
+        context: Arc<Context>,
+        _function: Function,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        let mut graphblas_operator: MaybeUninit<GrB_UnaryOp> = MaybeUninit::uninit();
+
+        let product_type = Product::graphblas_type_in_context(&context)?;
+        let argument_type = Argument::graphblas_type_in_context(&context)?;
+
+        context.call_without_detailed_error_information(|| unsafe {
+            GrB_UnaryOp_new(
+                graphblas_operator.as_mut_ptr(),
+                Some(call_unary_operator_function::<Argument, Product, Function>),
+                product_type,
+                argument_type,
+            )
+        })?;
+
+        Ok(Self {
+            context,
+            graphblas_operator: unsafe { graphblas_operator.assume_init() },
+            _argument_type: PhantomData,
+            _product_type: PhantomData,
+        })
+    }
+}
+
+impl<Argument: ValueType, Product: ValueType> UnaryOperator<Argument>
+    for CustomUnaryOperator<Argument, Product>
+{
+    fn graphblas_type(&self) -> GrB_UnaryOp {
+        self.graphblas_operator
+    }
+}
+
+impl<Argument: ValueType, Product: ValueType> GetContext
+    for CustomUnaryOperator<Argument, Product>
+{
+    fn context(&self) -> Arc<Context> {
+        self.context.clone()
+    }
+
+    fn context_ref(&self) -> &Arc<Context> {
+        &self.context
+    }
+}
+
+impl<Argument: ValueType, Product: ValueType> Drop for CustomUnaryOperator<Argument, Product> {
+    fn drop(&mut self) {
+        let _ = self
+            .context
+            .call_without_detailed_error_information(|| unsafe {
+                GrB_UnaryOp_free(&mut self.graphblas_operator)
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::sparse_vector::operations::{
+        FromVectorElementList, GetSparseVectorElementValue,
+    };
+    use crate::collections::sparse_vector::{SparseVector, VectorElementList};
+    use crate::operators::apply::{ApplyUnaryOperator, UnaryOperatorApplier};
+    use crate::operators::binary_operator::{Assignment, First};
+    use crate::operators::mask::SelectEntireVector;
+    use crate::operators::options::OperatorOptions;
+
+    struct ClampToUnitInterval;
+
+    impl UnaryOperatorFunction<f64, f64> for ClampToUnitInterval {
+        fn product(argument: &f64) -> f64 {
+            argument.clamp(0.0, 1.0)
+        }
+    }
+
+    struct IsNegative;
+
+    impl UnaryOperatorFunction<i32, bool> for IsNegative {
+        fn product(argument: &i32) -> bool {
+            *argument < 0
+        }
+    }
+
+    #[test]
+    fn apply_custom_unary_operator() {
+        let context = Context::init_default().unwrap();
+
+        let element_list = VectorElementList::<f64>::from_element_vector(vec![
+            (0, -0.5).into(),
+            (1, 0.25).into(),
+            (3, 7.0).into(),
+        ]);
+        let vector = SparseVector::<f64>::from_element_list(
+            context.clone(),
+            4,
+            element_list,
+            &First::<f64>::new(),
+        )
+        .unwrap();
+        let mut product = SparseVector::<f64>::new(context.clone(), 4).unwrap();
+
+        let clamp = CustomUnaryOperator::<f64>::new(context.clone(), ClampToUnitInterval).unwrap();
+        UnaryOperatorApplier::new()
+            .apply_to_vector(
+                &clamp,
+                &vector,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireVector::new(context),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.element_value(&0).unwrap(), Some(0.0));
+        assert_eq!(product.element_value(&1).unwrap(), Some(0.25));
+        assert_eq!(product.element_value(&2).unwrap(), None);
+        assert_eq!(product.element_value(&3).unwrap(), Some(1.0));
+    }
+
+    #[test]
+    fn custom_unary_operator_with_different_product_type() {
+        let context = Context::init_default().unwrap();
+
+        let element_list =
+            VectorElementList::<i32>::from_element_vector(vec![(0, -2).into(), (1, 3).into()]);
+        let vector = SparseVector::<i32>::from_element_list(
+            context.clone(),
+            2,
+            element_list,
+            &First::<i32>::new(),
+        )
+        .unwrap();
+        let mut product = SparseVector::<bool>::new(context.clone(), 2).unwrap();
+
+        let operator = CustomUnaryOperator::<i32, bool>::new(context.clone(), IsNegative).unwrap();
+        UnaryOperatorApplier::new()
+            .apply_to_vector(
+                &operator,
+                &vector,
+                &Assignment::new(),
+                &mut product,
+                &SelectEntireVector::new(context),
+                &OperatorOptions::new_default(),
+            )
+            .unwrap();
+
+        assert_eq!(product.element_value(&0).unwrap(), Some(true));
+        assert_eq!(product.element_value(&1).unwrap(), Some(false));
+    }
+}
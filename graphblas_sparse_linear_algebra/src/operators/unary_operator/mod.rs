@@ -1,3 +1,5 @@
+mod custom_unary_operator;
 mod unary_operators;
 
+pub use custom_unary_operator::*;
 pub use unary_operators::*;
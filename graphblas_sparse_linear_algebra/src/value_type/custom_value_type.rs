@@ -1,157 +1,353 @@
-use std::marker::PhantomData;
-use std::mem::MaybeUninit;
-use std::sync::Arc;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::mem::{size_of, MaybeUninit};
+use std::ptr;
+use std::sync::{Mutex, MutexGuard, PoisonError};
 
-use crate::bindings_to_graphblas_implementation::{
-    GrB_BOOL, GrB_FP32, GrB_FP64, GrB_INT16, GrB_INT32, GrB_INT64, GrB_INT8, GrB_Index, GrB_Type,
-    GrB_Type_free, GrB_UINT16, GrB_UINT32, GrB_UINT64, GrB_UINT8, GxB_Type_size,
+use crate::context::{graphblas_result, Context};
+use crate::error::{
+    GraphblasError, GraphblasErrorType, SparseLinearAlgebraError, SystemError, SystemErrorType,
 };
-use crate::context::{CallGraphBlasContext, Context};
-use crate::error::{SparseLinearAlgebraError, SystemError, SystemErrorType};
-use crate::index::{ElementIndex, IndexConversion};
+use crate::graphblas_bindings::{GrB_Type, GrB_Type_free, GxB_MAX_NAME_LEN, GxB_Type_new};
 
+use super::utilities_to_implement_traits_for_all_value_types::implement_macro_for_all_value_types_except_bool;
 use super::ValueType;
 
-pub(crate) trait CustomValueType: ValueType {
-    type Type;
-
-    // TODO: what to do when the same type is registered multiple times?
-    fn register(
-        context: Arc<Context>,
-    ) -> Result<Arc<RegisteredCustomValueType<Self::Type>>, SparseLinearAlgebraError>;
+/// A user-defined value type, stored by GraphBLAS as an opaque block of bytes.
+///
+/// The type is registered with each context when it is first used in the context.
+/// Collections and operators of the same custom value type only work together if they share their context.
+/// Use #[derive(GraphblasValueType)] to implement this trait.
+///
+/// # Safety
+/// GraphBLAS copies values bitwise, so the type must be #[repr(C)] and must not own any resources,
+/// e.g. it must not contain references, pointers, or heap-allocated data.
+pub unsafe trait CustomValueType: Copy + Default + Send + Sync + 'static {
+    /// Identifies the type in serialized collections. Must be shorter than 128 bytes.
+    const GRAPHBLAS_TYPE_NAME: &'static str;
 }
 
-pub(crate) trait RegisteredValueType<T: ValueType>: ValueType {
-    fn to_graphblas_type(registered_value_type: RegisteredCustomValueType<T>) -> GrB_Type;
-}
+/// A type that #[derive(GraphblasValueType)] accepts as a field.
+///
+/// Implemented for the numeric primitives, arrays of fields, and derived GraphblasValueTypes.
+///
+/// ```compile_fail
+/// use graphblas_sparse_linear_algebra::value_type::GraphblasValueType;
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy, Default, GraphblasValueType)]
+/// struct Label {
+///     text: Option<&'static str>,
+/// }
+/// ```
+///
+/// # Safety
+/// Every bit pattern of the size of the type must be a valid value,
+/// and the type must not own any resources, e.g. it must not be or contain references, pointers, or heap-allocated data.
+pub unsafe trait CustomValueTypeField: Copy + 'static {}
 
-pub(crate) struct RegisteredCustomValueType<T> {
-    context: Arc<Context>,
-    graphblas_type: GrB_Type,
-    _rust_type: PhantomData<T>,
+macro_rules! implement_custom_value_type_field {
+    ($value_type:ty) => {
+        unsafe impl CustomValueTypeField for $value_type {}
+    };
 }
 
-impl<T> Drop for RegisteredCustomValueType<T> {
-    fn drop(&mut self) {
-        let context = self.context.clone();
-        let _ = context.call(
-            || unsafe { GrB_Type_free(&mut self.graphblas_type.clone()) },
-            &self.graphblas_type,
-        );
+implement_macro_for_all_value_types_except_bool!(implement_custom_value_type_field);
+
+unsafe impl<T: CustomValueTypeField, const N: usize> CustomValueTypeField for [T; N] {}
+
+impl<T: CustomValueType> ValueType for T {
+    /// Registers the type with the context on first use
+    fn graphblas_type_in_context(context: &Context) -> Result<GrB_Type, SparseLinearAlgebraError> {
+        graphblas_type_of_custom_value_type::<T>(context)
     }
 }
 
-impl<T> RegisteredCustomValueType<T> {
-    pub fn new(context: Arc<Context>, graphblas_type: GrB_Type) -> Self {
-        RegisteredCustomValueType {
-            context,
-            graphblas_type,
-            _rust_type: PhantomData,
-        }
-    }
+// Custom value types are registered once per context, and are freed when the context is dropped.
+// Collections and operators hold their context, so a registered type outlives the GraphBLAS objects that use it.
+// Raw pointers are not Send, hence the GrB_Type is stored as usize.
+#[derive(Debug, Default)]
+pub(crate) struct RegisteredCustomValueTypes {
+    graphblas_types: Mutex<HashMap<TypeId, usize>>,
 }
 
-impl<T> RegisteredCustomValueType<T> {
-    pub fn to_graphblas_type(&self) -> GrB_Type {
-        self.graphblas_type.clone()
+impl RegisteredCustomValueTypes {
+    // The registry only holds addresses, so it remains consistent if a thread panicked while holding the lock
+    fn graphblas_types(&self) -> MutexGuard<'_, HashMap<TypeId, usize>> {
+        self.graphblas_types
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
     }
 
-    pub fn size_in_graphblas(&self) -> Result<ElementIndex, SparseLinearAlgebraError> {
-        let context = self.context.clone();
-
-        let mut size: MaybeUninit<GrB_Index> = MaybeUninit::uninit();
-
-        context.call(
-            || unsafe { GxB_Type_size(size.as_mut_ptr(), self.to_graphblas_type()) },
-            &self.to_graphblas_type(),
-        )?;
-
-        let size = unsafe { size.assume_init() };
-        Ok(ElementIndex::from_graphblas_index(size)?)
+    /// Must be called before GraphBLAS is finalized
+    pub(crate) fn free(&mut self) {
+        let graphblas_types = self
+            .graphblas_types
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        for (_, graphblas_type) in graphblas_types.drain() {
+            let mut graphblas_type = graphblas_type as GrB_Type;
+            let _ = unsafe { GrB_Type_free(&mut graphblas_type) };
+        }
     }
+}
 
-    pub fn context(&self) -> Arc<Context> {
-        self.context.clone()
-    }
+/// Registers a custom value type with the context, if it was not registered with the context yet.
+///
+/// Registration happens on first use otherwise.
+pub fn register_custom_value_type<T: CustomValueType>(
+    context: &Context,
+) -> Result<(), SparseLinearAlgebraError> {
+    graphblas_type_of_custom_value_type::<T>(context)?;
+    Ok(())
 }
 
-// TODO: consider to use a generic type like CustomType<T>(T). This should enable generic trait implementation for all custom types.
-// #[macro_export]
-macro_rules! implement_value_type_for_custom_type {
-    ($value_type: ty) => {
-        impl ValueType for $value_type {}
-
-        impl RegisteredValueType<$value_type> for $value_type {
-            fn to_graphblas_type(
-                registered_value_type: RegisteredCustomValueType<$value_type>,
-            ) -> GrB_Type {
-                registered_value_type.graphblas_type.clone()
-            }
+fn graphblas_type_of_custom_value_type<T: CustomValueType>(
+    context: &Context,
+) -> Result<GrB_Type, SparseLinearAlgebraError> {
+    let registered_custom_value_types = match context.registered_custom_value_types() {
+        Some(registered_custom_value_types) => registered_custom_value_types,
+        None => {
+            return Err(SystemError::new(
+                SystemErrorType::UninitialisedContext,
+                format!(
+                    "Unable to register custom value type {} with a context that is not ready",
+                    T::GRAPHBLAS_TYPE_NAME
+                ),
+                None,
+            )
+            .into())
         }
+    };
+    let mut graphblas_types = registered_custom_value_types.graphblas_types();
+    if let Some(graphblas_type) = graphblas_types.get(&TypeId::of::<T>()) {
+        return Ok(*graphblas_type as GrB_Type);
+    }
 
-        impl CustomValueType for $value_type {
-            type Type = $value_type;
-
-            fn register(
-                context: Arc<Context>,
-            ) -> Result<Arc<RegisteredCustomValueType<Self::Type>>, SparseLinearAlgebraError> {
-                let context = context.clone();
-                let mut graphblas_type: MaybeUninit<
-                    $crate::bindings_to_graphblas_implementation::GrB_Type,
-                > = MaybeUninit::uninit();
-                let size_of_self =
-                    IndexConversion::as_graphblas_index(std::mem::size_of::<$value_type>())?;
-
-                context.call_without_detailed_error_information(|| unsafe {
-                    $crate::bindings_to_graphblas_implementation::GrB_Type_new(
-                        graphblas_type.as_mut_ptr(),
-                        size_of_self,
-                    )
-                })?;
-
-                let graphblas_type = unsafe { graphblas_type.assume_init() };
-
-                Ok(Arc::new(
-                    $crate::value_type::RegisteredCustomValueType::new(
-                        context,
-                        graphblas_type,
-                    ),
-                ))
-            }
+    if T::GRAPHBLAS_TYPE_NAME.len() >= GxB_MAX_NAME_LEN as usize {
+        return Err(GraphblasError::new(
+            GraphblasErrorType::InvalidValue,
+            format!(
+                "The name of a custom value type must be shorter than {} bytes, found {}",
+                GxB_MAX_NAME_LEN,
+                T::GRAPHBLAS_TYPE_NAME
+            ),
+        )
+        .into());
+    }
+    let type_name = match CString::new(T::GRAPHBLAS_TYPE_NAME) {
+        Ok(type_name) => type_name,
+        Err(_) => {
+            return Err(GraphblasError::new(
+                GraphblasErrorType::InvalidValue,
+                format!(
+                    "The name of a custom value type must not contain a null character, found {:?}",
+                    T::GRAPHBLAS_TYPE_NAME
+                ),
+            )
+            .into())
         }
     };
+
+    let mut graphblas_type: MaybeUninit<GrB_Type> = MaybeUninit::uninit();
+    graphblas_result(
+        unsafe {
+            GxB_Type_new(
+                graphblas_type.as_mut_ptr(),
+                size_of::<T>(),
+                type_name.as_ptr(),
+                ptr::null(),
+            )
+        },
+        || {
+            format!(
+                "Unable to register custom value type {}",
+                T::GRAPHBLAS_TYPE_NAME
+            )
+        },
+    )?;
+    let graphblas_type = unsafe { graphblas_type.assume_init() };
+
+    graphblas_types.insert(TypeId::of::<T>(), graphblas_type as usize);
+    Ok(graphblas_type)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::context::Mode;
+    use crate::collections::sparse_matrix::operations::{
+        DeserializeSparseMatrix, GetSparseMatrixElementList, GetSparseMatrixElementValue,
+        SerializeSuitesparseGraphblasSparseMatrix, SetSparseMatrixElement,
+    };
+    use crate::collections::sparse_matrix::{GetGraphblasSparseMatrix, Size, SparseMatrix};
+    use crate::collections::sparse_scalar::{GetScalarValue, SparseScalar};
+    use crate::collections::sparse_vector::operations::{
+        GetSparseVectorElementList, GetSparseVectorElementValue, SetSparseVectorElement,
+    };
+    use crate::collections::sparse_vector::SparseVector;
+    use crate::collections::Collection;
+    use crate::collections::{LZ4HighCompressionLevel, LZ4HighCompressionSerializer};
+    use crate::context::CallGraphBlasContext;
+    use crate::context::Context;
+    use crate::graphblas_bindings::GxB_Type_size;
+    use crate::value_type::GraphblasValueType;
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, GraphblasValueType)]
+    #[graphblas_value_type(crate = "crate")]
+    struct Edge {
+        weight: f64,
+        timestamp: u64,
+        label: u8,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, GraphblasValueType)]
+    #[graphblas_value_type(name = "example_label", crate = "crate")]
+    struct Label {
+        text: [u8; 4],
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    struct TypeWithInvalidName {}
+
+    unsafe impl CustomValueType for TypeWithInvalidName {
+        const GRAPHBLAS_TYPE_NAME: &'static str = "invalid\0name";
+    }
 
     #[test]
-    fn create_new_custom_type() {
-        let context = Context::init_ready(Mode::NonBlocking).unwrap();
-
-        #[repr(C)]
-        #[derive(Clone, Debug, PartialEq, PartialOrd)]
-        struct CustomType {
-            value: String,
-            another_value: u128,
-        }
+    fn register_custom_value_type_twice() {
+        let context = Context::init_default().unwrap();
+
+        register_custom_value_type::<Edge>(&context).unwrap();
+        let graphblas_type = Edge::graphblas_type_in_context(&context).unwrap();
+        register_custom_value_type::<Edge>(&context).unwrap();
+        assert_eq!(
+            graphblas_type,
+            Edge::graphblas_type_in_context(&context).unwrap()
+        );
+
+        let mut size: MaybeUninit<usize> = MaybeUninit::uninit();
+        context
+            .call(
+                || unsafe { GxB_Type_size(size.as_mut_ptr(), graphblas_type) },
+                &graphblas_type,
+            )
+            .unwrap();
+        assert_eq!(unsafe { size.assume_init() }, size_of::<Edge>());
+    }
+
+    #[test]
+    fn register_custom_value_type_per_context() {
+        let context = Context::init_default().unwrap();
+        let other_context = Context::init_default().unwrap();
+
+        let graphblas_type = Edge::graphblas_type_in_context(&context).unwrap();
+        assert_ne!(
+            graphblas_type,
+            Edge::graphblas_type_in_context(&other_context).unwrap()
+        );
+
+        let edge = Edge {
+            weight: 2.0,
+            timestamp: 1,
+            label: 0,
+        };
+        let mut vector = SparseVector::<Edge>::new(context.clone(), 2).unwrap();
+        drop(other_context);
+        vector.set_value(0, edge).unwrap();
+        assert_eq!(vector.element_value(&0).unwrap(), Some(edge));
+        assert_eq!(
+            graphblas_type,
+            Edge::graphblas_type_in_context(&context).unwrap()
+        );
+    }
+
+    #[test]
+    fn register_custom_value_type_on_first_use() {
+        let context = Context::init_default().unwrap();
+        assert_eq!(Label::GRAPHBLAS_TYPE_NAME, "example_label");
+        assert_eq!(Edge::GRAPHBLAS_TYPE_NAME, "Edge");
+
+        let label = Label { text: *b"road" };
+        let mut vector = SparseVector::<Label>::new(context, 2).unwrap();
+        vector.set_value(1, label).unwrap();
+
+        assert_eq!(vector.element_value(&0).unwrap(), None);
+        assert_eq!(vector.element_value(&1).unwrap(), Some(label));
+    }
+
+    #[test]
+    fn register_custom_value_type_with_invalid_name() {
+        let context = Context::init_default().unwrap();
+
+        assert!(register_custom_value_type::<TypeWithInvalidName>(&context).is_err());
+        assert!(SparseVector::<TypeWithInvalidName>::new(context, 2).is_err());
+    }
+
+    #[test]
+    fn set_and_get_elements_of_custom_value_type() {
+        let context = Context::init_default().unwrap();
+        register_custom_value_type::<Edge>(&context).unwrap();
+
+        let edge = Edge {
+            weight: 0.5,
+            timestamp: 1_700_000_000,
+            label: 3,
+        };
 
-        implement_value_type_for_custom_type!(CustomType);
+        let mut matrix = SparseMatrix::<Edge>::new(context.clone(), Size::new(3, 3)).unwrap();
+        matrix.set_value(1, 2, edge).unwrap();
+        assert_eq!(matrix.element_value(&1, &2).unwrap(), Some(edge));
+        assert_eq!(matrix.element_value(&0, &0).unwrap(), None);
 
-        let custom_type = CustomType::register(context.clone()).unwrap();
-        let expected_size = std::mem::size_of::<CustomType>();
-        assert_eq!(expected_size, custom_type.size_in_graphblas().unwrap());
+        let element_list = matrix.element_list().unwrap();
+        assert_eq!(element_list.row_indices_ref(), &[1]);
+        assert_eq!(element_list.values_ref(), &[edge]);
+
+        let mut vector = SparseVector::<Edge>::new(context.clone(), 4).unwrap();
+        vector.set_value(3, edge).unwrap();
+        assert_eq!(vector.number_of_stored_elements().unwrap(), 1);
+        assert_eq!(vector.element_value(&3).unwrap(), Some(edge));
+        assert_eq!(vector.element_list().unwrap().indices_ref(), &[3]);
+
+        let scalar = SparseScalar::<Edge>::from_value(context, edge).unwrap();
+        assert_eq!(scalar.get_value().unwrap(), Some(edge));
     }
 
-    // #[test]
-    // fn create_new_type() {
-    //     let context = Context::init_ready(Mode::NonBlocking).unwrap();
+    #[test]
+    fn serialize_and_deserialize_custom_value_type() {
+        let context = Context::init_default().unwrap();
+        register_custom_value_type::<Edge>(&context).unwrap();
+
+        let edge = Edge {
+            weight: -1.25,
+            timestamp: 42,
+            label: 7,
+        };
+        let mut matrix = SparseMatrix::<Edge>::new(context.clone(), Size::new(2, 5)).unwrap();
+        matrix.set_value(1, 4, edge).unwrap();
 
-    //     let built_in_type = bool::register(context.to_owned()).unwrap();
-    //     let expected_size = mem::size_of::<bool>();
-    //     assert_eq!(expected_size, built_in_type.size_in_graphblas().unwrap());
-    // }
+        let serializer =
+            LZ4HighCompressionSerializer::new(context.clone(), LZ4HighCompressionLevel::DEFAULT)
+                .unwrap();
+        let serialized_matrix = unsafe {
+            serializer
+                .serialize_suitesparse_grapblas_sparse_matrix(matrix.graphblas_matrix())
+                .unwrap()
+        };
+
+        let deserialized_matrix = unsafe {
+            SparseMatrix::<Edge>::deserialize(context.clone(), serialized_matrix).unwrap()
+        };
+        assert_eq!(
+            deserialized_matrix.element_value(&1, &4).unwrap(),
+            Some(edge)
+        );
+
+        assert!(unsafe { SparseMatrix::<f64>::deserialize(context, serialized_matrix) }.is_err());
+    }
 }
@@ -501,6 +501,19 @@ macro_rules! implement_macro_with_1_type_trait_and_typed_graphblas_function_for_
 }
 pub(crate) use implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_integer_value_types;
 
+macro_rules! implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers {
+    ($macro_identifier:ident, $trait:ty, $graphblas_identifier:ident) => {
+        paste::paste! {
+            $macro_identifier!($trait, [<$graphblas_identifier _UINT8>], u8);
+            $macro_identifier!($trait, [<$graphblas_identifier _UINT16>], u16);
+            $macro_identifier!($trait, [<$graphblas_identifier _UINT32>], u32);
+            $macro_identifier!($trait, [<$graphblas_identifier _UINT64>], u64);
+            graphblas_sparse_linear_algebra_proc_macros::implement_macro_with_1_type_trait_and_typed_graphblas_function_for_usize!($macro_identifier, $trait, $graphblas_identifier);
+        }
+    };
+}
+pub(crate) use implement_macro_with_1_type_trait_and_typed_graphblas_function_for_unsigned_integers;
+
 macro_rules! implement_macro_with_1_type_trait_and_typed_graphblas_function_for_all_graphblas_index_integer_value_types {
     ($macro_identifier:ident, $trait:ty, $graphblas_identifier:ident) => {
         paste::paste! {
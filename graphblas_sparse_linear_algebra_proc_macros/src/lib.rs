@@ -1782,6 +1782,152 @@ pub fn implement_type_conversion_macro_for_usize(input: TokenStream) -> TokenStr
     TokenStream::from(expanded)
 }
 
+/// Implements CustomValueType, which makes the struct usable as a value type in GraphBLAS collections.
+///
+/// The struct must be #[repr(C)] and Copy, and must not have generic parameters.
+/// Every field must implement CustomValueTypeField, i.e. be a numeric primitive, an array of those, or a derived GraphblasValueType.
+/// The type is registered with GraphBLAS on first use, once per process.
+/// The name of the struct identifies the type in serialized collections,
+/// set #[graphblas_value_type(name = "...")] to use another name.
+/// Set #[graphblas_value_type(crate = "...")] when graphblas_sparse_linear_algebra is available under another path.
+#[proc_macro_derive(GraphblasValueType, attributes(graphblas_value_type))]
+pub fn derive_graphblas_value_type(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match implement_custom_value_type(&input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(error) => TokenStream::from(error.to_compile_error()),
+    }
+}
+
+fn implement_custom_value_type(
+    input: &syn::DeriveInput,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let identifier = &input.ident;
+
+    let fields = match &input.data {
+        syn::Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                identifier,
+                "GraphblasValueType can only be derived for a struct",
+            ))
+        }
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "GraphblasValueType cannot be derived for a struct with generic parameters",
+        ));
+    }
+    if !is_repr_c(&input.attrs)? {
+        return Err(syn::Error::new_spanned(
+            identifier,
+            "GraphblasValueType requires the struct to be #[repr(C)]",
+        ));
+    }
+
+    let options = graphblas_value_type_options(&input.attrs)?;
+    let type_name = match options.type_name {
+        Some(type_name) => type_name,
+        None => syn::LitStr::new(&identifier.to_string(), identifier.span()),
+    };
+    // A proc-macro cannot use $crate, the crate attribute allows graphblas_sparse_linear_algebra to derive for its own types
+    let crate_path = match options.crate_path {
+        Some(crate_path) => crate_path,
+        None => syn::parse_quote!(::graphblas_sparse_linear_algebra),
+    };
+    let field_types = fields.iter().map(|field| &field.ty);
+
+    Ok(quote! {
+        unsafe impl #crate_path::value_type::CustomValueType for #identifier {
+            const GRAPHBLAS_TYPE_NAME: &'static str = #type_name;
+        }
+
+        unsafe impl #crate_path::value_type::CustomValueTypeField for #identifier {}
+
+        const _: fn() = || {
+            fn graphblas_value_type_must_implement_copy<T: ::core::marker::Copy>() {}
+            graphblas_value_type_must_implement_copy::<#identifier>();
+
+            fn graphblas_value_type_field_must_implement_custom_value_type_field<
+                T: #crate_path::value_type::CustomValueTypeField,
+            >() {
+            }
+            #(graphblas_value_type_field_must_implement_custom_value_type_field::<#field_types>();)*
+        };
+    })
+}
+
+fn is_repr_c(attributes: &[syn::Attribute]) -> Result<bool, syn::Error> {
+    for attribute in attributes
+        .iter()
+        .filter(|attribute| attribute.path.is_ident("repr"))
+    {
+        if let syn::Meta::List(representations) = attribute.parse_meta()? {
+            for representation in representations.nested.iter() {
+                if let syn::NestedMeta::Meta(syn::Meta::Path(path)) = representation {
+                    if path.is_ident("C") {
+                        return Ok(true);
+                    }
+                }
+            }
+        }
+    }
+    Ok(false)
+}
+
+#[derive(Default)]
+struct GraphblasValueTypeOptions {
+    type_name: Option<syn::LitStr>,
+    crate_path: Option<syn::Path>,
+}
+
+fn graphblas_value_type_options(
+    attributes: &[syn::Attribute],
+) -> Result<GraphblasValueTypeOptions, syn::Error> {
+    let mut value_type_options = GraphblasValueTypeOptions::default();
+    for attribute in attributes
+        .iter()
+        .filter(|attribute| attribute.path.is_ident("graphblas_value_type"))
+    {
+        let options = match attribute.parse_meta()? {
+            syn::Meta::List(options) => options,
+            other => {
+                return Err(syn::Error::new_spanned(
+                    other,
+                    "expected #[graphblas_value_type(name = \"...\")]",
+                ))
+            }
+        };
+        for option in options.nested.iter() {
+            match option {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    lit: syn::Lit::Str(name),
+                    ..
+                })) if path.is_ident("name") => {
+                    value_type_options.type_name = Some(name.to_owned());
+                }
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    lit: syn::Lit::Str(crate_path),
+                    ..
+                })) if path.is_ident("crate") => {
+                    value_type_options.crate_path = Some(crate_path.parse()?);
+                }
+                other => {
+                    return Err(syn::Error::new_spanned(
+                        other,
+                        "expected name = \"...\" or crate = \"...\"",
+                    ))
+                }
+            }
+        }
+    }
+    Ok(value_type_options)
+}
+
 // NOTE: a proc-macro crate can only export proc-macros!
 // https://blog.jetbrains.com/rust/2022/03/18/procedural-macros-under-the-hood-part-i/
 // test by "RUSTFLAGS="-Z macro-backtrace" cargo +nightly test"